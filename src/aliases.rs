@@ -29,6 +29,18 @@ pub type HOOKPROC =
 		lParam: isize,
 	) -> isize;
 
+/// Type alias to
+/// [`PFTASKDIALOGCALLBACK`](https://docs.microsoft.com/en-us/windows/win32/api/commctrl/nc-commctrl-pftaskdialogcallback)
+/// callback function.
+pub type PFTASKDIALOGCALLBACK =
+	extern "system" fn(
+		hWnd: HWND,
+		msg: co::TDN,
+		wParam: usize,
+		lParam: isize,
+		lpRefData: isize,
+	) -> i32;
+
 /// Type alias to
 /// [`TIMERPROC`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nc-winuser-timerproc)
 /// callback function.
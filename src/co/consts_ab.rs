@@ -20,6 +20,37 @@ const_type! { ACCESS_RIGHTS, u32,
 	SYNCHRONIZE, 0x00100000
 }
 
+const_type_wm! { ACM,
+	/// Animation control
+	/// [messages](https://docs.microsoft.com/en-us/windows/win32/controls/bumper-animation-control-reference-messages)
+	/// (`u32`), convertible to [`WM`](crate::co::WM).
+	->
+	OPEN, WM::USER.0 + 103
+	PLAY, WM::USER.0 + 101
+	STOP, WM::USER.0 + 102
+	ISPLAYING, WM::USER.0 + 104
+}
+
+const_type_cmd! { ACN,
+	/// Animation control `WM_COMMAND`
+	/// [notifications](https://docs.microsoft.com/en-us/windows/win32/controls/bumper-animation-control-reference-notifications)
+	/// (`u16`), convertible to [`CMD`](crate::co::CMD).
+	->
+	START, 1
+	STOP, 2
+}
+
+const_type_ws! { ACS,
+	/// Animation control
+	/// [styles](https://docs.microsoft.com/en-us/windows/win32/controls/animation-control-styles)
+	/// (`u32`), convertible to [`WS`](crate::co::WS).
+	->
+	CENTER, 0x0001
+	TRANSPARENT, 0x0002
+	AUTOPLAY, 0x0004
+	TIMER, 0x0008
+}
+
 const_type! { ADRF, u32,
 	/// [`NMTVASYNCDRAW`](crate::NMTVASYNCDRAW) `dwRetFlags` (`u32`). Don't seem
 	/// to be defined anywhere, unconfirmed values.
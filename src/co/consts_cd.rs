@@ -123,6 +123,20 @@ const_type_ws! { CBS,
 	LOWERCASE, 0x4000
 }
 
+const_type! { CC, u32,
+	/// [`CHOOSECOLOR`](crate::CHOOSECOLOR) `Flags` (`u32`).
+	->
+	RGBINIT, 0x00000001
+	FULLOPEN, 0x00000002
+	PREVENTFULLOPEN, 0x00000004
+	SHOWHELP, 0x00000008
+	ENABLEHOOK, 0x00000010
+	ENABLETEMPLATE, 0x00000020
+	ENABLETEMPLATEHANDLE, 0x00000040
+	SOLIDCOLOR, 0x00000080
+	ANYCOLOR, 0x00000100
+}
+
 const_type_wm! { CCM,
 	/// Generic common controls
 	/// [messages](https://docs.microsoft.com/en-us/windows/win32/controls/common-controls-intro)
@@ -197,6 +211,31 @@ impl From<CDRF> for isize {
 	}
 }
 
+const_type! { CF, u16,
+	/// Standard
+	/// [clipboard formats](https://docs.microsoft.com/en-us/windows/win32/dataxchg/standard-clipboard-formats)
+	/// (`u16`). Custom formats are returned by
+	/// [`RegisterClipboardFormat`](crate::RegisterClipboardFormat).
+	->
+	TEXT, 1
+	BITMAP, 2
+	METAFILEPICT, 3
+	SYLK, 4
+	DIF, 5
+	TIFF, 6
+	OEMTEXT, 7
+	DIB, 8
+	PALETTE, 9
+	PENDATA, 10
+	RIFF, 11
+	WAVE, 12
+	UNICODETEXT, 13
+	ENHMETAFILE, 14
+	HDROP, 15
+	LOCALE, 16
+	DIBV5, 17
+}
+
 const_type! { CHARSET, u8,
 	/// [`LOGFONT`](crate::LOGFONT) `lfCharset` (`u8`).
 	->
@@ -222,6 +261,38 @@ const_type! { CHARSET, u8,
 	BALTIC, 186
 }
 
+const_type! { CHOOSEFONTF, u32,
+	/// [`CHOOSEFONT`](crate::CHOOSEFONT) `Flags` (`u32`).
+	->
+	SCREENFONTS, 0x00000001
+	PRINTERFONTS, 0x00000002
+	BOTH, 0x00000003
+	SHOWHELP, 0x00000004
+	ENABLEHOOK, 0x00000008
+	ENABLETEMPLATE, 0x00000010
+	ENABLETEMPLATEHANDLE, 0x00000020
+	INITTOLOGFONTSTRUCT, 0x00000040
+	USESTYLE, 0x00000080
+	EFFECTS, 0x00000100
+	APPLY, 0x00000200
+	SCRIPTSONLY, 0x00000400
+	NOVECTORFONTS, 0x00000800
+	NOSIMULATIONS, 0x00001000
+	LIMITSIZE, 0x00002000
+	FIXEDPITCHONLY, 0x00004000
+	WYSIWYG, 0x00008000
+	FORCEFONTEXIST, 0x00010000
+	SCALABLEONLY, 0x00020000
+	TTONLY, 0x00040000
+	NOFACESEL, 0x00080000
+	NOSTYLESEL, 0x00100000
+	NOSIZESEL, 0x00200000
+	SELECTSCRIPT, 0x00400000
+	NOSCRIPTSEL, 0x00800000
+	NOVERTFONTS, 0x01000000
+	INACTIVEFONTS, 0x02000000
+}
+
 const_type! { CLIP, u8,
 	/// [`LOGFONT`](crate::LOGFONT) `lfClipPrecision` (`u8`).
 	->
@@ -405,10 +476,56 @@ const_type! { DLGID, u16,
 	IGNORE, 5
 	YES, 6
 	NO, 7
+	CLOSE, 8
+	HELP, 9
 	TRYAGAIN, 10
 	CONTINUE, 11
 }
 
+const_type! { DPI_AWARENESS_CONTEXT, isize,
+	/// [`DPI_AWARENESS_CONTEXT`](https://docs.microsoft.com/en-us/windows/win32/hidpi/dpi-awareness-context)
+	/// values (`isize`).
+	->
+	UNAWARE, -1
+	SYSTEM_AWARE, -2
+	PER_MONITOR_AWARE, -3
+	PER_MONITOR_AWARE_V2, -4
+	UNAWARE_GDISCALED, -5
+}
+
+const_type! { DROPEFFECT, u32,
+	/// [`DROPEFFECT`](https://docs.microsoft.com/en-us/windows/win32/com/dropeffect-constants)
+	/// constants (`u32`).
+	->
+	NONE, 0
+	COPY, 1
+	MOVE, 2
+	LINK, 4
+	SCROLL, 0x80000000
+}
+
+const_type_ws! { DS,
+	/// Dialog box
+	/// [styles](https://docs.microsoft.com/en-us/windows/win32/dlgbox/dialog-box-styles)
+	/// (`u32`), convertible to [`WS`](crate::co::WS).
+	->
+	ABSALIGN, 0x01
+	SYSMODAL, 0x02
+	_3DLOOK, 0x0004
+	FIXEDSYS, 0x0008
+	NOFAILCREATE, 0x0010
+	LOCALEDIT, 0x20
+	SETFONT, 0x40
+	MODALFRAME, 0x80
+	NOIDLEMSG, 0x100
+	SETFOREGROUND, 0x200
+	CONTROL, 0x0400
+	CENTER, 0x0800
+	CENTERMOUSE, 0x1000
+	CONTEXTHELP, 0x2000
+	SHELLFONT, Self::SETFONT.0 | Self::FIXEDSYS.0
+}
+
 const_type_wm! { DTM,
 	/// Date and time picker control
 	/// [messages](https://docs.microsoft.com/en-us/windows/win32/controls/bumper-date-and-time-picker-control-reference-messages)
@@ -466,3 +583,12 @@ const_type_ws! { DTS,
 	APPCANPARSE, 0x0010
 	RIGHTALIGN, 0x0020
 }
+
+const_type! { DVASPECT, u32,
+	/// [`FORMATETC`](crate::FORMATETC) `dwAspect` (`u32`).
+	->
+	CONTENT, 1
+	THUMBNAIL, 2
+	ICON, 4
+	DOCPRINT, 8
+}
@@ -85,6 +85,16 @@ const_type! { GDTR, u32,
 	MAX, 0x0002
 }
 
+const_type! { GMEM, u32,
+	/// [`GlobalAlloc`](crate::HGLOBAL::GlobalAlloc) `uFlags` (`u32`).
+	->
+	FIXED, 0x0000
+	MOVEABLE, 0x0002
+	ZEROINIT, 0x0040
+	GHND, 0x0042
+	GPTR, 0x0040
+}
+
 const_type! { GW, u32,
 	/// [`GetWindow`](crate::HWND::GetWindow) `uCmd` (`u32`).
 	->
@@ -254,6 +264,26 @@ const_type! { HICF, u32,
 	TOGGLEDROPDOWN, 0x00000100
 }
 
+const_type_wm! { HKM,
+	/// Hot key control
+	/// [messages](https://docs.microsoft.com/en-us/windows/win32/controls/bumper-hot-key-control-reference-messages)
+	/// (`u32`), convertible to [`WM`](crate::co::WM).
+	->
+	SETHOTKEY, WM::USER.0 + 1
+	GETHOTKEY, WM::USER.0 + 2
+	SETRULES, WM::USER.0 + 3
+}
+
+const_type! { HOTKEYF, u8,
+	/// [`HKM_GETHOTKEY`](crate::msg::hkm::GetHotKey) and
+	/// [`HKM_SETHOTKEY`](crate::msg::hkm::SetHotKey) modifier flags (`u8`).
+	->
+	SHIFT, 0x01
+	CONTROL, 0x02
+	ALT, 0x04
+	EXT, 0x08
+}
+
 const_type! { HWND_PLACE, isize,
 	/// [`SetWindowPos`](crate::HWND::SetWindowPos) `hWndInsertAfter` (`isize`).
 	->
@@ -1,4 +1,4 @@
-use crate::co::{CCM, WM, WS};
+use crate::co::{CCM, HOTKEYF, WM, WS};
 
 const_type! { MB, u32,
 	/// [`MessageBox`](crate::HWND::MessageBox) `uType` (`u32`).
@@ -163,6 +163,16 @@ const_type! { MF, u32,
 	MOUSESELECT, 0x00008000
 }
 
+const_type! { MDITILE, u32,
+	/// [`WM_MDICASCADE`](crate::msg::wm::MdiCascade) and
+	/// [`WM_MDITILE`](crate::msg::wm::MdiTile) `flags` (`u32`).
+	->
+	VERTICAL, 0x0000
+	HORIZONTAL, 0x0001
+	SKIPDISABLED, 0x0002
+	ZORDER, 0x0004
+}
+
 const_type! { MFS, u32,
 	/// [`MENUITEMINFO`](crate::MENUITEMINFO) `fState` (`u32`).
 	->
@@ -204,12 +214,15 @@ const_type! { MIM, u32,
 const_type! { MIIM, u32,
 	/// [`MENUITEMINFO`](crate::MENUITEMINFO) `fMask` (`u32`).
 	->
-	MAXHEIGHT, 0x00000001
-	BACKGROUND, 0x00000002
-	HELPID, 0x00000004
-	MENUDATA, 0x00000008
-	STYLE, 0x00000010
-	APPLYTOSUBMENUS, 0x80000000
+	STATE, 0x00000001
+	ID, 0x00000002
+	SUBMENU, 0x00000004
+	CHECKMARKS, 0x00000008
+	TYPE, 0x00000010
+	DATA, 0x00000020
+	STRING, 0x00000040
+	BITMAP, 0x00000080
+	FTYPE, 0x00000100
 }
 
 const_type! { MK, u16,
@@ -236,6 +249,46 @@ const_type! { MNS, u32,
 	CHECKORBMP, 0x04000000
 }
 
+const_type! { MOD, u16,
+	/// [`RegisterHotKey`](crate::HWND::RegisterHotKey) `fsModifiers` (`u16`).
+	->
+	ALT, 0x0001
+	CONTROL, 0x0002
+	SHIFT, 0x0004
+	WIN, 0x0008
+	NOREPEAT, 0x4000
+}
+
+impl From<HOTKEYF> for MOD {
+	/// Converts the modifiers of a [`HotKey`](crate::gui::HotKey) control,
+	/// whose Shift and Alt bits are swapped in relation to `MOD`. The
+	/// [`HOTKEYF::EXT`](crate::co::HOTKEYF::EXT) flag has no equivalent and is
+	/// ignored.
+	fn from(hkf: HOTKEYF) -> Self {
+		let mut modifiers = MOD(0);
+		if hkf.has(HOTKEYF::ALT) { modifiers |= MOD::ALT; }
+		if hkf.has(HOTKEYF::CONTROL) { modifiers |= MOD::CONTROL; }
+		if hkf.has(HOTKEYF::SHIFT) { modifiers |= MOD::SHIFT; }
+		modifiers
+	}
+}
+
+const_type! { MONITOR, u32,
+	/// [`MonitorFromRect`](crate::HMONITOR::MonitorFromRect) and
+	/// [`MonitorFromWindow`](crate::HMONITOR::MonitorFromWindow) `dwFlags`
+	/// (`u32`).
+	->
+	DEFAULTTONULL, 0x00000000
+	DEFAULTTOPRIMARY, 0x00000001
+	DEFAULTTONEAREST, 0x00000002
+}
+
+const_type! { MONITORINFOF, u32,
+	/// [`MONITORINFO`](crate::MONITORINFO) `dwFlags` (`u32`).
+	->
+	PRIMARY, 0x00000001
+}
+
 const_type! { MSGF, u8,
 	/// [`WM_ENTERIDLE`](crate::msg::wm::EnterIdle) reason (`u8`).
 	->
@@ -243,6 +296,64 @@ const_type! { MSGF, u8,
 	MENU, 2
 }
 
+const_type! { NIF, u32,
+	/// [`NOTIFYICONDATA`](crate::NOTIFYICONDATA) `uFlags` (`u32`).
+	->
+	MESSAGE, 0x00000001
+	ICON, 0x00000002
+	TIP, 0x00000004
+	STATE, 0x00000008
+	INFO, 0x00000010
+	GUID, 0x00000020
+	REALTIME, 0x00000040
+	SHOWTIP, 0x00000080
+}
+
+const_type! { NIIF, u32,
+	/// [`NOTIFYICONDATA`](crate::NOTIFYICONDATA) `dwInfoFlags` (`u32`).
+	->
+	NONE, 0x00000000
+	INFO, 0x00000001
+	WARNING, 0x00000002
+	ERROR, 0x00000003
+	USER, 0x00000004
+	NOSOUND, 0x00000010
+	LARGE_ICON, 0x00000020
+	RESPECT_QUIET_TIME, 0x00000080
+}
+
+const_type! { NIM, u32,
+	/// [`Shell_NotifyIcon`](crate::Shell_NotifyIcon) `dwMessage` (`u32`).
+	->
+	ADD, 0x00000000
+	MODIFY, 0x00000001
+	DELETE, 0x00000002
+	SETFOCUS, 0x00000003
+	SETVERSION, 0x00000004
+}
+
+const_type! { NIN, u16,
+	/// Notification icon events, sent in the low-order word of `lParam` of the
+	/// [`NOTIFYICONDATA`](crate::NOTIFYICONDATA) `uCallbackMessage` (`u16`).
+	->
+	SELECT, 0x0400
+	KEYSELECT, 0x0401
+	BALLOONSHOW, 0x0402
+	BALLOONHIDE, 0x0403
+	BALLOONTIMEOUT, 0x0404
+	BALLOONUSERCLICK, 0x0405
+	POPUPOPEN, 0x0406
+	POPUPCLOSE, 0x0407
+}
+
+const_type! { NIS, u32,
+	/// [`NOTIFYICONDATA`](crate::NOTIFYICONDATA) `dwState` and `dwStateMask`
+	/// (`u32`).
+	->
+	HIDDEN, 0x00000001
+	SHAREDICON, 0x00000002
+}
+
 const_type! { NM, i32,
 	/// [`WM_NOTIFY`](crate::msg::wm::Notify) notification codes (`i32`).
 	///
@@ -272,3 +383,18 @@ const_type! { NM, i32,
 const_type_priv_values! { NM,
 	FIRST, 0
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn mod_from_hotkeyf() {
+		assert_eq!(MOD::from(HOTKEYF::SHIFT), MOD::SHIFT);
+		assert_eq!(MOD::from(HOTKEYF::ALT), MOD::ALT);
+		assert_eq!(MOD::from(HOTKEYF::CONTROL), MOD::CONTROL);
+		assert_eq!(MOD::from(HOTKEYF::CONTROL | HOTKEYF::SHIFT | HOTKEYF::EXT),
+			MOD::CONTROL | MOD::SHIFT); // EXT dropped
+		assert_eq!(MOD::from(HOTKEYF(0)), MOD(0));
+	}
+}
@@ -1,4 +1,4 @@
-use crate::co::{FF, QS, WS};
+use crate::co::{FF, NM, QS, WM, WS};
 
 const_type! { OCR, u32,
 	/// [`SetSystemCursor`](crate::HCURSOR::SetSystemCursor) `id` (`u32`).
@@ -19,6 +19,32 @@ const_type! { OCR, u32,
 	WAIT, 32514
 }
 
+const_type! { OFN, u32,
+	/// [`OPENFILENAME`](crate::OPENFILENAME) `Flags` (`u32`).
+	->
+	READONLY, 0x00000001
+	OVERWRITEPROMPT, 0x00000002
+	HIDEREADONLY, 0x00000004
+	NOCHANGEDIR, 0x00000008
+	SHOWHELP, 0x00000010
+	NOVALIDATE, 0x00000100
+	ALLOWMULTISELECT, 0x00000200
+	EXTENSIONDIFFERENT, 0x00000400
+	PATHMUSTEXIST, 0x00000800
+	FILEMUSTEXIST, 0x00001000
+	CREATEPROMPT, 0x00002000
+	SHAREAWARE, 0x00004000
+	NOREADONLYRETURN, 0x00008000
+	NOTESTFILECREATE, 0x00010000
+	NONETWORKBUTTON, 0x00020000
+	EXPLORER, 0x00080000
+	NODEREFERENCELINKS, 0x00100000
+	LONGNAMES, 0x00200000
+	ENABLESIZING, 0x00800000
+	DONTADDTORECENT, 0x02000000
+	FORCESHOWHIDDEN, 0x10000000
+}
+
 const_type! { OUT_PRECIS, u8,
 	/// [`LOGFONT`](crate::LOGFONT) `lfOutPrecision` (`u8`).
 	->
@@ -46,6 +72,16 @@ const_type_ws! { PBS,
 	SMOOTHREVERSE, 0x10
 }
 
+const_type! { PBST, u32,
+	/// Progress bar
+	/// [states](https://docs.microsoft.com/en-us/windows/win32/controls/pbm-setstate)
+	/// (`u32`).
+	->
+	NORMAL, 0x0001
+	ERROR, 0x0002
+	PAUSED, 0x0003
+}
+
 const_type! { PITCH, u8,
 	/// [`LOGFONT`](crate::LOGFONT) `lfPitchAndFamily` (`u8`), used with
 	/// [`FF`](crate::co::FF).
@@ -74,3 +110,140 @@ const_type! { PM, u32,
 	QS_PAINT, QS::PAINT.0 << 16
 	QS_SENDMESSAGE, QS::SENDMESSAGE.0 << 16
 }
+
+const_type! { PSBTN, u32,
+	/// [`PSM_PRESSBUTTON`](crate::msg::psm::PressButton) `button` (`u32`).
+	->
+	BACK, 0
+	NEXT, 1
+	FINISH, 2
+	OK, 3
+	APPLYNOW, 4
+	CANCEL, 5
+	HELP, 6
+}
+
+const_type! { PSH, u32,
+	/// [`PROPSHEETHEADER`](crate::PROPSHEETHEADER) `dwFlags` (`u32`).
+	->
+	DEFAULT, 0x00000000
+	PROPTITLE, 0x00000001
+	USEHICON, 0x00000002
+	USEICONID, 0x00000004
+	PROPSHEETPAGE, 0x00000008
+	WIZARDHASFINISH, 0x00000010
+	WIZARD, 0x00000020
+	USEPSTARTPAGE, 0x00000040
+	NOAPPLYNOW, 0x00000080
+	USECALLBACK, 0x00000100
+	HASHELP, 0x00000200
+	MODELESS, 0x00000400
+	RTLREADING, 0x00000800
+	WIZARDCONTEXTHELP, 0x00001000
+	WIZARD97, 0x01000000
+	WATERMARK, 0x00008000
+	USEHBMWATERMARK, 0x00010000
+	USEHPLWATERMARK, 0x00020000
+	STRETCHWATERMARK, 0x00040000
+	HEADER, 0x00080000
+	USEHBMHEADER, 0x00100000
+	USEPAGELANG, 0x00200000
+	WIZARD_LITE, 0x00400000
+	NOCONTEXTHELP, 0x02000000
+	AEROWIZARD, 0x00004000
+	RESIZABLE, 0x04000000
+	HEADERBITMAP, 0x08000000
+	NOMARGIN, 0x10000000
+}
+
+const_type_wm! { PSM,
+	/// Property sheet
+	/// [messages](https://docs.microsoft.com/en-us/windows/win32/controls/bumper-property-sheets-reference-messages)
+	/// (`u32`), convertible to [`WM`](crate::co::WM).
+	->
+	SETCURSEL, WM::USER.0 + 101
+	REMOVEPAGE, WM::USER.0 + 102
+	ADDPAGE, WM::USER.0 + 103
+	CHANGED, WM::USER.0 + 104
+	RESTARTWINDOWS, WM::USER.0 + 105
+	REBOOTSYSTEM, WM::USER.0 + 106
+	CANCELTOCLOSE, WM::USER.0 + 107
+	QUERYSIBLINGS, WM::USER.0 + 108
+	UNCHANGED, WM::USER.0 + 109
+	APPLY, WM::USER.0 + 110
+	SETWIZBUTTONS, WM::USER.0 + 112
+	PRESSBUTTON, WM::USER.0 + 113
+	GETTABCONTROL, WM::USER.0 + 116
+	ISDIALOGMESSAGE, WM::USER.0 + 117
+	GETCURRENTPAGEHWND, WM::USER.0 + 118
+	SETTITLE, WM::USER.0 + 120
+	SETFINISHTEXT, WM::USER.0 + 121
+	SETHEADERTITLE, WM::USER.0 + 126
+	SETHEADERSUBTITLE, WM::USER.0 + 128
+	ENABLEWIZBUTTONS, WM::USER.0 + 139
+	SETBUTTONTEXT, WM::USER.0 + 140
+}
+
+const_type_nm! { PSN,
+	/// Property sheet `WM_NOTIFY`
+	/// [notifications](https://docs.microsoft.com/en-us/windows/win32/controls/bumper-property-sheets-reference-notifications)
+	/// (`i32`), convertible to [`NM`](crate::co::NM).
+	->
+	SETACTIVE, Self::FIRST.0 - 0
+	KILLACTIVE, Self::FIRST.0 - 1
+	APPLY, Self::FIRST.0 - 2
+	RESET, Self::FIRST.0 - 3
+	HELP, Self::FIRST.0 - 5
+	WIZBACK, Self::FIRST.0 - 6
+	WIZNEXT, Self::FIRST.0 - 7
+	WIZFINISH, Self::FIRST.0 - 8
+	QUERYCANCEL, Self::FIRST.0 - 9
+	GETOBJECT, Self::FIRST.0 - 10
+	TRANSLATEACCELERATOR, Self::FIRST.0 - 12
+	QUERYINITIALFOCUS, Self::FIRST.0 - 13
+}
+const_type_priv_values! { PSN,
+	FIRST, -200
+}
+
+const_type! { PSNRET, isize,
+	/// [`PSN_APPLY`](https://docs.microsoft.com/en-us/windows/win32/controls/psn-apply)
+	/// and
+	/// [`PSN_KILLACTIVE`](https://docs.microsoft.com/en-us/windows/win32/controls/psn-killactive)
+	/// return values (`isize`).
+	->
+	NOERROR, 0
+	INVALID, 1
+	INVALID_NOCHANGEPAGE, 2
+	MESSAGEHANDLED, 3
+}
+
+const_type! { PSP, u32,
+	/// [`PROPSHEETPAGE`](crate::PROPSHEETPAGE) `dwFlags` (`u32`).
+	->
+	DEFAULT, 0x00000000
+	DLGINDIRECT, 0x00000001
+	USEHICON, 0x00000002
+	USEICONID, 0x00000004
+	USETITLE, 0x00000008
+	RTLREADING, 0x00000010
+	HASHELP, 0x00000020
+	USEREFPARENT, 0x00000040
+	USECALLBACK, 0x00000080
+	PREMATURE, 0x00000400
+	HIDEHEADER, 0x00000800
+	USEHEADERTITLE, 0x00001000
+	USEHEADERSUBTITLE, 0x00002000
+	USEFUSIONCONTEXT, 0x00004000
+}
+
+const_type! { PSWIZB, u32,
+	/// [`PSM_SETWIZBUTTONS`](crate::msg::psm::SetWizButtons) `buttons`
+	/// (`u32`).
+	->
+	BACK, 0x00000001
+	NEXT, 0x00000002
+	FINISH, 0x00000004
+	DISABLEDFINISH, 0x00000008
+	CANCEL, 0x00000010
+}
@@ -907,6 +907,101 @@ const_type_wsex! { TCS_EX,
 	REGISTERDROP, 0x00000002
 }
 
+const_type! { TDCBF, u32,
+	/// [`TASKDIALOGCONFIG`](crate::TASKDIALOGCONFIG) `dwCommonButtons` (`u32`).
+	->
+	OK, 0x0001
+	YES, 0x0002
+	NO, 0x0004
+	CANCEL, 0x0008
+	RETRY, 0x0010
+	CLOSE, 0x0020
+}
+
+const_type! { TDE, u32,
+	/// [`TDM_SET_ELEMENT_TEXT`](https://docs.microsoft.com/en-us/windows/win32/controls/tdm-set-element-text)
+	/// elements (`u32`).
+	->
+	CONTENT, 0
+	EXPANDED_INFORMATION, 1
+	FOOTER, 2
+	MAIN_INSTRUCTION, 3
+}
+
+const_type! { TDF, u32,
+	/// [`TASKDIALOGCONFIG`](crate::TASKDIALOGCONFIG) `dwFlags` (`u32`).
+	->
+	ENABLE_HYPERLINKS, 0x0001
+	USE_HICON_MAIN, 0x0002
+	USE_HICON_FOOTER, 0x0004
+	ALLOW_DIALOG_CANCELLATION, 0x0008
+	USE_COMMAND_LINKS, 0x0010
+	USE_COMMAND_LINKS_NO_ICON, 0x0020
+	EXPAND_FOOTER_AREA, 0x0040
+	EXPANDED_BY_DEFAULT, 0x0080
+	VERIFICATION_FLAG_CHECKED, 0x0100
+	SHOW_PROGRESS_BAR, 0x0200
+	SHOW_MARQUEE_PROGRESS_BAR, 0x0400
+	CALLBACK_TIMER, 0x0800
+	POSITION_RELATIVE_TO_WINDOW, 0x1000
+	RTL_LAYOUT, 0x2000
+	NO_DEFAULT_RADIO_BUTTON, 0x4000
+	CAN_BE_MINIMIZED, 0x8000
+	NO_SET_FOREGROUND, 0x0001_0000
+	SIZE_TO_CONTENT, 0x0100_0000
+}
+
+const_type_wm! { TDM,
+	/// Task dialog
+	/// [messages](https://docs.microsoft.com/en-us/windows/win32/controls/bumper-task-dialogs-reference-messages)
+	/// (`u32`), convertible to [`WM`](crate::co::WM).
+	->
+	NAVIGATE_PAGE, WM::USER.0 + 101
+	CLICK_BUTTON, WM::USER.0 + 102
+	SET_MARQUEE_PROGRESS_BAR, WM::USER.0 + 103
+	SET_PROGRESS_BAR_STATE, WM::USER.0 + 104
+	SET_PROGRESS_BAR_RANGE, WM::USER.0 + 105
+	SET_PROGRESS_BAR_POS, WM::USER.0 + 106
+	SET_PROGRESS_BAR_MARQUEE, WM::USER.0 + 107
+	SET_ELEMENT_TEXT, WM::USER.0 + 108
+	CLICK_RADIO_BUTTON, WM::USER.0 + 110
+	ENABLE_BUTTON, WM::USER.0 + 111
+	ENABLE_RADIO_BUTTON, WM::USER.0 + 112
+	CLICK_VERIFICATION, WM::USER.0 + 113
+	UPDATE_ELEMENT_TEXT, WM::USER.0 + 114
+	SET_BUTTON_ELEVATION_REQUIRED_STATE, WM::USER.0 + 115
+	UPDATE_ICON, WM::USER.0 + 116
+}
+
+const_type! { TDN, u32,
+	/// Task dialog
+	/// [notifications](https://docs.microsoft.com/en-us/windows/win32/controls/bumper-task-dialogs-reference-notifications),
+	/// received in the [`PFTASKDIALOGCALLBACK`](crate::PFTASKDIALOGCALLBACK)
+	/// callback (`u32`).
+	->
+	CREATED, 0
+	NAVIGATED, 1
+	BUTTON_CLICKED, 2
+	HYPERLINK_CLICKED, 3
+	TIMER, 4
+	DESTROYED, 5
+	RADIO_BUTTON_CLICKED, 6
+	DIALOG_CONSTRUCTED, 7
+	VERIFICATION_CLICKED, 8
+	HELP, 9
+	EXPANDO_BUTTON_CLICKED, 10
+}
+
+const_type! { TD_ICON, u16,
+	/// [`TASKDIALOGCONFIG`](crate::TASKDIALOGCONFIG) `pszMainIcon` and
+	/// `pszFooterIcon` predefined icons (`u16`).
+	->
+	WARNING, 0xffff
+	ERROR, 0xfffe
+	INFORMATION, 0xfffd
+	SHIELD, 0xfffc
+}
+
 const_type! { TME, u32,
 	/// [`TrackMouseEvent`](crate::TrackMouseEvent) `dwFlags` (`u32`).
 	->
@@ -1070,3 +1165,17 @@ const_type_wsex! { TVS_EX,
 	DIMMEDCHECKBOXES, 0x0200
 	DRAWIMAGEASYNC, 0x0400
 }
+
+const_type! { TYMED, u32,
+	/// [`TYMED`](https://docs.microsoft.com/en-us/windows/win32/api/objidl/ne-objidl-tymed)
+	/// enumeration (`u32`).
+	->
+	NULL, 0
+	HGLOBAL, 1
+	FILE, 2
+	ISTREAM, 4
+	ISTORAGE, 8
+	GDI, 16
+	MFPICT, 32
+	ENHMF, 64
+}
@@ -32,7 +32,7 @@ const_type_no_debug_display! { ERROR, u32,
 	///
 	/// Will print:
 	///
-	/// ```
+	/// ```text
 	/// [0x001c 28] The printer is out of paper.
 	/// ```
 	->
@@ -2667,6 +2667,16 @@ const_type_pub_values! { ERROR, // first COM values
 	E_CHANGED_STATE, 0x8000000c
 	E_ILLEGAL_STATE_CHANGE, 0x8000000d
 	E_ILLEGAL_METHOD_CALL, 0x8000000e
+
+	DRAGDROP_S_DROP, 0x00040100
+	DRAGDROP_S_CANCEL, 0x00040101
+	DRAGDROP_S_USEDEFAULTCURSORS, 0x00040102
+	DRAGDROP_E_NOTREGISTERED, 0x80040100
+	DRAGDROP_E_ALREADYREGISTERED, 0x80040101
+	DRAGDROP_E_INVALIDHWND, 0x80040102
+	DV_E_FORMATETC, 0x80040064
+	DV_E_TYMED, 0x80040069
+	OLE_E_ADVISENOTSUPPORTED, 0x80040003
 }
 
 const_type_pub_values! { ERROR, // RPC_E
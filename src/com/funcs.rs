@@ -8,7 +8,7 @@ use crate::aliases::WinResult;
 use crate::co;
 use crate::com::{PPVtbl, Vtbl};
 use crate::ffi::ole32;
-use crate::structs::{CLSID, GUID};
+use crate::structs::{CLSID, GUID, STGMEDIUM};
 
 /// [`CoCreateInstance`](https://docs.microsoft.com/en-us/windows/win32/api/combaseapi/nf-combaseapi-cocreateinstance)
 /// function.
@@ -86,3 +86,31 @@ pub fn CoInitializeEx(dwCoInit: co::COINIT) -> WinResult<co::ERROR> {
 pub fn CoUninitialize() {
 	unsafe { ole32::CoUninitialize() }
 }
+
+/// [`OleInitialize`](https://docs.microsoft.com/en-us/windows/win32/api/ole2/nf-ole2-oleinitialize)
+/// function. Returns some error codes as success status.
+///
+/// Initializes COM as single-threaded apartment, which is required by
+/// clipboard and drag-and-drop operations.
+///
+/// **Note:** Must be paired with an [`OleUninitialize`](crate::OleUninitialize)
+/// call.
+pub fn OleInitialize() -> WinResult<co::ERROR> {
+	let err = co::ERROR(unsafe { ole32::OleInitialize(std::ptr::null_mut()) });
+	match err {
+		co::ERROR::S_OK | co::ERROR::S_FALSE => Ok(err),
+		err => Err(err),
+	}
+}
+
+/// [`OleUninitialize`](https://docs.microsoft.com/en-us/windows/win32/api/ole2/nf-ole2-oleuninitialize)
+/// function.
+pub fn OleUninitialize() {
+	unsafe { ole32::OleUninitialize() }
+}
+
+/// [`ReleaseStgMedium`](https://docs.microsoft.com/en-us/windows/win32/api/ole2/nf-ole2-releasestgmedium)
+/// function.
+pub fn ReleaseStgMedium(pmedium: &mut STGMEDIUM) {
+	unsafe { ole32::ReleaseStgMedium(pmedium as *mut _ as *mut _) }
+}
@@ -0,0 +1,89 @@
+#![allow(non_snake_case)]
+
+use crate::aliases::WinResult;
+use crate::co::ERROR;
+use crate::com::{IUnknown, IUnknownVtbl, PPVtbl, Vtbl};
+use crate::ffi::{PCVOID, PVOID};
+use crate::structs::{FORMATETC, IID, STGMEDIUM};
+
+/// [`IDataObject`](crate::IDataObject) virtual table.
+#[repr(C)]
+pub struct IDataObjectVtbl {
+	iUnknownVtbl: IUnknownVtbl,
+
+	GetData: fn(PPVtbl<Self>, PCVOID, PVOID) -> u32,
+	GetDataHere: fn(PPVtbl<Self>, PCVOID, PVOID) -> u32,
+	QueryGetData: fn(PPVtbl<Self>, PCVOID) -> u32,
+	GetCanonicalFormatEtc: fn(PPVtbl<Self>, PCVOID, PVOID) -> u32,
+	SetData: fn(PPVtbl<Self>, PCVOID, PVOID, i32) -> u32,
+	EnumFormatEtc: fn(PPVtbl<Self>, u32, *mut PVOID) -> u32,
+	DAdvise: fn(PPVtbl<Self>, PCVOID, u32, PVOID, *mut u32) -> u32,
+	DUnadvise: fn(PPVtbl<Self>, u32) -> u32,
+	EnumDAdvise: fn(PPVtbl<Self>, *mut PVOID) -> u32,
+}
+
+impl_iid!(IDataObjectVtbl, 0x0000010e, 0x0000, 0x0000, 0xc000, 0x000000000046);
+
+//------------------------------------------------------------------------------
+
+/// [`IDataObject`](https://docs.microsoft.com/en-us/windows/win32/api/objidl/nn-objidl-idataobject)
+/// COM interface.
+///
+/// Inherits from:
+/// * [`IUnknown`](crate::IUnknown).
+///
+/// Automatically calls [`IUnknown::Release`](crate::IUnknown::Release) when the
+/// object goes out of scope.
+pub struct IDataObject {
+	/// Methods of base interface [`IUnknown`](crate::IUnknown).
+	pub IUnknown: IUnknown,
+}
+
+impl From<PPVtbl<IDataObjectVtbl>> for IDataObject {
+	fn from(ppv: PPVtbl<IDataObjectVtbl>) -> Self {
+		Self {
+			IUnknown: IUnknown::from(ppv as PPVtbl<IUnknownVtbl>),
+		}
+	}
+}
+
+impl IDataObject {
+	/// [`IDataObject::GetData`](https://docs.microsoft.com/en-us/windows/win32/api/objidl/nf-objidl-idataobject-getdata)
+	/// method.
+	///
+	/// **Note:** The returned [`STGMEDIUM`](crate::STGMEDIUM) must be released
+	/// with [`ReleaseStgMedium`](crate::ReleaseStgMedium).
+	pub fn GetData(&self, formatetc: &FORMATETC) -> WinResult<STGMEDIUM> {
+		let mut medium = STGMEDIUM::default();
+		unsafe {
+			let ppv = self.IUnknown.ppv::<IDataObjectVtbl>();
+			into_result!(
+				((**ppv).GetData)(
+					ppv,
+					formatetc as *const _ as *const _,
+					&mut medium as *mut _ as *mut _,
+				)
+			).map(|_| medium)
+		}
+	}
+
+	/// [`IDataObject::QueryGetData`](https://docs.microsoft.com/en-us/windows/win32/api/objidl/nf-objidl-idataobject-querygetdata)
+	/// method.
+	///
+	/// Returns `false` if the data object cannot render the given format.
+	pub fn QueryGetData(&self, formatetc: &FORMATETC) -> WinResult<bool> {
+		let err = ERROR(
+			unsafe {
+				let ppv = self.IUnknown.ppv::<IDataObjectVtbl>();
+				((**ppv).QueryGetData)(ppv, formatetc as *const _ as *const _)
+			},
+		);
+		match err {
+			ERROR::S_OK => Ok(true),
+			ERROR::S_FALSE
+				| ERROR::DV_E_FORMATETC
+				| ERROR::DV_E_TYMED => Ok(false),
+			err => Err(err),
+		}
+	}
+}
@@ -9,9 +9,11 @@ mod macros;
 pub mod shell;
 
 mod funcs;
+mod idataobject;
 mod iunknown;
 mod vtbl;
 
 pub use funcs::*;
+pub use idataobject::{IDataObject, IDataObjectVtbl};
 pub use iunknown::{IUnknown, IUnknownVtbl};
 pub use vtbl::{PPVtbl, Vtbl};
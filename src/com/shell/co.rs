@@ -1,6 +1,51 @@
 //! [Shell](https://docs.microsoft.com/en-us/windows/win32/api/_shell/)
 //! COM interfaces constants.
 
+const_type! { FOS, u32,
+	/// [`_FILEOPENDIALOGOPTIONS`](https://docs.microsoft.com/en-us/windows/win32/api/shobjidl_core/ne-shobjidl_core-_fileopendialogoptions)
+	/// enumeration (`u32`).
+	->
+	OVERWRITEPROMPT, 0x2
+	STRICTFILETYPES, 0x4
+	NOCHANGEDIR, 0x8
+	PICKFOLDERS, 0x20
+	FORCEFILESYSTEM, 0x40
+	ALLNONSTORAGEITEMS, 0x80
+	NOVALIDATE, 0x100
+	ALLOWMULTISELECT, 0x200
+	PATHMUSTEXIST, 0x800
+	FILEMUSTEXIST, 0x1000
+	CREATEPROMPT, 0x2000
+	SHAREAWARE, 0x4000
+	NOREADONLYRETURN, 0x8000
+	NOTESTFILECREATE, 0x10000
+	HIDEMRUPLACES, 0x20000
+	HIDEPINNEDPLACES, 0x40000
+	NODEREFERENCELINKS, 0x100000
+	OKBUTTONNEEDSINTERACTION, 0x200000
+	DONTADDTORECENT, 0x2000000
+	FORCESHOWHIDDEN, 0x10000000
+	DEFAULTNOMINIMODE, 0x20000000
+	FORCEPREVIEWPANEON, 0x40000000
+	SUPPORTSTREAMABLEITEMS, 0x80000000
+}
+
+const_type! { SIGDN, u32,
+	/// [`SIGDN`](https://docs.microsoft.com/en-us/windows/win32/api/shobjidl_core/ne-shobjidl_core-sigdn)
+	/// enumeration (`u32`).
+	->
+	NORMALDISPLAY, 0
+	PARENTRELATIVEPARSING, 0x80018001
+	DESKTOPABSOLUTEPARSING, 0x80028000
+	PARENTRELATIVEEDITING, 0x80031001
+	DESKTOPABSOLUTEEDITING, 0x8004c000
+	FILESYSPATH, 0x80058000
+	URL, 0x80068000
+	PARENTRELATIVEFORADDRESSBAR, 0x8007c001
+	PARENTRELATIVE, 0x80080001
+	PARENTRELATIVEFORUI, 0x80094001
+}
+
 const_type! { TBPF, u32,
 	/// [`ITaskbarList3::SetProgressState`](crate::shell::ITaskbarList3::SetProgressState)
 	/// `tbpFlags`.
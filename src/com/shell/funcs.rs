@@ -0,0 +1,41 @@
+//! Shell free functions.
+
+#![allow(non_snake_case)]
+
+use crate::aliases::WinResult;
+use crate::co;
+use crate::com::{PPVtbl, Vtbl};
+use crate::com::shell::{IShellItem, IShellItemVtbl};
+use crate::ffi::shell32;
+use crate::structs::GUID;
+use crate::WString;
+
+/// [`SHCreateItemFromParsingName`](https://docs.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-shcreateitemfromparsingname)
+/// function.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use winsafe::shell;
+///
+/// let item = shell::SHCreateItemFromParsingName("C:\\Temp").unwrap();
+/// ```
+pub fn SHCreateItemFromParsingName(pszPath: &str) -> WinResult<IShellItem> {
+	let mut ppv: PPVtbl<IShellItemVtbl> = std::ptr::null_mut();
+
+	match co::ERROR(
+		unsafe {
+			shell32::SHCreateItemFromParsingName(
+				WString::from_str(pszPath).as_ptr(),
+				std::ptr::null_mut(),
+				IShellItemVtbl::IID().as_ref() as *const GUID as *const _,
+				&mut ppv
+					as *mut PPVtbl<IShellItemVtbl>
+					as *mut *mut _,
+			)
+		}
+	) {
+		co::ERROR::S_OK => Ok(IShellItem::from(ppv)),
+		err => Err(err),
+	}
+}
@@ -0,0 +1,239 @@
+#![allow(non_snake_case)]
+
+use crate::aliases::WinResult;
+use crate::co::ERROR;
+use crate::com::{PPVtbl, Vtbl};
+use crate::com::shell::{co, IModalWindow, IModalWindowVtbl, IShellItem, IShellItemVtbl};
+use crate::ffi::{ole32, PCSTR, PCVOID, PSTR, PVOID};
+use crate::structs::IID;
+use crate::WString;
+
+/// [`IFileDialog`](crate::shell::IFileDialog) virtual table.
+#[repr(C)]
+pub struct IFileDialogVtbl {
+	iModalWindowVtbl: IModalWindowVtbl,
+
+	SetFileTypes: fn(PPVtbl<Self>, u32, PCVOID) -> u32,
+	SetFileTypeIndex: fn(PPVtbl<Self>, u32) -> u32,
+	GetFileTypeIndex: fn(PPVtbl<Self>, *mut u32) -> u32,
+	Advise: fn(PPVtbl<Self>, PVOID, *mut u32) -> u32,
+	Unadvise: fn(PPVtbl<Self>, u32) -> u32,
+	SetOptions: fn(PPVtbl<Self>, u32) -> u32,
+	GetOptions: fn(PPVtbl<Self>, *mut u32) -> u32,
+	SetDefaultFolder: fn(PPVtbl<Self>, PPVtbl<IShellItemVtbl>) -> u32,
+	SetFolder: fn(PPVtbl<Self>, PPVtbl<IShellItemVtbl>) -> u32,
+	GetFolder: fn(PPVtbl<Self>, *mut PPVtbl<IShellItemVtbl>) -> u32,
+	GetCurrentSelection: fn(PPVtbl<Self>, *mut PPVtbl<IShellItemVtbl>) -> u32,
+	SetFileName: fn(PPVtbl<Self>, PCSTR) -> u32,
+	GetFileName: fn(PPVtbl<Self>, *mut PSTR) -> u32,
+	SetTitle: fn(PPVtbl<Self>, PCSTR) -> u32,
+	SetOkButtonLabel: fn(PPVtbl<Self>, PCSTR) -> u32,
+	SetFileNameLabel: fn(PPVtbl<Self>, PCSTR) -> u32,
+	GetResult: fn(PPVtbl<Self>, *mut PPVtbl<IShellItemVtbl>) -> u32,
+	AddPlace: fn(PPVtbl<Self>, PPVtbl<IShellItemVtbl>, u32) -> u32,
+	SetDefaultExtension: fn(PPVtbl<Self>, PCSTR) -> u32,
+	Close: fn(PPVtbl<Self>, u32) -> u32,
+	SetClientGuid: fn(PPVtbl<Self>, PCVOID) -> u32,
+	ClearClientData: fn(PPVtbl<Self>) -> u32,
+	SetFilter: fn(PPVtbl<Self>, PVOID) -> u32,
+}
+
+impl_iid!(IFileDialogVtbl, 0x42f85136, 0xdb7e, 0x439c, 0x85f1, 0xe4075d135fc8);
+
+/// [`COMDLG_FILTERSPEC`](https://docs.microsoft.com/en-us/windows/win32/api/shtypes/ns-shtypes-comdlg_filterspec)
+/// struct, used only internally.
+#[repr(C)]
+struct COMDLG_FILTERSPEC {
+	pszName: PCSTR,
+	pszSpec: PCSTR,
+}
+
+//------------------------------------------------------------------------------
+
+/// [`IFileDialog`](https://docs.microsoft.com/en-us/windows/win32/api/shobjidl_core/nn-shobjidl_core-ifiledialog)
+/// COM interface.
+///
+/// Inherits from:
+/// * [`IModalWindow`](crate::shell::IModalWindow);
+/// * [`IUnknown`](crate::IUnknown).
+///
+/// Automatically calls [`IUnknown::Release`](crate::IUnknown::Release) when the
+/// object goes out of scope.
+pub struct IFileDialog {
+	/// Methods of base interface
+	/// [`IModalWindow`](crate::shell::IModalWindow).
+	pub IModalWindow: IModalWindow,
+}
+
+impl From<PPVtbl<IFileDialogVtbl>> for IFileDialog {
+	fn from(ppv: PPVtbl<IFileDialogVtbl>) -> Self {
+		Self {
+			IModalWindow: IModalWindow::from(ppv as PPVtbl<IModalWindowVtbl>),
+		}
+	}
+}
+
+impl IFileDialog {
+	/// [`IFileDialog::GetFileName`](https://docs.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialog-getfilename)
+	/// method.
+	pub fn GetFileName(&self) -> WinResult<String> {
+		let mut pstr: PSTR = std::ptr::null_mut();
+		unsafe {
+			let ppv = self.IModalWindow.IUnknown.ppv::<IFileDialogVtbl>();
+			into_result!( ((**ppv).GetFileName)(ppv, &mut pstr) )
+				.map(|_| {
+					let name = WString::from_wchars_nullt(pstr).to_string();
+					ole32::CoTaskMemFree(pstr as PVOID);
+					name
+				})
+		}
+	}
+
+	/// [`IFileDialog::GetFileTypeIndex`](https://docs.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialog-getfiletypeindex)
+	/// method.
+	pub fn GetFileTypeIndex(&self) -> WinResult<u32> {
+		let mut index: u32 = 0;
+		unsafe {
+			let ppv = self.IModalWindow.IUnknown.ppv::<IFileDialogVtbl>();
+			into_result!( ((**ppv).GetFileTypeIndex)(ppv, &mut index) )
+				.map(|_| index)
+		}
+	}
+
+	/// [`IFileDialog::GetOptions`](https://docs.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialog-getoptions)
+	/// method.
+	pub fn GetOptions(&self) -> WinResult<co::FOS> {
+		let mut fos: u32 = 0;
+		unsafe {
+			let ppv = self.IModalWindow.IUnknown.ppv::<IFileDialogVtbl>();
+			into_result!( ((**ppv).GetOptions)(ppv, &mut fos) )
+				.map(|_| co::FOS::from(fos))
+		}
+	}
+
+	/// [`IFileDialog::GetResult`](https://docs.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialog-getresult)
+	/// method.
+	pub fn GetResult(&self) -> WinResult<IShellItem> {
+		let mut ppvQueried: PPVtbl<IShellItemVtbl> = std::ptr::null_mut();
+		unsafe {
+			let ppv = self.IModalWindow.IUnknown.ppv::<IFileDialogVtbl>();
+			into_result!( ((**ppv).GetResult)(ppv, &mut ppvQueried) )
+				.map(|_| IShellItem::from(ppvQueried))
+		}
+	}
+
+	/// [`IFileDialog::SetDefaultExtension`](https://docs.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialog-setdefaultextension)
+	/// method.
+	pub fn SetDefaultExtension(&self, pszDefaultExtension: &str) -> WinResult<()> {
+		unsafe {
+			let ppv = self.IModalWindow.IUnknown.ppv::<IFileDialogVtbl>();
+			into_result!(
+				((**ppv).SetDefaultExtension)(ppv,
+					WString::from_str(pszDefaultExtension).as_ptr())
+			)
+		}
+	}
+
+	/// [`IFileDialog::SetDefaultFolder`](https://docs.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialog-setdefaultfolder)
+	/// method.
+	pub fn SetDefaultFolder(&self, psi: &IShellItem) -> WinResult<()> {
+		unsafe {
+			let ppv = self.IModalWindow.IUnknown.ppv::<IFileDialogVtbl>();
+			into_result!(
+				((**ppv).SetDefaultFolder)(ppv, psi.IUnknown.ppv::<IShellItemVtbl>())
+			)
+		}
+	}
+
+	/// [`IFileDialog::SetFileName`](https://docs.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialog-setfilename)
+	/// method.
+	pub fn SetFileName(&self, pszName: &str) -> WinResult<()> {
+		unsafe {
+			let ppv = self.IModalWindow.IUnknown.ppv::<IFileDialogVtbl>();
+			into_result!(
+				((**ppv).SetFileName)(ppv, WString::from_str(pszName).as_ptr())
+			)
+		}
+	}
+
+	/// [`IFileDialog::SetFileTypeIndex`](https://docs.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialog-setfiletypeindex)
+	/// method.
+	///
+	/// **Note:** The index is one-based.
+	pub fn SetFileTypeIndex(&self, iFileType: u32) -> WinResult<()> {
+		unsafe {
+			let ppv = self.IModalWindow.IUnknown.ppv::<IFileDialogVtbl>();
+			into_result!( ((**ppv).SetFileTypeIndex)(ppv, iFileType) )
+		}
+	}
+
+	/// [`IFileDialog::SetFileTypes`](https://docs.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialog-setfiletypes)
+	/// method.
+	///
+	/// Each filter is a tuple with its name and its specification, like
+	/// `("Text files", "*.txt;*.md")`.
+	pub fn SetFileTypes(&self, rgFilterSpec: &[(&str, &str)]) -> WinResult<()> {
+		let names_buf = rgFilterSpec.iter()
+			.map(|(name, _)| WString::from_str(name))
+			.collect::<Vec<_>>();
+		let specs_buf = rgFilterSpec.iter()
+			.map(|(_, spec)| WString::from_str(spec))
+			.collect::<Vec<_>>();
+		let filter_specs = names_buf.iter().zip(specs_buf.iter())
+			.map(|(name, spec)| COMDLG_FILTERSPEC {
+				pszName: unsafe { name.as_ptr() },
+				pszSpec: unsafe { spec.as_ptr() },
+			})
+			.collect::<Vec<_>>();
+
+		unsafe {
+			let ppv = self.IModalWindow.IUnknown.ppv::<IFileDialogVtbl>();
+			into_result!(
+				((**ppv).SetFileTypes)(ppv,
+					filter_specs.len() as u32, filter_specs.as_ptr() as PCVOID)
+			)
+		}
+	}
+
+	/// [`IFileDialog::SetFolder`](https://docs.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialog-setfolder)
+	/// method.
+	pub fn SetFolder(&self, psi: &IShellItem) -> WinResult<()> {
+		unsafe {
+			let ppv = self.IModalWindow.IUnknown.ppv::<IFileDialogVtbl>();
+			into_result!(
+				((**ppv).SetFolder)(ppv, psi.IUnknown.ppv::<IShellItemVtbl>())
+			)
+		}
+	}
+
+	/// [`IFileDialog::SetOkButtonLabel`](https://docs.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialog-setokbuttonlabel)
+	/// method.
+	pub fn SetOkButtonLabel(&self, pszText: &str) -> WinResult<()> {
+		unsafe {
+			let ppv = self.IModalWindow.IUnknown.ppv::<IFileDialogVtbl>();
+			into_result!(
+				((**ppv).SetOkButtonLabel)(ppv, WString::from_str(pszText).as_ptr())
+			)
+		}
+	}
+
+	/// [`IFileDialog::SetOptions`](https://docs.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialog-setoptions)
+	/// method.
+	pub fn SetOptions(&self, fos: co::FOS) -> WinResult<()> {
+		unsafe {
+			let ppv = self.IModalWindow.IUnknown.ppv::<IFileDialogVtbl>();
+			into_result!( ((**ppv).SetOptions)(ppv, fos.into()) )
+		}
+	}
+
+	/// [`IFileDialog::SetTitle`](https://docs.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialog-settitle)
+	/// method.
+	pub fn SetTitle(&self, pszTitle: &str) -> WinResult<()> {
+		unsafe {
+			let ppv = self.IModalWindow.IUnknown.ppv::<IFileDialogVtbl>();
+			into_result!(
+				((**ppv).SetTitle)(ppv, WString::from_str(pszTitle).as_ptr())
+			)
+		}
+	}
+}
@@ -0,0 +1,81 @@
+#![allow(non_snake_case)]
+
+use crate::aliases::WinResult;
+use crate::co::ERROR;
+use crate::com::{PPVtbl, Vtbl};
+use crate::com::shell::{IFileDialog, IFileDialogVtbl, IShellItemArray, IShellItemArrayVtbl};
+use crate::structs::IID;
+
+/// [`IFileOpenDialog`](crate::shell::IFileOpenDialog) virtual table.
+#[repr(C)]
+pub struct IFileOpenDialogVtbl {
+	iFileDialogVtbl: IFileDialogVtbl,
+
+	GetResults: fn(PPVtbl<Self>, *mut PPVtbl<IShellItemArrayVtbl>) -> u32,
+	GetSelectedItems: fn(PPVtbl<Self>, *mut PPVtbl<IShellItemArrayVtbl>) -> u32,
+}
+
+impl_iid!(IFileOpenDialogVtbl, 0xd57c7288, 0xd4ad, 0x4768, 0xbe02, 0x9d969532d960);
+
+//------------------------------------------------------------------------------
+
+/// [`IFileOpenDialog`](https://docs.microsoft.com/en-us/windows/win32/api/shobjidl_core/nn-shobjidl_core-ifileopendialog)
+/// COM interface.
+///
+/// Inherits from:
+/// * [`IFileDialog`](crate::shell::IFileDialog);
+/// * [`IModalWindow`](crate::shell::IModalWindow);
+/// * [`IUnknown`](crate::IUnknown).
+///
+/// Automatically calls [`IUnknown::Release`](crate::IUnknown::Release) when the
+/// object goes out of scope.
+///
+/// # Examples
+///
+/// Usually instantiated with [`CoCreateInstance`](crate::CoCreateInstance):
+/// ```rust,ignore
+/// use winsafe::{co, CoCreateInstance, shell};
+///
+/// let obj: shell::IFileOpenDialog = CoCreateInstance(
+///     &shell::clsid::FileOpenDialog,
+///     None,
+///     co::CLSCTX::INPROC_SERVER,
+/// ).unwrap();
+/// ```
+pub struct IFileOpenDialog {
+	/// Methods of base interface
+	/// [`IFileDialog`](crate::shell::IFileDialog).
+	pub IFileDialog: IFileDialog,
+}
+
+impl From<PPVtbl<IFileOpenDialogVtbl>> for IFileOpenDialog {
+	fn from(ppv: PPVtbl<IFileOpenDialogVtbl>) -> Self {
+		Self {
+			IFileDialog: IFileDialog::from(ppv as PPVtbl<IFileDialogVtbl>),
+		}
+	}
+}
+
+impl IFileOpenDialog {
+	/// [`IFileOpenDialog::GetResults`](https://docs.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifileopendialog-getresults)
+	/// method.
+	pub fn GetResults(&self) -> WinResult<IShellItemArray> {
+		let mut ppvQueried: PPVtbl<IShellItemArrayVtbl> = std::ptr::null_mut();
+		unsafe {
+			let ppv = self.IFileDialog.IModalWindow.IUnknown.ppv::<IFileOpenDialogVtbl>();
+			into_result!( ((**ppv).GetResults)(ppv, &mut ppvQueried) )
+				.map(|_| IShellItemArray::from(ppvQueried))
+		}
+	}
+
+	/// [`IFileOpenDialog::GetSelectedItems`](https://docs.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifileopendialog-getselecteditems)
+	/// method.
+	pub fn GetSelectedItems(&self) -> WinResult<IShellItemArray> {
+		let mut ppvQueried: PPVtbl<IShellItemArrayVtbl> = std::ptr::null_mut();
+		unsafe {
+			let ppv = self.IFileDialog.IModalWindow.IUnknown.ppv::<IFileOpenDialogVtbl>();
+			into_result!( ((**ppv).GetSelectedItems)(ppv, &mut ppvQueried) )
+				.map(|_| IShellItemArray::from(ppvQueried))
+		}
+	}
+}
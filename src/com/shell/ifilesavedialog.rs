@@ -0,0 +1,74 @@
+#![allow(non_snake_case)]
+
+use crate::aliases::WinResult;
+use crate::co::ERROR;
+use crate::com::{PPVtbl, Vtbl};
+use crate::com::shell::{IFileDialog, IFileDialogVtbl, IShellItem, IShellItemVtbl};
+use crate::ffi::{HANDLE, PVOID};
+use crate::structs::IID;
+
+/// [`IFileSaveDialog`](crate::shell::IFileSaveDialog) virtual table.
+#[repr(C)]
+pub struct IFileSaveDialogVtbl {
+	iFileDialogVtbl: IFileDialogVtbl,
+
+	SetSaveAsItem: fn(PPVtbl<Self>, PPVtbl<IShellItemVtbl>) -> u32,
+	SetProperties: fn(PPVtbl<Self>, PVOID) -> u32,
+	SetCollectedProperties: fn(PPVtbl<Self>, PVOID, i32) -> u32,
+	GetProperties: fn(PPVtbl<Self>, *mut PVOID) -> u32,
+	ApplyProperties: fn(PPVtbl<Self>, PPVtbl<IShellItemVtbl>, PVOID, HANDLE, PVOID) -> u32,
+}
+
+impl_iid!(IFileSaveDialogVtbl, 0x84bccd23, 0x5fde, 0x4cdb, 0xaea4, 0xaf64b83d78ab);
+
+//------------------------------------------------------------------------------
+
+/// [`IFileSaveDialog`](https://docs.microsoft.com/en-us/windows/win32/api/shobjidl_core/nn-shobjidl_core-ifilesavedialog)
+/// COM interface.
+///
+/// Inherits from:
+/// * [`IFileDialog`](crate::shell::IFileDialog);
+/// * [`IModalWindow`](crate::shell::IModalWindow);
+/// * [`IUnknown`](crate::IUnknown).
+///
+/// Automatically calls [`IUnknown::Release`](crate::IUnknown::Release) when the
+/// object goes out of scope.
+///
+/// # Examples
+///
+/// Usually instantiated with [`CoCreateInstance`](crate::CoCreateInstance):
+/// ```rust,ignore
+/// use winsafe::{co, CoCreateInstance, shell};
+///
+/// let obj: shell::IFileSaveDialog = CoCreateInstance(
+///     &shell::clsid::FileSaveDialog,
+///     None,
+///     co::CLSCTX::INPROC_SERVER,
+/// ).unwrap();
+/// ```
+pub struct IFileSaveDialog {
+	/// Methods of base interface
+	/// [`IFileDialog`](crate::shell::IFileDialog).
+	pub IFileDialog: IFileDialog,
+}
+
+impl From<PPVtbl<IFileSaveDialogVtbl>> for IFileSaveDialog {
+	fn from(ppv: PPVtbl<IFileSaveDialogVtbl>) -> Self {
+		Self {
+			IFileDialog: IFileDialog::from(ppv as PPVtbl<IFileDialogVtbl>),
+		}
+	}
+}
+
+impl IFileSaveDialog {
+	/// [`IFileSaveDialog::SetSaveAsItem`](https://docs.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifilesavedialog-setsaveasitem)
+	/// method.
+	pub fn SetSaveAsItem(&self, psi: &IShellItem) -> WinResult<()> {
+		unsafe {
+			let ppv = self.IFileDialog.IModalWindow.IUnknown.ppv::<IFileSaveDialogVtbl>();
+			into_result!(
+				((**ppv).SetSaveAsItem)(ppv, psi.IUnknown.ppv::<IShellItemVtbl>())
+			)
+		}
+	}
+}
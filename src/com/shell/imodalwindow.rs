@@ -0,0 +1,58 @@
+#![allow(non_snake_case)]
+
+use crate::aliases::WinResult;
+use crate::co::ERROR;
+use crate::com::{IUnknown, IUnknownVtbl, PPVtbl, Vtbl};
+use crate::ffi::HANDLE;
+use crate::handles::HWND;
+use crate::structs::IID;
+
+/// [`IModalWindow`](crate::shell::IModalWindow) virtual table.
+#[repr(C)]
+pub struct IModalWindowVtbl {
+	iUnknownVtbl: IUnknownVtbl,
+
+	Show: fn(PPVtbl<Self>, HANDLE) -> u32,
+}
+
+impl_iid!(IModalWindowVtbl, 0xb4db1657, 0x70d7, 0x485e, 0x8e3e, 0x6fcb5a5c1802);
+
+//------------------------------------------------------------------------------
+
+/// [`IModalWindow`](https://docs.microsoft.com/en-us/windows/win32/api/shobjidl_core/nn-shobjidl_core-imodalwindow)
+/// COM interface.
+///
+/// Inherits from:
+/// * [`IUnknown`](crate::IUnknown).
+///
+/// Automatically calls [`IUnknown::Release`](crate::IUnknown::Release) when the
+/// object goes out of scope.
+pub struct IModalWindow {
+	/// Methods of base interface [`IUnknown`](crate::IUnknown).
+	pub IUnknown: IUnknown,
+}
+
+impl From<PPVtbl<IModalWindowVtbl>> for IModalWindow {
+	fn from(ppv: PPVtbl<IModalWindowVtbl>) -> Self {
+		Self {
+			IUnknown: IUnknown::from(ppv as PPVtbl<IUnknownVtbl>)
+		}
+	}
+}
+
+impl IModalWindow {
+	/// [`IModalWindow::Show`](https://docs.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-imodalwindow-show)
+	/// method.
+	///
+	/// Returns `false` if the user cancelled the window.
+	pub fn Show(&self, hwndOwner: HWND) -> WinResult<bool> {
+		unsafe {
+			let ppv = self.IUnknown.ppv::<IModalWindowVtbl>();
+			match ERROR(((**ppv).Show)(ppv, hwndOwner.ptr)) {
+				ERROR::S_OK => Ok(true),
+				ERROR(0x800704c7) => Ok(false), // HRESULT_FROM_WIN32(ERROR_CANCELLED)
+				err => Err(err),
+			}
+		}
+	}
+}
@@ -0,0 +1,84 @@
+#![allow(non_snake_case)]
+
+use crate::aliases::WinResult;
+use crate::co::ERROR;
+use crate::com::{IUnknown, IUnknownVtbl, PPVtbl, Vtbl};
+use crate::com::shell::co;
+use crate::ffi::{ole32, PCVOID, PSTR, PVOID};
+use crate::structs::IID;
+use crate::WString;
+
+/// [`IShellItem`](crate::shell::IShellItem) virtual table.
+#[repr(C)]
+pub struct IShellItemVtbl {
+	iUnknownVtbl: IUnknownVtbl,
+
+	BindToHandler: fn(PPVtbl<Self>, PVOID, PCVOID, PCVOID, *mut PVOID) -> u32,
+	GetParent: fn(PPVtbl<Self>, *mut PPVtbl<IShellItemVtbl>) -> u32,
+	GetDisplayName: fn(PPVtbl<Self>, u32, *mut PSTR) -> u32,
+	GetAttributes: fn(PPVtbl<Self>, u32, *mut u32) -> u32,
+	Compare: fn(PPVtbl<Self>, PPVtbl<IShellItemVtbl>, u32, *mut i32) -> u32,
+}
+
+impl_iid!(IShellItemVtbl, 0x43826d1e, 0xe718, 0x42ee, 0xbc55, 0xa1e261c37bfe);
+
+//------------------------------------------------------------------------------
+
+/// [`IShellItem`](https://docs.microsoft.com/en-us/windows/win32/api/shobjidl_core/nn-shobjidl_core-ishellitem)
+/// COM interface.
+///
+/// Inherits from:
+/// * [`IUnknown`](crate::IUnknown).
+///
+/// Automatically calls [`IUnknown::Release`](crate::IUnknown::Release) when the
+/// object goes out of scope.
+///
+/// # Examples
+///
+/// Usually instantiated with
+/// [`SHCreateItemFromParsingName`](crate::shell::SHCreateItemFromParsingName):
+/// ```rust,ignore
+/// use winsafe::shell;
+///
+/// let obj = shell::SHCreateItemFromParsingName("C:\\Temp").unwrap();
+/// ```
+pub struct IShellItem {
+	/// Methods of base interface [`IUnknown`](crate::IUnknown).
+	pub IUnknown: IUnknown,
+}
+
+impl From<PPVtbl<IShellItemVtbl>> for IShellItem {
+	fn from(ppv: PPVtbl<IShellItemVtbl>) -> Self {
+		Self {
+			IUnknown: IUnknown::from(ppv as PPVtbl<IUnknownVtbl>)
+		}
+	}
+}
+
+impl IShellItem {
+	/// [`IShellItem::GetDisplayName`](https://docs.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ishellitem-getdisplayname)
+	/// method.
+	pub fn GetDisplayName(&self, sigdnName: co::SIGDN) -> WinResult<String> {
+		let mut pstr: PSTR = std::ptr::null_mut();
+		unsafe {
+			let ppv = self.IUnknown.ppv::<IShellItemVtbl>();
+			into_result!( ((**ppv).GetDisplayName)(ppv, sigdnName.into(), &mut pstr) )
+				.map(|_| {
+					let name = WString::from_wchars_nullt(pstr).to_string();
+					ole32::CoTaskMemFree(pstr as PVOID);
+					name
+				})
+		}
+	}
+
+	/// [`IShellItem::GetParent`](https://docs.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ishellitem-getparent)
+	/// method.
+	pub fn GetParent(&self) -> WinResult<IShellItem> {
+		let mut ppvQueried: PPVtbl<IShellItemVtbl> = std::ptr::null_mut();
+		unsafe {
+			let ppv = self.IUnknown.ppv::<IShellItemVtbl>();
+			into_result!( ((**ppv).GetParent)(ppv, &mut ppvQueried) )
+				.map(|_| IShellItem::from(ppvQueried))
+		}
+	}
+}
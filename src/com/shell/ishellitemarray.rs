@@ -0,0 +1,71 @@
+#![allow(non_snake_case)]
+
+use crate::aliases::WinResult;
+use crate::co::ERROR;
+use crate::com::{IUnknown, IUnknownVtbl, PPVtbl, Vtbl};
+use crate::com::shell::{IShellItem, IShellItemVtbl};
+use crate::ffi::{PCVOID, PVOID};
+use crate::structs::IID;
+
+/// [`IShellItemArray`](crate::shell::IShellItemArray) virtual table.
+#[repr(C)]
+pub struct IShellItemArrayVtbl {
+	iUnknownVtbl: IUnknownVtbl,
+
+	BindToHandler: fn(PPVtbl<Self>, PVOID, PCVOID, PCVOID, *mut PVOID) -> u32,
+	GetPropertyStore: fn(PPVtbl<Self>, u32, PCVOID, *mut PVOID) -> u32,
+	GetPropertyDescriptionList: fn(PPVtbl<Self>, PCVOID, PCVOID, *mut PVOID) -> u32,
+	GetAttributes: fn(PPVtbl<Self>, u32, u32, *mut u32) -> u32,
+	GetCount: fn(PPVtbl<Self>, *mut u32) -> u32,
+	GetItemAt: fn(PPVtbl<Self>, u32, *mut PPVtbl<IShellItemVtbl>) -> u32,
+	EnumItems: fn(PPVtbl<Self>, *mut PVOID) -> u32,
+}
+
+impl_iid!(IShellItemArrayVtbl, 0xb63ea76d, 0x1f85, 0x456f, 0xa19c, 0x48159efa858b);
+
+//------------------------------------------------------------------------------
+
+/// [`IShellItemArray`](https://docs.microsoft.com/en-us/windows/win32/api/shobjidl_core/nn-shobjidl_core-ishellitemarray)
+/// COM interface.
+///
+/// Inherits from:
+/// * [`IUnknown`](crate::IUnknown).
+///
+/// Automatically calls [`IUnknown::Release`](crate::IUnknown::Release) when the
+/// object goes out of scope.
+pub struct IShellItemArray {
+	/// Methods of base interface [`IUnknown`](crate::IUnknown).
+	pub IUnknown: IUnknown,
+}
+
+impl From<PPVtbl<IShellItemArrayVtbl>> for IShellItemArray {
+	fn from(ppv: PPVtbl<IShellItemArrayVtbl>) -> Self {
+		Self {
+			IUnknown: IUnknown::from(ppv as PPVtbl<IUnknownVtbl>)
+		}
+	}
+}
+
+impl IShellItemArray {
+	/// [`IShellItemArray::GetCount`](https://docs.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ishellitemarray-getcount)
+	/// method.
+	pub fn GetCount(&self) -> WinResult<u32> {
+		let mut count: u32 = 0;
+		unsafe {
+			let ppv = self.IUnknown.ppv::<IShellItemArrayVtbl>();
+			into_result!( ((**ppv).GetCount)(ppv, &mut count) )
+				.map(|_| count)
+		}
+	}
+
+	/// [`IShellItemArray::GetItemAt`](https://docs.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ishellitemarray-getitemat)
+	/// method.
+	pub fn GetItemAt(&self, dwIndex: u32) -> WinResult<IShellItem> {
+		let mut ppvQueried: PPVtbl<IShellItemVtbl> = std::ptr::null_mut();
+		unsafe {
+			let ppv = self.IUnknown.ppv::<IShellItemArrayVtbl>();
+			into_result!( ((**ppv).GetItemAt)(ppv, dwIndex, &mut ppvQueried) )
+				.map(|_| IShellItem::from(ppvQueried))
+		}
+	}
+}
@@ -4,10 +4,24 @@
 pub mod clsid;
 pub mod co;
 
+mod funcs;
+mod ifiledialog;
+mod ifileopendialog;
+mod ifilesavedialog;
+mod imodalwindow;
+mod ishellitem;
+mod ishellitemarray;
 mod itaskbarlist;
 mod itaskbarlist2;
 mod itaskbarlist3;
 
+pub use funcs::*;
+pub use ifiledialog::{IFileDialog, IFileDialogVtbl};
+pub use ifileopendialog::{IFileOpenDialog, IFileOpenDialogVtbl};
+pub use ifilesavedialog::{IFileSaveDialog, IFileSaveDialogVtbl};
+pub use imodalwindow::{IModalWindow, IModalWindowVtbl};
+pub use ishellitem::{IShellItem, IShellItemVtbl};
+pub use ishellitemarray::{IShellItemArray, IShellItemArrayVtbl};
 pub use itaskbarlist::{ITaskbarList, ITaskbarListVtbl};
 pub use itaskbarlist2::{ITaskbarList2, ITaskbarList2Vtbl};
 pub use itaskbarlist3::{ITaskbarList3, ITaskbarList3Vtbl};
@@ -41,7 +41,9 @@ impl AtomStr {
 
 /// Variant parameter for:
 ///
-/// * [`BM_GETIMAGE`](crate::msg::bm::GetImage) `image`.
+/// * [`BM_GETIMAGE`](crate::msg::bm::GetImage) `image`;
+/// * [`STM_GETIMAGE`](crate::msg::stm::GetImage) `image`.
+#[derive(Copy, Clone)]
 pub enum BitmapIcon {
 	Bitmap(HBITMAP),
 	Icon(HICON),
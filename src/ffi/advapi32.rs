@@ -2,7 +2,7 @@
 
 use crate::ffi::{BOOL, HANDLE, PCSTR, PCVOID, PVOID};
 
-#[link(name = "advapi32")]
+#[cfg_attr(windows, link(name = "advapi32"))]
 extern "system" {
 	pub fn RegCloseKey(hKey: HANDLE) -> BOOL;
 	pub fn RegGetValueW(hkey: HANDLE, lpSubKey: PCSTR, lpValue: PCSTR, dwFlags: u32, pdwType: *mut u32, pvData: PVOID, pcbData: *mut u32) -> BOOL;
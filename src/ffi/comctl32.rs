@@ -1,8 +1,8 @@
 //! Raw bindings to comctl32.lib functions.
 
-use crate::ffi::{BOOL, HANDLE, PFUNC};
+use crate::ffi::{BOOL, HANDLE, PCVOID, PFUNC};
 
-#[link(name = "comctl32")]
+#[cfg_attr(windows, link(name = "comctl32"))]
 extern "system" {
 	pub fn DefSubclassProc(hWnd: HANDLE, uMsg: u32, wParam: usize, lParam: isize) -> isize;
 	pub fn ImageList_Add(himl: HANDLE, hbmImage: HANDLE, hbmMask: HANDLE) -> i32;
@@ -18,6 +18,8 @@ extern "system" {
 	pub fn ImageList_ReplaceIcon(himl: HANDLE, i: i32, hicon: HANDLE) -> i32;
 	pub fn ImageList_SetImageCount(himl: HANDLE, uNewCount: u32) -> BOOL;
 	pub fn InitCommonControls();
+	pub fn PropertySheetW(lppsph: PCVOID) -> isize;
 	pub fn RemoveWindowSubclass(hWnd: HANDLE, pfnSubclass: PFUNC, uIdSubclass: usize) -> BOOL;
 	pub fn SetWindowSubclass(hWnd: HANDLE, pfnSubclass: PFUNC, uIdSubclass: usize, dwRefData: usize) -> BOOL;
+	pub fn TaskDialogIndirect(pTaskConfig: PCVOID, pnButton: *mut i32, pnRadioButton: *mut i32, pfVerificationFlagChecked: *mut BOOL) -> u32;
 }
@@ -0,0 +1,12 @@
+//! Raw bindings to comdlg32.lib functions.
+
+use crate::ffi::{BOOL, PVOID};
+
+#[cfg_attr(windows, link(name = "comdlg32"))]
+extern "system" {
+	pub fn ChooseColorW(lpcc: PVOID) -> BOOL;
+	pub fn ChooseFontW(lpcf: PVOID) -> BOOL;
+	pub fn CommDlgExtendedError() -> u32;
+	pub fn GetOpenFileNameW(lpofn: PVOID) -> BOOL;
+	pub fn GetSaveFileNameW(lpofn: PVOID) -> BOOL;
+}
@@ -2,7 +2,7 @@
 
 use crate::ffi::{BOOL, HANDLE, PCSTR, PCVOID, PVOID};
 
-#[link(name = "gdi32")]
+#[cfg_attr(windows, link(name = "gdi32"))]
 extern "system" {
 	pub fn CreateCompatibleDC(hdc: HANDLE) -> HANDLE;
 	pub fn CreateFontIndirectW(lplf: PCVOID) -> HANDLE;
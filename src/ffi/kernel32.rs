@@ -1,8 +1,8 @@
 //! Raw bindings to kernel32.lib functions.
 
-use crate::ffi::{BOOL, HANDLE, PCSTR, PCVOID, PSTR, PVOID};
+use crate::ffi::{BOOL, HANDLE, PCSTR, PCVOID, PFUNC, PSTR, PVOID};
 
-#[link(name = "kernel32")]
+#[cfg_attr(windows, link(name = "kernel32"))]
 extern "system" {
 	pub fn FileTimeToSystemTime(lpFileTime: PCVOID, lpSystemTime: PVOID) -> BOOL;
 	pub fn FormatMessageW(dwFlags: u32, lpSource: PCVOID, dwMessageId: u32, dwLanguageId: u32, lpBuffer: PSTR, nSize: u32, Arguments: PVOID) -> u32;
@@ -10,10 +10,16 @@ extern "system" {
 	pub fn GetEnvironmentStringsW() -> HANDLE;
 	pub fn GetLastError() -> u32;
 	pub fn GetModuleHandleW(lpModuleName: PCSTR) -> HANDLE;
+	pub fn GetProcAddress(hModule: HANDLE, lpProcName: *const u8) -> PFUNC;
 	pub fn GetSystemTime(lpSystemTime: PVOID);
 	pub fn GetSystemTimeAsFileTime(lpSystemTimeAsFileTime: PVOID);
 	pub fn GetSystemTimePreciseAsFileTime(lpSystemTimeAsFileTime: PVOID);
 	pub fn GetTickCount64() -> u64;
+	pub fn GlobalAlloc(uFlags: u32, dwBytes: usize) -> HANDLE;
+	pub fn GlobalFree(hMem: HANDLE) -> HANDLE;
+	pub fn GlobalLock(hMem: HANDLE) -> PVOID;
+	pub fn GlobalSize(hMem: HANDLE) -> usize;
+	pub fn GlobalUnlock(hMem: HANDLE) -> BOOL;
 	pub fn LocalFree(hMem: HANDLE) -> HANDLE;
 	pub fn lstrlenW(lpString: PCSTR) -> i32;
 	pub fn MulDiv(nNumber: i32, nNumerator: i32, nDenominator: i32) -> i32;
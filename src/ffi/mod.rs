@@ -13,6 +13,7 @@ pub type PVOID = *mut std::ffi::c_void;
 
 pub mod advapi32;
 pub mod comctl32;
+pub mod comdlg32;
 pub mod gdi32;
 pub mod kernel32;
 pub mod ole32;
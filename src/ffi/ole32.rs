@@ -1,10 +1,17 @@
 //! Raw bindings to ole32.lib functions.
 
-use crate::ffi::{PCVOID, PVOID};
+use crate::ffi::{HANDLE, PCVOID, PVOID};
 
-#[link(name = "ole32")]
+#[cfg_attr(windows, link(name = "ole32"))]
 extern "system" {
 	pub fn CoCreateInstance(rclsid: PCVOID, pUnkOuter: PVOID, dwClsContext: u32, riid: PCVOID, ppv: *mut PVOID) -> u32;
 	pub fn CoInitializeEx(lpReserved: PVOID, dwCoInit: u32) -> u32;
+	pub fn CoTaskMemFree(pv: PVOID);
 	pub fn CoUninitialize();
+	pub fn DoDragDrop(pDataObj: PVOID, pDropSource: PVOID, dwOKEffects: u32, pdwEffect: *mut u32) -> u32;
+	pub fn OleInitialize(pvReserved: PVOID) -> u32;
+	pub fn OleUninitialize();
+	pub fn RegisterDragDrop(hwnd: HANDLE, pDropTarget: PVOID) -> u32;
+	pub fn ReleaseStgMedium(pmedium: PVOID);
+	pub fn RevokeDragDrop(hwnd: HANDLE) -> u32;
 }
@@ -1,10 +1,14 @@
 //! Raw bindings to shell32.lib functions.
 
-use crate::ffi::{BOOL, HANDLE, PSTR, PVOID};
+use crate::ffi::{BOOL, HANDLE, PCSTR, PCVOID, PSTR, PVOID};
 
-#[link(name = "shell32")]
+#[cfg_attr(windows, link(name = "shell32"))]
 extern "system" {
+	pub fn DragAcceptFiles(hWnd: HANDLE, fAccept: BOOL);
 	pub fn DragFinish(hDrop: HANDLE);
 	pub fn DragQueryFileW(hDrop: HANDLE, hFile: u32, lpszFile: PSTR, cch: u32) -> u32;
 	pub fn DragQueryPoint(hDrop: HANDLE, ppt: PVOID) -> BOOL;
+	pub fn SHCreateItemFromParsingName(pszPath: PCSTR, pbc: PVOID, riid: PCVOID, ppv: *mut PVOID) -> u32;
+	pub fn SHCreateStdEnumFmtEtc(cfmt: u32, afmt: PCVOID, ppenumFormatEtc: *mut PVOID) -> u32;
+	pub fn Shell_NotifyIconW(dwMessage: u32, lpData: PVOID) -> BOOL;
 }
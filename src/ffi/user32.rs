@@ -2,8 +2,9 @@
 
 use crate::ffi::{BOOL, HANDLE, PCSTR, PCVOID, PFUNC, PSTR, PVOID};
 
-#[link(name = "user32")]
+#[cfg_attr(windows, link(name = "user32"))]
 extern "system" {
+	pub fn AddClipboardFormatListener(hwnd: HANDLE) -> BOOL;
 	pub fn AdjustWindowRectEx(lpRect: PVOID, dwStyle: u32, bMenu: BOOL, dwExStyle: u32) -> BOOL;
 	pub fn AppendMenuW(hMenu: HANDLE, uFlags: u32, uIDNewItem: usize, lpNewItem: PCSTR) -> BOOL;
 	pub fn ArrangeIconicWindows(hWnd: HANDLE) -> u32;
@@ -13,6 +14,7 @@ extern "system" {
 	pub fn CheckMenuItem(hMenu: HANDLE, uIDCheckItem: u32, uCheck: u32) -> i32;
 	pub fn ChildWindowFromPoint(hWndParent: HANDLE, PointX: i32, PointY: i32) -> HANDLE;
 	pub fn ClientToScreen(hWnd: HANDLE, lpPoint: PVOID) -> BOOL;
+	pub fn CloseClipboard() -> BOOL;
 	pub fn CloseWindow(hWnd: HANDLE) -> BOOL;
 	pub fn CreateAcceleratorTableW(paccel: PVOID, cAccel: i32) -> HANDLE;
 	pub fn CreateDialogParamW(hInstance: HANDLE, lpTemplateName: PCSTR, hWndParent: HANDLE, lpDialogFunc: PFUNC, dwInitParam: isize) -> HANDLE;
@@ -20,6 +22,8 @@ extern "system" {
 	pub fn CreatePopupMenu() -> HANDLE;
 	pub fn CreateWindowExW(dwExStyle: u32, lpClassName: PCSTR, lpWindowName: PCSTR, dwStyle: u32, X: i32, Y: i32, nWidth: i32, nHeight: i32, hWndParent: HANDLE, hMenu: HANDLE, hInstance: HANDLE, lpParam: PVOID) -> HANDLE;
 	pub fn DeferWindowPos(hWinPosInfo: HANDLE, hWnd: HANDLE, hWndInsertAfter: HANDLE, X: i32, Y: i32, cx: i32, cy: i32, uFlags: u32) -> HANDLE;
+	pub fn DefFrameProcW(hWnd: HANDLE, hWndMDIClient: HANDLE, uMsg: u32, wParam: usize, lParam: isize) -> isize;
+	pub fn DefMDIChildProcW(hWnd: HANDLE, uMsg: u32, wParam: usize, lParam: isize) -> isize;
 	pub fn DefWindowProcW(hWnd: HANDLE, Msg: u32, wParam: usize, lParam: isize) -> isize;
 	pub fn DeleteMenu(hMenu: HANDLE, uPosition: u32, uFlags: u32) -> BOOL;
 	pub fn DestroyAcceleratorTable(hAccel: HANDLE) -> BOOL;
@@ -28,12 +32,14 @@ extern "system" {
 	pub fn DestroyWindow(hWnd: HANDLE) -> BOOL;
 	pub fn DialogBoxParamW(hInstance: HANDLE, lpTemplateName: PCSTR, hWndParent: HANDLE, lpDialogFunc: PFUNC, dwInitParam: isize) -> isize;
 	pub fn DispatchMessageW(lpMsg: PCVOID) -> isize;
+	pub fn EmptyClipboard() -> BOOL;
 	pub fn EnableMenuItem(hMenu: HANDLE, uIDEnableItem: u32, uEnable: u32) -> BOOL;
 	pub fn EnableWindow(hWnd: HANDLE, bEnable: BOOL) -> BOOL;
 	pub fn EndDeferWindowPos(hWinPosInfo: HANDLE) -> BOOL;
 	pub fn EndDialog(hDlg: HANDLE, nResult: isize) -> BOOL;
 	pub fn EndPaint(hWnd: HANDLE, lpPaint: PCVOID) -> BOOL;
 	pub fn EnumChildWindows(hWndParent: HANDLE, lpEnumFunc: PFUNC, lParam: isize) -> BOOL;
+	pub fn EnumClipboardFormats(format: u32) -> u32;
 	pub fn FindWindowW(lpClassName: PCSTR, lpWindowName: PCSTR) -> HANDLE;
 	pub fn GetActiveWindow() -> HANDLE;
 	pub fn GetAncestor(hwnd: HANDLE, gaFlags: u32) -> HANDLE;
@@ -41,9 +47,14 @@ extern "system" {
 	pub fn GetCapture() -> HANDLE;
 	pub fn GetClassInfoExW(hInstance: HANDLE, lpszClass: PCSTR, lpwcx: PVOID) -> BOOL;
 	pub fn GetClassLongPtrW(hWnd: HANDLE, nIndex: i32) -> usize;
+	pub fn GetClassNameW(hWnd: HANDLE, lpClassName: PSTR, nMaxCount: i32) -> i32;
 	pub fn GetClientRect(hWnd: HANDLE, lpRect: PVOID) -> BOOL;
+	pub fn GetClipboardData(uFormat: u32) -> HANDLE;
+	pub fn GetClipboardFormatNameW(format: u32, lpszFormatName: PSTR, cchMaxCount: i32) -> i32;
+	pub fn GetClipboardSequenceNumber() -> u32;
 	pub fn GetDC(hWnd: HANDLE) -> HANDLE;
 	pub fn GetDesktopWindow() -> HANDLE;
+	pub fn GetCursorPos(lpPoint: PVOID) -> BOOL;
 	pub fn GetDialogBaseUnits() -> i32;
 	pub fn GetDlgCtrlID(hWnd: HANDLE) -> i32;
 	pub fn GetDlgItem(hDlg: HANDLE, nIDDlgItem: i32) -> HANDLE;
@@ -53,6 +64,7 @@ extern "system" {
 	pub fn GetMenuInfo(hMenu: HANDLE, lpmi: PVOID) -> BOOL;
 	pub fn GetMenuItemCount(hMenu: HANDLE) -> i32;
 	pub fn GetMenuItemID(hMenu: HANDLE, nPos: i32) -> i32;
+	pub fn GetMonitorInfoW(hMonitor: HANDLE, lpmi: PVOID) -> BOOL;
 	pub fn GetMessageW(lpMsg: PVOID, hWnd: HANDLE, wMsgFilterMin: u32, wMsgFilterMax: u32) -> BOOL;
 	pub fn GetNextDlgGroupItem(hDlg: HANDLE, hCtl: HANDLE, bPrevious: BOOL) -> HANDLE;
 	pub fn GetNextDlgTabItem(hDlg: HANDLE, hCtl: HANDLE, bPrevious: BOOL) -> HANDLE;
@@ -81,6 +93,7 @@ extern "system" {
 	pub fn InvalidateRect(hWnd: HANDLE, lpRect: PCVOID, bErase: BOOL) -> BOOL;
 	pub fn InvalidateRgn(hWnd: HANDLE, hRgn: HANDLE, bErase: BOOL) -> BOOL;
 	pub fn IsChild(hWndParent: HANDLE, hWnd: HANDLE) -> BOOL;
+	pub fn IsClipboardFormatAvailable(format: u32) -> BOOL;
 	pub fn IsDialogMessageW(hDlg: HANDLE, lpMsg: PVOID) -> BOOL;
 	pub fn IsGUIThread(bConvert: BOOL) -> BOOL;
 	pub fn IsIconic(hWnd: HANDLE) -> BOOL;
@@ -90,6 +103,7 @@ extern "system" {
 	pub fn IsWindowUnicode(hWnd: HANDLE) -> BOOL;
 	pub fn IsWindowVisible(hWnd: HANDLE) -> BOOL;
 	pub fn IsZoomed(hWnd: HANDLE) -> BOOL;
+	pub fn KillTimer(hWnd: HANDLE, uIDEvent: usize) -> BOOL;
 	pub fn LoadAcceleratorsW(hInstance: HANDLE, lpTableName: PCSTR) -> HANDLE;
 	pub fn LoadCursorW(hInstance: HANDLE, lpCursorName: PCSTR) -> HANDLE;
 	pub fn LoadIconW(hInstance: HANDLE, lpIconName: PCSTR) -> HANDLE;
@@ -97,19 +111,30 @@ extern "system" {
 	pub fn LockSetForegroundWindow(uLockCode: u32) -> BOOL;
 	pub fn MapDialogRect(hDlg: HANDLE, lpRect: PVOID) -> BOOL;
 	pub fn MessageBoxW(hWnd: HANDLE, lpText: PCSTR, lpCaption: PCSTR, uType: u32) -> i32;
+	pub fn MonitorFromRect(lprc: PCVOID, dwFlags: u32) -> HANDLE;
+	pub fn MonitorFromWindow(hwnd: HANDLE, dwFlags: u32) -> HANDLE;
 	pub fn MoveWindow(hWnd: HANDLE, X: i32, Y: i32, nWidth: i32, nHeight: i32, bRepaint: BOOL) -> BOOL;
+	pub fn OpenClipboard(hWndNewOwner: HANDLE) -> BOOL;
 	pub fn PeekMessageW(lpMsg: PVOID, hWnd: HANDLE, wMsgFilterMin: u32, wMsgFilterMax: u32, wRemoveMsg: u32) -> BOOL;
 	pub fn PostMessageW(hWnd: HANDLE, Msg: u32, wParam: usize, lParam: isize) -> BOOL;
 	pub fn PostQuitMessage(nExitCode: i32);
 	pub fn RealChildWindowFromPoint(hwndParent: HANDLE, PointX: i32, PointY: i32) -> HANDLE;
 	pub fn RedrawWindow(hWnd: HANDLE, lprcUpdate: PCVOID, hrgnUpdate: HANDLE, flags: u32) -> BOOL;
 	pub fn RegisterClassExW(lpwcx: PCVOID) -> u16;
+	pub fn RegisterClipboardFormatW(lpszFormat: PCSTR) -> u32;
+	pub fn RegisterHotKey(hWnd: HANDLE, id: i32, fsModifiers: u32, vk: u32) -> BOOL;
+	pub fn RegisterWindowMessageW(lpString: PCSTR) -> u32;
+	pub fn ReleaseCapture() -> BOOL;
 	pub fn ReleaseDC(hWnd: HANDLE, hDC: HANDLE) -> i32;
+	pub fn RemoveClipboardFormatListener(hwnd: HANDLE) -> BOOL;
 	pub fn RemoveMenu(hMenu: HANDLE, uPosition: u32, uFlags: u32) -> BOOL;
 	pub fn ScreenToClient(hWnd: HANDLE, lpPoint: PVOID) -> BOOL;
 	pub fn SendMessageW(hWnd: HANDLE, Msg: u32, wParam: usize, lParam: isize) -> isize;
 	pub fn SetCaretBlinkTime(uMSeconds: u32) -> BOOL;
 	pub fn SetCaretPos(X: i32, Y: i32) -> BOOL;
+	pub fn SetCapture(hWnd: HANDLE) -> HANDLE;
+	pub fn SetClipboardData(uFormat: u32, hMem: HANDLE) -> HANDLE;
+	pub fn SetCursor(hCursor: HANDLE) -> HANDLE;
 	pub fn SetCursorPos(X: i32, Y: i32) -> BOOL;
 	pub fn SetFocus(hWnd: HANDLE) -> HANDLE;
 	pub fn SetForegroundWindow(hWnd: HANDLE) -> BOOL;
@@ -121,6 +146,7 @@ extern "system" {
 	pub fn SetScrollPos(hWnd: HANDLE, nBar: i32, nPos: i32, bRedraw: BOOL) -> i32;
 	pub fn SetScrollRange(hWnd: HANDLE, nBar: i32, nMinPos: i32, nMaxPos: i32, bRedraw: BOOL) -> BOOL;
 	pub fn SetSystemCursor(hcur: HANDLE, id: u32) -> BOOL;
+	pub fn SetTimer(hWnd: HANDLE, nIDEvent: usize, uElapse: u32, lpTimerFunc: PFUNC) -> usize;
 	pub fn SetWindowDisplayAffinity(hWnd: HANDLE, dwAffinity: u32) -> BOOL;
 	pub fn SetWindowLongPtrW(hWnd: HANDLE, nIndex: i32, dwNewLong: isize) -> isize;
 	pub fn SetWindowPlacement(hWnd: HANDLE, lpwndpl: PCVOID) -> BOOL;
@@ -136,8 +162,10 @@ extern "system" {
 	pub fn TrackMouseEvent(lpEventTrack: PVOID) -> BOOL;
 	pub fn TrackPopupMenu(hMenu: HANDLE, uFlags: u32, x: i32, y: i32, nReserved: i32, hWnd: HANDLE, prcRect: PCVOID) -> BOOL;
 	pub fn TranslateAcceleratorW(hWnd: HANDLE, hAccTable: HANDLE, lpMsg: PVOID) -> i32;
+	pub fn TranslateMDISysAccel(hWndClient: HANDLE, lpMsg: PVOID) -> BOOL;
 	pub fn TranslateMessage(lpMsg: PCVOID) -> BOOL;
 	pub fn UnregisterClassW(lpClassName: PCSTR, hInstance: HANDLE) -> BOOL;
+	pub fn UnregisterHotKey(hWnd: HANDLE, id: i32) -> BOOL;
 	pub fn UpdateWindow(hWnd: HANDLE) -> BOOL;
 	pub fn ValidateRect(hWnd: HANDLE, lpRect: PCVOID) -> BOOL;
 	pub fn ValidateRgn(hWnd: HANDLE, hRgn: HANDLE) -> BOOL;
@@ -2,7 +2,7 @@
 
 use crate::ffi::{BOOL, HANDLE, PCSTR, PCVOID, PVOID};
 
-#[link(name = "uxtheme")]
+#[cfg_attr(windows, link(name = "uxtheme"))]
 extern "system" {
 	pub fn CloseThemeData(hTheme: HANDLE) -> u32;
 	pub fn DrawThemeBackground(hTheme: HANDLE, hdc: HANDLE, iPartId: i32, iStateId: i32, pRect: PCVOID, pClipRect: PCVOID) -> u32;
@@ -7,18 +7,25 @@ use std::collections::HashMap;
 use crate::aliases::WinResult;
 use crate::co;
 use crate::enums::BroadNull;
-use crate::ffi::{comctl32, kernel32, user32};
-use crate::handles::{HINSTANCE, HWND};
+use crate::ffi::{BOOL, comctl32, comdlg32, HANDLE, kernel32, PVOID, shell32, user32};
+use crate::handles::{HGLOBAL, HINSTANCE, HWND};
 use crate::msg::MsgSend;
-use crate::privs::{bool_to_winresult, parse_multi_z_str, ptr_as_opt};
+use crate::privs::{bool_to_winresult, parse_multi_z_str, ptr_as_opt, user32_proc};
 use crate::structs::{
 	ATOM,
+	CHOOSECOLOR,
+	CHOOSEFONT,
 	COLORREF,
 	FILETIME,
 	MSG,
+	NOTIFYICONDATA,
+	OPENFILENAME,
 	OSVERSIONINFOEX,
+	POINT,
+	PROPSHEETHEADER,
 	RECT,
 	SYSTEMTIME,
+	TASKDIALOGCONFIG,
 	TIME_ZONE_INFORMATION,
 	TRACKMOUSEEVENT,
 	WNDCLASSEX,
@@ -43,12 +50,74 @@ pub fn AdjustWindowRectEx(
 	)
 }
 
+/// [`ChooseColor`](https://docs.microsoft.com/en-us/windows/win32/api/commdlg/nf-commdlg-choosecolorw-r1)
+/// function.
+///
+/// Returns `false` if the user cancelled the dialog. On failure, the error
+/// carries the [`CommDlgExtendedError`](https://docs.microsoft.com/en-us/windows/win32/api/commdlg/nf-commdlg-commdlgextendederror)
+/// code.
+pub fn ChooseColor(lpcc: &mut CHOOSECOLOR) -> WinResult<bool> {
+	match unsafe { comdlg32::ChooseColorW(lpcc as *mut _ as *mut _) } {
+		0 => match unsafe { comdlg32::CommDlgExtendedError() } {
+			0 => Ok(false),
+			err => Err(co::ERROR(err)),
+		},
+		_ => Ok(true),
+	}
+}
+
+/// [`ChooseFont`](https://docs.microsoft.com/en-us/windows/win32/api/commdlg/nf-commdlg-choosefontw)
+/// function.
+///
+/// Returns `false` if the user cancelled the dialog. On failure, the error
+/// carries the [`CommDlgExtendedError`](https://docs.microsoft.com/en-us/windows/win32/api/commdlg/nf-commdlg-commdlgextendederror)
+/// code.
+pub fn ChooseFont(lpcf: &mut CHOOSEFONT) -> WinResult<bool> {
+	match unsafe { comdlg32::ChooseFontW(lpcf as *mut _ as *mut _) } {
+		0 => match unsafe { comdlg32::CommDlgExtendedError() } {
+			0 => Ok(false),
+			err => Err(co::ERROR(err)),
+		},
+		_ => Ok(true),
+	}
+}
+
+/// [`CloseClipboard`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-closeclipboard)
+/// function.
+pub fn CloseClipboard() -> WinResult<()> {
+	bool_to_winresult(unsafe { user32::CloseClipboard() })
+}
+
 /// [`DispatchMessage`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-dispatchmessagew)
 /// function.
 pub fn DispatchMessage(lpMsg: &MSG) -> isize {
 	unsafe { user32::DispatchMessageW(lpMsg as *const _ as *const _) }
 }
 
+/// [`EmptyClipboard`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-emptyclipboard)
+/// function.
+pub fn EmptyClipboard() -> WinResult<()> {
+	bool_to_winresult(unsafe { user32::EmptyClipboard() })
+}
+
+/// This function calls
+/// [`EnumClipboardFormats`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-enumclipboardformats)
+/// repeatedly to retrieve all formats currently on the clipboard.
+pub fn EnumClipboardFormats() -> WinResult<Vec<co::CF>> {
+	let mut formats = Vec::default();
+	let mut format: u32 = 0;
+	loop {
+		format = unsafe { user32::EnumClipboardFormats(format) };
+		if format == 0 {
+			return match GetLastError() {
+				co::ERROR::SUCCESS => Ok(formats), // no more formats
+				err => Err(err),
+			};
+		}
+		formats.push(co::CF(format as u16));
+	}
+}
+
 /// [`FileTimeToSystemTime`](https://docs.microsoft.com/en-us/windows/win32/api/timezoneapi/nf-timezoneapi-filetimetosystemtime)
 /// function.
 pub fn FileTimeToSystemTime(
@@ -70,6 +139,46 @@ pub fn GetAsyncKeyState(vKey: co::VK) -> bool {
 	unsafe { user32::GetAsyncKeyState(vKey.0 as i32) != 0 }
 }
 
+/// [`GetClipboardData`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getclipboarddata)
+/// function.
+///
+/// The returned memory block is owned by the clipboard, and it must not be
+/// freed.
+pub fn GetClipboardData(uFormat: co::CF) -> WinResult<HGLOBAL> {
+	ptr_as_opt(unsafe { user32::GetClipboardData(uFormat.0 as u32) })
+		.map(|ptr| HGLOBAL { ptr })
+		.ok_or_else(|| GetLastError())
+}
+
+/// [`GetClipboardFormatName`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getclipboardformatnamew)
+/// function.
+///
+/// Fails for the standard formats, which have no names.
+pub fn GetClipboardFormatName(format: co::CF) -> WinResult<String> {
+	let mut buf = WString::new_alloc_buffer(256); // arbitrary
+	match unsafe {
+		user32::GetClipboardFormatNameW(
+			format.0 as u32, buf.as_mut_ptr(), buf.buffer_size() as i32)
+	} {
+		0 => Err(GetLastError()),
+		_ => Ok(buf.to_string()),
+	}
+}
+
+/// [`GetClipboardSequenceNumber`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getclipboardsequencenumber)
+/// function.
+pub fn GetClipboardSequenceNumber() -> u32 {
+	unsafe { user32::GetClipboardSequenceNumber() }
+}
+
+/// [`GetCursorPos`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getcursorpos)
+/// function.
+pub fn GetCursorPos() -> WinResult<POINT> {
+	let mut pt = POINT::default();
+	bool_to_winresult(unsafe { user32::GetCursorPos(&mut pt as *mut _ as *mut _) })
+		.map(|_| pt)
+}
+
 /// [`GetDialogBaseUnits`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getdialogbaseunits)
 /// function.
 pub fn GetDialogBaseUnits() -> i32 {
@@ -82,6 +191,19 @@ pub fn GetDoubleClickTime() -> u32 {
 	unsafe { user32::GetDoubleClickTime() }
 }
 
+/// [`GetDpiForSystem`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getdpiforsystem)
+/// function.
+///
+/// Available since Windows 10 version 1607. The function is resolved at
+/// runtime, and fails with
+/// [`ERROR::PROC_NOT_FOUND`](crate::co::ERROR::PROC_NOT_FOUND) on older
+/// systems.
+pub fn GetDpiForSystem() -> WinResult<u32> {
+	let func: extern "system" fn() -> u32 =
+		unsafe { std::mem::transmute(user32_proc("GetDpiForSystem")?) };
+	Ok(func())
+}
+
 /// [`GetEnvironmentStrings`](https://docs.microsoft.com/en-us/windows/win32/api/processenv/nf-processenv-getenvironmentstringsw)
 /// function.
 ///
@@ -148,12 +270,44 @@ pub fn GetMessage(lpMsg: &mut MSG, hWnd: Option<HWND>,
 	}
 }
 
+/// [`GetOpenFileName`](https://docs.microsoft.com/en-us/windows/win32/api/commdlg/nf-commdlg-getopenfilenamew)
+/// function.
+///
+/// Returns `false` if the user cancelled the dialog. On failure, the error
+/// carries the [`CommDlgExtendedError`](https://docs.microsoft.com/en-us/windows/win32/api/commdlg/nf-commdlg-commdlgextendederror)
+/// code.
+pub fn GetOpenFileName(lpofn: &mut OPENFILENAME) -> WinResult<bool> {
+	match unsafe { comdlg32::GetOpenFileNameW(lpofn as *mut _ as *mut _) } {
+		0 => match unsafe { comdlg32::CommDlgExtendedError() } {
+			0 => Ok(false),
+			err => Err(co::ERROR(err)),
+		},
+		_ => Ok(true),
+	}
+}
+
 /// [`GetQueueStatus`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getqueuestatus)
 /// function.
 pub fn GetQueueStatus(flags: co::QS) -> u32 {
 	unsafe { user32::GetQueueStatus(flags.0) }
 }
 
+/// [`GetSaveFileName`](https://docs.microsoft.com/en-us/windows/win32/api/commdlg/nf-commdlg-getsavefilenamew)
+/// function.
+///
+/// Returns `false` if the user cancelled the dialog. On failure, the error
+/// carries the [`CommDlgExtendedError`](https://docs.microsoft.com/en-us/windows/win32/api/commdlg/nf-commdlg-commdlgextendederror)
+/// code.
+pub fn GetSaveFileName(lpofn: &mut OPENFILENAME) -> WinResult<bool> {
+	match unsafe { comdlg32::GetSaveFileNameW(lpofn as *mut _ as *mut _) } {
+		0 => match unsafe { comdlg32::CommDlgExtendedError() } {
+			0 => Ok(false),
+			err => Err(co::ERROR(err)),
+		},
+		_ => Ok(true),
+	}
+}
+
 /// [`GetSysColor`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getsyscolor)
 /// function.
 pub fn GetSysColor(nIndex: co::COLOR) -> COLORREF {
@@ -221,6 +375,12 @@ pub fn InitCommonControls() {
 	unsafe { comctl32::InitCommonControls() }
 }
 
+/// [`IsClipboardFormatAvailable`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-isclipboardformatavailable)
+/// function.
+pub fn IsClipboardFormatAvailable(format: co::CF) -> bool {
+	unsafe { user32::IsClipboardFormatAvailable(format.0 as u32) != 0 }
+}
+
 /// [`IsGUIThread`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-isguithread)
 /// function.
 pub fn IsGUIThread(bConvert: bool) -> WinResult<bool> {
@@ -416,6 +576,18 @@ pub fn PostQuitMessage(nExitCode: co::ERROR) {
 	unsafe { user32::PostQuitMessage(nExitCode.0 as i32) }
 }
 
+/// [`PropertySheet`](https://docs.microsoft.com/en-us/windows/win32/api/prsht/nf-prsht-propertysheetw)
+/// function.
+///
+/// For modal property sheets, returns a positive value if changes were saved,
+/// or zero otherwise.
+pub fn PropertySheet(lppsph: &PROPSHEETHEADER) -> WinResult<isize> {
+	match unsafe { comctl32::PropertySheetW(lppsph as *const _ as *const _) } {
+		-1 => Err(GetLastError()),
+		ret => Ok(ret),
+	}
+}
+
 /// [`RegisterClassEx`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerclassexw)
 /// function.
 pub fn RegisterClassEx(lpwcx: &WNDCLASSEX) -> WinResult<ATOM> {
@@ -427,6 +599,36 @@ pub fn RegisterClassEx(lpwcx: &WNDCLASSEX) -> WinResult<ATOM> {
 	}
 }
 
+/// [`RegisterClipboardFormat`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerclipboardformatw)
+/// function.
+///
+/// Registering the same format name again returns the same value.
+pub fn RegisterClipboardFormat(lpszFormat: &str) -> WinResult<co::CF> {
+	match unsafe {
+		user32::RegisterClipboardFormatW(WString::from_str(lpszFormat).as_ptr())
+	} {
+		0 => Err(GetLastError()),
+		format => Ok(co::CF(format as u16)),
+	}
+}
+
+/// [`RegisterWindowMessage`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerwindowmessagew)
+/// function.
+pub fn RegisterWindowMessage(lpString: &str) -> WinResult<co::WM> {
+	match unsafe {
+		user32::RegisterWindowMessageW(WString::from_str(lpString).as_ptr())
+	} {
+		0 => Err(GetLastError()),
+		msg => Ok(co::WM(msg)),
+	}
+}
+
+/// [`ReleaseCapture`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-releasecapture)
+/// function.
+pub fn ReleaseCapture() -> WinResult<()> {
+	bool_to_winresult(unsafe { user32::ReleaseCapture() })
+}
+
 /// [`SetCaretBlinkTime`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setcaretblinktime)
 /// function.
 pub fn SetCaretBlinkTime(uMSeconds: u32) -> WinResult<()> {
@@ -441,6 +643,17 @@ pub fn SetCaretPos(x: i32, y: i32) -> WinResult<()> {
 	bool_to_winresult(unsafe { user32::SetCaretPos(x, y) })
 }
 
+/// [`SetClipboardData`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setclipboarddata)
+/// function.
+///
+/// If the function succeeds, the memory block is owned by the system, and it
+/// must not be freed.
+pub fn SetClipboardData(uFormat: co::CF, hMem: HGLOBAL) -> WinResult<HGLOBAL> {
+	ptr_as_opt(unsafe { user32::SetClipboardData(uFormat.0 as u32, hMem.ptr) })
+		.map(|ptr| HGLOBAL { ptr })
+		.ok_or_else(|| GetLastError())
+}
+
 /// [`SetCursorPos`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setcursorpos)
 /// function.
 pub fn SetCursorPos(x: i32, y: i32) -> WinResult<()> {
@@ -459,6 +672,38 @@ pub fn SetProcessDPIAware() -> WinResult<()> {
 	bool_to_winresult(unsafe { user32::SetProcessDPIAware() })
 }
 
+/// [`SetProcessDpiAwarenessContext`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setprocessdpiawarenesscontext)
+/// function.
+///
+/// Available since Windows 10 version 1703. The function is resolved at
+/// runtime, and fails with
+/// [`ERROR::PROC_NOT_FOUND`](crate::co::ERROR::PROC_NOT_FOUND) on older
+/// systems.
+pub fn SetProcessDpiAwarenessContext(
+	value: co::DPI_AWARENESS_CONTEXT) -> WinResult<()>
+{
+	let func: extern "system" fn(HANDLE) -> BOOL = unsafe {
+		std::mem::transmute(user32_proc("SetProcessDpiAwarenessContext")?)
+	};
+	bool_to_winresult(func(value.0 as *mut _))
+}
+
+/// [`Shell_NotifyIcon`](https://docs.microsoft.com/en-us/windows/win32/api/shellapi/nf-shellapi-shell_notifyiconw)
+/// function.
+pub fn Shell_NotifyIcon(
+	dwMessage: co::NIM, lpData: &NOTIFYICONDATA) -> WinResult<()>
+{
+	match unsafe {
+		shell32::Shell_NotifyIconW(dwMessage.0, lpData as *const _ as *mut _)
+	} {
+		0 => Err(match GetLastError() {
+			co::ERROR::SUCCESS => co::ERROR::GEN_FAILURE, // last error is not always set
+			err => err,
+		}),
+		_ => Ok(()),
+	}
+}
+
 /// [`ShowCursor`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-showcursor)
 /// function.
 pub fn ShowCursor(bShow: bool) -> i32 {
@@ -493,6 +738,27 @@ pub unsafe fn SystemParametersInfo<T>(
 	)
 }
 
+/// [`SystemParametersInfoForDpi`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-systemparametersinfofordpi)
+/// function.
+///
+/// The `pvParam` type varies according to the `uiAction`. If you set it wrong,
+/// you're likely to cause a buffer overrun.
+///
+/// Available since Windows 10 version 1607. The function is resolved at
+/// runtime, and fails with
+/// [`ERROR::PROC_NOT_FOUND`](crate::co::ERROR::PROC_NOT_FOUND) on older
+/// systems.
+pub unsafe fn SystemParametersInfoForDpi<T>(
+	uiAction: co::SPI, uiParam: u32,
+	pvParam: &mut T, fWinIni: co::SPIF, dpi: u32) -> WinResult<()>
+{
+	let func: extern "system" fn(u32, u32, PVOID, u32, u32) -> BOOL =
+		std::mem::transmute(user32_proc("SystemParametersInfoForDpi")?);
+	bool_to_winresult(
+		func(uiAction.0, uiParam, pvParam as *mut _ as *mut _, fWinIni.0, dpi),
+	)
+}
+
 /// [`SystemTimeToFileTime`](https://docs.microsoft.com/en-us/windows/win32/api/timezoneapi/nf-timezoneapi-systemtimetofiletime)
 /// function.
 pub fn SystemTimeToFileTime(
@@ -529,6 +795,33 @@ pub fn SystemTimeToTzSpecificLocalTime(
 	)
 }
 
+/// [`TaskDialogIndirect`](https://docs.microsoft.com/en-us/windows/win32/api/commctrl/nf-commctrl-taskdialogindirect)
+/// function.
+///
+/// Returns the ID of the clicked button, the ID of the selected radio button,
+/// and whether the verification checkbox was checked.
+pub fn TaskDialogIndirect(
+	pTaskConfig: &TASKDIALOGCONFIG) -> WinResult<(i32, i32, bool)>
+{
+	let mut pnButton: i32 = 0;
+	let mut pnRadioButton: i32 = 0;
+	let mut pfVerificationFlagChecked: i32 = 0;
+
+	match co::ERROR(
+		unsafe {
+			comctl32::TaskDialogIndirect(
+				pTaskConfig as *const _ as *const _,
+				&mut pnButton,
+				&mut pnRadioButton,
+				&mut pfVerificationFlagChecked,
+			)
+		}
+	) {
+		co::ERROR::S_OK => Ok((pnButton, pnRadioButton, pfVerificationFlagChecked != 0)),
+		err => Err(err),
+	}
+}
+
 /// [`TrackMouseEvent`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-trackmouseevent)
 /// function.
 pub fn TrackMouseEvent(lpEventTrack: &mut TRACKMOUSEEVENT) -> WinResult<()> {
@@ -0,0 +1,287 @@
+use std::collections::HashMap;
+
+use crate::aliases::WinResult;
+use crate::co;
+use crate::gui::base::Base;
+use crate::handles::{HACCEL, HWND};
+use crate::structs::{ACCEL, MSG};
+
+/// Accelerator tables of the windows other than the main one, keyed by window
+/// handle.
+static mut WINDOW_ACCELS: Option<HashMap<usize, HACCEL>> = None;
+
+fn window_accels() -> &'static mut HashMap<usize, HACCEL> {
+	unsafe {
+		(*std::ptr::addr_of_mut!(WINDOW_ACCELS)).get_or_insert_with(HashMap::new)
+	}
+}
+
+/// Adds the privileged handlers which make the message loop use the given
+/// accelerator table while the window exists.
+pub(crate) fn add_accel_table_handlers(base_ref: &Base, haccel: HACCEL) {
+	let base_ptr = base_ref as *const Base;
+
+	base_ref.privileged_events_ref().wm(base_ref.create_wm(), move |_| {
+		let hwnd = unsafe { *(*base_ptr).hwnd_ref() };
+		window_accels().insert(hwnd.ptr as usize, haccel);
+		0
+	});
+
+	base_ref.privileged_events_ref().wm_nc_destroy(move || {
+		let hwnd = unsafe { *(*base_ptr).hwnd_ref() };
+		window_accels().remove(&(hwnd.ptr as usize));
+	});
+}
+
+/// If the top-level window has its own accelerator table, tries to translate
+/// the message with it, returning whether it was translated.
+pub(crate) fn translate_window_accel(hwnd_top_level: HWND, msg: &mut MSG) -> bool {
+	match window_accels().get(&(hwnd_top_level.ptr as usize)) {
+		Some(haccel) => hwnd_top_level.TranslateAccelerator(*haccel, msg).is_ok(),
+		None => false,
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Parses a keyboard shortcut like `"Ctrl+Shift+S"`, returning the modifiers
+/// and the virtual key code, or `None` if the string is not a valid shortcut.
+///
+/// Modifiers are `Ctrl`, `Shift` and `Alt`, in any order, and the key comes
+/// last. Keys can be a letter, a digit, `F1` to `F24`, a named key like `Del`
+/// or `PageUp`, or one of the characters `+ - , .`. Case is ignored.
+///
+/// The returned modifiers always include `co::ACCELF::VIRTKEY`.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use winsafe::co;
+/// use winsafe::gui::parse_shortcut;
+///
+/// assert!(parse_shortcut("Ctrl+Shift+S")
+///     == Some((co::ACCELF::VIRTKEY | co::ACCELF::CONTROL | co::ACCELF::SHIFT, co::VK::from('S' as u16))));
+/// assert!(parse_shortcut("Ctrl++") == Some((co::ACCELF::VIRTKEY | co::ACCELF::CONTROL, co::VK::OEM_PLUS)));
+/// assert!(parse_shortcut("Ctrl+Foo").is_none());
+/// ```
+pub fn parse_shortcut(shortcut: &str) -> Option<(co::ACCELF, co::VK)> {
+	let shortcut = shortcut.trim();
+	let (mods_part, key_part) = if shortcut.ends_with("++") || shortcut == "+" {
+		(&shortcut[..shortcut.len() - 1], "+") // the key itself is a plus sign
+	} else {
+		match shortcut.rfind('+') {
+			Some(idx) => (&shortcut[..idx + 1], &shortcut[idx + 1..]),
+			None => ("", shortcut),
+		}
+	};
+
+	let mut f_virt = co::ACCELF::VIRTKEY;
+	for modifier in mods_part.split('+').map(|m| m.trim()).filter(|m| !m.is_empty()) {
+		let flag = match modifier.to_ascii_lowercase().as_str() {
+			"ctrl" | "control" => co::ACCELF::CONTROL,
+			"shift" => co::ACCELF::SHIFT,
+			"alt" => co::ACCELF::ALT,
+			_ => return None,
+		};
+		if f_virt.has(flag) {
+			return None; // repeated modifier
+		}
+		f_virt |= flag;
+	}
+
+	parse_key(key_part.trim()).map(|vk| (f_virt, vk))
+}
+
+fn parse_key(key: &str) -> Option<co::VK> {
+	let lower = key.to_ascii_lowercase();
+	let mut chars = key.chars();
+
+	if let (Some(ch), None) = (chars.next(), chars.next()) { // single character
+		return match ch.to_ascii_uppercase() {
+			ch @ 'A'..='Z' | ch @ '0'..='9' => Some(co::VK(ch as u16)),
+			'+' => Some(co::VK::OEM_PLUS),
+			'-' => Some(co::VK::OEM_MINUS),
+			',' => Some(co::VK::OEM_COMMA),
+			'.' => Some(co::VK::OEM_PERIOD),
+			_ => None,
+		};
+	}
+
+	if let Some(num) = lower.strip_prefix('f') {
+		if let Ok(num @ 1..=24) = num.parse::<u16>() {
+			return Some(co::VK(co::VK::F1.0 + num - 1));
+		}
+	}
+
+	if let Some(num) = lower.strip_prefix("num") {
+		if let Ok(num @ 0..=9) = num.parse::<u16>() {
+			return Some(co::VK(co::VK::NUMPAD0.0 + num));
+		}
+	}
+
+	Some(match lower.as_str() {
+		"enter" | "return" => co::VK::RETURN,
+		"esc" | "escape" => co::VK::ESCAPE,
+		"space" => co::VK::SPACE,
+		"tab" => co::VK::TAB,
+		"backspace" | "back" => co::VK::BACK,
+		"del" | "delete" => co::VK::DELETE,
+		"ins" | "insert" => co::VK::INSERT,
+		"home" => co::VK::HOME,
+		"end" => co::VK::END,
+		"pgup" | "pageup" => co::VK::PRIOR,
+		"pgdn" | "pagedown" => co::VK::NEXT,
+		"left" => co::VK::LEFT,
+		"right" => co::VK::RIGHT,
+		"up" => co::VK::UP,
+		"down" => co::VK::DOWN,
+		"pause" => co::VK::PAUSE,
+		"plus" => co::VK::OEM_PLUS,
+		"minus" => co::VK::OEM_MINUS,
+		_ => return None,
+	})
+}
+
+//------------------------------------------------------------------------------
+
+/// Builds an accelerator table from keyboard shortcuts written as strings, like
+/// `"Ctrl+Shift+S"`, as parsed by
+/// [`parse_shortcut`](crate::gui::parse_shortcut).
+///
+/// The table can be set in
+/// [`WindowMainOpts::accel_table`](crate::gui::WindowMainOpts::accel_table) or
+/// [`WindowModalOpts::accel_table`](crate::gui::WindowModalOpts::accel_table).
+/// Pressing a shortcut sends a
+/// [`WM_COMMAND`](crate::gui::events::WindowEvents::wm_command_accel_menu)
+/// message with the command ID to the window.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use winsafe::gui::{AccelTableBuilder, WindowMain, WindowMainOpts};
+///
+/// const ID_SAVE: u16 = 1001;
+/// const ID_SAVE_AS: u16 = 1002;
+///
+/// let haccel = AccelTableBuilder::new()
+///     .add("Ctrl+S", ID_SAVE)
+///     .add("Ctrl+Shift+S", ID_SAVE_AS)
+///     .build()
+///     .unwrap();
+///
+/// let wnd = WindowMain::new(WindowMainOpts {
+///     accel_table: haccel,
+///     ..Default::default()
+/// });
+/// ```
+#[derive(Default)]
+pub struct AccelTableBuilder {
+	accels: Vec<ACCEL>,
+}
+
+impl AccelTableBuilder {
+	/// Creates a new, empty builder.
+	pub fn new() -> AccelTableBuilder {
+		Self::default()
+	}
+
+	/// Adds a keyboard shortcut, like `"Ctrl+Shift+S"`, which will send the
+	/// given command ID.
+	///
+	/// # Panics
+	///
+	/// Panics if the shortcut is not valid.
+	pub fn add(self, shortcut: &str, cmd_id: u16) -> AccelTableBuilder {
+		match parse_shortcut(shortcut) {
+			Some((modifiers, key)) => self.add_key(modifiers, key, cmd_id),
+			None => panic!("Invalid keyboard shortcut: \"{}\".", shortcut),
+		}
+	}
+
+	/// Adds a keyboard shortcut with the given modifiers and virtual key code,
+	/// which will send the given command ID.
+	pub fn add_key(mut self,
+		modifiers: co::ACCELF, key: co::VK, cmd_id: u16) -> AccelTableBuilder
+	{
+		self.accels.push(ACCEL {
+			fVirt: modifiers | co::ACCELF::VIRTKEY,
+			key,
+			cmd: cmd_id,
+		});
+		self
+	}
+
+	/// Returns the entries added so far.
+	pub fn entries(&self) -> &[ACCEL] {
+		&self.accels
+	}
+
+	/// Creates the accelerator table.
+	///
+	/// The table is not owned by the windows which use it. It must be destroyed
+	/// with [`DestroyAcceleratorTable`](crate::HACCEL::DestroyAcceleratorTable)
+	/// after the windows are destroyed.
+	pub fn build(mut self) -> WinResult<HACCEL> {
+		HACCEL::CreateAcceleratorTable(&mut self.accels)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const CTRL: co::ACCELF = co::ACCELF(co::ACCELF::VIRTKEY.0 | co::ACCELF::CONTROL.0);
+
+	#[test]
+	fn modifiers() {
+		assert_eq!(parse_shortcut("Ctrl+Shift+Alt+S"), Some((
+			co::ACCELF::VIRTKEY | co::ACCELF::CONTROL | co::ACCELF::SHIFT | co::ACCELF::ALT,
+			co::VK('S' as u16),
+		)));
+		assert_eq!(parse_shortcut("A"), Some((co::ACCELF::VIRTKEY, co::VK('A' as u16))));
+		assert_eq!(parse_shortcut(" Control + 5 "), Some((CTRL, co::VK('5' as u16))));
+		assert_eq!(parse_shortcut("Ctrl+Ctrl+A"), None); // repeated modifier
+		assert_eq!(parse_shortcut("Ctrl+control+A"), None);
+		assert_eq!(parse_shortcut("Meta+A"), None);
+	}
+
+	#[test]
+	fn plus_key() {
+		assert_eq!(parse_shortcut("Ctrl++"), Some((CTRL, co::VK::OEM_PLUS)));
+		assert_eq!(parse_shortcut("+"), Some((co::ACCELF::VIRTKEY, co::VK::OEM_PLUS)));
+		assert_eq!(parse_shortcut("Ctrl+Plus"), Some((CTRL, co::VK::OEM_PLUS)));
+		assert_eq!(parse_shortcut("Ctrl+"), None); // no key
+		assert_eq!(parse_shortcut(""), None);
+	}
+
+	#[test]
+	fn function_keys() {
+		assert_eq!(parse_key("F1"), Some(co::VK::F1));
+		assert_eq!(parse_key("F12"), Some(co::VK::F12));
+		assert_eq!(parse_key("F24"), Some(co::VK::F24));
+		assert_eq!(parse_key("F0"), None);
+		assert_eq!(parse_key("F25"), None);
+		assert_eq!(parse_key("F"), Some(co::VK('F' as u16))); // plain letter
+	}
+
+	#[test]
+	fn numpad_keys() {
+		assert_eq!(parse_key("Num0"), Some(co::VK::NUMPAD0));
+		assert_eq!(parse_key("Num9"), Some(co::VK::NUMPAD9));
+		assert_eq!(parse_key("Num10"), None);
+		assert_eq!(parse_key("Num"), None);
+	}
+
+	#[test]
+	fn case_insensitive() {
+		assert_eq!(parse_shortcut("ctrl+shift+s"), parse_shortcut("CTRL+SHIFT+S"));
+		assert_eq!(parse_shortcut("ctrl+shift+s"), Some((
+			co::ACCELF::VIRTKEY | co::ACCELF::CONTROL | co::ACCELF::SHIFT,
+			co::VK('S' as u16),
+		)));
+		assert_eq!(parse_key("f5"), Some(co::VK::F5));
+		assert_eq!(parse_key("nUm3"), Some(co::VK::NUMPAD3));
+		assert_eq!(parse_key("PgUp"), Some(co::VK::PRIOR));
+		assert_eq!(parse_key("ESCAPE"), Some(co::VK::ESCAPE));
+	}
+}
@@ -1,11 +1,17 @@
+use std::cell::Cell;
 use std::ptr::NonNull;
 
 use crate::aliases::WinResult;
 use crate::co;
-use crate::funcs::{DispatchMessage, GetMessage, TranslateMessage};
+use crate::funcs::{DispatchMessage, GetMessage, PostQuitMessage, TranslateMessage};
+use crate::gui::accel_table::translate_window_accel;
 use crate::gui::events::{ProcessResult, WindowEvents};
+use crate::gui::privs::system_dpi;
+use crate::gui::timer::remove_window_timers;
+use crate::gui::ui_thread::UiThreadQueue;
+use crate::gui::very_unsafe_cell::VeryUnsafeCell;
 use crate::handles::{HACCEL, HINSTANCE, HWND};
-use crate::msg::WndMsg;
+use crate::msg::{wm, WndMsg};
 use crate::structs::MSG;
 
 /// Base to `RawBase` and `DlgBase`.
@@ -15,6 +21,11 @@ pub(crate) struct Base {
 	ptr_parent: Option<NonNull<Base>>,
 	user_events: WindowEvents, // ordinary window events, inserted by user: only last added is executed (overwrite previous)
 	privileged_events: WindowEvents, // inserted internally to automate tasks: all will be executed
+	ui_thread_queue: UiThreadQueue, // closures sent by UiThreadSender
+	dpi: Cell<u32>, // zero while the window has the system DPI
+	dpi_rescalers: VeryUnsafeCell<Vec<Box<dyn Fn(u32, u32)>>>, // helpers which keep lengths in pixels, called with old and new DPI
+	cursor_setters: VeryUnsafeCell<Vec<Box<dyn Fn() -> bool>>>, // helpers which may set the cursor on WM_SETCURSOR
+	child_hwnds: VeryUnsafeCell<Vec<NonNull<HWND>>>, // handles of native controls, cleared when this window is destroyed
 }
 
 impl Base {
@@ -25,6 +36,11 @@ impl Base {
 			ptr_parent: parent_ref.map(|parent_ref| NonNull::from(parent_ref)), // ref implicitly converted to pointer
 			user_events: WindowEvents::new(),
 			privileged_events: WindowEvents::new(),
+			ui_thread_queue: UiThreadQueue::default(),
+			dpi: Cell::new(0),
+			dpi_rescalers: VeryUnsafeCell::new(Vec::default()),
+			cursor_setters: VeryUnsafeCell::new(Vec::default()),
+			child_hwnds: VeryUnsafeCell::new(Vec::default()),
 		}
 	}
 
@@ -33,7 +49,15 @@ impl Base {
 	}
 
 	pub fn set_hwnd(&mut self, hwnd: HWND) {
+		if hwnd.is_null() && !self.hwnd.is_null() { // window being destroyed
+			remove_window_timers(self.hwnd);
+			self.ui_thread_queue.clear(); // closures not run yet are dropped
+			for hwnd_ptr in self.child_hwnds.as_mut().drain(..) {
+				unsafe { *hwnd_ptr.as_ptr() = HWND::null_handle(); } // child controls are gone too
+			}
+		}
 		self.hwnd = hwnd;
+		self.user_events.set_locked(!hwnd.is_null()); // events can be removed, but not added
 	}
 
 	pub fn create_wm(&self) -> co::WM {
@@ -54,10 +78,16 @@ impl Base {
 		})
 	}
 
+	/// Registers the handle of a native control just created, which will be set
+	/// to null when this window is destroyed, so the control can be created
+	/// again.
+	pub fn add_child_hwnd(&self, hwnd_ptr: NonNull<HWND>) {
+		self.child_hwnds.as_mut().push(hwnd_ptr);
+	}
+
+	/// After the window is created, adding user events panics, but removing them
+	/// is allowed.
 	pub fn user_events_ref(&self) -> &WindowEvents {
-		if !self.hwnd.is_null() {
-			panic!("Cannot add event after window is created.");
-		}
 		&self.user_events
 	}
 
@@ -68,15 +98,90 @@ impl Base {
 		&self.privileged_events
 	}
 
+	pub fn ui_thread_queue(&self) -> &UiThreadQueue {
+		&self.ui_thread_queue
+	}
+
+	/// Returns the current DPI of the window, which is the system DPI until the
+	/// window receives a DPI change.
+	pub fn dpi(&self) -> WinResult<u32> {
+		match self.dpi.get() {
+			0 => system_dpi(),
+			dpi => Ok(dpi),
+		}
+	}
+
+	/// Stores the new DPI, then lets the helpers rescale their lengths. Called
+	/// before the window and its children are resized to the new DPI.
+	pub fn change_dpi(&self, new_dpi: u32) -> WinResult<()> {
+		let old_dpi = self.dpi()?;
+		self.dpi.set(new_dpi);
+		if new_dpi != old_dpi {
+			for rescaler in self.dpi_rescalers.iter() {
+				rescaler(old_dpi, new_dpi);
+			}
+		}
+		Ok(())
+	}
+
+	/// Adds a function to be called with the old and new DPI when the window
+	/// DPI changes. Like the privileged events, panics if the window is already
+	/// created.
+	pub fn privileged_dpi_rescaler<F>(&self, func: F)
+		where F: Fn(u32, u32) + 'static,
+	{
+		if !self.hwnd.is_null() {
+			panic!("Cannot add privileged event after window is created.");
+		}
+		self.dpi_rescalers.as_mut().push(Box::new(func));
+	}
+
+	/// Adds a function to be called on `WM_SETCURSOR`, which returns `true` if
+	/// it set the cursor. Like the privileged events, panics if the window is
+	/// already created.
+	pub fn privileged_cursor_setter<F>(&self, func: F)
+		where F: Fn() -> bool + 'static,
+	{
+		if !self.hwnd.is_null() {
+			panic!("Cannot add privileged event after window is created.");
+		}
+		self.cursor_setters.as_mut().push(Box::new(func));
+	}
+
+	/// Runs the cursor setters until one of them sets the cursor, in which case
+	/// `WM_SETCURSOR` must not reach the user handler nor the default procedure,
+	/// which would set the class cursor back.
+	pub fn run_cursor_setters(&self) -> bool {
+		self.cursor_setters.iter().any(|setter| setter())
+	}
+
 	pub fn process_effective_message(&mut self, wm_any: WndMsg) -> ProcessResult {
 		self.user_events.process_effective_message(wm_any)
 	}
 
 	pub fn process_privileged_messages(&mut self, wm_any: WndMsg) {
+		self.ui_thread_queue.process_message(wm_any.msg_id);
 		self.privileged_events.process_all_messages(wm_any);
+
+		if wm_any.msg_id == self.create_wm() {
+			if self.user_events.accepts_files() {
+				let hwnd = self.hwnd;
+				hwnd.DragAcceptFiles(true);
+				self.user_events.set_drop_files_off(move || hwnd.DragAcceptFiles(false));
+			}
+			if self.user_events.listens_clipboard() {
+				self.hwnd.AddClipboardFormatListener()
+					.unwrap_or_else(|err| PostQuitMessage(err));
+			}
+		} else if wm_any.msg_id == co::WM::DESTROY && self.user_events.listens_clipboard() {
+			self.hwnd.RemoveClipboardFormatListener()
+				.unwrap_or_else(|err| PostQuitMessage(err));
+		}
 	}
 
-	pub fn run_main_loop(haccel: Option<HACCEL>) -> WinResult<()> {
+	pub fn run_main_loop(
+		haccel: Option<HACCEL>, hmdiclient: Option<HWND>) -> WinResult<()>
+	{
 		loop {
 			let mut msg = MSG::default();
 			if !GetMessage(&mut msg, None, 0, 0)? {
@@ -94,15 +199,37 @@ impl Base {
 			let hwnd_top_level = msg.hwnd.GetAncestor(co::GA::ROOT)
 				.unwrap_or(msg.hwnd);
 
-			// If we have an accelerator table, try to translate the message.
+			// If we have a MDI client, try to translate the MDI child window
+			// keyboard shortcuts, like Ctrl+F4.
+			if let Some(hmdiclient) = hmdiclient {
+				if hmdiclient.TranslateMDISysAccel(&mut msg) {
+					continue; // message translated
+				}
+			}
+
+			// If the window has its own accelerator table, try to translate the
+			// message; otherwise try the main accelerator table, if any.
+			if translate_window_accel(hwnd_top_level, &mut msg) {
+				continue; // message translated
+			}
 			if let Some(haccel) = haccel {
 				if hwnd_top_level.TranslateAccelerator(haccel, &mut msg).is_ok() {
 					continue; // message translated
 				}
 			}
 
-			// Try to process keyboard actions for child controls.
-			if hwnd_top_level.IsDialogMessage(&mut msg) {
+			// Try to process keyboard actions for child controls. Within a MDI
+			// frame, the child controls belong to the active MDI child window.
+			let hwnd_dlg = match hmdiclient {
+				Some(hmdiclient) if hwnd_top_level == hmdiclient.GetAncestor(co::GA::ROOT)
+					.unwrap_or(hmdiclient) =>
+				{
+					hmdiclient.SendMessage(wm::MdiGetActive {})
+						.unwrap_or(hwnd_top_level)
+				},
+				_ => hwnd_top_level,
+			};
+			if hwnd_dlg.IsDialogMessage(&mut msg) {
 				continue;
 			}
 
@@ -111,3 +238,45 @@ impl Base {
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn close_msg() -> WndMsg {
+		WndMsg { msg_id: co::WM::CLOSE, wparam: 0, lparam: 0 }
+	}
+
+	fn fake_hwnd() -> HWND {
+		unsafe { HWND::from_ptr(0x1234 as *mut std::ffi::c_void) }
+	}
+
+	#[test]
+	fn remove_user_event_after_creation() {
+		let mut base = Base::new(None, false);
+		let handle = base.user_events_ref().wm(co::WM::CLOSE, |_| 0);
+		base.set_hwnd(fake_hwnd()); // simulate window creation
+
+		assert!(base.user_events_ref().remove(handle));
+		assert!(!base.user_events_ref().remove(handle));
+		assert_eq!(base.process_effective_message(close_msg()), ProcessResult::NotHandled);
+	}
+
+	#[test]
+	fn clear_user_event_after_creation() {
+		let mut base = Base::new(None, false);
+		base.user_events_ref().wm(co::WM::CLOSE, |_| 0);
+		base.set_hwnd(fake_hwnd());
+
+		assert!(base.user_events_ref().clear_wm(co::WM::CLOSE));
+		assert_eq!(base.process_effective_message(close_msg()), ProcessResult::NotHandled);
+	}
+
+	#[test]
+	#[should_panic(expected = "Cannot add event after window is created.")]
+	fn add_user_event_after_creation() {
+		let mut base = Base::new(None, false);
+		base.set_hwnd(fake_hwnd());
+		base.user_events_ref().wm(co::WM::CLOSE, |_| 0);
+	}
+}
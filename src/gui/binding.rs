@@ -0,0 +1,429 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use std::str::FromStr;
+
+use crate::gui::base::Base;
+use crate::gui::native_controls::{CheckBox, ComboBox, Edit};
+use crate::gui::very_unsafe_cell::VeryUnsafeCell;
+
+/// A model value which notifies its changes, and which can be two-way bound to
+/// the controls.
+///
+/// When bound, any change to the value is written to the control, and any
+/// change made by the user in the control is converted, validated and stored
+/// in the value. Since the bindings use the privileged events, the user
+/// handlers of the controls keep working.
+///
+/// Like the controls it's bound to, an `Observable` can only be used in the
+/// GUI thread.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use winsafe::gui::{CheckBox, Converter, Edit, Observable};
+///
+/// let txt_name: Edit; // initialize them somewhere...
+/// let txt_age: Edit;
+/// let chk_admin: CheckBox;
+///
+/// let name = Observable::new(String::default());
+/// name.bind_edit(&txt_name, Converter::identity());
+///
+/// let age = Observable::new(18_u32);
+/// age.add_validator(|age| match *age {
+///     18..=120 => Ok(()),
+///     _ => Err("Age must be between 18 and 120.".to_owned()),
+/// });
+/// age.bind_edit(&txt_age, Converter::parse());
+/// age.subscribe_error(|err| println!("{}", err.unwrap_or("Ok")));
+///
+/// let admin = Observable::new(false);
+/// admin.bind_check_box(&chk_admin, Converter::identity());
+///
+/// admin.set(true).unwrap(); // check box is checked
+/// ```
+#[derive(Clone)]
+pub struct Observable<T>(Rc<VeryUnsafeCell<Obj<T>>>);
+
+struct Obj<T> { // actual fields of Observable
+	value: T,
+	error: Option<String>,
+	notifying: bool, // subscribers are being called
+	changed_meanwhile: bool, // set by a subscriber, which must be notified again
+	validators: Vec<Box<dyn Fn(&T) -> Result<(), String>>>,
+	subscribers: Vec<Box<dyn FnMut(&T)>>,
+	error_subscribers: Vec<Box<dyn FnMut(Option<&str>)>>,
+}
+
+impl<T: Clone + PartialEq + 'static> Observable<T> {
+	/// Creates a new `Observable` with the given initial value, which is not
+	/// validated.
+	pub fn new(value: T) -> Observable<T> {
+		Self(
+			Rc::new(VeryUnsafeCell::new(
+				Obj {
+					value,
+					error: None,
+					notifying: false,
+					changed_meanwhile: false,
+					validators: Vec::default(),
+					subscribers: Vec::default(),
+					error_subscribers: Vec::default(),
+				},
+			)),
+		)
+	}
+
+	/// Returns a copy of the current value.
+	pub fn get(&self) -> T {
+		self.0.value.clone()
+	}
+
+	/// Validates and stores the value, notifying the subscribers and updating
+	/// the bound controls if it changed.
+	///
+	/// If a validator fails, the value is not stored and the error is returned,
+	/// also notifying the error subscribers.
+	///
+	/// A subscriber may set the value again; in this case, after all the
+	/// subscribers are notified, they are notified again with the new value.
+	pub fn set(&self, value: T) -> Result<(), String> {
+		if let Err(err) = self.validate(&value) {
+			self.set_error(Some(err.clone()));
+			return Err(err);
+		}
+		self.set_error(None);
+
+		if value != self.0.value {
+			self.0.as_mut().value = value;
+			if self.0.notifying {
+				self.0.as_mut().changed_meanwhile = true; // outer set() notifies it
+			} else {
+				self.notify_subscribers();
+			}
+		}
+		Ok(())
+	}
+
+	/// Returns the error of the last value which failed to be converted or
+	/// validated, if any.
+	pub fn error(&self) -> Option<String> {
+		self.0.error.clone()
+	}
+
+	/// Adds a validator, which must return `Ok` for the value to be stored.
+	/// Validators are run in the order they were added.
+	pub fn add_validator<F>(&self, func: F)
+		where F: Fn(&T) -> Result<(), String> + 'static,
+	{
+		self.0.as_mut().validators.push(Box::new(func));
+	}
+
+	/// Adds a function to be called after the value changes.
+	pub fn subscribe<F>(&self, func: F)
+		where F: FnMut(&T) + 'static,
+	{
+		self.0.as_mut().subscribers.push(Box::new(func));
+	}
+
+	/// Adds a function to be called when a value fails to be converted or
+	/// validated, receiving the error, or when a valid value clears the error,
+	/// receiving `None`.
+	pub fn subscribe_error<F>(&self, func: F)
+		where F: FnMut(Option<&str>) + 'static,
+	{
+		self.0.as_mut().error_subscribers.push(Box::new(func));
+	}
+
+	/// Two-way binds the value to the text of an [`Edit`](crate::gui::Edit),
+	/// which is updated at each
+	/// [`EN_CHANGE`](crate::gui::events::EditEvents::en_change).
+	///
+	/// # Panics
+	///
+	/// Panics if the control or the parent window are already created.
+	pub fn bind_edit(&self, edit: &Edit, conv: Converter<T, String>) {
+		let on_change = self.bind(edit.base_ref().parent_ref(), conv,
+			{
+				let edit = edit.clone();
+				move || edit.text().unwrap_or_default()
+			},
+			{
+				let edit = edit.clone();
+				move |text| if !edit.hwnd().is_null() { edit.set_text(&text).ok(); }
+			},
+		);
+		edit.on().privileged_en_change(on_change);
+	}
+
+	/// Two-way binds the value to the check state of a
+	/// [`CheckBox`](crate::gui::CheckBox), which is updated at each
+	/// [`BN_CLICKED`](crate::gui::events::ButtonEvents::bn_clicked).
+	///
+	/// # Panics
+	///
+	/// Panics if the control or the parent window are already created.
+	pub fn bind_check_box(&self, chk: &CheckBox, conv: Converter<T, bool>) {
+		let on_change = self.bind(chk.base_ref().parent_ref(), conv,
+			{
+				let chk = chk.clone();
+				move || chk.is_checked()
+			},
+			{
+				let chk = chk.clone();
+				move |checked| if !chk.hwnd().is_null() { chk.set_check(checked) }
+			},
+		);
+		chk.on().privileged_bn_clicked(on_change);
+	}
+
+	/// Two-way binds the value to the selected index of a
+	/// [`ComboBox`](crate::gui::ComboBox), which is updated at each
+	/// [`CBN_SELCHANGE`](crate::gui::events::ComboBoxEvents::cbn_sel_change).
+	///
+	/// # Panics
+	///
+	/// Panics if the control or the parent window are already created.
+	pub fn bind_combo_box(&self, cmb: &ComboBox, conv: Converter<T, Option<u32>>) {
+		let on_change = self.bind(cmb.base_ref().parent_ref(), conv,
+			{
+				let cmb = cmb.clone();
+				move || cmb.items().selected_index()
+			},
+			{
+				let cmb = cmb.clone();
+				move |index| if !cmb.hwnd().is_null() { cmb.items().set_selected(index) }
+			},
+		);
+		cmb.on().privileged_cbn_sel_change(on_change);
+	}
+
+	fn validate(&self, value: &T) -> Result<(), String> {
+		self.0.validators.iter()
+			.map(|validator| validator(value))
+			.find(|res| res.is_err())
+			.unwrap_or(Ok(()))
+	}
+
+	fn notify_subscribers(&self) {
+		self.0.as_mut().notifying = true;
+		loop {
+			self.0.as_mut().changed_meanwhile = false;
+			let value = self.get();
+
+			// Taken out while running, since subscribers may subscribe again.
+			let mut subscribers = std::mem::take(&mut self.0.as_mut().subscribers);
+			for subscriber in subscribers.iter_mut() {
+				subscriber(&value);
+			}
+			let added = std::mem::replace(&mut self.0.as_mut().subscribers, subscribers);
+			self.0.as_mut().subscribers.extend(added);
+
+			if !self.0.changed_meanwhile {
+				break;
+			}
+		}
+		self.0.as_mut().notifying = false;
+	}
+
+	fn set_error(&self, error: Option<String>) {
+		if error != self.0.error {
+			self.0.as_mut().error = error;
+			let error = self.error();
+
+			let mut subscribers = std::mem::take(&mut self.0.as_mut().error_subscribers);
+			for subscriber in subscribers.iter_mut() {
+				subscriber(error.as_deref());
+			}
+			let added = std::mem::replace(&mut self.0.as_mut().error_subscribers, subscribers);
+			self.0.as_mut().error_subscribers.extend(added);
+		}
+	}
+
+	/// Writes the value to the control when it changes, and when the control is
+	/// created. Returns the function to be called when the control changes.
+	///
+	/// The write function must do nothing while the control is not created.
+	fn bind<C, R, W>(&self,
+		parent_ref: &Base, conv: Converter<T, C>,
+		read_ctrl: R, write_ctrl: W) -> impl FnMut() + 'static
+		where C: 'static,
+			R: Fn() -> C + 'static,
+			W: Fn(C) + 'static,
+	{
+		let conv = Rc::new(conv);
+		let updating = Rc::new(Cell::new(false)); // prevents the control notifications from looping back
+		let write = Rc::new({
+			let conv = conv.clone();
+			let updating = updating.clone();
+			move |value: &T| {
+				if !updating.get() {
+					updating.set(true);
+					write_ctrl((conv.to_ctrl)(value));
+					updating.set(false);
+				}
+			}
+		});
+
+		self.subscribe({
+			let write = write.clone();
+			move |value| write(value)
+		});
+
+		parent_ref.privileged_events_ref().wm(parent_ref.create_wm(), {
+			let me = self.clone();
+			let write = write.clone();
+			move |_| { write(&me.get()); 0 } // control was created in a previous privileged handler
+		});
+
+		let me = self.clone();
+		move || {
+			if !updating.get() {
+				updating.set(true);
+				let resync = match (conv.from_ctrl)(read_ctrl()) {
+					Ok(value) => me.set(value.clone()).is_ok() // error already stored
+						&& me.get() != value, // a subscriber replaced it
+					Err(err) => { me.set_error(Some(err)); false },
+				};
+				updating.set(false);
+
+				if resync {
+					write(&me.get());
+				}
+			}
+		}
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Converts an [`Observable`](crate::gui::Observable) value to and from a
+/// control property.
+pub struct Converter<T, C> {
+	to_ctrl: Box<dyn Fn(&T) -> C>,
+	from_ctrl: Box<dyn Fn(C) -> Result<T, String>>,
+}
+
+impl<T, C> Converter<T, C> {
+	/// Creates a new `Converter` with the given functions. The conversion from
+	/// the control may fail, returning the error message.
+	pub fn new<F, G>(to_ctrl: F, from_ctrl: G) -> Converter<T, C>
+		where F: Fn(&T) -> C + 'static,
+			G: Fn(C) -> Result<T, String> + 'static,
+	{
+		Self {
+			to_ctrl: Box::new(to_ctrl),
+			from_ctrl: Box::new(from_ctrl),
+		}
+	}
+}
+
+impl<T: Clone + 'static> Converter<T, T> {
+	/// Creates a new `Converter` which passes the value unchanged, for a value
+	/// of the same type of the control property.
+	pub fn identity() -> Converter<T, T> {
+		Self::new(|value| value.clone(), |value| Ok(value))
+	}
+}
+
+impl<T: FromStr + ToString + 'static> Converter<T, String> {
+	/// Creates a new `Converter` which formats the value with `to_string`, and
+	/// parses the text, ignoring leading and trailing spaces, with `parse`.
+	pub fn parse() -> Converter<T, String> {
+		Self::new(
+			|value| value.to_string(),
+			|text| text.trim().parse()
+				.map_err(|_| format!("Invalid value: \"{}\".", text.trim())),
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::cell::RefCell;
+
+	use super::*;
+
+	fn recorder<T: Clone + 'static>() -> (Rc<RefCell<Vec<T>>>, impl FnMut(&T)) {
+		let log = Rc::new(RefCell::new(Vec::default()));
+		let log2 = log.clone();
+		(log, move |value: &T| log2.borrow_mut().push(value.clone()))
+	}
+
+	#[test]
+	fn validator_blocks_store() {
+		let obs = Observable::new(1);
+		obs.add_validator(|n| if *n < 10 { Ok(()) } else { Err("Too big.".to_owned()) });
+		let (log, func) = recorder();
+		obs.subscribe(func);
+
+		assert_eq!(obs.set(20), Err("Too big.".to_owned()));
+		assert_eq!(obs.get(), 1);
+		assert_eq!(obs.error(), Some("Too big.".to_owned()));
+
+		assert_eq!(obs.set(5), Ok(()));
+		assert_eq!(obs.get(), 5);
+		assert_eq!(obs.error(), None);
+		assert_eq!(*log.borrow(), [5]);
+	}
+
+	#[test]
+	fn error_subscribers() {
+		let obs = Observable::new(1);
+		obs.add_validator(|n| if *n > 0 { Ok(()) } else { Err("Not positive.".to_owned()) });
+		let errors = Rc::new(RefCell::new(Vec::default()));
+		obs.subscribe_error({
+			let errors = errors.clone();
+			move |err| errors.borrow_mut().push(err.map(|e| e.to_owned()))
+		});
+
+		obs.set(0).ok();
+		obs.set(-1).ok(); // same error, not notified again
+		obs.set(2).unwrap();
+		obs.set(3).unwrap(); // no error to clear
+		assert_eq!(*errors.borrow(), [Some("Not positive.".to_owned()), None]);
+	}
+
+	#[test]
+	fn parse_converter() {
+		let conv = Converter::<u32, String>::parse();
+		assert_eq!((conv.to_ctrl)(&42), "42");
+		assert_eq!((conv.from_ctrl)(" 42 ".to_owned()), Ok(42));
+		assert_eq!((conv.from_ctrl)(" abc ".to_owned()), Err("Invalid value: \"abc\".".to_owned()));
+		assert_eq!((conv.from_ctrl)("-1".to_owned()), Err("Invalid value: \"-1\".".to_owned()));
+		assert!((conv.from_ctrl)("".to_owned()).is_err());
+	}
+
+	#[test]
+	fn subscribe_from_subscriber() {
+		let obs = Observable::new(0);
+		let (log, func) = recorder();
+		let mut func = Some(func);
+		obs.subscribe({
+			let obs = obs.clone();
+			move |_| if let Some(func) = func.take() {
+				obs.subscribe(func); // added while notifying
+			}
+		});
+
+		obs.set(1).unwrap();
+		assert!(log.borrow().is_empty()); // not notified of the current change
+		obs.set(2).unwrap();
+		assert_eq!(*log.borrow(), [2]);
+	}
+
+	#[test]
+	fn nested_set_notifies_again() {
+		let obs = Observable::new(0);
+		obs.subscribe({
+			let obs = obs.clone();
+			move |n| if *n > 10 { obs.set(10).unwrap(); } // clamp
+		});
+		let (log, func) = recorder();
+		obs.subscribe(func);
+
+		obs.set(15).unwrap();
+		assert_eq!(obs.get(), 10);
+		assert_eq!(*log.borrow(), [15, 10]); // last notification has the final value
+	}
+}
@@ -0,0 +1,324 @@
+use std::path::PathBuf;
+
+use crate::aliases::WinResult;
+use crate::co;
+use crate::funcs::{
+	CloseClipboard,
+	EmptyClipboard,
+	EnumClipboardFormats,
+	GetClipboardData,
+	IsClipboardFormatAvailable,
+	RegisterClipboardFormat,
+	SetClipboardData,
+};
+use crate::gui::drag_drop::{
+	bytes_to_files,
+	bytes_to_hglobal,
+	bytes_to_text,
+	files_to_bytes,
+	hglobal_to_bytes,
+	text_to_bytes,
+};
+use crate::gui::traits::{baseref_from_parent, Parent};
+use crate::structs::BITMAPINFOHEADER;
+
+/// The open [clipboard](https://docs.microsoft.com/en-us/windows/win32/dataxchg/clipboard),
+/// which is closed with [`CloseClipboard`](crate::CloseClipboard) when the
+/// object goes out of scope.
+///
+/// Only one window can have the clipboard open at a time, so keep the object
+/// alive for the shortest time possible.
+///
+/// The first `set_*` call empties the clipboard and takes its ownership, so
+/// the same data can be set in several formats at once.
+///
+/// To be notified when the clipboard changes, use the
+/// [`wm_clipboard_update`](crate::gui::events::WindowEvents::wm_clipboard_update)
+/// event.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use winsafe::gui::{Clipboard, WindowMain};
+///
+/// let wnd: WindowMain; // initialize it somewhere...
+///
+/// {
+///     let mut clip = Clipboard::open(&wnd).unwrap();
+///     clip.set_text("Hello").unwrap();
+///     clip.set_html("<b>Hello</b>").unwrap();
+/// } // clipboard closed here
+///
+/// let text = Clipboard::open(&wnd).unwrap().text().unwrap();
+/// ```
+///
+/// Copying structured rows between applications with a custom format:
+///
+/// ```rust,ignore
+/// use winsafe::gui::{Clipboard, WindowMain};
+///
+/// let wnd: WindowMain; // initialize it somewhere...
+/// let rows: Vec<u8>; // serialized somehow...
+///
+/// let mut clip = Clipboard::open(&wnd).unwrap();
+/// let fmt = clip.register_format("MyCompany.Rows").unwrap();
+/// clip.set_custom(fmt, &rows).unwrap();
+/// ```
+pub struct Clipboard {
+	emptied: bool,
+}
+
+impl Drop for Clipboard {
+	fn drop(&mut self) {
+		CloseClipboard().ok();
+	}
+}
+
+impl Clipboard {
+	/// Opens the clipboard, associating it with the given window.
+	///
+	/// Fails if another window has the clipboard open.
+	///
+	/// # Panics
+	///
+	/// Panics if the window is not created yet.
+	pub fn open(owner: &dyn Parent) -> WinResult<Clipboard> {
+		let hwnd = *baseref_from_parent(owner).hwnd_ref();
+		if hwnd.is_null() {
+			panic!("Cannot open the clipboard before the window is created.");
+		}
+		hwnd.OpenClipboard()?;
+		Ok(Self { emptied: false })
+	}
+
+	/// Tells whether the clipboard has data in the given format.
+	pub fn is_available(&self, format: co::CF) -> bool {
+		IsClipboardFormatAvailable(format)
+	}
+
+	/// Returns all the formats currently on the clipboard.
+	pub fn formats(&self) -> WinResult<Vec<co::CF>> {
+		EnumClipboardFormats()
+	}
+
+	/// Registers a custom format with
+	/// [`RegisterClipboardFormat`](crate::RegisterClipboardFormat). Any
+	/// application registering the same name receives the same format.
+	pub fn register_format(&self, name: &str) -> WinResult<co::CF> {
+		RegisterClipboardFormat(name)
+	}
+
+	/// Empties the clipboard, taking its ownership.
+	pub fn clear(&mut self) -> WinResult<()> {
+		EmptyClipboard()?;
+		self.emptied = true;
+		Ok(())
+	}
+
+	/// Retrieves the text in the [`CF::UNICODETEXT`](crate::co::CF::UNICODETEXT)
+	/// format, if any. The system converts the other text formats
+	/// automatically.
+	pub fn text(&self) -> WinResult<Option<String>> {
+		Ok(self.custom(co::CF::UNICODETEXT)?
+			.map(|bytes| bytes_to_text(&bytes)))
+	}
+
+	/// Sets the text in the [`CF::UNICODETEXT`](crate::co::CF::UNICODETEXT)
+	/// format.
+	pub fn set_text(&mut self, text: &str) -> WinResult<()> {
+		self.set_custom(co::CF::UNICODETEXT, &text_to_bytes(text))
+	}
+
+	/// Retrieves the file paths in the [`CF::HDROP`](crate::co::CF::HDROP)
+	/// format, if any, which is used by Explorer when files are copied.
+	pub fn files(&self) -> WinResult<Option<Vec<PathBuf>>> {
+		Ok(self.custom(co::CF::HDROP)?
+			.map(|bytes| bytes_to_files(&bytes)))
+	}
+
+	/// Sets the file paths in the [`CF::HDROP`](crate::co::CF::HDROP) format,
+	/// so they can be pasted in Explorer.
+	pub fn set_files(&mut self, paths: &[PathBuf]) -> WinResult<()> {
+		self.set_custom(co::CF::HDROP, &files_to_bytes(paths))
+	}
+
+	/// Retrieves the image in the [`CF::DIB`](crate::co::CF::DIB) format, if
+	/// any. Returns the header and the data following it: the color masks or
+	/// the color table, if any, then the pixels.
+	pub fn dib(&self) -> WinResult<Option<(BITMAPINFOHEADER, Vec<u8>)>> {
+		Ok(self.custom(co::CF::DIB)?.and_then(|bytes| bytes_to_dib(&bytes)))
+	}
+
+	/// Sets the image in the [`CF::DIB`](crate::co::CF::DIB) format. The data
+	/// following the header are the color masks or the color table, if any,
+	/// then the pixels.
+	pub fn set_dib(&mut self, bih: &BITMAPINFOHEADER, data: &[u8]) -> WinResult<()> {
+		self.set_custom(co::CF::DIB, &dib_to_bytes(bih, data))
+	}
+
+	/// Retrieves the fragment of the
+	/// [HTML format](https://docs.microsoft.com/en-us/windows/win32/dataxchg/html-clipboard-format),
+	/// if any, which is used by browsers and office applications.
+	pub fn html(&self) -> WinResult<Option<String>> {
+		let format = self.register_format("HTML Format")?;
+		Ok(self.custom(format)?.and_then(|bytes| bytes_to_html(&bytes)))
+	}
+
+	/// Sets the given fragment in the
+	/// [HTML format](https://docs.microsoft.com/en-us/windows/win32/dataxchg/html-clipboard-format),
+	/// wrapping it in the required header. Browsers and office applications
+	/// paste it as rich text.
+	///
+	/// You'll usually want to set the plain text too, with
+	/// [`set_text`](crate::gui::Clipboard::set_text).
+	pub fn set_html(&mut self, fragment: &str) -> WinResult<()> {
+		let format = self.register_format("HTML Format")?;
+		self.set_custom(format, &html_to_bytes(fragment))
+	}
+
+	/// Retrieves the raw bytes of the given format, if any, usually one
+	/// registered with
+	/// [`register_format`](crate::gui::Clipboard::register_format).
+	///
+	/// Formats which are not stored in global memory, like
+	/// [`CF::BITMAP`](crate::co::CF::BITMAP), are not supported.
+	pub fn custom(&self, format: co::CF) -> WinResult<Option<Vec<u8>>> {
+		if !self.is_available(format) {
+			return Ok(None);
+		}
+		let hglobal = GetClipboardData(format)?;
+		hglobal_to_bytes(hglobal).map(Some)
+	}
+
+	/// Sets the raw bytes of the given format, usually one registered with
+	/// [`register_format`](crate::gui::Clipboard::register_format).
+	pub fn set_custom(&mut self, format: co::CF, bytes: &[u8]) -> WinResult<()> {
+		if !self.emptied {
+			self.clear()?;
+		}
+		let hglobal = bytes_to_hglobal(bytes)?;
+		SetClipboardData(format, hglobal)
+			.map(|_| ()) // memory block now owned by the system
+			.inspect_err(|_| { hglobal.GlobalFree().ok(); })
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Splits a [`CF::DIB`](crate::co::CF::DIB) block into the header and the data
+/// following it.
+fn bytes_to_dib(bytes: &[u8]) -> Option<(BITMAPINFOHEADER, Vec<u8>)> {
+	let hdr_sz = bytes.get(0..4) // biSize, may be bigger if a BITMAPV5HEADER
+		.map(|sz| u32::from_ne_bytes([sz[0], sz[1], sz[2], sz[3]]) as usize)?;
+	if hdr_sz < std::mem::size_of::<BITMAPINFOHEADER>() || bytes.len() < hdr_sz {
+		return None;
+	}
+	let bih = unsafe {
+		std::ptr::read_unaligned(bytes.as_ptr() as *const BITMAPINFOHEADER)
+	};
+	Some((bih, bytes[hdr_sz..].to_vec()))
+}
+
+/// Joins the header and the data following it into a
+/// [`CF::DIB`](crate::co::CF::DIB) block.
+fn dib_to_bytes(bih: &BITMAPINFOHEADER, data: &[u8]) -> Vec<u8> {
+	let mut bytes = unsafe {
+		std::slice::from_raw_parts(
+			bih as *const _ as *const u8,
+			std::mem::size_of::<BITMAPINFOHEADER>(),
+		)
+	}.to_vec();
+	bytes.extend_from_slice(data);
+	bytes
+}
+
+/// Extracts the fragment of an HTML format block, using the byte offsets in
+/// its header.
+fn bytes_to_html(bytes: &[u8]) -> Option<String> {
+	let header = String::from_utf8_lossy(bytes);
+	let offset = |name: &str| -> Option<usize> {
+		let pos = header.find(name)? + name.len();
+		header[pos..].split(|ch: char| ch == '\r' || ch == '\n')
+			.next()?.trim().parse().ok()
+	};
+	let start = offset("StartFragment:")?;
+	let end = offset("EndFragment:")?;
+	bytes.get(start..end)
+		.map(|frag| String::from_utf8_lossy(frag).into_owned())
+}
+
+/// Wraps the fragment in an HTML format block, with the header and its byte
+/// offsets.
+fn html_to_bytes(fragment: &str) -> Vec<u8> {
+	const PREFIX: &str = "<html><body>\r\n<!--StartFragment-->";
+	const SUFFIX: &str = "<!--EndFragment-->\r\n</body></html>";
+	let header = |start_html: usize, end_html: usize,
+		start_frag: usize, end_frag: usize| -> String
+	{
+		format!("Version:0.9\r\nStartHTML:{:010}\r\nEndHTML:{:010}\r\n\
+			StartFragment:{:010}\r\nEndFragment:{:010}\r\n",
+			start_html, end_html, start_frag, end_frag)
+	};
+
+	let start_html = header(0, 0, 0, 0).len(); // offsets have fixed width
+	let start_frag = start_html + PREFIX.len();
+	let end_frag = start_frag + fragment.len();
+	let end_html = end_frag + SUFFIX.len();
+
+	let mut bytes = header(start_html, end_html, start_frag, end_frag).into_bytes();
+	bytes.extend_from_slice(PREFIX.as_bytes());
+	bytes.extend_from_slice(fragment.as_bytes());
+	bytes.extend_from_slice(SUFFIX.as_bytes());
+	bytes.push(0); // terminating null
+	bytes
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn offset(bytes: &[u8], name: &str) -> usize {
+		let text = String::from_utf8_lossy(bytes);
+		let pos = text.find(name).unwrap() + name.len();
+		text[pos..pos + 10].parse().unwrap()
+	}
+
+	#[test]
+	fn html_round_trip() {
+		for frag in ["", "<b>Hello</b>", "<i>ação</i> ☃ 𝄞 日本語"].iter() {
+			let bytes = html_to_bytes(frag);
+			assert_eq!(bytes_to_html(&bytes).as_deref(), Some(*frag));
+
+			// Offsets are in bytes, not chars.
+			assert_eq!(&bytes[offset(&bytes, "StartFragment:")..offset(&bytes, "EndFragment:")],
+				frag.as_bytes());
+			let html = &bytes[offset(&bytes, "StartHTML:")..offset(&bytes, "EndHTML:")];
+			assert!(html.starts_with(b"<html>"));
+			assert!(html.ends_with(b"</html>"));
+			assert_eq!(offset(&bytes, "EndHTML:"), bytes.len() - 1); // before the null
+		}
+	}
+
+	#[test]
+	fn html_malformed() {
+		assert!(bytes_to_html(b"").is_none());
+		assert!(bytes_to_html(b"Version:0.9\r\nStartFragment:0000000010\r\n").is_none()); // no end
+		assert!(bytes_to_html(b"StartFragment:5\r\nEndFragment:9000\r\n").is_none()); // past the end
+	}
+
+	#[test]
+	fn dib_round_trip() {
+		let mut bih = BITMAPINFOHEADER::default(); // biSize is private, set by default()
+		bih.biWidth = 2;
+		bih.biHeight = -3;
+		bih.biBitCount = 32;
+		let pixels = (0..24).collect::<Vec<u8>>();
+		let (bih2, data) = bytes_to_dib(&dib_to_bytes(&bih, &pixels)).unwrap();
+		assert_eq!((bih2.biWidth, bih2.biHeight, bih2.biBitCount), (2, -3, 32));
+		assert_eq!(data, pixels);
+
+		let bytes = dib_to_bytes(&bih, &[]);
+		assert!(bytes_to_dib(&bytes[..bytes.len() - 1]).is_none()); // truncated header
+		assert!(bytes_to_dib(&[]).is_none());
+	}
+}
@@ -0,0 +1,106 @@
+use crate::aliases::WinResult;
+use crate::co;
+use crate::funcs::ChooseColor;
+use crate::gui::traits::{baseref_from_parent, Parent};
+use crate::structs::{CHOOSECOLOR, COLORREF};
+
+/// The system [color dialog](https://docs.microsoft.com/en-us/windows/win32/dlgbox/color-dialog-box),
+/// created with [`ChooseColor`](crate::ChooseColor).
+///
+/// The 16 custom colors defined by the user are kept in the object between
+/// calls to [`show`](crate::gui::ColorDialog::show). They can be retrieved with
+/// [`custom_colors`](crate::gui::ColorDialog::custom_colors) to be saved, and
+/// restored with
+/// [`set_custom_colors`](crate::gui::ColorDialog::set_custom_colors).
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use winsafe::COLORREF;
+/// use winsafe::gui::{ColorDialog, WindowMain};
+///
+/// let wnd: WindowMain; // initialize it somewhere...
+///
+/// let mut dlg = ColorDialog::new();
+/// dlg.set_color(COLORREF::new(0, 120, 215));
+///
+/// if let Some(color) = dlg.show(&wnd).unwrap() {
+///     println!("Chosen: {}", color);
+/// }
+/// ```
+pub struct ColorDialog {
+	color: COLORREF,
+	custom_colors: [COLORREF; 16],
+	full_open: bool,
+}
+
+impl Default for ColorDialog {
+	fn default() -> Self {
+		Self {
+			color: COLORREF::new(0, 0, 0),
+			custom_colors: [COLORREF::new(255, 255, 255); 16],
+			full_open: false,
+		}
+	}
+}
+
+impl ColorDialog {
+	/// Creates a new color dialog, with black as the initial color and all the
+	/// custom colors white.
+	pub fn new() -> ColorDialog {
+		Self::default()
+	}
+
+	/// Returns the initially selected color, which is the last chosen one after
+	/// the dialog is accepted.
+	pub fn color(&self) -> COLORREF {
+		self.color
+	}
+
+	/// Sets the initially selected color.
+	pub fn set_color(&mut self, color: COLORREF) {
+		self.color = color;
+	}
+
+	/// Returns the 16 custom colors.
+	pub fn custom_colors(&self) -> [COLORREF; 16] {
+		self.custom_colors
+	}
+
+	/// Sets the custom colors. Up to 16 colors are used, the remaining ones are
+	/// left untouched.
+	pub fn set_custom_colors(&mut self, colors: &[COLORREF]) {
+		for (dest, src) in self.custom_colors.iter_mut().zip(colors.iter()) {
+			*dest = *src;
+		}
+	}
+
+	/// Sets whether the dialog is displayed with the custom colors section
+	/// expanded.
+	///
+	/// Defaults to `false`.
+	pub fn set_full_open(&mut self, full_open: bool) {
+		self.full_open = full_open;
+	}
+
+	/// Displays the modal dialog, returning the chosen color, or `None` if the
+	/// user cancelled.
+	///
+	/// The custom colors are updated even if the user cancelled.
+	pub fn show(&mut self, parent: &dyn Parent) -> WinResult<Option<COLORREF>> {
+		let mut cc = CHOOSECOLOR::default();
+		cc.hwndOwner = *baseref_from_parent(parent).hwnd_ref();
+		cc.rgbResult = self.color;
+		cc.lpCustColors = self.custom_colors.as_mut_ptr();
+		cc.Flags = co::CC::RGBINIT | co::CC::ANYCOLOR;
+		if self.full_open {
+			cc.Flags |= co::CC::FULLOPEN;
+		}
+
+		if !ChooseColor(&mut cc)? {
+			return Ok(None); // user cancelled
+		}
+		self.color = cc.rgbResult;
+		Ok(Some(cc.rgbResult))
+	}
+}
@@ -7,6 +7,7 @@ use crate::gui::events::ProcessResult;
 use crate::gui::privs::ui_font;
 use crate::handles::{HFONT, HWND};
 use crate::msg::{MsgSendRecv, wm, WndMsg};
+use crate::structs::PROPSHEETPAGE;
 
 /// Base to all dialog windows.
 pub(crate) struct DlgBase {
@@ -34,6 +35,10 @@ impl DlgBase {
 		&self.base
 	}
 
+	pub fn dialog_id(&self) -> i32 {
+		self.dialog_id
+	}
+
 	pub fn create_dialog_param(&self) -> WinResult<()> {
 		if !self.base.hwnd_ref().is_null() {
 			panic!("Cannot create dialog twice.");
@@ -66,6 +71,22 @@ impl DlgBase {
 
 	extern "system" fn dialog_proc(
 		hwnd: HWND, msg: co::WM, wparam: usize, lparam: isize) -> isize
+	{
+		Self::process_proc(hwnd, msg, wparam, lparam, false)
+	}
+
+	/// Dialog procedure for property sheet pages, whose `WM_INITDIALOG` carries
+	/// a pointer to the [`PROPSHEETPAGE`](crate::PROPSHEETPAGE) struct, which
+	/// then carries the pointer to self in `lParam`.
+	pub(crate) extern "system" fn page_proc(
+		hwnd: HWND, msg: co::WM, wparam: usize, lparam: isize) -> isize
+	{
+		Self::process_proc(hwnd, msg, wparam, lparam, true)
+	}
+
+	fn process_proc(
+		hwnd: HWND, msg: co::WM, wparam: usize, lparam: isize,
+		is_page: bool) -> isize
 	{
 		|hwnd: HWND, msg, wparam, lparam| -> WinResult<isize>
 		{
@@ -74,7 +95,12 @@ impl DlgBase {
 			let ptr_self = match msg {
 				co::WM::INITDIALOG => { // first message being handled
 					let wm_idlg = wm::InitDialog::from_generic_wm(wm_any);
-					let ptr_self = wm_idlg.additional_data as *mut Self;
+					let ptr_self = if is_page {
+						let psp = unsafe { &*(wm_idlg.additional_data as *const PROPSHEETPAGE) };
+						psp.lParam as *mut Self
+					} else {
+						wm_idlg.additional_data as *mut Self
+					};
 					hwnd.SetWindowLongPtr(co::GWLP::DWLP_USER, ptr_self as isize); // store
 					let ref_self = unsafe { &mut *ptr_self };
 					ref_self.base.set_hwnd(hwnd); // store HWND in struct field
@@ -92,6 +118,10 @@ impl DlgBase {
 			// Execute privileged closures.
 			let ref_self = unsafe { &mut *ptr_self };
 			ref_self.base.process_privileged_messages(wm_any);
+			if msg == co::WM::SETCURSOR && ref_self.base.run_cursor_setters() {
+				hwnd.SetWindowLongPtr(co::GWLP::DWLP_MSGRESULT, true as isize);
+				return Ok(true as isize); // cursor set, halt further processing
+			}
 
 			if wm_any.msg_id == co::WM::INITDIALOG {
 				// Child controls are created in privileged closures, so we set the
@@ -6,7 +6,7 @@ use crate::enums::HwndPlace;
 use crate::funcs::PostQuitMessage;
 use crate::gui::base::Base;
 use crate::gui::dlg_base::DlgBase;
-use crate::gui::privs::{auto_ctrl_id, multiply_dpi, paint_control_borders};
+use crate::gui::privs::{auto_ctrl_id, multiply_dpi_for, paint_control_borders};
 use crate::structs::POINT;
 
 #[derive(Clone)]
@@ -52,7 +52,8 @@ impl DlgControl {
 
 					// Set control position within parent.
 					let mut dlg_pos = self2.0.position;
-					multiply_dpi(Some(&mut dlg_pos), None)?;
+					multiply_dpi_for(self2.base_ref().parent_ref().unwrap(),
+						Some(&mut dlg_pos), None)?;
 					self2.base_ref().hwnd_ref().SetWindowPos(
 						HwndPlace::None,
 						dlg_pos.x, dlg_pos.y, 0, 0,
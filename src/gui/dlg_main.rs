@@ -53,7 +53,7 @@ impl DlgMain {
 		self.set_icon_if_any(hinst)?;
 		self.base_ref().hwnd_ref().ShowWindow(cmd_show.unwrap_or(co::SW::SHOW));
 
-		Base::run_main_loop(haccel) // blocks until window is closed
+		Base::run_main_loop(haccel, None) // blocks until window is closed
 	}
 
 	fn default_message_handlers(&self) {
@@ -0,0 +1,39 @@
+use std::sync::Arc;
+
+use crate::aliases::WinResult;
+use crate::gui::base::Base;
+use crate::gui::dlg_base::DlgBase;
+
+#[derive(Clone)]
+pub(crate) struct DlgModeless {
+	base: Arc<DlgBase>,
+}
+
+impl DlgModeless {
+	pub fn new(parent_ref: &Base, dialog_id: i32) -> DlgModeless {
+		let dlg = Self {
+			base: Arc::new(
+				DlgBase::new(Some(parent_ref), dialog_id),
+			),
+		};
+		dlg.default_message_handlers();
+		dlg
+	}
+
+	pub fn base_ref(&self) -> &Base {
+		self.base.base_ref()
+	}
+
+	pub fn create(&self) -> WinResult<()> {
+		self.base.create_dialog_param()
+	}
+
+	fn default_message_handlers(&self) {
+		self.base_ref().user_events_ref().wm_close({
+			let self2 = self.clone();
+			move || {
+				self2.base_ref().hwnd_ref().DestroyWindow(); // modeless dialogs are not ended with EndDialog
+			}
+		});
+	}
+}
@@ -0,0 +1,779 @@
+#![allow(non_snake_case)]
+
+use std::path::PathBuf;
+use std::ptr::NonNull;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use crate::aliases::WinResult;
+use crate::co;
+use crate::com::{IDataObject, IDataObjectVtbl, IUnknownVtbl, OleInitialize, OleUninitialize, PPVtbl, ReleaseStgMedium, Vtbl};
+use crate::ffi::{BOOL, ole32, PCVOID, PVOID, shell32};
+use crate::funcs::PostQuitMessage;
+use crate::gui::base::Base;
+use crate::gui::traits::{baseref_from_parent, Parent};
+use crate::gui::very_unsafe_cell::VeryUnsafeCell;
+use crate::handles::{HGLOBAL, HWND};
+use crate::structs::{DROPFILES, FORMATETC, IID, POINT, STGMEDIUM};
+
+const DATADIR_GET: u32 = 1;
+
+/// Callback which receives the dragged data, the key state, the mouse position
+/// and the effects allowed by the source, returning the chosen effect.
+type DropFn = dyn FnMut(&DropData, co::MK, POINT, co::DROPEFFECT) -> co::DROPEFFECT;
+
+/// A [drop target](https://docs.microsoft.com/en-us/windows/win32/api/oleidl/nn-oleidl-idroptarget)
+/// attached to a window, which receives text, files and custom formats dragged
+/// from this or any other application.
+///
+/// The target is registered with
+/// [`RegisterDragDrop`](https://docs.microsoft.com/en-us/windows/win32/api/ole2/nf-ole2-registerdragdrop)
+/// when the window is created, and revoked when the window is destroyed. The
+/// effect returned by the closures is displayed to the user as the mouse
+/// cursor, and it's always restricted to the effects allowed by the source.
+///
+/// If you only need to receive files, consider the simpler
+/// [`drop_files`](crate::gui::events::WindowEvents::drop_files) event.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use winsafe::co;
+/// use winsafe::gui::{DropTarget, WindowMain};
+///
+/// let wnd: WindowMain; // initialize it somewhere...
+///
+/// let target = DropTarget::new(&wnd);
+///
+/// target.on_drag_enter(|data, _, _, _| {
+///     if data.has_format(co::CF::UNICODETEXT) {
+///         co::DROPEFFECT::COPY
+///     } else {
+///         co::DROPEFFECT::NONE
+///     }
+/// });
+///
+/// target.on_drop(|data, _, pt, _| {
+///     println!("Dropped at {}: {}", pt, data.text().unwrap_or_default());
+///     co::DROPEFFECT::COPY
+/// });
+/// ```
+#[derive(Clone)]
+pub struct DropTarget(Arc<VeryUnsafeCell<Obj>>);
+
+struct Obj { // actual fields of DropTarget
+	parent_ptr: NonNull<Base>,
+	drag_enter: Option<Box<DropFn>>,
+	drag_over: Option<Box<DropFn>>,
+	drag_leave: Option<Box<dyn FnMut()>>,
+	drop: Option<Box<DropFn>>,
+}
+
+unsafe impl Send for DropTarget {}
+unsafe impl Sync for DropTarget {}
+
+impl DropTarget {
+	/// Instantiates a new `DropTarget` object, to be registered when the parent
+	/// window is created.
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window is already created.
+	pub fn new(parent: &dyn Parent) -> DropTarget {
+		let parent_ref = baseref_from_parent(parent);
+		if !parent_ref.hwnd_ref().is_null() {
+			panic!("Cannot add a drop target after the window is created.");
+		}
+
+		let me = Self(
+			Arc::new(VeryUnsafeCell::new(
+				Obj {
+					parent_ptr: NonNull::from(parent_ref),
+					drag_enter: None,
+					drag_over: None,
+					drag_leave: None,
+					drop: None,
+				},
+			)),
+		);
+		me.default_message_handlers(parent_ref);
+		me
+	}
+
+	/// Sets the closure called when the mouse enters the window while dragging.
+	/// The mouse position is in client coordinates.
+	///
+	/// If not set, the drop is refused with
+	/// [`DROPEFFECT::NONE`](crate::co::DROPEFFECT::NONE).
+	pub fn on_drag_enter<F>(&self, func: F)
+		where F: FnMut(&DropData, co::MK, POINT, co::DROPEFFECT) -> co::DROPEFFECT + 'static,
+	{
+		self.0.as_mut().drag_enter = Some(Box::new(func));
+	}
+
+	/// Sets the closure called when the mouse moves over the window while
+	/// dragging. The mouse position is in client coordinates.
+	///
+	/// If not set, the effect returned by the
+	/// [`on_drag_enter`](crate::gui::DropTarget::on_drag_enter) closure is
+	/// kept.
+	pub fn on_drag_over<F>(&self, func: F)
+		where F: FnMut(&DropData, co::MK, POINT, co::DROPEFFECT) -> co::DROPEFFECT + 'static,
+	{
+		self.0.as_mut().drag_over = Some(Box::new(func));
+	}
+
+	/// Sets the closure called when the mouse leaves the window, or the drag is
+	/// cancelled.
+	pub fn on_drag_leave<F>(&self, func: F)
+		where F: FnMut() + 'static,
+	{
+		self.0.as_mut().drag_leave = Some(Box::new(func));
+	}
+
+	/// Sets the closure called when the data is dropped on the window. The
+	/// mouse position is in client coordinates. The returned effect is
+	/// reported back to the source, which may delete the original data if it's
+	/// [`DROPEFFECT::MOVE`](crate::co::DROPEFFECT::MOVE).
+	pub fn on_drop<F>(&self, func: F)
+		where F: FnMut(&DropData, co::MK, POINT, co::DROPEFFECT) -> co::DROPEFFECT + 'static,
+	{
+		self.0.as_mut().drop = Some(Box::new(func));
+	}
+
+	fn hwnd(&self) -> HWND {
+		unsafe { *self.0.parent_ptr.as_ref().hwnd_ref() }
+	}
+
+	fn default_message_handlers(&self, parent_ref: &Base) {
+		parent_ref.privileged_events_ref().wm(parent_ref.create_wm(), {
+			let me = self.clone();
+			move |_| {
+				|| -> WinResult<()> {
+					OleInitialize()?;
+					let target_obj = TargetObj::new_ptr(
+						&DROP_TARGET_VTBL,
+						IID::new(0x00000122, 0x0000, 0x0000, 0xc000, 0x000000000046),
+						TargetData {
+							target: me.clone(),
+							hwnd: me.hwnd(),
+							data_obj: None,
+							last_effect: co::DROPEFFECT::NONE,
+						},
+					);
+					let hr = co::ERROR(
+						unsafe { ole32::RegisterDragDrop(me.hwnd().ptr, target_obj as _) },
+					);
+					com_release(target_obj); // now owned by OLE, if registered
+					match hr {
+						co::ERROR::S_OK => Ok(()),
+						err => Err(err),
+					}
+				}
+				().unwrap_or_else(|err| PostQuitMessage(err));
+				0
+			}
+		});
+
+		parent_ref.privileged_events_ref().wm_destroy({
+			let me = self.clone();
+			move || {
+				unsafe { ole32::RevokeDragDrop(me.hwnd().ptr); }
+				OleUninitialize();
+			}
+		});
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Data being dragged over a [`DropTarget`](crate::gui::DropTarget).
+pub struct DropData<'a> {
+	data_obj: &'a IDataObject,
+}
+
+impl<'a> DropData<'a> {
+	/// Tells whether the data can be retrieved in the given format.
+	pub fn has_format(&self, format: co::CF) -> bool {
+		self.data_obj.QueryGetData(&FORMATETC::new(format, co::TYMED::HGLOBAL))
+			.unwrap_or(false)
+	}
+
+	/// Retrieves the text, if the data has the
+	/// [`CF::UNICODETEXT`](crate::co::CF::UNICODETEXT) format.
+	pub fn text(&self) -> Option<String> {
+		self.custom(co::CF::UNICODETEXT)
+			.map(|bytes| bytes_to_text(&bytes))
+	}
+
+	/// Retrieves the file paths, if the data has the
+	/// [`CF::HDROP`](crate::co::CF::HDROP) format.
+	pub fn files(&self) -> Option<Vec<PathBuf>> {
+		self.custom(co::CF::HDROP)
+			.map(|bytes| bytes_to_files(&bytes))
+	}
+
+	/// Retrieves the raw bytes of the given format, usually one registered with
+	/// [`RegisterClipboardFormat`](crate::RegisterClipboardFormat). Only
+	/// formats stored in global memory are supported.
+	pub fn custom(&self, format: co::CF) -> Option<Vec<u8>> {
+		let mut medium = self.data_obj
+			.GetData(&FORMATETC::new(format, co::TYMED::HGLOBAL)).ok()?;
+		let bytes = medium.hGlobal()
+			.and_then(|hglobal| hglobal_to_bytes(hglobal).ok());
+		ReleaseStgMedium(&mut medium);
+		bytes
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Data to be dragged from a window to this or any other application, with
+/// [`do_drag_drop`](crate::gui::DragData::do_drag_drop).
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use winsafe::co;
+/// use winsafe::gui::{DragData, WindowMain};
+///
+/// let wnd: WindowMain; // initialize it somewhere...
+///
+/// wnd.on().wm_l_button_down(|_| {
+///     let effect = DragData::new()
+///         .text("Hello")
+///         .do_drag_drop(co::DROPEFFECT::COPY)
+///         .unwrap();
+///     println!("Effect: {}", effect);
+/// });
+/// ```
+#[derive(Default)]
+pub struct DragData {
+	formats: Vec<(co::CF, Vec<u8>)>,
+}
+
+impl DragData {
+	/// Creates a new, empty `DragData`.
+	pub fn new() -> DragData {
+		Self::default()
+	}
+
+	/// Adds text, in the [`CF::UNICODETEXT`](crate::co::CF::UNICODETEXT)
+	/// format.
+	pub fn text(self, text: &str) -> DragData {
+		self.custom(co::CF::UNICODETEXT, &text_to_bytes(text))
+	}
+
+	/// Adds file paths, in the [`CF::HDROP`](crate::co::CF::HDROP) format.
+	pub fn files(self, paths: &[PathBuf]) -> DragData {
+		self.custom(co::CF::HDROP, &files_to_bytes(paths))
+	}
+
+	/// Adds raw bytes in the given format, usually one registered with
+	/// [`RegisterClipboardFormat`](crate::RegisterClipboardFormat). If the
+	/// format was already added, it's replaced.
+	pub fn custom(mut self, format: co::CF, bytes: &[u8]) -> DragData {
+		self.formats.retain(|(cf, _)| *cf != format);
+		self.formats.push((format, bytes.to_vec()));
+		self
+	}
+
+	/// Starts the modal drag-and-drop loop, returning when the data is dropped
+	/// or the drag is cancelled.
+	///
+	/// Returns the effect chosen by the drop target, or
+	/// [`DROPEFFECT::NONE`](crate::co::DROPEFFECT::NONE) if the drag was
+	/// cancelled.
+	///
+	/// **Note:** Must be called while the mouse button is pressed, usually
+	/// when handling [`wm_l_button_down`](crate::gui::events::WindowEvents::wm_l_button_down)
+	/// or a begin drag notification of a control.
+	pub fn do_drag_drop(&self, allowed: co::DROPEFFECT) -> WinResult<co::DROPEFFECT> {
+		OleInitialize()?;
+		let res = self.drag_drop_loop(allowed);
+		OleUninitialize();
+		res
+	}
+
+	fn drag_drop_loop(&self, allowed: co::DROPEFFECT) -> WinResult<co::DROPEFFECT> {
+		let data_obj = DataObj::new_ptr(
+			&DATA_OBJECT_VTBL,
+			IDataObjectVtbl::IID(),
+			SourceData {
+				fmts: self.formats.iter()
+					.map(|(cf, _)| FORMATETC::new(*cf, co::TYMED::HGLOBAL))
+					.collect(),
+				bytes: self.formats.iter()
+					.map(|(_, bytes)| bytes.clone())
+					.collect(),
+			},
+		);
+		let drop_source = SourceObj::new_ptr(
+			&DROP_SOURCE_VTBL,
+			IID::new(0x00000121, 0x0000, 0x0000, 0xc000, 0x000000000046),
+			(),
+		);
+
+		let mut effect: u32 = 0;
+		let hr = co::ERROR(
+			unsafe {
+				ole32::DoDragDrop(data_obj as _, drop_source as _, allowed.0, &mut effect)
+			},
+		);
+		com_release(data_obj);
+		com_release(drop_source);
+
+		match hr {
+			co::ERROR::DRAGDROP_S_DROP => Ok(co::DROPEFFECT(effect)),
+			co::ERROR::DRAGDROP_S_CANCEL => Ok(co::DROPEFFECT::NONE),
+			err => Err(err),
+		}
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Reads the whole contents of a global memory block.
+pub(crate) fn hglobal_to_bytes(hglobal: HGLOBAL) -> WinResult<Vec<u8>> {
+	let sz = hglobal.GlobalSize()?;
+	let ptr = hglobal.GlobalLock()?;
+	let bytes = unsafe { std::slice::from_raw_parts(ptr as *const u8, sz) }.to_vec();
+	hglobal.GlobalUnlock()?;
+	Ok(bytes)
+}
+
+/// Allocates a moveable global memory block with a copy of the given bytes.
+pub(crate) fn bytes_to_hglobal(bytes: &[u8]) -> WinResult<HGLOBAL> {
+	let hglobal = HGLOBAL::GlobalAlloc(co::GMEM::MOVEABLE, bytes.len().max(1))?;
+	match hglobal.GlobalLock() {
+		Ok(ptr) => {
+			unsafe { std::ptr::copy_nonoverlapping(bytes.as_ptr(), ptr as *mut u8, bytes.len()); }
+			hglobal.GlobalUnlock()?;
+			Ok(hglobal)
+		},
+		Err(err) => {
+			hglobal.GlobalFree()?;
+			Err(err)
+		},
+	}
+}
+
+/// Encodes text as null-terminated UTF-16.
+pub(crate) fn text_to_bytes(text: &str) -> Vec<u8> {
+	text.encode_utf16()
+		.chain(std::iter::once(0))
+		.flat_map(|ch| ch.to_ne_bytes())
+		.collect()
+}
+
+/// Decodes null-terminated UTF-16 text.
+pub(crate) fn bytes_to_text(bytes: &[u8]) -> String {
+	let wchars: Vec<u16> = bytes.chunks_exact(2)
+		.map(|pair| u16::from_ne_bytes([pair[0], pair[1]]))
+		.take_while(|ch| *ch != 0)
+		.collect();
+	String::from_utf16_lossy(&wchars)
+}
+
+/// Encodes file paths as a [`DROPFILES`](crate::DROPFILES) block.
+pub(crate) fn files_to_bytes(paths: &[PathBuf]) -> Vec<u8> {
+	let df = DROPFILES {
+		pFiles: std::mem::size_of::<DROPFILES>() as u32,
+		fWide: 1,
+		..Default::default()
+	};
+
+	let mut bytes = unsafe {
+		std::slice::from_raw_parts(&df as *const _ as *const u8, df.pFiles as usize)
+	}.to_vec();
+	for path in paths.iter() {
+		bytes.extend(text_to_bytes(&path.to_string_lossy()));
+	}
+	bytes.extend([0, 0]); // double null-terminated list
+	bytes
+}
+
+/// Decodes file paths from a [`DROPFILES`](crate::DROPFILES) block.
+pub(crate) fn bytes_to_files(bytes: &[u8]) -> Vec<PathBuf> {
+	if bytes.len() < std::mem::size_of::<DROPFILES>() {
+		return Vec::default();
+	}
+	let df = unsafe { std::ptr::read_unaligned(bytes.as_ptr() as *const DROPFILES) };
+	let list = bytes.get(df.pFiles as usize..).unwrap_or_default();
+
+	let names: Vec<String> = if df.fWide != 0 {
+		let wchars: Vec<u16> = list.chunks_exact(2)
+			.map(|pair| u16::from_ne_bytes([pair[0], pair[1]]))
+			.collect();
+		wchars.split(|ch| *ch == 0)
+			.take_while(|name| !name.is_empty())
+			.map(|name| String::from_utf16_lossy(name))
+			.collect()
+	} else {
+		list.split(|ch| *ch == 0)
+			.take_while(|name| !name.is_empty())
+			.map(|name| String::from_utf8_lossy(name).into_owned())
+			.collect()
+	};
+	names.into_iter().map(PathBuf::from).collect()
+}
+
+//------------------------------------------------------------------------------
+
+/// A COM object implemented in Rust: the virtual table pointer, followed by the
+/// reference count and the object data.
+#[repr(C)]
+struct ComObj<V: 'static, T> {
+	vtbl: &'static V,
+	ref_count: AtomicU32,
+	iid: IID,
+	data: T,
+}
+
+impl<V, T> ComObj<V, T> {
+	/// Allocates the object with a reference count of 1.
+	fn new_ptr(vtbl: &'static V, iid: IID, data: T) -> *mut Self {
+		Box::into_raw(Box::new(Self { vtbl, ref_count: AtomicU32::new(1), iid, data }))
+	}
+}
+
+extern "system" fn com_query_interface<V, T>(
+	this: *mut ComObj<V, T>, riid: PCVOID, ppv: *mut PVOID) -> u32
+{
+	let riid = unsafe { &*(riid as *const IID) };
+	let iid_unk = IUnknownVtbl::IID();
+	if riid.as_ref() == iid_unk.as_ref() || riid.as_ref() == unsafe { (*this).iid.as_ref() } {
+		unsafe { *ppv = this as _; }
+		com_add_ref(this);
+		co::ERROR::S_OK.0
+	} else {
+		unsafe { *ppv = std::ptr::null_mut(); }
+		co::ERROR::E_NOINTERFACE.0
+	}
+}
+
+extern "system" fn com_add_ref<V, T>(this: *mut ComObj<V, T>) -> u32 {
+	unsafe { (*this).ref_count.fetch_add(1, Ordering::SeqCst) + 1 }
+}
+
+extern "system" fn com_release<V, T>(this: *mut ComObj<V, T>) -> u32 {
+	let ref_count = unsafe { (*this).ref_count.fetch_sub(1, Ordering::SeqCst) - 1 };
+	if ref_count == 0 {
+		drop(unsafe { Box::from_raw(this) });
+	}
+	ref_count
+}
+
+//------------------------------------------------------------------------------
+
+type TargetObj = ComObj<DropTargetVtbl, TargetData>;
+
+struct TargetData {
+	target: DropTarget,
+	hwnd: HWND,
+	data_obj: Option<IDataObject>, // while dragging over the window
+	last_effect: co::DROPEFFECT,
+}
+
+impl TargetData {
+	fn run(&mut self,
+		func: Option<&mut Box<DropFn>>, default: co::DROPEFFECT,
+		key_state: u32, pt: POINT, pdwEffect: *mut u32)
+	{
+		let allowed = co::DROPEFFECT(unsafe { *pdwEffect });
+		let effect = match (func, self.data_obj.as_ref()) {
+			(Some(func), Some(data_obj)) => {
+				let mut pt = pt; // OLE passes screen coordinates
+				self.hwnd.ScreenToClient(&mut pt)
+					.unwrap_or_else(|err| PostQuitMessage(err));
+				func(&DropData { data_obj }, co::MK(key_state as u16), pt, allowed)
+			},
+			_ => default,
+		} & allowed;
+		unsafe { *pdwEffect = effect.0; }
+		self.last_effect = effect;
+	}
+
+	fn store_data_obj(&mut self, pDataObj: PVOID) {
+		let data_obj = IDataObject::from(pDataObj as PPVtbl<IDataObjectVtbl>);
+		unsafe { data_obj.IUnknown.AddRef(); } // reference is owned by the caller
+		self.data_obj = Some(data_obj);
+	}
+}
+
+#[repr(C)]
+struct DropTargetVtbl {
+	QueryInterface: extern "system" fn(*mut TargetObj, PCVOID, *mut PVOID) -> u32,
+	AddRef: extern "system" fn(*mut TargetObj) -> u32,
+	Release: extern "system" fn(*mut TargetObj) -> u32,
+	DragEnter: extern "system" fn(*mut TargetObj, PVOID, u32, POINT, *mut u32) -> u32,
+	DragOver: extern "system" fn(*mut TargetObj, u32, POINT, *mut u32) -> u32,
+	DragLeave: extern "system" fn(*mut TargetObj) -> u32,
+	Drop: extern "system" fn(*mut TargetObj, PVOID, u32, POINT, *mut u32) -> u32,
+}
+
+static DROP_TARGET_VTBL: DropTargetVtbl = DropTargetVtbl {
+	QueryInterface: com_query_interface,
+	AddRef: com_add_ref,
+	Release: com_release,
+	DragEnter: target_drag_enter,
+	DragOver: target_drag_over,
+	DragLeave: target_drag_leave,
+	Drop: target_drop,
+};
+
+extern "system" fn target_drag_enter(
+	this: *mut TargetObj, pDataObj: PVOID,
+	grfKeyState: u32, pt: POINT, pdwEffect: *mut u32) -> u32
+{
+	let me = unsafe { &mut (*this).data };
+	me.store_data_obj(pDataObj);
+	let target = me.target.clone();
+	me.run(target.0.as_mut().drag_enter.as_mut(), co::DROPEFFECT::NONE,
+		grfKeyState, pt, pdwEffect);
+	co::ERROR::S_OK.0
+}
+
+extern "system" fn target_drag_over(
+	this: *mut TargetObj,
+	grfKeyState: u32, pt: POINT, pdwEffect: *mut u32) -> u32
+{
+	let me = unsafe { &mut (*this).data };
+	let target = me.target.clone();
+	let last_effect = me.last_effect;
+	me.run(target.0.as_mut().drag_over.as_mut(), last_effect,
+		grfKeyState, pt, pdwEffect);
+	co::ERROR::S_OK.0
+}
+
+extern "system" fn target_drag_leave(this: *mut TargetObj) -> u32 {
+	let me = unsafe { &mut (*this).data };
+	me.data_obj = None;
+	if let Some(drag_leave) = me.target.0.as_mut().drag_leave.as_mut() {
+		drag_leave();
+	}
+	co::ERROR::S_OK.0
+}
+
+extern "system" fn target_drop(
+	this: *mut TargetObj, pDataObj: PVOID,
+	grfKeyState: u32, pt: POINT, pdwEffect: *mut u32) -> u32
+{
+	let me = unsafe { &mut (*this).data };
+	me.store_data_obj(pDataObj);
+	let target = me.target.clone();
+	me.run(target.0.as_mut().drop.as_mut(), co::DROPEFFECT::NONE,
+		grfKeyState, pt, pdwEffect);
+	me.data_obj = None;
+	co::ERROR::S_OK.0
+}
+
+//------------------------------------------------------------------------------
+
+type DataObj = ComObj<DataObjectVtbl, SourceData>;
+
+struct SourceData {
+	fmts: Vec<FORMATETC>, // contiguous, passed to SHCreateStdEnumFmtEtc
+	bytes: Vec<Vec<u8>>,
+}
+
+impl SourceData {
+	fn find(&self, pformatetc: PCVOID) -> Option<usize> {
+		let fmt = unsafe { &*(pformatetc as *const FORMATETC) };
+		if fmt.dwAspect != co::DVASPECT::CONTENT || !fmt.tymed.has(co::TYMED::HGLOBAL) {
+			return None;
+		}
+		self.fmts.iter().position(|f| f.cfFormat == fmt.cfFormat)
+	}
+}
+
+#[repr(C)]
+struct DataObjectVtbl {
+	QueryInterface: extern "system" fn(*mut DataObj, PCVOID, *mut PVOID) -> u32,
+	AddRef: extern "system" fn(*mut DataObj) -> u32,
+	Release: extern "system" fn(*mut DataObj) -> u32,
+	GetData: extern "system" fn(*mut DataObj, PCVOID, PVOID) -> u32,
+	GetDataHere: extern "system" fn(*mut DataObj, PCVOID, PVOID) -> u32,
+	QueryGetData: extern "system" fn(*mut DataObj, PCVOID) -> u32,
+	GetCanonicalFormatEtc: extern "system" fn(*mut DataObj, PCVOID, PVOID) -> u32,
+	SetData: extern "system" fn(*mut DataObj, PCVOID, PVOID, BOOL) -> u32,
+	EnumFormatEtc: extern "system" fn(*mut DataObj, u32, *mut PVOID) -> u32,
+	DAdvise: extern "system" fn(*mut DataObj, PCVOID, u32, PVOID, *mut u32) -> u32,
+	DUnadvise: extern "system" fn(*mut DataObj, u32) -> u32,
+	EnumDAdvise: extern "system" fn(*mut DataObj, *mut PVOID) -> u32,
+}
+
+static DATA_OBJECT_VTBL: DataObjectVtbl = DataObjectVtbl {
+	QueryInterface: com_query_interface,
+	AddRef: com_add_ref,
+	Release: com_release,
+	GetData: data_get_data,
+	GetDataHere: data_not_impl,
+	QueryGetData: data_query_get_data,
+	GetCanonicalFormatEtc: data_not_impl,
+	SetData: data_set_data,
+	EnumFormatEtc: data_enum_format_etc,
+	DAdvise: data_dadvise,
+	DUnadvise: data_dunadvise,
+	EnumDAdvise: data_enum_dadvise,
+};
+
+extern "system" fn data_get_data(
+	this: *mut DataObj, pformatetcIn: PCVOID, pmedium: PVOID) -> u32
+{
+	let me = unsafe { &(*this).data };
+	match me.find(pformatetcIn) {
+		Some(idx) => match bytes_to_hglobal(&me.bytes[idx]) {
+			Ok(hglobal) => {
+				let medium = unsafe { &mut *(pmedium as *mut STGMEDIUM) };
+				*medium = STGMEDIUM::default(); // receiver frees the memory block
+				medium.set_hGlobal(hglobal);
+				co::ERROR::S_OK.0
+			},
+			Err(_) => co::ERROR::E_OUTOFMEMORY.0,
+		},
+		None => co::ERROR::DV_E_FORMATETC.0,
+	}
+}
+
+extern "system" fn data_query_get_data(this: *mut DataObj, pformatetc: PCVOID) -> u32 {
+	match unsafe { &(*this).data }.find(pformatetc) {
+		Some(_) => co::ERROR::S_OK.0,
+		None => co::ERROR::DV_E_FORMATETC.0,
+	}
+}
+
+extern "system" fn data_enum_format_etc(
+	this: *mut DataObj, dwDirection: u32, ppenumFormatEtc: *mut PVOID) -> u32
+{
+	if dwDirection != DATADIR_GET {
+		return co::ERROR::E_NOTIMPL.0;
+	}
+	let me = unsafe { &(*this).data };
+	unsafe {
+		shell32::SHCreateStdEnumFmtEtc(
+			me.fmts.len() as u32, me.fmts.as_ptr() as _, ppenumFormatEtc)
+	}
+}
+
+extern "system" fn data_not_impl(_: *mut DataObj, _: PCVOID, _: PVOID) -> u32 {
+	co::ERROR::E_NOTIMPL.0
+}
+
+extern "system" fn data_set_data(_: *mut DataObj, _: PCVOID, _: PVOID, _: BOOL) -> u32 {
+	co::ERROR::E_NOTIMPL.0
+}
+
+extern "system" fn data_dadvise(
+	_: *mut DataObj, _: PCVOID, _: u32, _: PVOID, _: *mut u32) -> u32
+{
+	co::ERROR::OLE_E_ADVISENOTSUPPORTED.0
+}
+
+extern "system" fn data_dunadvise(_: *mut DataObj, _: u32) -> u32 {
+	co::ERROR::OLE_E_ADVISENOTSUPPORTED.0
+}
+
+extern "system" fn data_enum_dadvise(_: *mut DataObj, _: *mut PVOID) -> u32 {
+	co::ERROR::OLE_E_ADVISENOTSUPPORTED.0
+}
+
+//------------------------------------------------------------------------------
+
+type SourceObj = ComObj<DropSourceVtbl, ()>;
+
+#[repr(C)]
+struct DropSourceVtbl {
+	QueryInterface: extern "system" fn(*mut SourceObj, PCVOID, *mut PVOID) -> u32,
+	AddRef: extern "system" fn(*mut SourceObj) -> u32,
+	Release: extern "system" fn(*mut SourceObj) -> u32,
+	QueryContinueDrag: extern "system" fn(*mut SourceObj, BOOL, u32) -> u32,
+	GiveFeedback: extern "system" fn(*mut SourceObj, u32) -> u32,
+}
+
+static DROP_SOURCE_VTBL: DropSourceVtbl = DropSourceVtbl {
+	QueryInterface: com_query_interface,
+	AddRef: com_add_ref,
+	Release: com_release,
+	QueryContinueDrag: source_query_continue_drag,
+	GiveFeedback: source_give_feedback,
+};
+
+extern "system" fn source_query_continue_drag(
+	_: *mut SourceObj, fEscapePressed: BOOL, grfKeyState: u32) -> u32
+{
+	let buttons = (co::MK::LBUTTON | co::MK::RBUTTON).0 as u32;
+	if fEscapePressed != 0 {
+		co::ERROR::DRAGDROP_S_CANCEL.0
+	} else if grfKeyState & buttons == 0 { // mouse button released
+		co::ERROR::DRAGDROP_S_DROP.0
+	} else {
+		co::ERROR::S_OK.0
+	}
+}
+
+extern "system" fn source_give_feedback(_: *mut SourceObj, _: u32) -> u32 {
+	co::ERROR::DRAGDROP_S_USEDEFAULTCURSORS.0 // cursor reflects the target effect
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn header(wide: bool) -> Vec<u8> {
+		let df = DROPFILES {
+			pFiles: std::mem::size_of::<DROPFILES>() as u32,
+			fWide: wide as i32,
+			..Default::default()
+		};
+		unsafe {
+			std::slice::from_raw_parts(&df as *const _ as *const u8, df.pFiles as usize)
+		}.to_vec()
+	}
+
+	#[test]
+	fn text_round_trip() {
+		for text in ["", "plain", "ação ☃ 𝄞", "multi\r\nline"].iter() {
+			let bytes = text_to_bytes(text);
+			assert_eq!(&bytes[bytes.len() - 2..], [0, 0]); // null-terminated
+			assert_eq!(bytes_to_text(&bytes), *text);
+		}
+	}
+
+	#[test]
+	fn text_truncated() {
+		let bytes = text_to_bytes("abc");
+		assert_eq!(bytes_to_text(&bytes[..4]), "ab"); // no terminator
+		assert_eq!(bytes_to_text(&bytes[..5]), "ab"); // odd byte ignored
+		assert_eq!(bytes_to_text(&[]), "");
+	}
+
+	#[test]
+	fn files_round_trip() {
+		let paths = vec![PathBuf::from("C:\\Temp\\a.txt"), PathBuf::from("D:\\ação\\☃.doc")];
+		assert_eq!(bytes_to_files(&files_to_bytes(&paths)), paths);
+		assert!(bytes_to_files(&files_to_bytes(&[])).is_empty());
+	}
+
+	#[test]
+	fn files_ansi() {
+		let mut bytes = header(false);
+		bytes.extend(b"C:\\a.txt\0C:\\b\0\0");
+		assert_eq!(bytes_to_files(&bytes),
+			[PathBuf::from("C:\\a.txt"), PathBuf::from("C:\\b")]);
+	}
+
+	#[test]
+	fn files_truncated() {
+		let bytes = files_to_bytes(&[PathBuf::from("C:\\a.txt"), PathBuf::from("C:\\b")]);
+		let header_len = std::mem::size_of::<DROPFILES>();
+
+		assert!(bytes_to_files(&bytes[..header_len - 1]).is_empty()); // truncated header
+		assert!(bytes_to_files(&bytes[..header_len]).is_empty()); // no list
+		assert_eq!(bytes_to_files(&bytes[..header_len + 4]), [PathBuf::from("C:")]); // partial name
+
+		let mut past_end = header(true);
+		past_end[0] = 0xff; // pFiles beyond the block
+		assert!(bytes_to_files(&past_end).is_empty());
+	}
+}
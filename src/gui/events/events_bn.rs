@@ -2,7 +2,7 @@ use std::ptr::NonNull;
 
 use crate::co;
 use crate::gui::base::Base;
-use crate::gui::events::WindowEvents;
+use crate::gui::events::{EventHandle, WindowEvents};
 use crate::structs::{NMBCDROPDOWN, NMBCHOTITEM, NMCUSTOMDRAW};
 
 ctrl_events_proxy! {
@@ -19,6 +19,8 @@ ctrl_events_proxy! {
 }
 
 impl ButtonEvents {
+	privileged_cmd_event! { privileged_bn_clicked, co::BN::CLICKED.into() }
+
 	nfy_event_p! { bcn_drop_down, co::BCN::DROPDOWN.into(), NMBCDROPDOWN,
 		/// [`BCN_DROPDOWN`](https://docs.microsoft.com/en-us/windows/win32/controls/bcn-dropdown)
 		/// notification.
@@ -89,12 +91,12 @@ impl ButtonEvents {
 	/// notification.
 	///
 	/// Notifies about custom draw operations on the button.
-	pub fn nm_custom_draw<F>(&self, func: F)
+	pub fn nm_custom_draw<F>(&self, func: F) -> EventHandle
 		where F: FnMut(&NMCUSTOMDRAW) -> co::CDRF + 'static,
 	{
 		self.parent_user_events().add_nfy(self.ctrl_id, co::NM::CUSTOMDRAW, {
 			let mut func = func;
 			move |p| Some(func(unsafe { p.cast_nmhdr::<NMCUSTOMDRAW>() }).into())
-		});
+		})
 	}
 }
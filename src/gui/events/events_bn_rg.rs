@@ -3,7 +3,7 @@ use std::rc::Rc;
 
 use crate::co;
 use crate::gui::base::Base;
-use crate::gui::events::WindowEvents;
+use crate::gui::events::{EventHandle, WindowEvents};
 use crate::gui::very_unsafe_cell::VeryUnsafeCell;
 
 /// Exposes button control
@@ -33,6 +33,12 @@ impl RadioGroupEvents {
 		unsafe { self.parent_ptr.as_ref().user_events_ref() }
 	}
 
+	/// Removes the event handler identified by the given handle, just like
+	/// [`WindowEvents::remove`](crate::gui::events::WindowEvents::remove).
+	pub fn remove(&self, handle: EventHandle) -> bool {
+		self.parent_user_events().remove(handle)
+	}
+
 	/// [`BN_CLICKED`](https://docs.microsoft.com/en-us/windows/win32/controls/bn-clicked)
 	/// command notification for all radio buttons in the group.
 	///
@@ -54,17 +60,19 @@ impl RadioGroupEvents {
 	///     }
 	/// });
 	/// ```
-	pub fn bn_clicked<F>(&self, func: F)
+	pub fn bn_clicked<F>(&self, func: F) -> EventHandle
 		where F: FnMut() + 'static,
 	{
 		let shared_func = Rc::new(VeryUnsafeCell::new(func));
+		let handle = EventHandle::next(); // all buttons share the handle
 
 		for ctrl_id in self.ctrl_ids.iter() {
-			self.parent_user_events().wm_command(co::BN::CLICKED.into(), *ctrl_id, {
+			self.parent_user_events().add_cmd(co::BN::CLICKED.into(), *ctrl_id, handle, {
 				let shared_func = shared_func.clone();
 				move || shared_func.as_mut()()
 			});
 		}
+		handle
 	}
 
 	/// [`BN_DBLCLK`](https://docs.microsoft.com/en-us/windows/win32/controls/bn-dblclk)
@@ -75,17 +83,19 @@ impl RadioGroupEvents {
 	/// [`BS_RADIOBUTTON`](crate::co::BS::RADIOBUTTON), and
 	/// [`BS_OWNERDRAW`](crate::co::BS::OWNERDRAW) buttons. Other button types
 	/// send only if they have the [`BS_NOTIFY`](crate::co::BS::NOTIFY) style.
-	pub fn bn_dbl_clk<F>(&self, func: F)
+	pub fn bn_dbl_clk<F>(&self, func: F) -> EventHandle
 		where F: FnMut() + 'static,
 	{
 		let shared_func = Rc::new(VeryUnsafeCell::new(func));
+		let handle = EventHandle::next(); // all buttons share the handle
 
 		for ctrl_id in self.ctrl_ids.iter() {
-			self.parent_user_events().wm_command(co::BN::DBLCLK.into(), *ctrl_id, {
+			self.parent_user_events().add_cmd(co::BN::DBLCLK.into(), *ctrl_id, handle, {
 				let shared_func = shared_func.clone();
 				move || shared_func.as_mut()()
 			});
 		}
+		handle
 	}
 
 	/// [`BN_KILLFOCUS`](https://docs.microsoft.com/en-us/windows/win32/controls/bn-killfocus)
@@ -94,17 +104,19 @@ impl RadioGroupEvents {
 	/// Sent when a button loses the keyboard focus. The button must have the
 	/// [`BS_NOTIFY`](crate::co::BS::NOTIFY) style to send this notification
 	/// code.
-	pub fn bn_kill_focus<F>(&self, func: F)
+	pub fn bn_kill_focus<F>(&self, func: F) -> EventHandle
 		where F: FnMut() + 'static,
 	{
 		let shared_func = Rc::new(VeryUnsafeCell::new(func));
+		let handle = EventHandle::next(); // all buttons share the handle
 
 		for ctrl_id in self.ctrl_ids.iter() {
-			self.parent_user_events().wm_command(co::BN::KILLFOCUS.into(), *ctrl_id, {
+			self.parent_user_events().add_cmd(co::BN::KILLFOCUS.into(), *ctrl_id, handle, {
 				let shared_func = shared_func.clone();
 				move || shared_func.as_mut()()
 			});
 		}
+		handle
 	}
 
 	/// [`BN_SETFOCUS`](https://docs.microsoft.com/en-us/windows/win32/controls/bn-setfocus)
@@ -113,16 +125,18 @@ impl RadioGroupEvents {
 	/// Sent when a button receives the keyboard focus. The button must have
 	/// the [`BS_NOTIFY`](crate::co::BS::NOTIFY) style to send this
 	/// notification code.
-	pub fn bn_set_focus<F>(&self, func: F)
+	pub fn bn_set_focus<F>(&self, func: F) -> EventHandle
 		where F: FnMut() + 'static,
 	{
 		let shared_func = Rc::new(VeryUnsafeCell::new(func));
+		let handle = EventHandle::next(); // all buttons share the handle
 
 		for ctrl_id in self.ctrl_ids.iter() {
-			self.parent_user_events().wm_command(co::BN::SETFOCUS.into(), *ctrl_id, {
+			self.parent_user_events().add_cmd(co::BN::SETFOCUS.into(), *ctrl_id, handle, {
 				let shared_func = shared_func.clone();
 				move || shared_func.as_mut()()
 			});
 		}
+		handle
 	}
 }
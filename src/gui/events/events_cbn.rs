@@ -2,7 +2,7 @@ use std::ptr::NonNull;
 
 use crate::co;
 use crate::gui::base::Base;
-use crate::gui::events::WindowEvents;
+use crate::gui::events::{EventHandle, WindowEvents};
 
 ctrl_events_proxy! {
 	/// Exposes combo box control
@@ -18,6 +18,8 @@ ctrl_events_proxy! {
 }
 
 impl ComboBoxEvents {
+	privileged_cmd_event! { privileged_cbn_sel_change, co::CBN::SELCHANGE.into() }
+
 	cmd_event! { cbn_close_up, co::CBN::CLOSEUP.into(),
 		/// [`CBN_CLOSEUP`](https://docs.microsoft.com/en-us/windows/win32/controls/cbn-closeup)
 		/// notification.
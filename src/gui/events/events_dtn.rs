@@ -2,7 +2,7 @@ use std::ptr::NonNull;
 
 use crate::co;
 use crate::gui::base::Base;
-use crate::gui::events::WindowEvents;
+use crate::gui::events::{EventHandle, WindowEvents};
 use crate::structs::{
 	NMDATETIMECHANGE,
 	NMDATETIMEFORMAT,
@@ -2,7 +2,7 @@ use std::ptr::NonNull;
 
 use crate::co;
 use crate::gui::base::Base;
-use crate::gui::events::WindowEvents;
+use crate::gui::events::{EventHandle, WindowEvents};
 
 ctrl_events_proxy! {
 	/// Exposes edit control
@@ -18,6 +18,8 @@ ctrl_events_proxy! {
 }
 
 impl EditEvents {
+	privileged_cmd_event! { privileged_en_change, co::EN::CHANGE.into() }
+
 	cmd_event! { en_align_ltr_ec, co::EN::ALIGN_LTR_EC.into(),
 		/// [`EN_ALIGN_LTR_EC`](https://docs.microsoft.com/en-us/windows/win32/controls/en-align-ltr-ec)
 		/// command notification.
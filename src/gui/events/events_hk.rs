@@ -0,0 +1,28 @@
+use std::ptr::NonNull;
+
+use crate::co;
+use crate::gui::base::Base;
+use crate::gui::events::{EventHandle, WindowEvents};
+
+ctrl_events_proxy! {
+	/// Exposes hot key control
+	/// [notifications](https://docs.microsoft.com/en-us/windows/win32/controls/hot-key-controls).
+	///
+	/// These event methods are just proxies to the
+	/// [`WindowEvents`](crate::gui::events::WindowEvents) of the parent window, who
+	/// is the real responsible for the child event handling.
+	///
+	/// You cannot directly instantiate this object, it is created internally by the
+	/// control.
+	HotKeyEvents
+}
+
+impl HotKeyEvents {
+	cmd_event! { en_change, co::EN::CHANGE.into(),
+		/// [`EN_CHANGE`](https://docs.microsoft.com/en-us/windows/win32/controls/en-change)
+		/// command notification.
+		///
+		/// Sent when the user has changed the key combination displayed in the
+		/// hot key control.
+	}
+}
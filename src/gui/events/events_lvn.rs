@@ -2,7 +2,7 @@ use std::ptr::NonNull;
 
 use crate::co;
 use crate::gui::base::Base;
-use crate::gui::events::WindowEvents;
+use crate::gui::events::{EventHandle, WindowEvents};
 use crate::structs::{
 	NMCUSTOMDRAW,
 	NMITEMACTIVATE,
@@ -222,7 +222,7 @@ impl ListViewEvents {
 	/// particular callback item. For example, the control will send this
 	/// notification code when it receives shortcut keyboard input or when it
 	/// receives an [`LVM_FINDITEM`](crate::msg::lvm::FindItem) message.
-	pub fn lvn_od_find_item<F>(&self, func: F)
+	pub fn lvn_od_find_item<F>(&self, func: F) -> EventHandle
 		where F: FnMut(&mut NMLVFINDITEM) -> Option<u32> + 'static,
 	{
 		self.parent_user_events().add_nfy(self.ctrl_id, co::LVN::ODFINDITEM.into(), {
@@ -233,7 +233,7 @@ impl ListViewEvents {
 					None => -1,
 				})
 			}
-		});
+		})
 	}
 
 	nfy_event_p! { lvn_od_state_changed, co::LVN::ODSTATECHANGED.into(), NMLVODSTATECHANGE,
@@ -263,13 +263,13 @@ impl ListViewEvents {
 	/// notification.
 	///
 	/// Sent by a list-view control to notify about drawing operations.
-	pub fn nm_custom_draw<F>(&self, func: F)
+	pub fn nm_custom_draw<F>(&self, func: F) -> EventHandle
 		where F: FnMut(&NMCUSTOMDRAW) -> co::CDRF + 'static,
 	{
 		self.parent_user_events().add_nfy(self.ctrl_id, co::NM::CUSTOMDRAW, {
 			let mut func = func;
 			move |p| Some(func(unsafe { p.cast_nmhdr::<NMCUSTOMDRAW>() }).into())
-		});
+		})
 	}
 
 	nfy_event_p! { nm_dbl_clk, co::NM::DBLCLK, NMITEMACTIVATE,
@@ -284,13 +284,13 @@ impl ListViewEvents {
 	/// notification.
 	///
 	/// Sent by a list-view control when the mouse hovers over an item.
-	pub fn nm_hover<F>(&self, func: F)
+	pub fn nm_hover<F>(&self, func: F) -> EventHandle
 		where F: FnMut() -> i32 + 'static,
 	{
 		self.parent_user_events().add_nfy(self.ctrl_id, co::NM::HOVER, {
 			let mut func = func;
 			move |_| Some(func() as isize)
-		});
+		})
 	}
 
 	nfy_event! { nm_kill_focus, co::NM::KILLFOCUS,
@@ -305,13 +305,13 @@ impl ListViewEvents {
 	///
 	/// Sent by a list-view control when the user clicks an item with the right
 	/// mouse button.
-	pub fn nm_r_click<F>(&self, func: F)
+	pub fn nm_r_click<F>(&self, func: F) -> EventHandle
 		where F: FnMut(&NMITEMACTIVATE) -> i32 + 'static,
 	{
 		self.parent_user_events().add_nfy(self.ctrl_id, co::NM::RCLICK, {
 			let mut func = func;
 			move |p| Some(func(unsafe { p.cast_nmhdr::<NMITEMACTIVATE>() }) as isize)
-		});
+		})
 	}
 
 	nfy_event_p! { nm_r_dbl_clk, co::NM::RDBLCLK, NMITEMACTIVATE,
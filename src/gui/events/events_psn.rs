@@ -0,0 +1,137 @@
+use std::ptr::NonNull;
+
+use crate::co;
+use crate::gui::base::Base;
+use crate::gui::events::{EventHandle, WindowEvents};
+
+ctrl_events_proxy! {
+	/// Exposes property sheet page
+	/// [notifications](https://docs.microsoft.com/en-us/windows/win32/controls/bumper-property-sheets-reference-notifications).
+	///
+	/// These event methods are just proxies to the
+	/// [`WindowEvents`](crate::gui::events::WindowEvents) of the page itself, who
+	/// is the real responsible for the notification handling.
+	///
+	/// You cannot directly instantiate this object, it is created internally by the
+	/// page.
+	PropertyPageEvents
+}
+
+impl PropertyPageEvents {
+	/// Adds a handler whose result is stored as `DWLP_MSGRESULT`, which is how a
+	/// dialog procedure returns a value from `WM_NOTIFY`.
+	fn add_psn_ret<F>(&self, code: co::PSN, func: F) -> EventHandle
+		where F: FnMut() -> isize + 'static,
+	{
+		let page_ptr = self.parent_ptr;
+		self.parent_user_events().add_nfy(self.ctrl_id, code.into(), {
+			let mut func = func;
+			move |_| {
+				let ret = func();
+				let page_hwnd = unsafe { page_ptr.as_ref() }.hwnd_ref();
+				page_hwnd.SetWindowLongPtr(co::GWLP::DWLP_MSGRESULT, ret);
+				Some(true as isize)
+			}
+		})
+	}
+
+	/// [`PSN_APPLY`](https://docs.microsoft.com/en-us/windows/win32/controls/psn-apply)
+	/// notification.
+	///
+	/// Sent when the user clicks the OK, Close or Apply buttons. Return `true` to
+	/// accept the changes; returning `false` keeps the property sheet open and
+	/// activates this page.
+	pub fn psn_apply<F>(&self, func: F) -> EventHandle
+		where F: FnMut() -> bool + 'static,
+	{
+		let mut func = func;
+		self.add_psn_ret(co::PSN::APPLY, move || {
+			if func() { co::PSNRET::NOERROR } else { co::PSNRET::INVALID }.0
+		})
+	}
+
+	/// [`PSN_KILLACTIVE`](https://docs.microsoft.com/en-us/windows/win32/controls/psn-killactive)
+	/// notification.
+	///
+	/// Sent when the page is about to lose activation, either because another
+	/// page is being activated or the user clicked OK. This is where the page
+	/// validation takes place: return `true` if the page contents are valid, or
+	/// `false` to prevent the page from losing activation.
+	pub fn psn_kill_active<F>(&self, func: F) -> EventHandle
+		where F: FnMut() -> bool + 'static,
+	{
+		let mut func = func;
+		self.add_psn_ret(co::PSN::KILLACTIVE, move || !func() as isize)
+	}
+
+	/// [`PSN_QUERYCANCEL`](https://docs.microsoft.com/en-us/windows/win32/controls/psn-querycancel)
+	/// notification.
+	///
+	/// Sent when the user clicks the Cancel button. Return `true` to allow the
+	/// cancel operation, or `false` to prevent it.
+	pub fn psn_query_cancel<F>(&self, func: F) -> EventHandle
+		where F: FnMut() -> bool + 'static,
+	{
+		let mut func = func;
+		self.add_psn_ret(co::PSN::QUERYCANCEL, move || !func() as isize)
+	}
+
+	/// [`PSN_RESET`](https://docs.microsoft.com/en-us/windows/win32/controls/psn-reset)
+	/// notification.
+	///
+	/// Sent when the property sheet is about to be destroyed because the user
+	/// canceled it, so the changes must be discarded.
+	pub fn psn_reset<F>(&self, func: F) -> EventHandle
+		where F: FnMut() + 'static,
+	{
+		let mut func = func;
+		self.add_psn_ret(co::PSN::RESET, move || { func(); 0 })
+	}
+
+	/// [`PSN_SETACTIVE`](https://docs.microsoft.com/en-us/windows/win32/controls/psn-setactive)
+	/// notification.
+	///
+	/// Sent when the page is about to be activated.
+	pub fn psn_set_active<F>(&self, func: F) -> EventHandle
+		where F: FnMut() + 'static,
+	{
+		let mut func = func;
+		self.add_psn_ret(co::PSN::SETACTIVE, move || { func(); 0 })
+	}
+
+	/// [`PSN_WIZBACK`](https://docs.microsoft.com/en-us/windows/win32/controls/psn-wizback)
+	/// notification.
+	///
+	/// Sent when the user clicks the Back button of a wizard. Return `true` to
+	/// go to the previous page, or `false` to stay on this page.
+	pub fn psn_wiz_back<F>(&self, func: F) -> EventHandle
+		where F: FnMut() -> bool + 'static,
+	{
+		let mut func = func;
+		self.add_psn_ret(co::PSN::WIZBACK, move || if func() { 0 } else { -1 })
+	}
+
+	/// [`PSN_WIZFINISH`](https://docs.microsoft.com/en-us/windows/win32/controls/psn-wizfinish)
+	/// notification.
+	///
+	/// Sent when the user clicks the Finish button of a wizard. Return `true` to
+	/// close the wizard, or `false` to keep it open.
+	pub fn psn_wiz_finish<F>(&self, func: F) -> EventHandle
+		where F: FnMut() -> bool + 'static,
+	{
+		let mut func = func;
+		self.add_psn_ret(co::PSN::WIZFINISH, move || !func() as isize)
+	}
+
+	/// [`PSN_WIZNEXT`](https://docs.microsoft.com/en-us/windows/win32/controls/psn-wiznext)
+	/// notification.
+	///
+	/// Sent when the user clicks the Next button of a wizard. Return `true` to
+	/// go to the next page, or `false` to stay on this page.
+	pub fn psn_wiz_next<F>(&self, func: F) -> EventHandle
+		where F: FnMut() -> bool + 'static,
+	{
+		let mut func = func;
+		self.add_psn_ret(co::PSN::WIZNEXT, move || if func() { 0 } else { -1 })
+	}
+}
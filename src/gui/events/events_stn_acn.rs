@@ -0,0 +1,54 @@
+use std::ptr::NonNull;
+
+use crate::co;
+use crate::gui::base::Base;
+use crate::gui::events::{EventHandle, WindowEvents};
+
+ctrl_events_proxy! {
+	/// Exposes image view
+	/// [static control notifications](https://docs.microsoft.com/en-us/windows/win32/controls/bumper-static-control-reference-notifications)
+	/// and
+	/// [animation control notifications](https://docs.microsoft.com/en-us/windows/win32/controls/bumper-animation-control-reference-notifications).
+	///
+	/// These event methods are just proxies to the
+	/// [`WindowEvents`](crate::gui::events::WindowEvents) of the parent window, who
+	/// is the real responsible for the child event handling.
+	///
+	/// You cannot directly instantiate this object, it is created internally by the
+	/// control.
+	ImageViewEvents
+}
+
+impl ImageViewEvents {
+	cmd_event! { acn_start, co::ACN::START.into(),
+		/// [`ACN_START`](https://docs.microsoft.com/en-us/windows/win32/controls/acn-start)
+		/// notification.
+		///
+		/// Notifies an animated image view that the associated AVI clip has
+		/// started playing.
+	}
+
+	cmd_event! { acn_stop, co::ACN::STOP.into(),
+		/// [`ACN_STOP`](https://docs.microsoft.com/en-us/windows/win32/controls/acn-stop)
+		/// notification.
+		///
+		/// Notifies an animated image view that the associated AVI clip has
+		/// stopped playing.
+	}
+
+	cmd_event! { stn_clicked, co::STN::CLICKED.into(),
+		/// [`STN_CLICKED`](https://docs.microsoft.com/en-us/windows/win32/controls/stn-clicked)
+		/// notification.
+		///
+		/// Sent when the user clicks a static image view that has the
+		/// [`SS_NOTIFY`](crate::co::SS::NOTIFY) style.
+	}
+
+	cmd_event! { stn_dbl_clk, co::STN::DBLCLK.into(),
+		/// [`STN_DBLCLK`](https://docs.microsoft.com/en-us/windows/win32/controls/stn-dblclk)
+		/// notification.
+		///
+		/// Sent when the user double-clicks a static image view that has the
+		/// [`SS_NOTIFY`](crate::co::SS::NOTIFY) style.
+	}
+}
@@ -1,13 +1,17 @@
+use std::path::PathBuf;
 use std::rc::Rc;
 
 use crate::co;
-use crate::gui::events::func_store::FuncStore;
+use crate::funcs::PostQuitMessage;
+use crate::gui::events::func_store::{EventHandle, FuncStore};
 use crate::gui::very_unsafe_cell::VeryUnsafeCell;
 use crate::handles::{HDC, HICON};
 use crate::msg::{MsgSendRecv, wm, WndMsg};
+use crate::structs::POINT;
 
 /// The result of processing a message.
-pub(crate) enum ProcessResult {
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ProcessResult {
 	/// Message was not handled because no function was found.
 	NotHandled,
 	/// Message handled, and return value is meaningful.
@@ -42,6 +46,10 @@ struct Obj { // actual fields of WindowEvents
 		(u16, co::NM), // idFrom, code
 		Box<dyn FnMut(wm::Notify) -> Option<isize>>, // return value may be meaningful
 	>,
+	drop_files_handles: Vec<EventHandle>, // drop_files handlers; DragAcceptFiles is on while not empty
+	listens_clipboard: bool, // AddClipboardFormatListener must be called upon creation
+	locked: bool, // window already created, only removal is allowed
+	drop_files_off: Option<Box<dyn Fn()>>, // turns off DragAcceptFiles, set upon creation
 }
 
 impl WindowEvents {
@@ -53,6 +61,10 @@ impl WindowEvents {
 					tmrs: FuncStore::new(),
 					cmds: FuncStore::new(),
 					nfys: FuncStore::new(),
+					drop_files_handles: Vec::default(),
+					listens_clipboard: false,
+					locked: false,
+					drop_files_off: None,
 				},
 			),
 		)
@@ -73,45 +85,31 @@ impl WindowEvents {
 			co::WM::NOTIFY => {
 				let wm_nfy = wm::Notify::from_generic_wm(wm_any);
 				let key = (wm_nfy.nmhdr.idFrom as u16, wm_nfy.nmhdr.code);
-				match self.0.as_mut().nfys.find(key) {
-					Some(func) => { // we have a stored function to handle this WM_NOTIFY notification
-						match func(wm_nfy) { // execute user function
-							Some(res) => ProcessResult::HandledWithRet(res), // meaningful return value
-							None => ProcessResult::HandledWithoutRet,
-						}
-					},
+				match self.0.as_mut().nfys.run_last(key, |func| func(wm_nfy)) { // execute user function
+					Some(Some(res)) => ProcessResult::HandledWithRet(res), // meaningful return value
+					Some(None) => ProcessResult::HandledWithoutRet,
 					None => ProcessResult::NotHandled, // no stored WM_NOTIFY notification
 				}
 			},
 			co::WM::COMMAND => {
 				let wm_cmd = wm::Command::from_generic_wm(wm_any);
 				let key = (wm_cmd.code, wm_cmd.ctrl_id);
-				match self.0.as_mut().cmds.find(key) {
-					Some(func) => { // we have a stored function to handle this WM_COMMAND notification
-						func(); // execute user function
-						ProcessResult::HandledWithoutRet
-					},
+				match self.0.as_mut().cmds.run_last(key, |func| func()) { // execute user function
+					Some(_) => ProcessResult::HandledWithoutRet,
 					None => ProcessResult::NotHandled, // no stored WM_COMMAND notification
 				}
 			},
 			co::WM::TIMER => {
 				let wm_tmr = wm::Timer::from_generic_wm(wm_any);
-				match self.0.as_mut().tmrs.find(wm_tmr.timer_id) {
-					Some(func) => { // we have a stored function to handle this WM_TIMER message
-						func(); // execute user function
-						ProcessResult::HandledWithoutRet
-					},
+				match self.0.as_mut().tmrs.run_last(wm_tmr.timer_id, |func| func()) { // execute user function
+					Some(_) => ProcessResult::HandledWithoutRet,
 					None => ProcessResult::NotHandled, // no stored WM_TIMER message
 				}
 			}
 			_ => { // any other message
-				match self.0.as_mut().msgs.find(wm_any.msg_id) {
-					Some(func) => { // we have a stored function to handle this message
-						match func(wm_any) { // execute user function
-							Some(res) => ProcessResult::HandledWithRet(res), // meaningful return value
-							None => ProcessResult::HandledWithoutRet,
-						}
-					},
+				match self.0.as_mut().msgs.run_last(wm_any.msg_id, |func| func(wm_any)) { // execute user function
+					Some(Some(res)) => ProcessResult::HandledWithRet(res), // meaningful return value
+					Some(None) => ProcessResult::HandledWithoutRet,
 					None => ProcessResult::NotHandled, // no stored function
 				}
 			},
@@ -150,18 +148,143 @@ impl WindowEvents {
 		}
 	}
 
+	/// Tells whether [`drop_files`](crate::gui::events::WindowEvents::drop_files)
+	/// was added, so the window must accept dropped files.
+	pub(crate) fn accepts_files(&self) -> bool {
+		!self.0.drop_files_handles.is_empty()
+	}
+
+	/// Tells whether
+	/// [`wm_clipboard_update`](crate::gui::events::WindowEvents::wm_clipboard_update)
+	/// was added, so the window must be registered as a clipboard listener.
+	pub(crate) fn listens_clipboard(&self) -> bool {
+		self.0.listens_clipboard
+	}
+
+	/// Once locked, adding functions panics, but removing them is still allowed.
+	pub(crate) fn set_locked(&self, locked: bool) {
+		self.0.as_mut().locked = locked;
+	}
+
+	/// Sets the function which makes the window stop accepting dropped files,
+	/// called when the last
+	/// [`drop_files`](crate::gui::events::WindowEvents::drop_files) handler is
+	/// removed.
+	pub(crate) fn set_drop_files_off<F>(&self, func: F)
+		where F: Fn() + 'static,
+	{
+		self.0.as_mut().drop_files_off = Some(Box::new(func));
+	}
+
+	/// Updates the `drop_files` handlers after a removal, turning off
+	/// `DragAcceptFiles` if none is left.
+	fn drop_files_removed<P: Fn(&EventHandle) -> bool>(&self, removed: P) {
+		let obj = self.0.as_mut();
+		if obj.drop_files_handles.is_empty() {
+			return;
+		}
+		obj.drop_files_handles.retain(|handle| !removed(handle));
+		if obj.drop_files_handles.is_empty() {
+			if let Some(drop_files_off) = obj.drop_files_off.as_ref() {
+				drop_files_off();
+			}
+		}
+	}
+
+	fn check_unlocked(&self) {
+		if self.0.locked {
+			panic!("Cannot add event after window is created.");
+		}
+	}
+
 	/// Raw add message.
-	pub(crate) fn add_msg<F>(&self, ident: co::WM, func: F)
+	pub(crate) fn add_msg<F>(&self, ident: co::WM, func: F) -> EventHandle
 		where F: FnMut(WndMsg) -> Option<isize> + 'static,
 	{
-		self.0.as_mut().msgs.insert(ident, Box::new(func));
+		self.check_unlocked();
+		let handle = EventHandle::next();
+		self.0.as_mut().msgs.insert(ident, handle, Box::new(func));
+		handle
+	}
+
+	/// Raw add command, under an existing handle, so many commands can share
+	/// the same handle.
+	pub(crate) fn add_cmd<F>(&self,
+		code: co::CMD, ctrl_id: u16, handle: EventHandle, func: F)
+		where F: FnMut() + 'static,
+	{
+		self.check_unlocked();
+		self.0.as_mut().cmds.insert((code, ctrl_id), handle, Box::new(func));
 	}
 
 	/// Raw add notification.
-	pub(crate) fn add_nfy<F>(&self, id_from: u16, code: co::NM, func: F)
+	pub(crate) fn add_nfy<F>(&self, id_from: u16, code: co::NM, func: F) -> EventHandle
 		where F: FnMut(wm::Notify) -> Option<isize> + 'static,
 	{
-		self.0.as_mut().nfys.insert((id_from, code), Box::new(func));
+		self.check_unlocked();
+		let handle = EventHandle::next();
+		self.0.as_mut().nfys.insert((id_from, code), handle, Box::new(func));
+		handle
+	}
+
+	/// Removes the event handler identified by the given handle, which was
+	/// returned when the handler was added. Returns `false` if the handler was
+	/// not found, possibly because it was already removed.
+	///
+	/// A handler can remove itself, or any other handler, while it's running.
+	/// Unlike adding, removing is allowed after the window is created.
+	///
+	/// # Examples
+	///
+	/// ```rust,ignore
+	/// use winsafe::gui::WindowMain;
+	///
+	/// let wnd: WindowMain; // initialize it somewhere...
+	///
+	/// let handle = wnd.on().wm_l_button_down(|_| println!("Clicked."));
+	/// wnd.on().remove(handle);
+	/// ```
+	pub fn remove(&self, handle: EventHandle) -> bool {
+		self.drop_files_removed(|h| *h == handle);
+		let obj = self.0.as_mut();
+		// A handle belongs to a single store, but RadioGroup commands share it.
+		obj.msgs.remove(handle)
+			| obj.tmrs.remove(handle)
+			| obj.cmds.remove(handle)
+			| obj.nfys.remove(handle)
+	}
+
+	/// Removes all the handlers of the given [window message](crate::co::WM),
+	/// added with [`wm`](crate::gui::events::WindowEvents::wm) or any specific
+	/// message method. Returns `false` if no handler was found.
+	///
+	/// To remove `WM_COMMAND`, `WM_NOTIFY` and `WM_TIMER` handlers, use
+	/// [`clear_command`](crate::gui::events::WindowEvents::clear_command),
+	/// [`clear_notify`](crate::gui::events::WindowEvents::clear_notify) and
+	/// [`clear_timer`](crate::gui::events::WindowEvents::clear_timer).
+	pub fn clear_wm(&self, ident: co::WM) -> bool {
+		if ident == co::WM::DROPFILES {
+			self.drop_files_removed(|_| true);
+		}
+		self.0.as_mut().msgs.remove_all(ident)
+	}
+
+	/// Removes all the [`WM_COMMAND`](crate::msg::wm::Command) handlers for the
+	/// given code and control ID. Returns `false` if no handler was found.
+	pub fn clear_command(&self, code: co::CMD, ctrl_id: u16) -> bool {
+		self.0.as_mut().cmds.remove_all((code, ctrl_id))
+	}
+
+	/// Removes all the [`WM_NOTIFY`](crate::msg::wm::Notify) handlers for the
+	/// given ID and notification code. Returns `false` if no handler was found.
+	pub fn clear_notify(&self, id_from: u16, code: co::NM) -> bool {
+		self.0.as_mut().nfys.remove_all((id_from, code))
+	}
+
+	/// Removes all the [`WM_TIMER`](crate::msg::wm::Timer) handlers for the
+	/// given timer ID. Returns `false` if no handler was found.
+	pub fn clear_timer(&self, timer_id: u32) -> bool {
+		self.0.as_mut().tmrs.remove_all(timer_id)
 	}
 }
 
@@ -172,13 +295,13 @@ macro_rules! wm_empty {
 		$(#[$doc:meta])*
 	) => {
 		$(#[$doc])*
-		pub fn $name<F>(&self, func: F)
+		pub fn $name<F>(&self, func: F) -> EventHandle
 			where F: FnMut() + 'static,
 		{
 			self.add_msg($wmconst, {
 				let mut func = func;
 				move |_| { func(); None } // return value is never meaningful
-			});
+			})
 		}
 	};
 }
@@ -190,13 +313,13 @@ macro_rules! wm_ret_none {
 		$(#[$doc:meta])*
 	) => {
 		$(#[$doc])*
-		pub fn $name<F>(&self, func: F)
+		pub fn $name<F>(&self, func: F) -> EventHandle
 			where F: FnMut($parm) + 'static,
 		{
 			self.add_msg($wmconst, {
 				let mut func = func;
 				move |p| { func(<$parm>::from_generic_wm(p)); None } // return value is never meaningful
-			});
+			})
 		}
 	};
 }
@@ -228,23 +351,29 @@ impl WindowEvents {
 	///     }
 	/// });
 	/// ```
-	pub fn wm<F>(&self, ident: co::WM, func: F)
+	pub fn wm<F>(&self, ident: co::WM, func: F) -> EventHandle
 		where F: FnMut(WndMsg) -> isize + 'static,
 	{
 		self.add_msg(ident, {
 			let mut func = func;
 			move |p| Some(func(p)) // return value is meaningful
-		});
+		})
 	}
 
 	/// [`WM_TIMER`](crate::msg::wm::Timer) message, narrowed to a specific timer
 	/// ID.
 	///
 	/// Posted to the installing thread's message queue when a timer expires.
-	pub fn wm_timer<F>(&self, timer_id: u32, func: F)
+	///
+	/// On 32-bit targets, IDs from `0x8000_0000` up are reserved to the timers
+	/// created with `set_timer`.
+	pub fn wm_timer<F>(&self, timer_id: u32, func: F) -> EventHandle
 		where F: FnMut() + 'static,
 	{
-		self.0.as_mut().tmrs.insert(timer_id, Box::new(func));
+		self.check_unlocked();
+		let handle = EventHandle::next();
+		self.0.as_mut().tmrs.insert(timer_id, handle, Box::new(func));
+		handle
 	}
 
 	/// [`WM_COMMAND`](crate::msg::wm::Command) message, for specific code and
@@ -258,10 +387,12 @@ impl WindowEvents {
 	/// specific command notifications, which will give you the correct message
 	/// parameters. This generic method should be used when you have a custom,
 	/// non-standard window notification.
-	pub fn wm_command<F>(&self, code: co::CMD, ctrl_id: u16, func: F)
+	pub fn wm_command<F>(&self, code: co::CMD, ctrl_id: u16, func: F) -> EventHandle
 		where F: FnMut() + 'static,
 	{
-		self.0.as_mut().cmds.insert((code, ctrl_id), Box::new(func));
+		let handle = EventHandle::next();
+		self.add_cmd(code, ctrl_id, handle, func);
+		handle
 	}
 
 	/// [`WM_COMMAND`](crate::msg::wm::Command) message, handling both
@@ -286,20 +417,23 @@ impl WindowEvents {
 	///     }
 	/// });
 	/// ```
-	pub fn wm_command_accel_menu<F>(&self, ctrl_id: u16, func: F)
+	pub fn wm_command_accel_menu<F>(&self, ctrl_id: u16, func: F) -> EventHandle
 		where F: FnMut() + 'static,
 	{
 		let shared_func = Rc::new(VeryUnsafeCell::new(func));
+		let handle = EventHandle::next(); // both commands share the handle
 
-		self.wm_command(co::CMD::Menu, ctrl_id, {
+		self.add_cmd(co::CMD::Menu, ctrl_id, handle, {
 			let shared_func = shared_func.clone();
 			move || shared_func.as_mut()()
 		});
 
-		self.wm_command(co::CMD::Accelerator, ctrl_id, {
+		self.add_cmd(co::CMD::Accelerator, ctrl_id, handle, {
 			let shared_func = shared_func.clone();
 			move || shared_func.as_mut()()
 		});
+
+		handle
 	}
 
 	/// [`WM_NOTIFY`](crate::msg::wm::Notify) message, for specific ID and
@@ -313,13 +447,13 @@ impl WindowEvents {
 	/// specific notifications, which will give you the correct notification
 	/// struct. This generic method should be used when you have a custom,
 	/// non-standard window notification.
-	pub fn wm_notify<F>(&self, id_from: u16, code: co::NM, func: F)
+	pub fn wm_notify<F>(&self, id_from: u16, code: co::NM, func: F) -> EventHandle
 		where F: FnMut(wm::Notify) -> isize + 'static,
 	{
 		self.add_nfy(id_from, code, {
 			let mut func = func;
 			move |p| Some(func(p)) // return value is meaningful
-		});
+		})
 	}
 
 	wm_ret_none! { wm_activate, co::WM::ACTIVATE, wm::Activate,
@@ -354,13 +488,13 @@ impl WindowEvents {
 	/// Notifies a window that the user generated an application command event,
 	/// for example, by clicking an application command button using the mouse or
 	/// typing an application command key on the keyboard.
-	pub fn wm_app_command<F>(&self, func: F)
+	pub fn wm_app_command<F>(&self, func: F) -> EventHandle
 		where F: FnMut(wm::AppCommand) + 'static,
 	{
 		self.add_msg(co::WM::APPCOMMAND, {
 			let mut func = func;
 			move |p| { func(wm::AppCommand::from_generic_wm(p)); Some(true as isize) }
-		});
+		})
 	}
 
 	wm_empty! { wm_cancel_mode, co::WM::CANCELMODE,
@@ -391,6 +525,34 @@ impl WindowEvents {
 		/// when the window is activated, moved, or sized.
 	}
 
+	/// [`WM_CLIPBOARDUPDATE`](crate::msg::wm::ClipboardUpdate) message.
+	///
+	/// Sent when the contents of the clipboard have changed.
+	///
+	/// The window is automatically registered with
+	/// [`AddClipboardFormatListener`](crate::HWND::AddClipboardFormatListener)
+	/// when created, and unregistered when destroyed.
+	///
+	/// # Examples
+	///
+	/// ```rust,ignore
+	/// use winsafe::gui::WindowMain;
+	///
+	/// let wnd: WindowMain; // initialize it somewhere...
+	///
+	/// wnd.on().wm_clipboard_update(|| println!("Clipboard changed."));
+	/// ```
+	pub fn wm_clipboard_update<F>(&self, func: F) -> EventHandle
+		where F: FnMut() + 'static,
+	{
+		self.check_unlocked();
+		self.0.as_mut().listens_clipboard = true;
+		self.add_msg(co::WM::CLIPBOARDUPDATE, {
+			let mut func = func;
+			move |_| { func(); None } // return value is never meaningful
+		})
+	}
+
 	wm_empty! { wm_close, co::WM::CLOSE,
 		/// [`WM_CLOSE`](crate::msg::wm::Close) message.
 		///
@@ -412,6 +574,19 @@ impl WindowEvents {
 		/// user may have clicked the right mouse button (right-clicked) in the
 		/// window, pressed Shift+F10 or pressed the applications key (context
 		/// menu key) available on some keyboards.
+		///
+		/// To receive the message parameters, use
+		/// [`wm_context_menu_params`](crate::gui::events::WindowEvents::wm_context_menu_params).
+	}
+
+	wm_ret_none! { wm_context_menu_params, co::WM::CONTEXTMENU, wm::ContextMenu,
+		/// [`WM_CONTEXTMENU`](crate::msg::wm::ContextMenu) message, like
+		/// [`wm_context_menu`](crate::gui::events::WindowEvents::wm_context_menu),
+		/// but receiving the window which was clicked and the cursor position,
+		/// which is -1, -1 if the menu was requested with the keyboard.
+		///
+		/// Both methods handle the same message, so only the last one added is
+		/// executed.
 	}
 
 	/// [`WM_CREATE`](crate::msg::wm::Create) message, sent only to non-dialog
@@ -444,13 +619,13 @@ impl WindowEvents {
 	///     }
 	/// });
 	/// ```
-	pub fn wm_create<F>(&self, func: F)
+	pub fn wm_create<F>(&self, func: F) -> EventHandle
 		where F: FnMut(wm::Create) -> i32 + 'static,
 	{
 		self.add_msg(co::WM::CREATE, {
 			let mut func = func;
 			move |p| Some(func(wm::Create::from_generic_wm(p)) as isize)
-		});
+		})
 	}
 
 	/// [`WM_CTLCOLORBTN`](crate::msg::wm::CtlColorBtn) message.
@@ -459,13 +634,13 @@ impl WindowEvents {
 	/// parent window can change the button's text and background colors.
 	/// However, only owner-drawn buttons respond to the parent window processing
 	/// this message.
-	pub fn wm_ctl_color_btn<F>(&self, func: F)
+	pub fn wm_ctl_color_btn<F>(&self, func: F) -> EventHandle
 		where F: FnMut(wm::CtlColorBtn) -> HDC + 'static,
 	{
 		self.add_msg(co::WM::CTLCOLORBTN, {
 			let mut func = func;
 			move |p| Some(func(wm::CtlColorBtn::from_generic_wm(p)).ptr as isize)
-		});
+		})
 	}
 
 	/// [`WM_CTLCOLORDLG`](crate::msg::wm::CtlColorDlg) message.
@@ -473,13 +648,13 @@ impl WindowEvents {
 	/// Sent to a dialog box before the system draws the dialog box. By
 	/// responding to this message, the dialog box can set its text and
 	/// background colors using the specified display device context handle.
-	pub fn wm_ctl_color_dlg<F>(&self, func: F)
+	pub fn wm_ctl_color_dlg<F>(&self, func: F) -> EventHandle
 		where F: FnMut(wm::CtlColorDlg) -> HDC + 'static,
 	{
 		self.add_msg(co::WM::CTLCOLORDLG, {
 			let mut func = func;
 			move |p| Some(func(wm::CtlColorDlg::from_generic_wm(p)).ptr as isize)
-		});
+		})
 	}
 
 	/// [`WM_CTLCOLOREDIT`](crate::msg::wm::CtlColorEdit) message.
@@ -488,13 +663,13 @@ impl WindowEvents {
 	/// its parent window when the control is about to be drawn. By responding to
 	/// this message, the parent window can use the specified device context
 	/// handle to set the text and background colors of the edit control.
-	pub fn wm_ctl_color_edit<F>(&self, func: F)
+	pub fn wm_ctl_color_edit<F>(&self, func: F) -> EventHandle
 		where F: FnMut(wm::CtlColorEdit) -> HDC + 'static,
 	{
 		self.add_msg(co::WM::CTLCOLOREDIT, {
 			let mut func = func;
 			move |p| Some(func(wm::CtlColorEdit::from_generic_wm(p)).ptr as isize)
-		});
+		})
 	}
 
 	/// [`WM_CTLCOLORLISTBOX`](crate::msg::wm::CtlColorListBox) message.
@@ -503,13 +678,13 @@ impl WindowEvents {
 	/// box. By responding to this message, the parent window can set the text
 	/// and background colors of the list box by using the specified display
 	/// device context handle.
-	pub fn wm_ctl_color_list_box<F>(&self, func: F)
+	pub fn wm_ctl_color_list_box<F>(&self, func: F) -> EventHandle
 		where F: FnMut(wm::CtlColorListBox) -> HDC + 'static,
 	{
 		self.add_msg(co::WM::CTLCOLORLISTBOX, {
 			let mut func = func;
 			move |p| Some(func(wm::CtlColorListBox::from_generic_wm(p)).ptr as isize)
-		});
+		})
 	}
 
 	/// [`WM_CTLCOLORSCROLLBAR`](crate::msg::wm::CtlColorScrollBar) message.
@@ -518,13 +693,13 @@ impl WindowEvents {
 	/// about to be drawn. By responding to this message, the parent window can
 	/// use the display context handle to set the background color of the scroll
 	/// bar control.
-	pub fn wm_ctl_color_scroll_bar<F>(&self, func: F)
+	pub fn wm_ctl_color_scroll_bar<F>(&self, func: F) -> EventHandle
 		where F: FnMut(wm::CtlColorScrollBar) -> HDC + 'static,
 	{
 		self.add_msg(co::WM::CTLCOLORSCROLLBAR, {
 			let mut func = func;
 			move |p| Some(func(wm::CtlColorScrollBar::from_generic_wm(p)).ptr as isize)
-		});
+		})
 	}
 
 	/// [`WM_CTLCOLORSTATIC`](crate::msg::wm::CtlColorStatic) message.
@@ -534,13 +709,13 @@ impl WindowEvents {
 	/// By responding to this message, the parent window can use the specified
 	/// device context handle to set the text foreground and background colors of
 	/// the static control.
-	pub fn wm_ctl_color_static<F>(&self, func: F)
+	pub fn wm_ctl_color_static<F>(&self, func: F) -> EventHandle
 		where F: FnMut(wm::CtlColorStatic) -> HDC + 'static,
 	{
 		self.add_msg(co::WM::CTLCOLORSTATIC, {
 			let mut func = func;
 			move |p| Some(func(wm::CtlColorStatic::from_generic_wm(p)).ptr as isize)
-		});
+		})
 	}
 
 	wm_ret_none! { wm_dead_char, co::WM::DEADCHAR, wm::DeadChar,
@@ -569,6 +744,22 @@ impl WindowEvents {
 		/// still exist.
 	}
 
+	wm_ret_none! { wm_dpi_changed, co::WM::DPICHANGED, wm::DpiChanged,
+		/// [`WM_DPICHANGED`](crate::msg::wm::DpiChanged) message.
+		///
+		/// Sent when the effective DPI for a window has changed, usually because
+		/// the window was moved to a monitor with a different scaling factor.
+		///
+		/// The library already rescales the child controls, their UI font, the
+		/// lengths kept by [`Resizer`](crate::gui::Resizer),
+		/// [`Layout`](crate::gui::Layout), [`Splitter`](crate::gui::Splitter)
+		/// and [`StatusBar`](crate::gui::StatusBar), and the window itself.
+		/// Controls created afterwards also use the new DPI.
+		///
+		/// The library doesn't own image lists, custom fonts or list view
+		/// column widths, so this is where you recreate or rescale them.
+	}
+
 	wm_ret_none! { wm_drop_files, co::WM::DROPFILES, wm::DropFiles,
 		/// [`WM_DROPFILES`](crate::msg::wm::DropFiles) message.
 		///
@@ -576,6 +767,48 @@ impl WindowEvents {
 		/// has registered itself as a recipient of dropped files.
 	}
 
+	/// [`WM_DROPFILES`](crate::msg::wm::DropFiles) message, already parsed
+	/// into the dropped paths and the drop point, in client coordinates.
+	///
+	/// Unlike [`wm_drop_files`](crate::gui::events::WindowEvents::wm_drop_files),
+	/// [`DragAcceptFiles`](crate::HWND::DragAcceptFiles) is automatically called
+	/// when the window or control is created, so it accepts dropped files
+	/// without further setup. When the last `drop_files` handler is removed,
+	/// the window stops accepting dropped files.
+	///
+	/// # Examples
+	///
+	/// ```rust,ignore
+	/// use winsafe::gui::WindowMain;
+	///
+	/// let wnd: WindowMain; // initialize it somewhere...
+	///
+	/// wnd.on().drop_files(|paths, pt| {
+	///     for path in paths.iter() {
+	///         println!("Dropped at {}x{}: {}", pt.x, pt.y, path.display());
+	///     }
+	/// });
+	/// ```
+	pub fn drop_files<F>(&self, func: F) -> EventHandle
+		where F: FnMut(Vec<PathBuf>, POINT) + 'static,
+	{
+		self.check_unlocked();
+		let handle = self.add_msg(co::WM::DROPFILES, {
+			let mut func = func;
+			move |p| {
+				let hdrop = wm::DropFiles::from_generic_wm(p).hdrop;
+				let (pt, _) = hdrop.DragQueryPoint(); // must come first, DragQueryFile releases the HDROP
+				match hdrop.DragQueryFile() {
+					Ok(files) => func(files.iter().map(PathBuf::from).collect(), pt),
+					Err(err) => PostQuitMessage(err),
+				}
+				None // return value is never meaningful
+			}
+		});
+		self.0.as_mut().drop_files_handles.push(handle);
+		handle
+	}
+
 	wm_ret_none! { wm_enable, co::WM::ENABLE, wm::Enable,
 		/// [`WM_ENABLE`](crate::msg::wm::Enable) message.
 		///
@@ -626,13 +859,13 @@ impl WindowEvents {
 	/// Sent when the window background must be erased (for example, when a
 	/// window is resized). The message is sent to prepare an invalidated portion
 	/// of a window for painting.
-	pub fn wm_erase_bkgnd<F>(&self, func: F)
+	pub fn wm_erase_bkgnd<F>(&self, func: F) -> EventHandle
 		where F: FnMut(wm::EraseBkgnd) -> i32 + 'static,
 	{
 		self.add_msg(co::WM::ERASEBKGND, {
 			let mut func = func;
 			move |p| Some(func(wm::EraseBkgnd::from_generic_wm(p)) as isize)
-		});
+		})
 	}
 
 	wm_ret_none! { wm_exit_size_move, co::WM::EXITSIZEMOVE, wm::ExitSizeMove,
@@ -664,6 +897,13 @@ impl WindowEvents {
 		/// Indicates that the user pressed the F1 key.
 	}
 
+	wm_ret_none! { wm_hot_key, co::WM::HOTKEY, wm::HotKey,
+		/// [`WM_HOTKEY`](crate::msg::wm::HotKey) message.
+		///
+		/// Posted when the user presses a hot key registered by the
+		/// [`RegisterHotKey`](crate::HWND::RegisterHotKey) function.
+	}
+
 	/// [`WM_INITDIALOG`](crate::msg::wm::InitDialog) message, sent only to dialog
 	/// windows. Non-dialog windows receive
 	/// [`WM_CREATE`](crate::gui::events::WindowEvents::wm_create) instead.
@@ -672,13 +912,13 @@ impl WindowEvents {
 	/// displayed. Dialog box procedures typically use this message to initialize
 	/// controls and carry out any other initialization tasks that affect the
 	/// appearance of the dialog box.
-	pub fn wm_init_dialog<F>(&self, func: F)
+	pub fn wm_init_dialog<F>(&self, func: F) -> EventHandle
 		where F: FnMut(wm::InitDialog) -> bool + 'static,
 	{
 		self.add_msg(co::WM::INITDIALOG, {
 			let mut func = func;
 			move |p| Some(func(wm::InitDialog::from_generic_wm(p)) as isize)
-		});
+		})
 	}
 
 	wm_ret_none! { wm_init_menu_popup, co::WM::INITMENUPOPUP, wm::InitMenuPopup,
@@ -805,13 +1045,13 @@ impl WindowEvents {
 	/// calculated. By processing this message, an application can control the
 	/// content of the window's client area when the size or position of the
 	/// window changes.
-	pub fn wm_nc_calc_size<F>(&self, func: F)
+	pub fn wm_nc_calc_size<F>(&self, func: F) -> EventHandle
 		where F: FnMut(wm::NcCalcSize) -> co::WVR + 'static
 	{
 		self.add_msg(co::WM::NCCALCSIZE, {
 			let mut func = func;
 			move |p| Some(func(wm::NcCalcSize::from_generic_wm(p)).0 as isize)
-		});
+		})
 	}
 
 	/// [`WM_NCCREATE`](crate::msg::wm::NcCreate) message.
@@ -819,13 +1059,13 @@ impl WindowEvents {
 	/// Sent prior to the
 	/// [`WM_CREATE`](crate::gui::events::WindowEvents::wm_create) message when a
 	/// window is first created.
-	pub fn wm_nc_create<F>(&self, func: F)
+	pub fn wm_nc_create<F>(&self, func: F) -> EventHandle
 		where F: FnMut(wm::NcCreate) -> bool + 'static,
 	{
 		self.add_msg(co::WM::NCCREATE, {
 			let mut func = func;
 			move |p| Some(func(wm::NcCreate::from_generic_wm(p)) as isize)
-		});
+		})
 	}
 
 	wm_empty! { wm_nc_destroy, co::WM::NCDESTROY,
@@ -894,13 +1134,13 @@ impl WindowEvents {
 	///
 	/// Sent to an icon when the user requests that the window be restored to its
 	/// previous size and position.
-	pub fn wm_query_open<F>(&self, func: F)
+	pub fn wm_query_open<F>(&self, func: F) -> EventHandle
 		where F: FnMut(wm::QueryOpen) -> bool + 'static,
 	{
 		self.add_msg(co::WM::QUERYOPEN, {
 			let mut func = func;
 			move |p| Some(func(wm::QueryOpen::from_generic_wm(p)) as isize)
-		});
+		})
 	}
 
 	wm_ret_none! { wm_r_button_dbl_clk, co::WM::RBUTTONDBLCLK, wm::RButtonDblClk,
@@ -955,7 +1195,7 @@ impl WindowEvents {
 	/// Associates a new large or small icon with a window. The system displays
 	/// the large icon in the Alt+TAB dialog box, and the small icon in the
 	/// window caption.
-	pub fn wm_set_icon<F>(&self, func: F)
+	pub fn wm_set_icon<F>(&self, func: F) -> EventHandle
 		where F: FnMut(wm::SetIcon) -> Option<HICON> + 'static,
 	{
 		self.add_msg(co::WM::SETICON, {
@@ -966,7 +1206,7 @@ impl WindowEvents {
 					None => 0,
 				},
 			)
-		});
+		})
 	}
 
 	wm_ret_none! { wm_show_window, co::WM::SHOWWINDOW, wm::ShowWindow,
@@ -1132,3 +1372,82 @@ impl WindowEvents {
 		/// mouse.
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use std::cell::Cell;
+
+	use super::*;
+	use crate::funcs::MAKEDWORD;
+	use crate::handles::HWND;
+	use crate::structs::NMHDR;
+
+	fn generic_wm(msg_id: co::WM, wparam: usize, lparam: isize) -> WndMsg {
+		WndMsg { msg_id, wparam, lparam }
+	}
+
+	#[test]
+	fn route_wm() {
+		let events = WindowEvents::new();
+		events.wm(co::WM::APP, |_| 1);
+		events.wm(co::WM::APP, |p| p.wparam as isize); // last added wins
+		events.wm_close(|| {});
+
+		assert_eq!(events.process_effective_message(generic_wm(co::WM::APP, 42, 0)),
+			ProcessResult::HandledWithRet(42));
+		assert_eq!(events.process_effective_message(generic_wm(co::WM::CLOSE, 0, 0)),
+			ProcessResult::HandledWithoutRet);
+		assert_eq!(events.process_effective_message(generic_wm(co::WM::DESTROY, 0, 0)),
+			ProcessResult::NotHandled);
+	}
+
+	#[test]
+	fn route_command() {
+		let events = WindowEvents::new();
+		let ran = Rc::new(Cell::new(0));
+		events.wm_command(co::CMD::Menu, 1001, { let ran = ran.clone(); move || ran.set(1) });
+		events.wm_command(co::CMD::Accelerator, 1001, { let ran = ran.clone(); move || ran.set(2) });
+
+		let cmd = |code: co::CMD, ctrl_id| generic_wm(co::WM::COMMAND,
+			MAKEDWORD(ctrl_id, code.0) as usize, 0);
+
+		assert_eq!(events.process_effective_message(cmd(co::CMD::Accelerator, 1001)),
+			ProcessResult::HandledWithoutRet);
+		assert_eq!(ran.get(), 2);
+		assert_eq!(events.process_effective_message(cmd(co::CMD::Menu, 1001)),
+			ProcessResult::HandledWithoutRet);
+		assert_eq!(ran.get(), 1);
+		assert_eq!(events.process_effective_message(cmd(co::CMD::Menu, 1002)),
+			ProcessResult::NotHandled); // other control ID
+	}
+
+	#[test]
+	fn route_notify() {
+		let events = WindowEvents::new();
+		events.wm_notify(1001, co::NM::CLICK, |p| p.nmhdr.idFrom as isize);
+
+		let nfy = |id_from, code| NMHDR {
+			hwndFrom: unsafe { HWND::null_handle() },
+			idFrom: id_from,
+			code,
+		};
+		let process = |nmhdr: &NMHDR| events.process_effective_message(
+			generic_wm(co::WM::NOTIFY, nmhdr.idFrom, nmhdr as *const _ as isize));
+
+		assert_eq!(process(&nfy(1001, co::NM::CLICK)), ProcessResult::HandledWithRet(1001));
+		assert_eq!(process(&nfy(1001, co::NM::DBLCLK)), ProcessResult::NotHandled);
+		assert_eq!(process(&nfy(1002, co::NM::CLICK)), ProcessResult::NotHandled);
+	}
+
+	#[test]
+	fn privileged_run_all() {
+		let events = WindowEvents::new();
+		let ran = Rc::new(Cell::new(0));
+		for _ in 0..3 {
+			let ran = ran.clone();
+			events.wm_close(move || ran.set(ran.get() + 1));
+		}
+		events.process_all_messages(generic_wm(co::WM::CLOSE, 0, 0));
+		assert_eq!(ran.get(), 3);
+	}
+}
@@ -1,6 +1,38 @@
+static mut BASE_HANDLE_ID: u64 = 1;
+
+/// Identifies an event handler, returned when the handler is added. It can be
+/// used to remove the handler with
+/// [`WindowEvents::remove`](crate::gui::events::WindowEvents::remove).
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct EventHandle(u64);
+
+impl EventHandle {
+	/// Returns the next sequential handle.
+	pub(crate) fn next() -> EventHandle {
+		unsafe {
+			let new_id = BASE_HANDLE_ID;
+			BASE_HANDLE_ID += 1;
+			Self(new_id)
+		}
+	}
+}
+
+struct Elem<K, F> {
+	id: K,
+	handle: EventHandle,
+	func: F,
+	alive: bool, // removed while running are only dropped afterwards
+}
+
 /// Stores closures, associating them with an identifier.
+///
+/// Functions can be added and removed while the store is running a function,
+/// that is, from within an event handler; the changes are applied only after
+/// the outermost function returns.
 pub(crate) struct FuncStore<K, F> {
-	elems: Vec<(K, F)>,
+	elems: Vec<Elem<K, F>>,
+	pending: Vec<Elem<K, F>>, // added while running
+	running: u32, // depth of nested runs
 }
 
 impl<K: Copy + Eq, F> FuncStore<K, F> {
@@ -8,41 +40,173 @@ impl<K: Copy + Eq, F> FuncStore<K, F> {
 	pub fn new() -> FuncStore<K, F> {
 		Self {
 			elems: Vec::new(),
+			pending: Vec::new(),
+			running: 0,
 		}
 	}
 
-	/// Inserts a new function into the store, associated to the given identifier.
-	pub fn insert(&mut self, id: K, func: F) {
-		if self.elems.is_empty() {
-			self.elems.reserve(16); // arbitrary, prealloc for speed
+	/// Inserts a new function into the store, associated to the given identifier
+	/// and handle.
+	pub fn insert(&mut self, id: K, handle: EventHandle, func: F) {
+		let elem = Elem { id, handle, func, alive: true };
+		if self.running > 0 {
+			self.pending.push(elem);
+		} else {
+			if self.elems.is_empty() {
+				self.elems.reserve(16); // arbitrary, prealloc for speed
+			}
+			self.elems.push(elem);
 		}
-		self.elems.push((id, func));
 	}
 
-	/// Finds the last added function associated to the given identifier, if any.
-	pub fn find(&mut self, id: K) -> Option<&mut F> {
+	/// Finds the last added function associated to the given identifier, if any,
+	/// and passes it to the supplied callback, returning its result.
+	pub fn run_last<R, C: FnOnce(&mut F) -> R>(&mut self, id: K, callback: C) -> Option<R> {
 		// Linear search, more performant for small collections.
 		// Searches backwards, so the function added last will overwrite the first.
-		for elem in self.elems.iter_mut().rev() {
-			if elem.0 == id {
-				return Some(&mut elem.1);
-			}
-		}
-		None
+		let idx = self.elems.iter()
+			.rposition(|elem| elem.alive && elem.id == id)?;
+
+		self.running += 1;
+		let ret = callback(&mut self.elems[idx].func);
+		self.running -= 1;
+		self.flush();
+		Some(ret)
 	}
 
 	/// Finds all the functions associated to the given identifier, if any, and
 	/// passes each one of them, first to last, to the supplied callback.
 	pub fn find_all<C: Fn(&mut F)>(&mut self, id: K, callback: C) {
-		for elem in self.elems.iter_mut() {
-			if elem.0 == id {
-				callback(&mut elem.1);
+		self.running += 1;
+		for idx in 0..self.elems.len() { // functions added meanwhile go to pending
+			let elem = &mut self.elems[idx];
+			if elem.alive && elem.id == id {
+				callback(&mut elem.func);
 			}
 		}
+		self.running -= 1;
+		self.flush();
+	}
+
+	/// Removes all the functions added with the given handle, returning whether
+	/// any function was found.
+	pub fn remove(&mut self, handle: EventHandle) -> bool {
+		self.remove_where(|elem| elem.handle == handle)
+	}
+
+	/// Removes all the functions associated to the given identifier, returning
+	/// whether any function was found.
+	pub fn remove_all(&mut self, id: K) -> bool {
+		self.remove_where(|elem| elem.id == id)
 	}
 
 	/// Tells whether no functions have been added.
 	pub fn is_empty(&self) -> bool {
-		self.elems.is_empty()
+		self.pending.is_empty()
+			&& !self.elems.iter().any(|elem| elem.alive)
+	}
+
+	fn remove_where<P: Fn(&Elem<K, F>) -> bool>(&mut self, pred: P) -> bool {
+		let mut found = false;
+		for elem in self.elems.iter_mut().filter(|elem| elem.alive) {
+			if pred(elem) {
+				elem.alive = false;
+				found = true;
+			}
+		}
+		let num_pending = self.pending.len();
+		self.pending.retain(|elem| !pred(elem));
+		found |= self.pending.len() != num_pending;
+
+		self.flush();
+		found
+	}
+
+	/// If not running, drops the removed functions and appends the pending ones.
+	fn flush(&mut self) {
+		if self.running == 0 {
+			self.elems.retain(|elem| elem.alive);
+			self.elems.append(&mut self.pending);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::cell::RefCell;
+
+	use super::*;
+
+	fn store_with(elems: &[(u32, i32)]) -> (FuncStore<u32, i32>, Vec<EventHandle>) {
+		let mut store = FuncStore::new();
+		let handles = elems.iter()
+			.map(|(id, val)| {
+				let handle = EventHandle::next();
+				store.insert(*id, handle, *val);
+				handle
+			})
+			.collect();
+		(store, handles)
+	}
+
+	#[test]
+	fn run_last_wins() {
+		let (mut store, handles) = store_with(&[(1, 10), (2, 20), (1, 30)]);
+		assert_eq!(store.run_last(1, |val| *val), Some(30));
+		assert_eq!(store.run_last(2, |val| *val), Some(20));
+		assert_eq!(store.run_last(3, |val| *val), None);
+
+		assert!(store.remove(handles[2]));
+		assert_eq!(store.run_last(1, |val| *val), Some(10)); // previous one is back
+	}
+
+	#[test]
+	fn find_all_in_insertion_order() {
+		let (mut store, _) = store_with(&[(1, 10), (2, 20), (1, 30), (1, 40)]);
+		let ran = RefCell::new(Vec::new());
+		store.find_all(1, |val| ran.borrow_mut().push(*val));
+		assert_eq!(*ran.borrow(), vec![10, 30, 40]);
+	}
+
+	#[test]
+	fn remove_while_running() {
+		let (mut store, handles) = store_with(&[(1, 10), (1, 20), (1, 30)]);
+		let store_ptr = &mut store as *mut FuncStore<u32, i32>; // handlers reach the store through the window
+		let ran = RefCell::new(Vec::new());
+
+		store.find_all(1, |val| {
+			ran.borrow_mut().push(*val);
+			if *val == 10 {
+				assert!(unsafe { &mut *store_ptr }.remove(handles[1]));
+				assert!(unsafe { &mut *store_ptr }.remove(handles[0])); // itself
+			}
+		});
+		assert_eq!(*ran.borrow(), vec![10, 30]); // removed function is not run
+		assert_eq!(store.elems.len(), 1); // removed ones dropped after running
+		assert_eq!(store.run_last(1, |val| *val), Some(30));
+	}
+
+	#[test]
+	fn insert_while_running() {
+		let (mut store, _) = store_with(&[(1, 10)]);
+		let store_ptr = &mut store as *mut FuncStore<u32, i32>;
+
+		let ret = store.run_last(1, |val| {
+			unsafe { &mut *store_ptr }.insert(1, EventHandle::next(), 20);
+			*val
+		});
+		assert_eq!(ret, Some(10)); // new function is not run in the same pass
+		assert!(store.pending.is_empty());
+		assert_eq!(store.run_last(1, |val| *val), Some(20));
+	}
+
+	#[test]
+	fn remove_all_and_is_empty() {
+		let (mut store, _) = store_with(&[(1, 10), (2, 20), (1, 30)]);
+		assert!(store.remove_all(1));
+		assert!(!store.remove_all(1));
+		assert!(!store.is_empty());
+		assert!(store.remove_all(2));
+		assert!(store.is_empty());
 	}
 }
@@ -21,6 +21,12 @@ macro_rules! ctrl_events_proxy {
 			fn parent_user_events(&self) -> &WindowEvents {
 				unsafe { self.parent_ptr.as_ref().user_events_ref() }
 			}
+
+			/// Removes the event handler identified by the given handle, just
+			/// like [`WindowEvents::remove`](crate::gui::events::WindowEvents::remove).
+			pub fn remove(&self, handle: EventHandle) -> bool {
+				self.parent_user_events().remove(handle)
+			}
 		}
 	}
 }
@@ -32,13 +38,27 @@ macro_rules! cmd_event {
 		$(#[$doc:meta])*
 	) => {
 		$(#[$doc])*
-		pub fn $name<F>(&self, func: F)
+		pub fn $name<F>(&self, func: F) -> EventHandle
 			where F: FnMut() + 'static,
 		{
 			self.parent_user_events().wm_command($cmd, self.ctrl_id, {
 				let mut func = func;
 				move || func()
-			});
+			})
+		}
+	};
+}
+
+/// Declares a crate-only method for a `WM_COMMAND` notification, whose handler
+/// is added to the privileged events of the parent window, thus not replacing
+/// the user handler.
+macro_rules! privileged_cmd_event {
+	($name:ident, $cmd:expr) => {
+		pub(crate) fn $name<F>(&self, func: F) -> EventHandle
+			where F: FnMut() + 'static,
+		{
+			unsafe { self.parent_ptr.as_ref() }.privileged_events_ref()
+				.wm_command($cmd, self.ctrl_id, func)
 		}
 	};
 }
@@ -52,13 +72,13 @@ macro_rules! nfy_event {
 		$(#[$doc:meta])*
 	) => {
 		$(#[$doc])*
-		pub fn $name<F>(&self, func: F)
+		pub fn $name<F>(&self, func: F) -> EventHandle
 			where F: FnMut() + 'static,
 		{
 			self.parent_user_events().add_nfy(self.ctrl_id, $nfy, {
 				let mut func = func;
 				move |_| { func(); None }
-			});
+			})
 		}
 	};
 }
@@ -71,13 +91,13 @@ macro_rules! nfy_event_p {
 		$(#[$doc:meta])*
 	) => {
 		$(#[$doc])*
-		pub fn $name<F>(&self, func: F)
+		pub fn $name<F>(&self, func: F) -> EventHandle
 			where F: FnMut(&$struc) + 'static,
 		{
 			self.parent_user_events().add_nfy(self.ctrl_id, $nfy, {
 				let mut func = func;
 				move |p| { func(unsafe { p.cast_nmhdr::<$struc>() }); None }
-			});
+			})
 		}
 	};
 }
@@ -90,13 +110,13 @@ macro_rules! nfy_event_mut_p {
 		$(#[$doc:meta])*
 	) => {
 		$(#[$doc])*
-		pub fn $name<F>(&self, func: F)
+		pub fn $name<F>(&self, func: F) -> EventHandle
 			where F: FnMut(&mut $struc) + 'static,
 		{
 			self.parent_user_events().add_nfy(self.ctrl_id, $nfy, {
 				let mut func = func;
 				move |p| { func(unsafe { p.cast_nmhdr_mut::<$struc>() }); None }
-			});
+			})
 		}
 	};
 }
@@ -109,13 +129,13 @@ macro_rules! nfy_event_p_bool {
 		$(#[$doc:meta])*
 	) => {
 		$(#[$doc])*
-		pub fn $name<F>(&self, func: F)
+		pub fn $name<F>(&self, func: F) -> EventHandle
 			where F: FnMut(&$struc) -> bool + 'static,
 		{
 			self.parent_user_events().add_nfy(self.ctrl_id, $nfy, {
 				let mut func = func;
 				move |p| Some(func(unsafe { p.cast_nmhdr::<$struc>() }) as isize)
-			});
+			})
 		}
 	};
 }
@@ -128,13 +148,13 @@ macro_rules! nfy_event_mut_p_bool {
 		$(#[$doc:meta])*
 	) => {
 		$(#[$doc])*
-		pub fn $name<F>(&self, func: F)
+		pub fn $name<F>(&self, func: F) -> EventHandle
 			where F: FnMut(&mut $struc) -> bool + 'static,
 		{
 			self.parent_user_events().add_nfy(self.ctrl_id, $nfy, {
 				let mut func = func;
 				move |p| Some(func(unsafe { p.cast_nmhdr_mut::<$struc>() }) as isize)
-			});
+			})
 		}
 	};
 }
@@ -13,10 +13,13 @@ mod events_bn;
 mod events_cbn;
 mod events_dtn;
 mod events_en;
+mod events_hk;
 mod events_lbn;
 mod events_lvn;
 mod events_mcn;
+mod events_psn;
 mod events_sbn;
+mod events_stn_acn;
 mod events_stn;
 mod events_wm;
 
@@ -25,10 +28,14 @@ pub use events_bn::ButtonEvents;
 pub use events_cbn::ComboBoxEvents;
 pub use events_dtn::DateTimePickerEvents;
 pub use events_en::EditEvents;
+pub use events_hk::HotKeyEvents;
 pub use events_lbn::ListBoxEvents;
 pub use events_lvn::ListViewEvents;
 pub use events_mcn::MonthCalendarEvents;
+pub use events_psn::PropertyPageEvents;
 pub use events_sbn::StatusBarEvents;
+pub use events_stn_acn::ImageViewEvents;
 pub use events_stn::LabelEvents;
-pub use events_wm::WindowEvents;
-pub(crate) use events_wm::ProcessResult;
+pub use events_wm::{ProcessResult, WindowEvents};
+pub use func_store::EventHandle;
+pub(crate) use func_store::FuncStore;
@@ -0,0 +1,301 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::ThreadId;
+use std::time::Duration;
+
+use crate::gui::base::Base;
+use crate::gui::timer::{set_timer, TimerHandle};
+use crate::gui::ui_thread::UiThreadSender;
+use crate::handles::HWND;
+
+/// The main window, which polls the tasks, set when it's created.
+static mut EXECUTOR: Option<Executor> = None;
+
+struct Executor {
+	hwnd: HWND,
+	sender: UiThreadSender,
+	thread_id: ThreadId, // the GUI thread, which owns the tasks
+}
+
+/// Tasks being run, all of them in the GUI thread.
+static mut TASKS: Option<HashMap<u64, Slot>> = None;
+
+static mut BASE_TASK_ID: u64 = 1;
+
+enum Slot {
+	Idle(Pin<Box<dyn Future<Output = ()>>>),
+	Polling { woken: bool }, // woken while being polled, must be polled again
+}
+
+fn tasks() -> &'static mut HashMap<u64, Slot> {
+	unsafe {
+		(*std::ptr::addr_of_mut!(TASKS)).get_or_insert_with(HashMap::new)
+	}
+}
+
+fn executor() -> &'static Executor {
+	match unsafe { &*std::ptr::addr_of!(EXECUTOR) } {
+		Some(executor) => executor,
+		None => panic!("Cannot run futures before the main window is created."),
+	}
+}
+
+fn executor_sender() -> UiThreadSender {
+	executor().sender.clone()
+}
+
+/// Returns the executor window, panicking if not called from the GUI thread,
+/// since the tasks are not thread safe.
+fn executor_hwnd_in_gui_thread() -> HWND {
+	let executor = executor();
+	if std::thread::current().id() != executor.thread_id {
+		panic!("Cannot run futures outside the GUI thread.");
+	}
+	executor.hwnd
+}
+
+/// Adds the privileged handlers which make the main window run the futures
+/// spawned with [`spawn_local`](crate::gui::spawn_local).
+pub(crate) fn add_executor_handlers(base_ref: &Base) {
+	let base_ptr = base_ref as *const Base;
+
+	base_ref.privileged_events_ref().wm(base_ref.create_wm(), move |_| {
+		unsafe {
+			EXECUTOR = Some(Executor {
+				hwnd: *(*base_ptr).hwnd_ref(),
+				sender: UiThreadSender::new(&*base_ptr),
+				thread_id: std::thread::current().id(),
+			});
+		}
+		0
+	});
+
+	base_ref.privileged_events_ref().wm_nc_destroy(|| {
+		unsafe {
+			EXECUTOR = None;
+			(*std::ptr::addr_of_mut!(TASKS)).take(); // pending tasks are dropped
+		}
+	});
+}
+
+struct TaskWaker {
+	sender: UiThreadSender,
+	task_id: u64,
+}
+
+impl Wake for TaskWaker {
+	fn wake(self: Arc<Self>) {
+		self.wake_by_ref();
+	}
+
+	fn wake_by_ref(self: &Arc<Self>) {
+		let task_id = self.task_id;
+		self.sender.post(move || poll_task(task_id))
+			.ok(); // if the window is gone, the task is gone too
+	}
+}
+
+fn poll_task(task_id: u64) {
+	let mut fut = match tasks().get_mut(&task_id) {
+		Some(slot) => match std::mem::replace(slot, Slot::Polling { woken: false }) {
+			Slot::Idle(fut) => fut,
+			Slot::Polling { .. } => { // re-entrant poll, from a nested modal loop
+				*slot = Slot::Polling { woken: true };
+				return;
+			},
+		},
+		None => return, // task already finished
+	};
+
+	let waker = Waker::from(Arc::new(TaskWaker { sender: executor_sender(), task_id }));
+	let mut cx = Context::from_waker(&waker);
+
+	match fut.as_mut().poll(&mut cx) { // the map may be modified while polling
+		Poll::Ready(()) => {
+			tasks().remove(&task_id);
+		},
+		Poll::Pending => {
+			let woken = matches!(tasks().get(&task_id), Some(Slot::Polling { woken: true }));
+			tasks().insert(task_id, Slot::Idle(fut));
+			if woken {
+				waker.wake();
+			}
+		},
+	}
+}
+
+/// Runs a future in the GUI thread, without blocking the message loop. The
+/// future starts running right after the current event handler returns.
+///
+/// The future can await [`sleep`](crate::gui::sleep),
+/// [`spawn_blocking`](crate::gui::spawn_blocking),
+/// [`WindowModal::show_modal_async`](crate::gui::WindowModal::show_modal_async),
+/// or any other future whose waker can be called from any thread. Pending
+/// futures are dropped when the main window is destroyed.
+///
+/// # Panics
+///
+/// Panics if the main window is not created yet, or if not called from the
+/// GUI thread, which created the main window.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use winsafe::gui::{Button, Label, spawn_blocking, spawn_local};
+///
+/// let btn: Button; // initialize them somewhere...
+/// let lbl: Label;
+///
+/// btn.on().bn_clicked({
+///     let lbl = lbl.clone();
+///     move || {
+///         let lbl = lbl.clone();
+///         spawn_local(async move {
+///             lbl.set_text("Downloading...").unwrap();
+///             let len = spawn_blocking(|| download_file()).await;
+///             lbl.set_text(&format!("Downloaded {} bytes.", len)).unwrap();
+///         });
+///     }
+/// });
+/// ```
+pub fn spawn_local<F>(fut: F)
+	where F: Future<Output = ()> + 'static,
+{
+	executor_hwnd_in_gui_thread();
+	let sender = executor_sender();
+	let task_id = unsafe {
+		let new_id = BASE_TASK_ID;
+		BASE_TASK_ID += 1;
+		new_id
+	};
+
+	tasks().insert(task_id, Slot::Idle(Box::pin(fut)));
+	sender.post(move || poll_task(task_id))
+		.unwrap_or_else(|_| { tasks().remove(&task_id); });
+}
+
+//------------------------------------------------------------------------------
+
+struct Shared<T> {
+	result: Option<T>,
+	waker: Option<Waker>,
+}
+
+/// A future which resolves when its result is set by someone else.
+struct OneShot<T>(Arc<Mutex<Shared<T>>>);
+
+impl<T> OneShot<T> {
+	fn new() -> OneShot<T> {
+		Self(Arc::new(Mutex::new(Shared { result: None, waker: None })))
+	}
+
+	fn setter(&self) -> impl FnOnce(T) {
+		let shared = self.0.clone();
+		move |result| {
+			let mut shared = shared.lock().unwrap();
+			shared.result = Some(result);
+			if let Some(waker) = shared.waker.take() {
+				waker.wake();
+			}
+		}
+	}
+}
+
+impl<T> Future for OneShot<T> {
+	type Output = T;
+
+	fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+		let mut shared = self.0.lock().unwrap();
+		match shared.result.take() {
+			Some(result) => Poll::Ready(result),
+			None => {
+				shared.waker = Some(cx.waker().clone());
+				Poll::Pending
+			},
+		}
+	}
+}
+
+/// Runs the closure in a new thread, returning a future which resolves to the
+/// closure result. Awaiting it from a future spawned with
+/// [`spawn_local`](crate::gui::spawn_local) doesn't block the GUI thread.
+pub fn spawn_blocking<F, T>(func: F) -> impl Future<Output = T>
+	where F: FnOnce() -> T + Send + 'static,
+		T: Send + 'static,
+{
+	let one_shot = OneShot::new();
+	let set_result = one_shot.setter();
+	std::thread::spawn(move || set_result(func()));
+	one_shot
+}
+
+/// Returns a future which resolves after the given duration, measured by a
+/// one-shot timer in the main window, so no thread is blocked.
+///
+/// # Panics
+///
+/// Panics if polled before the main window is created, or outside the GUI
+/// thread, like from a future not spawned with
+/// [`spawn_local`](crate::gui::spawn_local).
+pub fn sleep(duration: Duration) -> impl Future<Output = ()> {
+	Sleep {
+		interval_ms: duration.as_millis().min(u32::MAX as u128) as u32,
+		timer: None,
+	}
+}
+
+struct Sleep {
+	interval_ms: u32,
+	timer: Option<(TimerHandle, OneShot<()>)>, // set on first poll
+}
+
+impl Future for Sleep {
+	type Output = ();
+
+	fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+		if self.timer.is_none() {
+			let one_shot = OneShot::new();
+			let mut set_result = Some(one_shot.setter());
+			match set_timer(executor_hwnd_in_gui_thread(), self.interval_ms, true,
+				Box::new(move || if let Some(set_result) = set_result.take() {
+					set_result(());
+				}))
+			{
+				Ok(timer) => self.timer = Some((timer, one_shot)),
+				Err(_) => return Poll::Ready(()), // no timers left, don't hang the task
+			}
+		}
+		Pin::new(&mut self.timer.as_mut().unwrap().1).poll(cx)
+	}
+}
+
+impl Drop for Sleep {
+	fn drop(&mut self) {
+		if let Some((timer, _)) = self.timer.take() {
+			timer.kill().ok(); // sleep cancelled, no-op if already fired
+		}
+	}
+}
+
+/// Runs the closure from the message loop, after the current task yields,
+/// returning a future which resolves to the closure result. This allows a task
+/// to run a modal loop without nesting it inside the poll.
+pub(crate) fn run_deferred<F, T>(func: F) -> impl Future<Output = T>
+	where F: FnOnce() -> T + 'static,
+		T: 'static,
+{
+	struct AssertSend<F>(F); // the closure is posted to the same thread
+	unsafe impl<F> Send for AssertSend<F> {}
+
+	let one_shot = OneShot::new();
+	let deferred = AssertSend((one_shot.setter(), func));
+	executor_sender().post(move || {
+		let deferred = deferred;
+		let (set_result, func) = deferred.0;
+		set_result(func());
+	}).ok(); // if the window is gone, the task is gone too
+	one_shot
+}
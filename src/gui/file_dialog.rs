@@ -0,0 +1,329 @@
+use std::path::{Path, PathBuf};
+
+use crate::aliases::WinResult;
+use crate::co;
+use crate::com::{CoCreateInstance, CoInitializeEx, CoUninitialize};
+use crate::com::shell;
+use crate::funcs::{GetOpenFileName, GetSaveFileName};
+use crate::gui::traits::{baseref_from_parent, Parent};
+use crate::handles::HWND;
+use crate::privs::parse_multi_z_str;
+use crate::structs::OPENFILENAME;
+use crate::WString;
+
+/// Buffer size, in chars, used by the legacy dialogs to return the paths.
+const LEGACY_BUF_SZ: usize = 32 * 1024;
+
+/// Builds and displays the system
+/// [common item dialogs](https://docs.microsoft.com/en-us/windows/win32/shell/common-file-dialog)
+/// to open files, save a file or pick a folder.
+///
+/// The dialogs are created with
+/// [`IFileOpenDialog`](crate::shell::IFileOpenDialog) and
+/// [`IFileSaveDialog`](crate::shell::IFileSaveDialog). If these cannot be
+/// instantiated, the open and save dialogs fall back to
+/// [`GetOpenFileName`](crate::GetOpenFileName) and
+/// [`GetSaveFileName`](crate::GetSaveFileName).
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use winsafe::gui::{FileDialog, WindowMain};
+///
+/// let wnd: WindowMain; // initialize it somewhere...
+///
+/// let files = FileDialog::new()
+///     .title("Open images")
+///     .filter("Images", "*.png;*.jpg")
+///     .filter("All files", "*.*")
+///     .multi_select(true)
+///     .show_open(&wnd)
+///     .unwrap();
+///
+/// for file in files.iter() {
+///     println!("{}", file.display());
+/// }
+/// ```
+#[derive(Default)]
+pub struct FileDialog {
+	title: Option<String>,
+	filters: Vec<(String, String)>,
+	default_ext: Option<String>,
+	initial_dir: Option<PathBuf>,
+	file_name: Option<String>,
+	multi_select: bool,
+}
+
+impl FileDialog {
+	/// Creates a new builder, with no filters.
+	pub fn new() -> FileDialog {
+		Self::default()
+	}
+
+	/// Sets the title of the dialog.
+	pub fn title(mut self, title: &str) -> FileDialog {
+		self.title = Some(title.to_owned());
+		self
+	}
+
+	/// Adds a file type filter, like `("Text files", "*.txt;*.log")`. The first
+	/// filter added is the initially selected one.
+	pub fn filter(mut self, name: &str, spec: &str) -> FileDialog {
+		self.filters.push((name.to_owned(), spec.to_owned()));
+		self
+	}
+
+	/// Sets the extension, without the dot, appended to the file name typed by
+	/// the user if it has none.
+	pub fn default_extension(mut self, ext: &str) -> FileDialog {
+		self.default_ext = Some(ext.trim_start_matches('.').to_owned());
+		self
+	}
+
+	/// Sets the folder initially displayed.
+	pub fn initial_dir<P: AsRef<Path>>(mut self, dir: P) -> FileDialog {
+		self.initial_dir = Some(dir.as_ref().to_path_buf());
+		self
+	}
+
+	/// Sets the file name initially displayed in the file name field.
+	pub fn file_name(mut self, name: &str) -> FileDialog {
+		self.file_name = Some(name.to_owned());
+		self
+	}
+
+	/// Sets whether the open dialog allows selecting multiple files.
+	///
+	/// Defaults to `false`.
+	pub fn multi_select(mut self, multi: bool) -> FileDialog {
+		self.multi_select = multi;
+		self
+	}
+
+	/// Displays a modal dialog to open one or more existing files, returning
+	/// the chosen paths, or an empty `Vec` if the user cancelled.
+	pub fn show_open(&self, parent: &dyn Parent) -> WinResult<Vec<PathBuf>> {
+		let hparent = *baseref_from_parent(parent).hwnd_ref();
+
+		with_com(|| {
+			let fo: shell::IFileOpenDialog = match CoCreateInstance(
+				&shell::clsid::FileOpenDialog,
+				None,
+				co::CLSCTX::INPROC_SERVER,
+			) {
+				Ok(fo) => fo,
+				Err(_) => return self.legacy_open(hparent),
+			};
+
+			let mut fos = shell::co::FOS::FORCEFILESYSTEM
+				| shell::co::FOS::PATHMUSTEXIST | shell::co::FOS::FILEMUSTEXIST;
+			if self.multi_select {
+				fos |= shell::co::FOS::ALLOWMULTISELECT;
+			}
+			self.setup(&fo.IFileDialog, fos)?;
+
+			if !fo.IFileDialog.IModalWindow.Show(hparent)? {
+				return Ok(Vec::default()); // user cancelled
+			}
+
+			let results = fo.GetResults()?;
+			let mut paths = Vec::with_capacity(results.GetCount()? as usize);
+			for i in 0..results.GetCount()? {
+				let item = results.GetItemAt(i)?;
+				paths.push(PathBuf::from(item.GetDisplayName(shell::co::SIGDN::FILESYSPATH)?));
+			}
+			Ok(paths)
+		})
+	}
+
+	/// Displays a modal dialog to choose the path of a file to be saved,
+	/// prompting the user if it already exists. Returns `None` if the user
+	/// cancelled.
+	pub fn show_save(&self, parent: &dyn Parent) -> WinResult<Option<PathBuf>> {
+		let hparent = *baseref_from_parent(parent).hwnd_ref();
+
+		with_com(|| {
+			let fs: shell::IFileSaveDialog = match CoCreateInstance(
+				&shell::clsid::FileSaveDialog,
+				None,
+				co::CLSCTX::INPROC_SERVER,
+			) {
+				Ok(fs) => fs,
+				Err(_) => return self.legacy_save(hparent),
+			};
+
+			self.setup(&fs.IFileDialog,
+				shell::co::FOS::FORCEFILESYSTEM | shell::co::FOS::PATHMUSTEXIST
+					| shell::co::FOS::OVERWRITEPROMPT)?;
+
+			if !fs.IFileDialog.IModalWindow.Show(hparent)? {
+				return Ok(None); // user cancelled
+			}
+
+			let item = fs.IFileDialog.GetResult()?;
+			Ok(Some(PathBuf::from(item.GetDisplayName(shell::co::SIGDN::FILESYSPATH)?)))
+		})
+	}
+
+	/// Displays a modal dialog to choose a folder. Returns `None` if the user
+	/// cancelled.
+	///
+	/// Filters, default extension and multi-selection are ignored. Unlike the
+	/// other dialogs, there is no legacy fallback: if
+	/// [`IFileOpenDialog`](crate::shell::IFileOpenDialog) cannot be
+	/// instantiated, an error is returned.
+	pub fn pick_folder(&self, parent: &dyn Parent) -> WinResult<Option<PathBuf>> {
+		let hparent = *baseref_from_parent(parent).hwnd_ref();
+
+		with_com(|| {
+			let fo: shell::IFileOpenDialog = CoCreateInstance(
+				&shell::clsid::FileOpenDialog,
+				None,
+				co::CLSCTX::INPROC_SERVER,
+			)?;
+
+			let fd = &fo.IFileDialog;
+			fd.SetOptions(fd.GetOptions()?
+				| shell::co::FOS::PICKFOLDERS | shell::co::FOS::FORCEFILESYSTEM
+				| shell::co::FOS::PATHMUSTEXIST)?;
+			if let Some(title) = &self.title {
+				fd.SetTitle(title)?;
+			}
+			self.set_initial_dir(fd);
+
+			if !fd.IModalWindow.Show(hparent)? {
+				return Ok(None); // user cancelled
+			}
+
+			let item = fd.GetResult()?;
+			Ok(Some(PathBuf::from(item.GetDisplayName(shell::co::SIGDN::FILESYSPATH)?)))
+		})
+	}
+
+	fn setup(&self, fd: &shell::IFileDialog, fos: shell::co::FOS) -> WinResult<()> {
+		fd.SetOptions(fd.GetOptions()? | fos)?;
+
+		if let Some(title) = &self.title {
+			fd.SetTitle(title)?;
+		}
+		if !self.filters.is_empty() {
+			let filters: Vec<(&str, &str)> = self.filters.iter()
+				.map(|(name, spec)| (name.as_str(), spec.as_str()))
+				.collect();
+			fd.SetFileTypes(&filters)?;
+			fd.SetFileTypeIndex(1)?; // one-based index
+		}
+		if let Some(ext) = &self.default_ext {
+			fd.SetDefaultExtension(ext)?;
+		}
+		if let Some(file_name) = &self.file_name {
+			fd.SetFileName(file_name)?;
+		}
+		self.set_initial_dir(fd);
+		Ok(())
+	}
+
+	fn set_initial_dir(&self, fd: &shell::IFileDialog) {
+		if let Some(dir) = &self.initial_dir {
+			if let Ok(item) = shell::SHCreateItemFromParsingName(&dir.to_string_lossy()) {
+				fd.SetFolder(&item).ok(); // a nonexistent folder is not an error
+			}
+		}
+	}
+
+	fn legacy_open(&self, hparent: HWND) -> WinResult<Vec<PathBuf>> {
+		let mut flags = co::OFN::EXPLORER | co::OFN::PATHMUSTEXIST
+			| co::OFN::FILEMUSTEXIST | co::OFN::HIDEREADONLY | co::OFN::NOCHANGEDIR;
+		if self.multi_select {
+			flags |= co::OFN::ALLOWMULTISELECT;
+		}
+
+		let (filter_buf, mut file_buf, init_dir_buf, title_buf, def_ext_buf) = self.legacy_bufs();
+		let mut ofn = self.legacy_ofn(hparent, flags,
+			&filter_buf, &mut file_buf, &init_dir_buf, &title_buf, &def_ext_buf);
+
+		if !GetOpenFileName(&mut ofn)? {
+			return Ok(Vec::default()); // user cancelled
+		}
+
+		let strs = parse_multi_z_str(unsafe { file_buf.as_ptr() });
+		Ok(match strs.split_first() {
+			Some((first, [])) => vec![PathBuf::from(first)], // single file, full path
+			Some((dir, names)) => names.iter() // directory followed by file names
+				.map(|name| Path::new(dir).join(name))
+				.collect(),
+			None => Vec::default(),
+		})
+	}
+
+	fn legacy_save(&self, hparent: HWND) -> WinResult<Option<PathBuf>> {
+		let flags = co::OFN::EXPLORER | co::OFN::PATHMUSTEXIST
+			| co::OFN::OVERWRITEPROMPT | co::OFN::HIDEREADONLY | co::OFN::NOCHANGEDIR;
+
+		let (filter_buf, mut file_buf, init_dir_buf, title_buf, def_ext_buf) = self.legacy_bufs();
+		let mut ofn = self.legacy_ofn(hparent, flags,
+			&filter_buf, &mut file_buf, &init_dir_buf, &title_buf, &def_ext_buf);
+
+		if !GetSaveFileName(&mut ofn)? {
+			return Ok(None); // user cancelled
+		}
+		Ok(Some(PathBuf::from(file_buf.to_string())))
+	}
+
+	fn legacy_bufs(&self) -> (WString, WString, WString, WString, WString) {
+		let filter_buf = if self.filters.is_empty() {
+			WString::default()
+		} else {
+			let mut filter = String::default(); // name\0spec\0name\0spec\0\0
+			for (name, spec) in self.filters.iter() {
+				filter.push_str(&format!("{}\0{}\0", name, spec));
+			}
+			WString::from_str(&filter)
+		};
+
+		let mut file_buf = WString::new_alloc_buffer(LEGACY_BUF_SZ);
+		if let Some(file_name) = &self.file_name {
+			WString::from_str(file_name).copy_to_slice(file_buf.as_mut_slice());
+		}
+
+		(
+			filter_buf,
+			file_buf,
+			WString::from_opt_str(self.initial_dir.as_ref().map(|d| d.to_string_lossy()).as_deref()),
+			WString::from_opt_str(self.title.as_deref()),
+			WString::from_opt_str(self.default_ext.as_deref()),
+		)
+	}
+
+	fn legacy_ofn(&self, hparent: HWND, flags: co::OFN,
+		filter_buf: &WString, file_buf: &mut WString, init_dir_buf: &WString,
+		title_buf: &WString, def_ext_buf: &WString) -> OPENFILENAME
+	{
+		let mut ofn = OPENFILENAME::default();
+		ofn.hwndOwner = hparent;
+		ofn.Flags = flags;
+		unsafe {
+			ofn.lpstrFilter = filter_buf.as_ptr();
+			ofn.nFilterIndex = if self.filters.is_empty() { 0 } else { 1 };
+			ofn.lpstrFile = file_buf.as_mut_ptr();
+			ofn.nMaxFile = file_buf.buffer_size() as u32;
+			ofn.lpstrInitialDir = init_dir_buf.as_ptr();
+			ofn.lpstrTitle = title_buf.as_ptr();
+			ofn.lpstrDefExt = def_ext_buf.as_ptr();
+		}
+		ofn
+	}
+}
+
+/// Runs the closure with COM initialized as single-threaded apartment, as
+/// required by the shell dialogs.
+fn with_com<T, F>(func: F) -> WinResult<T>
+	where F: FnOnce() -> WinResult<T>,
+{
+	let init = CoInitializeEx(co::COINIT::APARTMENTTHREADED)?;
+	let res = func(); // all COM objects are released when the closure returns
+	if init != co::ERROR::RPC_E_CHANGED_MODE {
+		CoUninitialize(); // pair the successful initialization
+	}
+	res
+}
@@ -0,0 +1,139 @@
+use crate::aliases::WinResult;
+use crate::co;
+use crate::funcs::ChooseFont;
+use crate::gui::traits::{baseref_from_parent, Parent};
+use crate::structs::{CHOOSEFONT, COLORREF, LOGFONT};
+
+/// The system [font dialog](https://docs.microsoft.com/en-us/windows/win32/dlgbox/font-dialog-box),
+/// created with [`ChooseFont`](crate::ChooseFont).
+///
+/// The chosen font and color are kept in the object, being the initial ones
+/// the next time the dialog is shown.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use winsafe::gui::{FontDialog, WindowMain};
+///
+/// let wnd: WindowMain; // initialize it somewhere...
+///
+/// let mut dlg = FontDialog::new();
+///
+/// if let Some((lf, color)) = dlg.show(&wnd).unwrap() {
+///     println!("Chosen: {}, {}", lf.lfFaceName(), color);
+/// }
+/// ```
+pub struct FontDialog {
+	font: LOGFONT,
+	color: COLORREF,
+	point_size: i32,
+	effects: bool,
+	fixed_pitch_only: bool,
+	size_limits: Option<(i32, i32)>,
+}
+
+impl Default for FontDialog {
+	fn default() -> Self {
+		Self {
+			font: LOGFONT::default(),
+			color: COLORREF::new(0, 0, 0),
+			point_size: 0,
+			effects: true,
+			fixed_pitch_only: false,
+			size_limits: None,
+		}
+	}
+}
+
+impl FontDialog {
+	/// Creates a new font dialog, with effects enabled and no initial font.
+	pub fn new() -> FontDialog {
+		Self::default()
+	}
+
+	/// Returns the initially selected font, which is the last chosen one after
+	/// the dialog is accepted.
+	pub fn font(&self) -> &LOGFONT {
+		&self.font
+	}
+
+	/// Sets the initially selected font.
+	pub fn set_font(&mut self, font: &LOGFONT) {
+		self.font = font.clone();
+	}
+
+	/// Returns the initially selected text color, which is the last chosen one
+	/// after the dialog is accepted.
+	pub fn color(&self) -> COLORREF {
+		self.color
+	}
+
+	/// Sets the initially selected text color. Only used if effects are
+	/// enabled.
+	pub fn set_color(&mut self, color: COLORREF) {
+		self.color = color;
+	}
+
+	/// Returns the size of the last chosen font, in tenths of a point.
+	pub fn point_size(&self) -> i32 {
+		self.point_size
+	}
+
+	/// Sets whether the dialog displays the strikeout, underline and text color
+	/// options.
+	///
+	/// Defaults to `true`.
+	pub fn set_effects(&mut self, effects: bool) {
+		self.effects = effects;
+	}
+
+	/// Sets whether only fixed-pitch fonts are listed.
+	///
+	/// Defaults to `false`.
+	pub fn set_fixed_pitch_only(&mut self, fixed_pitch_only: bool) {
+		self.fixed_pitch_only = fixed_pitch_only;
+	}
+
+	/// Limits the font sizes the user can choose, in points.
+	///
+	/// Defaults to none.
+	pub fn set_size_limits(&mut self, limits: Option<(i32, i32)>) {
+		self.size_limits = limits;
+	}
+
+	/// Displays the modal dialog, returning the chosen font and text color, or
+	/// `None` if the user cancelled.
+	pub fn show(&mut self,
+		parent: &dyn Parent) -> WinResult<Option<(LOGFONT, COLORREF)>>
+	{
+		let mut lf = self.font.clone();
+
+		let mut cf = CHOOSEFONT::default();
+		cf.hwndOwner = *baseref_from_parent(parent).hwnd_ref();
+		cf.lpLogFont = &mut lf;
+		cf.rgbColors = self.color;
+		cf.Flags = co::CHOOSEFONTF::SCREENFONTS | co::CHOOSEFONTF::FORCEFONTEXIST;
+		if !lf.lfFaceName().is_empty() {
+			cf.Flags |= co::CHOOSEFONTF::INITTOLOGFONTSTRUCT; // else the system default is selected
+		}
+		if self.effects {
+			cf.Flags |= co::CHOOSEFONTF::EFFECTS;
+		}
+		if self.fixed_pitch_only {
+			cf.Flags |= co::CHOOSEFONTF::FIXEDPITCHONLY;
+		}
+		if let Some((min, max)) = self.size_limits {
+			cf.Flags |= co::CHOOSEFONTF::LIMITSIZE;
+			cf.nSizeMin = min;
+			cf.nSizeMax = max;
+		}
+
+		if !ChooseFont(&mut cf)? {
+			return Ok(None); // user cancelled
+		}
+		self.color = cf.rgbColors;
+		self.point_size = cf.iPointSize;
+		self.font = lf.clone();
+		Ok(Some((lf, cf.rgbColors)))
+	}
+}
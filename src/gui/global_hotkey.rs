@@ -0,0 +1,74 @@
+use std::collections::HashMap;
+use std::ptr::NonNull;
+
+use crate::aliases::WinResult;
+use crate::co;
+use crate::gui::base::Base;
+use crate::gui::privs::auto_hotkey_id;
+use crate::handles::HWND;
+use crate::msg::wm;
+
+type HotKeyFunc = Box<dyn FnMut(wm::HotKey)>;
+
+/// Global hot keys of all windows, keyed by window handle and hot key ID. The
+/// closure is taken out while running.
+static mut HOTKEYS: Option<HashMap<(usize, i32), Option<HotKeyFunc>>> = None;
+
+fn hotkeys() -> &'static mut HashMap<(usize, i32), Option<HotKeyFunc>> {
+	unsafe {
+		(*std::ptr::addr_of_mut!(HOTKEYS)).get_or_insert_with(HashMap::new)
+	}
+}
+
+/// Adds the handlers which deliver `WM_HOTKEY` to the registered closures, and
+/// unregister the remaining hot keys when the window is destroyed.
+pub(crate) fn add_hotkey_handlers(base_ref: &Base) {
+	let base_ptr = NonNull::from(base_ref);
+
+	base_ref.privileged_events_ref().wm_hot_key(move |p| {
+		let hwnd = unsafe { *base_ptr.as_ref().hwnd_ref() };
+		let key = (hwnd.ptr as usize, p.hotkey_id);
+		let mut func = match hotkeys().get_mut(&key).and_then(|func| func.take()) {
+			Some(func) => func,
+			None => return, // not ours, or already running
+		};
+
+		func(p); // may unregister this or other hot keys
+
+		if let Some(slot) = hotkeys().get_mut(&key) {
+			*slot = Some(func); // put it back, unless unregistered
+		}
+	});
+
+	base_ref.privileged_events_ref().wm_destroy(move || {
+		let hwnd = unsafe { *base_ptr.as_ref().hwnd_ref() };
+		let hotkey_ids = hotkeys().keys()
+			.filter(|(hwnd_key, _)| *hwnd_key == hwnd.ptr as usize)
+			.map(|(_, hotkey_id)| *hotkey_id)
+			.collect::<Vec<_>>();
+		for hotkey_id in hotkey_ids {
+			hotkeys().remove(&(hwnd.ptr as usize, hotkey_id));
+			hwnd.UnregisterHotKey(hotkey_id).ok(); // window is going away, nobody to report to
+		}
+	});
+}
+
+pub(crate) fn register_hotkey(hwnd: HWND,
+	modifiers: co::MOD, vkey_code: co::VK, func: HotKeyFunc) -> WinResult<i32>
+{
+	if hwnd.is_null() {
+		panic!("Cannot register a hot key before the window is created.");
+	}
+
+	let hotkey_id = auto_hotkey_id();
+	hwnd.RegisterHotKey(hotkey_id, modifiers, vkey_code)?;
+	hotkeys().insert((hwnd.ptr as usize, hotkey_id), Some(func));
+	Ok(hotkey_id)
+}
+
+pub(crate) fn unregister_hotkey(hwnd: HWND, hotkey_id: i32) -> WinResult<()> {
+	match hotkeys().remove(&(hwnd.ptr as usize, hotkey_id)) {
+		Some(_) => hwnd.UnregisterHotKey(hotkey_id),
+		None => Ok(()), // never registered, or already unregistered
+	}
+}
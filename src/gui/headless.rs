@@ -0,0 +1,119 @@
+//! Message dispatching and layout computations which run without any native
+//! window, so event handlers and layouts can be exercised by automated tests,
+//! on any host.
+
+use crate::gui::events::{ProcessResult, WindowEvents};
+use crate::gui::layout_calc::{resz_axis, resz_min_parent_len};
+use crate::gui::native_controls::StatusBarPart;
+use crate::gui::resizer::Resz;
+use crate::msg::{MsgSend, WndMsg};
+use crate::structs::{RECT, SIZE};
+
+/// A window without a native handle, whose events are fed synthetic messages.
+///
+/// Messages are dispatched to the handlers exactly as a real window does: only
+/// the last handler added for a message is executed, and its result tells
+/// whether the default window procedure would be called.
+///
+/// **Note:** Messages carrying pointers, like
+/// [`WM_NOTIFY`](crate::msg::wm::Notify), must point to valid structs.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use winsafe::{co, msg};
+/// use winsafe::gui::headless::HeadlessWindow;
+///
+/// let wnd = HeadlessWindow::new();
+/// wnd.on().wm_command_accel_menu(1001, || println!("Clicked."));
+///
+/// let handled = wnd.send(msg::wm::Command {
+///     code: co::CMD::Menu,
+///     ctrl_id: 1001,
+///     ctrl_hwnd: None,
+/// });
+/// assert!(handled.is_some());
+/// ```
+pub struct HeadlessWindow {
+	events: WindowEvents,
+}
+
+impl Default for HeadlessWindow {
+	fn default() -> Self {
+		Self { events: WindowEvents::new() }
+	}
+}
+
+impl HeadlessWindow {
+	/// Creates a new `HeadlessWindow`, with no handlers.
+	pub fn new() -> HeadlessWindow {
+		Self::default()
+	}
+
+	/// Exposes the window events, to add handlers just like a real window.
+	pub fn on(&self) -> &WindowEvents {
+		&self.events
+	}
+
+	/// Runs the last handler added for the message, if any.
+	pub fn dispatch(&self, wm_any: WndMsg) -> ProcessResult {
+		self.events.process_effective_message(wm_any)
+	}
+
+	/// Runs all the handlers added for the message, first to last, discarding
+	/// the results. This is how the internal handlers of the library are
+	/// executed.
+	pub fn dispatch_all(&self, wm_any: WndMsg) {
+		self.events.process_all_messages(wm_any)
+	}
+
+	/// Runs the last handler added for the message, returning the converted
+	/// result, or `None` if there is no handler. A handled message without a
+	/// meaningful result is converted from zero.
+	pub fn send<M: MsgSend>(&self, msg: M) -> Option<M::RetType> {
+		match self.dispatch(msg.as_generic_wm()) {
+			ProcessResult::HandledWithRet(res) => Some(msg.convert_ret(res)),
+			ProcessResult::HandledWithoutRet => Some(msg.convert_ret(0)),
+			ProcessResult::NotHandled => None,
+		}
+	}
+}
+
+/// Computes the rectangle where a [`Resizer`](crate::gui::Resizer) places a
+/// control, when the parent client area changes from `sz_parent_orig` to
+/// `sz_parent`. The `min` and `max` sizes are in pixels, already adjusted to
+/// the DPI; zero means no limit.
+pub fn resizer_rect(horz: Resz, vert: Resz,
+	rc_orig: RECT, sz_parent_orig: SIZE, sz_parent: SIZE,
+	min: SIZE, max: SIZE) -> RECT
+{
+	let (x, cx) = resz_axis(horz, sz_parent.cx, sz_parent_orig.cx,
+		rc_orig.left, rc_orig.right - rc_orig.left, min.cx, max.cx);
+	let (y, cy) = resz_axis(vert, sz_parent.cy, sz_parent_orig.cy,
+		rc_orig.top, rc_orig.bottom - rc_orig.top, min.cy, max.cy);
+	RECT { left: x, top: y, right: x + cx, bottom: y + cy }
+}
+
+/// Computes the minimum parent client area which a
+/// [`Resizer`](crate::gui::Resizer) enforces for a control, so it keeps its
+/// minimum size and doesn't move past the left or top edge.
+pub fn resizer_min_parent(horz: Resz, vert: Resz,
+	rc_orig: RECT, sz_parent_orig: SIZE, min: SIZE) -> SIZE
+{
+	SIZE::new(
+		resz_min_parent_len(horz, sz_parent_orig.cx,
+			rc_orig.left, rc_orig.right - rc_orig.left, min.cx),
+		resz_min_parent_len(vert, sz_parent_orig.cy,
+			rc_orig.top, rc_orig.bottom - rc_orig.top, min.cy),
+	)
+}
+
+/// Computes the right edges of the [`StatusBar`](crate::gui::StatusBar) parts
+/// for the given client area width, as sent in
+/// [`SB_SETPARTS`](crate::msg::sb::SetParts). Fixed widths are in pixels,
+/// already adjusted to the DPI.
+pub fn status_bar_right_edges(parts: &[StatusBarPart], client_cx: i32) -> Vec<i32> {
+	let mut right_edges = vec![0; parts.len()];
+	crate::gui::layout_calc::status_bar_right_edges(parts, client_cx, &mut right_edges);
+	right_edges
+}
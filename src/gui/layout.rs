@@ -0,0 +1,324 @@
+use std::ptr::NonNull;
+use std::sync::Arc;
+
+use crate::aliases::WinResult;
+use crate::co;
+use crate::enums::HwndPlace;
+use crate::funcs::{MulDiv, PostQuitMessage};
+use crate::gui::base::Base;
+use crate::gui::layout_calc::{Cell, Kind, Node};
+use crate::gui::traits::{baseref_from_parent, Child, hwndref_from_child, Parent};
+use crate::gui::very_unsafe_cell::VeryUnsafeCell;
+use crate::handles::{HDWP, HWND};
+use crate::structs::{RECT, SIZE};
+
+/// In a [`LayoutNode`](crate::gui::LayoutNode) stack or grid, determines how
+/// the length of a row or column is computed.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Track {
+	/// The track takes the natural length of its contents, which, for a child
+	/// control, is the size it was created with.
+	Auto,
+	/// The track has a fixed length, in pixels, which will be adjusted to
+	/// match the current DPI of the parent window.
+	Fixed(u32),
+	/// The track takes a share of the space left by the auto and fixed tracks,
+	/// proportional to the given weight.
+	Star(f32),
+}
+
+/// A cell of a [`LayoutNode::grid`](crate::gui::LayoutNode::grid).
+pub struct GridCell {
+	row: u32,
+	col: u32,
+	row_span: u32,
+	col_span: u32,
+	node: LayoutNode,
+}
+
+impl GridCell {
+	/// Creates a new cell, placing the node at the given zero-based row and
+	/// column, spanning a single track in each direction.
+	pub fn at(row: u32, col: u32, node: LayoutNode) -> GridCell {
+		Self { row, col, row_span: 1, col_span: 1, node }
+	}
+
+	/// Sets the number of rows and columns the cell spans.
+	///
+	/// Spanning cells don't contribute to the natural size of auto tracks.
+	pub fn span(mut self, row_span: u32, col_span: u32) -> GridCell {
+		self.row_span = row_span.max(1);
+		self.col_span = col_span.max(1);
+		self
+	}
+}
+
+/// A node of the tree given to [`Layout::new`](crate::gui::Layout::new),
+/// which can be a child control, a stack or a grid.
+///
+/// All lengths are in pixels, and will be adjusted to match the current DPI of
+/// the parent window.
+pub struct LayoutNode(Node<NonNull<HWND>>);
+
+impl LayoutNode {
+	/// Creates a node which holds a child control. The control is sized to fill
+	/// the area given by its parent node.
+	pub fn ctrl(child: &dyn Child) -> LayoutNode {
+		Self(Node::new(Kind::Leaf {
+			item: NonNull::from(hwndref_from_child(child)), // ref implicitly converted to pointer
+			natural: SIZE::default(), // retrieved when the parent window is created
+		}))
+	}
+
+	/// Creates a node which places its items side by side, from left to right.
+	pub fn hstack(spacing: u32, items: Vec<(Track, LayoutNode)>) -> LayoutNode {
+		Self::stack(true, spacing, items)
+	}
+
+	/// Creates a node which stacks its items from top to bottom.
+	pub fn vstack(spacing: u32, items: Vec<(Track, LayoutNode)>) -> LayoutNode {
+		Self::stack(false, spacing, items)
+	}
+
+	fn stack(horz: bool,
+		spacing: u32, items: Vec<(Track, LayoutNode)>) -> LayoutNode
+	{
+		Self(Node::new(Kind::Stack {
+			horz,
+			spacing: spacing as i32,
+			items: items.into_iter()
+				.map(|(track, node)| (track, node.0))
+				.collect(),
+		}))
+	}
+
+	/// Creates a node which places its cells in the given columns and rows.
+	/// The `spacing` holds the horizontal and vertical gaps between the
+	/// tracks.
+	///
+	/// # Panics
+	///
+	/// Panics if a cell is out of the grid bounds.
+	pub fn grid(cols: &[Track], rows: &[Track],
+		spacing: SIZE, cells: Vec<GridCell>) -> LayoutNode
+	{
+		for cell in cells.iter() {
+			if cell.col + cell.col_span > cols.len() as u32
+				|| cell.row + cell.row_span > rows.len() as u32
+			{
+				panic!("Grid cell at row {}, column {} is out of bounds.", cell.row, cell.col);
+			}
+		}
+
+		Self(Node::new(Kind::Grid {
+			cols: cols.to_vec(),
+			rows: rows.to_vec(),
+			spacing,
+			cells: cells.into_iter()
+				.map(|cell| Cell {
+					row: cell.row as usize,
+					col: cell.col as usize,
+					row_span: cell.row_span as usize,
+					col_span: cell.col_span as usize,
+					node: cell.node.0,
+				})
+				.collect(),
+		}))
+	}
+
+	/// Sets the space around the node.
+	pub fn margins(mut self, margins: RECT) -> LayoutNode {
+		self.0.margins = margins;
+		self
+	}
+
+	/// Sets the minimum size of the node, not including the margins.
+	pub fn min_size(mut self, min: SIZE) -> LayoutNode {
+		self.0.min = min;
+		self
+	}
+
+	/// Sets the maximum size of the node, not including the margins. A zero
+	/// length means no limit in that direction. If the area given to the node
+	/// is larger, the node is placed at its top left corner.
+	pub fn max_size(mut self, max: SIZE) -> LayoutNode {
+		self.0.max = max;
+		self
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Arranges child controls in stacks and grids, recomputing their positions
+/// and sizes whenever the parent window is resized.
+///
+/// As an alternative to [`Resizer`](crate::gui::Resizer), the controls can
+/// be created with any position, since their rectangles are entirely computed
+/// by the layout; only their sizes are used, by [`Track::Auto`](crate::gui::Track::Auto).
+///
+/// When the DPI of the parent window changes, all lengths, including the
+/// natural sizes of the controls, are rescaled accordingly.
+#[derive(Clone)]
+pub struct Layout(Arc<VeryUnsafeCell<Obj>>);
+
+struct Obj { // actual fields of Layout
+	parent_ptr: NonNull<Base>,
+	root: Node<NonNull<HWND>>,
+	num_ctrls: usize,
+	created: bool,
+}
+
+impl Layout {
+	/// Instantiates a new `Layout`, which will arrange the controls of the
+	/// given tree within the parent client area.
+	///
+	/// The layout is first applied when the parent window is created, so it
+	/// must be instantiated after the child controls.
+	///
+	/// # Examples
+	///
+	/// In the example below, the list view takes all the space above a row of
+	/// two buttons, which are kept at the right.
+	///
+	/// ```rust,ignore
+	/// use winsafe::gui::{Button, Layout, LayoutNode, ListView, Track, WindowMain};
+	/// use winsafe::RECT;
+	///
+	/// let wnd: WindowMain; // initialize them somewhere...
+	/// let list: ListView;
+	/// let btn_ok: Button;
+	/// let btn_cancel: Button;
+	///
+	/// let layout = Layout::new(&wnd,
+	///     LayoutNode::vstack(8, vec![
+	///         (Track::Star(1.0), LayoutNode::ctrl(&list)),
+	///         (Track::Auto, LayoutNode::hstack(8, vec![
+	///             (Track::Star(1.0), LayoutNode::vstack(0, vec![])),
+	///             (Track::Auto, LayoutNode::ctrl(&btn_ok)),
+	///             (Track::Auto, LayoutNode::ctrl(&btn_cancel)),
+	///         ])),
+	///     ]).margins(RECT { left: 10, top: 10, right: 10, bottom: 10 }),
+	/// );
+	/// ```
+	pub fn new(parent: &dyn Parent, root: LayoutNode) -> Layout {
+		let parent_ref = baseref_from_parent(parent);
+
+		let mut num_ctrls = 0;
+		let mut root = root.0;
+		root.for_each_leaf_mut(&mut |_, _| num_ctrls += 1);
+
+		let layout = Self(
+			Arc::new(VeryUnsafeCell::new(
+				Obj {
+					parent_ptr: NonNull::from(parent_ref),
+					root,
+					num_ctrls,
+					created: false,
+				},
+			)),
+		);
+
+		let parent_events = parent_ref.privileged_events_ref();
+
+		parent_events.wm(parent_ref.create_wm(), {
+			let layout = layout.clone();
+			move |_| {
+				layout.create().unwrap_or_else(|err| PostQuitMessage(err));
+				0
+			}
+		});
+		parent_events.wm_size({
+			let layout = layout.clone();
+			move |p| {
+				if p.request != co::SIZE_R::MINIMIZED {
+					layout.resize(p.client_area)
+						.unwrap_or_else(|err| PostQuitMessage(err));
+				}
+			}
+		});
+		parent_ref.privileged_dpi_rescaler({
+			let layout = layout.clone();
+			move |old_dpi, new_dpi| layout.rescale(old_dpi, new_dpi)
+		});
+
+		layout
+	}
+
+	/// Returns the minimum size of the parent client area needed to display all
+	/// the controls with their natural sizes.
+	///
+	/// Note that the value is only available after the parent window is
+	/// created.
+	pub fn natural_size(&self) -> SIZE {
+		self.0.root.measure()
+	}
+
+	/// Recomputes the positions and sizes of all controls. This is
+	/// automatically done when the parent window is resized.
+	pub fn relayout(&self) -> WinResult<()> {
+		if !self.0.created {
+			return Ok(()); // dialogs receive WM_SIZE before WM_INITDIALOG
+		}
+		let rc_parent = self.hparent().GetClientRect()?;
+		self.resize(SIZE::new(rc_parent.right, rc_parent.bottom))
+	}
+
+	fn hparent(&self) -> HWND {
+		unsafe { *self.0.parent_ptr.as_ref().hwnd_ref() }
+	}
+
+	fn create(&self) -> WinResult<()> {
+		let hparent = self.hparent();
+		let mut res = Ok(());
+
+		self.0.as_mut().root.for_each_leaf_mut(&mut |hwnd_ptr, natural| {
+			if res.is_ok() {
+				res = unsafe { hwnd_ptr.as_ref() }.GetWindowRect()
+					.map(|rc| *natural = SIZE::new(rc.right - rc.left, rc.bottom - rc.top));
+			}
+		});
+		res?;
+
+		let dpi = unsafe { self.0.parent_ptr.as_ref() }.dpi()? as i32;
+		self.0.as_mut().root.scale(&|len| MulDiv(len, dpi, 96));
+
+		self.0.as_mut().created = true;
+		let rc_parent = hparent.GetClientRect()?;
+		self.resize(SIZE::new(rc_parent.right, rc_parent.bottom))
+	}
+
+	fn rescale(&self, old_dpi: u32, new_dpi: u32) {
+		if !self.0.created {
+			return; // lengths not scaled yet, will be scaled with the new DPI
+		}
+		let scale = |len: i32| MulDiv(len, new_dpi as i32, old_dpi as i32);
+		let root = &mut self.0.as_mut().root;
+		root.scale(&scale);
+		root.for_each_leaf_mut(&mut |_, natural| {
+			*natural = SIZE::new(scale(natural.cx), scale(natural.cy));
+		});
+	}
+
+	fn resize(&self, client_area: SIZE) -> WinResult<()> {
+		if !self.0.created || self.0.num_ctrls == 0 {
+			return Ok(());
+		}
+
+		let mut rects = Vec::with_capacity(self.0.num_ctrls);
+		self.0.root.arrange(
+			RECT { left: 0, top: 0, right: client_area.cx, bottom: client_area.cy },
+			&mut |hwnd_ptr, rc| rects.push((*hwnd_ptr, rc)),
+		);
+
+		let hdwp = HDWP::BeginDeferWindowPos(rects.len() as u32)?;
+		for (hwnd_ptr, rc) in rects.iter() {
+			hdwp.DeferWindowPos(
+				unsafe { *hwnd_ptr.as_ref() },
+				HwndPlace::None,
+				rc.left, rc.top, rc.right - rc.left, rc.bottom - rc.top,
+				co::SWP::NOZORDER,
+			)?;
+		}
+		hdwp.EndDeferWindowPos()
+	}
+}
@@ -0,0 +1,742 @@
+//! Pure layout computations, which don't touch any window. Coordinates are in
+//! pixels, relative to the parent client area.
+
+use crate::gui::layout::Track;
+use crate::gui::native_controls::StatusBarPart;
+use crate::gui::resizer::Resz;
+use crate::structs::{RECT, SIZE};
+
+/// A node of the layout tree. Leaves carry an arbitrary item, which is handed
+/// back along with its computed rectangle.
+pub(crate) struct Node<T> {
+	pub kind: Kind<T>,
+	pub margins: RECT, // space around the node, inside the area given by the parent
+	pub min: SIZE, // excluding margins
+	pub max: SIZE, // excluding margins; zero means unconstrained
+}
+
+pub(crate) enum Kind<T> {
+	Leaf {
+		item: T,
+		natural: SIZE, // preferred size, used by auto tracks
+	},
+	Stack {
+		horz: bool,
+		spacing: i32,
+		items: Vec<(Track, Node<T>)>,
+	},
+	Grid {
+		cols: Vec<Track>,
+		rows: Vec<Track>,
+		spacing: SIZE,
+		cells: Vec<Cell<T>>,
+	},
+}
+
+pub(crate) struct Cell<T> {
+	pub row: usize,
+	pub col: usize,
+	pub row_span: usize,
+	pub col_span: usize,
+	pub node: Node<T>,
+}
+
+/// Size constraints of a single track, as given to
+/// [`solve_tracks`](crate::gui::layout_calc::solve_tracks).
+#[derive(Clone, Copy)]
+pub(crate) struct TrackSpec {
+	pub track: Track,
+	pub natural: i32,
+	pub min: i32,
+	pub max: i32, // i32::MAX if unconstrained
+}
+
+impl<T> Node<T> {
+	pub fn new(kind: Kind<T>) -> Node<T> {
+		Self {
+			kind,
+			margins: RECT::default(),
+			min: SIZE::default(),
+			max: SIZE::default(),
+		}
+	}
+
+	/// Calls the function on every leaf, allowing its natural size to be set.
+	pub fn for_each_leaf_mut(&mut self, func: &mut dyn FnMut(&T, &mut SIZE)) {
+		match &mut self.kind {
+			Kind::Leaf { item, natural } => func(item, natural),
+			Kind::Stack { items, .. } => {
+				for (_, node) in items.iter_mut() {
+					node.for_each_leaf_mut(func);
+				}
+			},
+			Kind::Grid { cells, .. } => {
+				for cell in cells.iter_mut() {
+					cell.node.for_each_leaf_mut(func);
+				}
+			},
+		}
+	}
+
+	/// Applies the function to every length of the tree which is not a natural
+	/// size: margins, spacings, minimum and maximum sizes, and fixed tracks.
+	/// Used to adjust the lengths to the current DPI.
+	pub fn scale(&mut self, func: &dyn Fn(i32) -> i32) {
+		self.margins = RECT {
+			left: func(self.margins.left),
+			top: func(self.margins.top),
+			right: func(self.margins.right),
+			bottom: func(self.margins.bottom),
+		};
+		self.min = SIZE::new(func(self.min.cx), func(self.min.cy));
+		self.max = SIZE::new(func(self.max.cx), func(self.max.cy));
+
+		let scale_track = |track: &mut Track| {
+			if let Track::Fixed(px) = track {
+				*px = func(*px as i32) as u32;
+			}
+		};
+
+		match &mut self.kind {
+			Kind::Leaf { .. } => {},
+			Kind::Stack { spacing, items, .. } => {
+				*spacing = func(*spacing);
+				for (track, node) in items.iter_mut() {
+					scale_track(track);
+					node.scale(func);
+				}
+			},
+			Kind::Grid { cols, rows, spacing, cells } => {
+				cols.iter_mut().for_each(scale_track);
+				rows.iter_mut().for_each(scale_track);
+				*spacing = SIZE::new(func(spacing.cx), func(spacing.cy));
+				for cell in cells.iter_mut() {
+					cell.node.scale(func);
+				}
+			},
+		}
+	}
+
+	/// Returns the preferred size of the node, including its margins.
+	pub fn measure(&self) -> SIZE {
+		let content = match &self.kind {
+			Kind::Leaf { natural, .. } => *natural,
+			Kind::Stack { horz, spacing, items } => {
+				let mut main = 0;
+				let mut cross = 0;
+				for (track, node) in items.iter() {
+					let sz = node.measure();
+					let (sz_main, sz_cross) = if *horz { (sz.cx, sz.cy) } else { (sz.cy, sz.cx) };
+					main += match track {
+						Track::Fixed(px) => *px as i32,
+						_ => sz_main,
+					};
+					cross = cross.max(sz_cross);
+				}
+				main += *spacing * items.len().saturating_sub(1) as i32;
+				if *horz { SIZE::new(main, cross) } else { SIZE::new(cross, main) }
+			},
+			Kind::Grid { cols, rows, spacing, cells } => {
+				let (col_specs, row_specs) = grid_specs(cols, rows, cells);
+				SIZE::new(
+					col_specs.iter().map(|s| natural_len(s)).sum::<i32>()
+						+ spacing.cx * cols.len().saturating_sub(1) as i32,
+					row_specs.iter().map(|s| natural_len(s)).sum::<i32>()
+						+ spacing.cy * rows.len().saturating_sub(1) as i32,
+				)
+			},
+		};
+
+		let content = self.clamp(content);
+		SIZE::new(
+			content.cx + self.margins.left + self.margins.right,
+			content.cy + self.margins.top + self.margins.bottom,
+		)
+	}
+
+	/// Computes the rectangles of all leaves, given the area available to the
+	/// node, calling the function for each one of them.
+	pub fn arrange(&self, rc: RECT, func: &mut dyn FnMut(&T, RECT)) {
+		let rc = RECT {
+			left: rc.left + self.margins.left,
+			top: rc.top + self.margins.top,
+			right: (rc.right - self.margins.right).max(rc.left + self.margins.left),
+			bottom: (rc.bottom - self.margins.bottom).max(rc.top + self.margins.top),
+		};
+		let sz = self.clamp(SIZE::new(rc.right - rc.left, rc.bottom - rc.top));
+		let rc = RECT { // if clamped, stick to top left
+			left: rc.left,
+			top: rc.top,
+			right: rc.left + sz.cx,
+			bottom: rc.top + sz.cy,
+		};
+
+		match &self.kind {
+			Kind::Leaf { item, .. } => func(item, rc),
+			Kind::Stack { horz, spacing, items } => {
+				let specs = items.iter()
+					.map(|(track, node)| node.track_spec(*track, *horz))
+					.collect::<Vec<_>>();
+				let avail = if *horz { rc.right - rc.left } else { rc.bottom - rc.top };
+				let lens = solve_tracks(avail, *spacing, &specs);
+
+				let mut pos = if *horz { rc.left } else { rc.top };
+				for ((_, node), len) in items.iter().zip(lens.iter()) {
+					node.arrange(
+						if *horz {
+							RECT { left: pos, top: rc.top, right: pos + len, bottom: rc.bottom }
+						} else {
+							RECT { left: rc.left, top: pos, right: rc.right, bottom: pos + len }
+						},
+						func,
+					);
+					pos += len + *spacing;
+				}
+			},
+			Kind::Grid { cols, rows, spacing, cells } => {
+				let (col_specs, row_specs) = grid_specs(cols, rows, cells);
+				let col_lens = solve_tracks(rc.right - rc.left, spacing.cx, &col_specs);
+				let row_lens = solve_tracks(rc.bottom - rc.top, spacing.cy, &row_specs);
+
+				for cell in cells.iter() {
+					let (left, right) = span_bounds(rc.left, spacing.cx,
+						&col_lens, cell.col, cell.col_span);
+					let (top, bottom) = span_bounds(rc.top, spacing.cy,
+						&row_lens, cell.row, cell.row_span);
+					cell.node.arrange(RECT { left, top, right, bottom }, func);
+				}
+			},
+		}
+	}
+
+	fn clamp(&self, sz: SIZE) -> SIZE {
+		SIZE::new(
+			clamp_len(sz.cx, self.min.cx, max_len(self.max.cx)),
+			clamp_len(sz.cy, self.min.cy, max_len(self.max.cy)),
+		)
+	}
+
+	/// Constraints of the node along the given axis, including margins.
+	fn track_spec(&self, track: Track, horz: bool) -> TrackSpec {
+		let (margins, min, max) = if horz {
+			(self.margins.left + self.margins.right, self.min.cx, self.max.cx)
+		} else {
+			(self.margins.top + self.margins.bottom, self.min.cy, self.max.cy)
+		};
+		let sz = self.measure();
+		TrackSpec {
+			track,
+			natural: if horz { sz.cx } else { sz.cy },
+			min: min + margins,
+			max: max_len(max).saturating_add(margins),
+		}
+	}
+}
+
+/// Computes the constraints of the columns and rows of a grid. Only cells
+/// spanning a single track contribute to its natural and minimum sizes.
+fn grid_specs<T>(cols: &[Track], rows: &[Track],
+	cells: &[Cell<T>]) -> (Vec<TrackSpec>, Vec<TrackSpec>)
+{
+	let new_spec = |track: &Track| TrackSpec { track: *track, natural: 0, min: 0, max: i32::MAX };
+	let mut col_specs = cols.iter().map(new_spec).collect::<Vec<_>>();
+	let mut row_specs = rows.iter().map(new_spec).collect::<Vec<_>>();
+
+	for cell in cells.iter() {
+		if cell.col_span == 1 {
+			if let Some(spec) = col_specs.get_mut(cell.col) {
+				let cell_spec = cell.node.track_spec(spec.track, true);
+				spec.natural = spec.natural.max(cell_spec.natural);
+				spec.min = spec.min.max(cell_spec.min);
+			}
+		}
+		if cell.row_span == 1 {
+			if let Some(spec) = row_specs.get_mut(cell.row) {
+				let cell_spec = cell.node.track_spec(spec.track, false);
+				spec.natural = spec.natural.max(cell_spec.natural);
+				spec.min = spec.min.max(cell_spec.min);
+			}
+		}
+	}
+	(col_specs, row_specs)
+}
+
+/// Returns the start and end coordinates of a span of tracks.
+fn span_bounds(start: i32, spacing: i32,
+	lens: &[i32], first: usize, span: usize) -> (i32, i32)
+{
+	let first = first.min(lens.len());
+	let last = (first + span.max(1)).min(lens.len());
+	let begin = start + lens[..first].iter().map(|len| len + spacing).sum::<i32>();
+	let len = lens[first..last].iter().sum::<i32>()
+		+ spacing * (last - first).saturating_sub(1) as i32;
+	(begin, begin + len)
+}
+
+/// Distributes the available length among the tracks, separated by the given
+/// spacing:
+///
+/// * fixed tracks get their own length;
+/// * auto tracks get their natural length;
+/// * star tracks share the remaining length, proportionally to their weights.
+///
+/// All tracks are clamped to their minimum and maximum lengths; star tracks
+/// which hit a limit are frozen, and the remaining length is redistributed
+/// among the others.
+pub(crate) fn solve_tracks(avail: i32, spacing: i32, specs: &[TrackSpec]) -> Vec<i32> {
+	let mut lens = vec![0; specs.len()];
+	let mut remaining = avail - spacing * specs.len().saturating_sub(1) as i32;
+	let mut stars = Vec::with_capacity(specs.len());
+
+	for (idx, spec) in specs.iter().enumerate() {
+		lens[idx] = match spec.track {
+			Track::Fixed(px) => clamp_len(px as i32, spec.min, spec.max),
+			Track::Auto => clamp_len(spec.natural, spec.min, spec.max),
+			Track::Star(_) => {
+				stars.push(idx);
+				continue;
+			},
+		};
+		remaining -= lens[idx];
+	}
+
+	let weight = |idx: usize| match specs[idx].track {
+		Track::Star(w) if w > 0.0 => w,
+		_ => 0.0,
+	};
+
+	while !stars.is_empty() {
+		let avail_stars = remaining.max(0);
+		let total_weight: f32 = stars.iter().map(|idx| weight(*idx)).sum();
+		let shares = stars.iter()
+			.map(|idx| if total_weight > 0.0 {
+				(avail_stars as f32 * weight(*idx) / total_weight) as i32
+			} else {
+				0
+			})
+			.collect::<Vec<_>>();
+
+		let mut frozen = Vec::default();
+		for (idx, share) in stars.iter().zip(shares.iter()) {
+			let spec = &specs[*idx];
+			if *share < spec.min || *share > spec.max {
+				lens[*idx] = clamp_len(*share, spec.min, spec.max);
+				remaining -= lens[*idx];
+				frozen.push(*idx);
+			}
+		}
+
+		if frozen.is_empty() {
+			for (idx, share) in stars.iter().zip(shares.iter()) {
+				lens[*idx] = *share;
+			}
+			let leftover = avail_stars - shares.iter().sum::<i32>(); // rounding
+			if let Some(last) = stars.iter().rev().find(|idx| weight(**idx) > 0.0) {
+				lens[*last] = clamp_len(lens[*last] + leftover, specs[*last].min, specs[*last].max);
+			}
+			break;
+		}
+		stars.retain(|idx| !frozen.contains(idx));
+	}
+
+	lens
+}
+
+fn natural_len(spec: &TrackSpec) -> i32 {
+	match spec.track {
+		Track::Fixed(px) => px as i32,
+		_ => spec.natural,
+	}
+}
+
+fn max_len(max: i32) -> i32 {
+	if max <= 0 { i32::MAX } else { max }
+}
+
+fn clamp_len(len: i32, min: i32, max: i32) -> i32 {
+	len.min(max).max(min)
+}
+
+//------------------------------------------------------------------------------
+
+/// Computes the position and length of a [`Resizer`](crate::gui::Resizer)
+/// control along one axis, after the parent was resized from `parent_orig` to
+/// `parent_len`. Zero `max` means no limit.
+pub(crate) fn resz_axis(resz: Resz, parent_len: i32, parent_orig: i32,
+	orig_pos: i32, orig_len: i32, min: i32, max: i32) -> (i32, i32)
+{
+	let (pos_ratio, size_ratio) = resz.ratios();
+	let delta = (parent_len - parent_orig) as f32;
+
+	let pos = orig_pos + (delta * pos_ratio).round() as i32;
+	let mut len = orig_len + (delta * size_ratio).round() as i32;
+	if max > 0 {
+		len = len.min(max);
+	}
+	(pos, len.max(min))
+}
+
+/// Computes the minimum parent length, along one axis, which keeps a
+/// [`Resizer`](crate::gui::Resizer) control within its minimum length and away
+/// from the left/top edge.
+pub(crate) fn resz_min_parent_len(resz: Resz, parent_orig: i32,
+	orig_pos: i32, orig_len: i32, min: i32) -> i32
+{
+	let (pos_ratio, size_ratio) = resz.ratios();
+	let mut min_parent = 0;
+	if size_ratio > 0.0 && min > 0 {
+		min_parent = min_parent.max(
+			parent_orig + ((min - orig_len) as f32 / size_ratio).ceil() as i32);
+	}
+	if pos_ratio > 0.0 {
+		min_parent = min_parent.max(
+			parent_orig - (orig_pos as f32 / pos_ratio).floor() as i32);
+	}
+	min_parent
+}
+
+/// Computes the right edges of the [`StatusBar`](crate::gui::StatusBar) parts
+/// for the given client area width. Proportional parts share the width left by
+/// the fixed ones; the last edge is always -1, so the last part reaches the
+/// right border.
+pub(crate) fn status_bar_right_edges(
+	parts: &[StatusBarPart], client_cx: i32, right_edges: &mut [i32])
+{
+	let mut total_proportions: u32 = 0;
+	let mut cx_available = client_cx.max(0) as u32;
+
+	for part in parts.iter() {
+		match part {
+			StatusBarPart::Fixed(pixels) => cx_available = cx_available.saturating_sub(*pixels),
+			StatusBarPart::Proportional(prop) => total_proportions += *prop as u32,
+		}
+	}
+
+	let mut total_cx = client_cx.max(0) as u32;
+
+	for (idx, part) in parts.iter().enumerate().rev() {
+		right_edges[idx] = total_cx as i32;
+		total_cx = total_cx.saturating_sub(match part {
+			StatusBarPart::Fixed(pixels) => *pixels,
+			StatusBarPart::Proportional(prop) => match total_proportions {
+				0 => 0,
+				total => (cx_available / total) * (*prop as u32),
+			},
+		});
+	}
+	if let Some(last) = right_edges.last_mut() {
+		*last = -1;
+	}
+}
+
+/// Moves and shrinks the rectangle, if needed, so it fits entirely within the
+/// area, like a window within the work area of a monitor.
+pub(crate) fn fit_rect_in_area(rc: RECT, area: RECT) -> RECT {
+	let cx = (rc.right - rc.left).min(area.right - area.left);
+	let cy = (rc.bottom - rc.top).min(area.bottom - area.top);
+	let x = rc.left.max(area.left).min(area.right - cx);
+	let y = rc.top.max(area.top).min(area.bottom - cy);
+	RECT { left: x, top: y, right: x + cx, bottom: y + cy }
+}
+
+/// Distributes the available space among the [`Splitter`](crate::gui::Splitter)
+/// panes, proportionally to their ratios, but respecting their minimum sizes.
+/// Collapsed panes are passed as `None`, and receive zero. Any rounding
+/// remainder goes to the last visible pane.
+pub(crate) fn distribute_space(available: i32, panes: &[Option<(f32, i32)>]) -> Vec<i32> {
+	let mut sizes = vec![0; panes.len()];
+	let mut fixed = vec![false; panes.len()]; // panes clamped to their min size
+
+	loop {
+		let free_space = available - panes.iter().zip(fixed.iter())
+			.filter(|(_, f)| **f)
+			.map(|(p, _)| p.map(|(_, min)| min).unwrap_or(0))
+			.sum::<i32>();
+		let free_ratio: f32 = panes.iter().zip(fixed.iter())
+			.filter(|(_, f)| !**f)
+			.filter_map(|(p, _)| p.map(|(ratio, _)| ratio))
+			.sum();
+
+		let mut clamped_any = false;
+		for (idx, pane) in panes.iter().enumerate() {
+			sizes[idx] = match pane {
+				None => 0,
+				Some((_, min)) if fixed[idx] => *min,
+				Some((ratio, min)) => {
+					let sz = if free_ratio > 0.0 {
+						(free_space.max(0) as f32 * ratio / free_ratio) as i32
+					} else {
+						0
+					};
+					if sz < *min {
+						fixed[idx] = true;
+						clamped_any = true;
+					}
+					sz
+				},
+			};
+		}
+
+		if !clamped_any {
+			break;
+		}
+	}
+
+	let total: i32 = sizes.iter().sum();
+	if let Some(last) = panes.iter().rposition(|p| p.is_some()) {
+		if !fixed[last] && total < available {
+			sizes[last] += available - total;
+		}
+	}
+	sizes
+}
+
+/// Computes the new sizes of the two panes adjacent to a dragged bar, given
+/// the intended size of the first one. Sizes are clamped so neither pane gets
+/// smaller than its minimum size.
+pub(crate) fn move_bar(cur_sizes: (i32, i32), min_sizes: (i32, i32), wanted: i32) -> (i32, i32) {
+	let combined = cur_sizes.0 + cur_sizes.1;
+	if combined < min_sizes.0 + min_sizes.1 {
+		return cur_sizes; // not enough room to move
+	}
+	let first = wanted.max(min_sizes.0).min(combined - min_sizes.1);
+	(first, combined - first)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn spec(track: Track, natural: i32) -> TrackSpec {
+		TrackSpec { track, natural, min: 0, max: i32::MAX }
+	}
+
+	fn leaf(item: u32, cx: i32, cy: i32) -> Node<u32> {
+		Node::new(Kind::Leaf { item, natural: SIZE::new(cx, cy) })
+	}
+
+	fn arranged(node: &Node<u32>, rc: RECT) -> Vec<(u32, (i32, i32, i32, i32))> {
+		let mut rcs = Vec::default();
+		node.arrange(rc, &mut |item, rc| rcs.push((*item, (rc.left, rc.top, rc.right, rc.bottom))));
+		rcs
+	}
+
+	#[test]
+	fn solve_fixed_auto_star() {
+		let specs = [
+			spec(Track::Fixed(50), 0),
+			spec(Track::Auto, 30),
+			spec(Track::Star(1.0), 0),
+			spec(Track::Star(3.0), 0),
+		];
+		// 190 pixels left for the stars, rounding leftover goes to the last one.
+		assert_eq!(solve_tracks(300, 10, &specs), [50, 30, 47, 143]);
+	}
+
+	#[test]
+	fn solve_limits() {
+		let specs = [
+			TrackSpec { min: 20, ..spec(Track::Fixed(10), 0) },
+			TrackSpec { max: 60, ..spec(Track::Auto, 100) },
+			spec(Track::Star(1.0), 0),
+		];
+		assert_eq!(solve_tracks(200, 0, &specs), [20, 60, 120]);
+		assert_eq!(solve_tracks(50, 0, &specs), [20, 60, 0]); // overflow, stars get nothing
+	}
+
+	#[test]
+	fn solve_star_clamping() {
+		let specs = [
+			TrackSpec { max: 50, ..spec(Track::Star(1.0), 0) },
+			spec(Track::Star(1.0), 0),
+			TrackSpec { min: 120, ..spec(Track::Star(1.0), 0) },
+		];
+		// Clamped stars are frozen, the others share what's left.
+		assert_eq!(solve_tracks(300, 0, &specs), [50, 130, 120]);
+	}
+
+	#[test]
+	fn solve_zero_weight() {
+		let specs = [spec(Track::Star(0.0), 0), spec(Track::Star(2.0), 0)];
+		assert_eq!(solve_tracks(100, 0, &specs), [0, 100]);
+	}
+
+	#[test]
+	fn spans() {
+		let lens = [10, 20, 30];
+		assert_eq!(span_bounds(5, 2, &lens, 0, 1), (5, 15));
+		assert_eq!(span_bounds(5, 2, &lens, 1, 2), (17, 69)); // inner spacing included
+		assert_eq!(span_bounds(5, 2, &lens, 1, 0), (17, 37)); // zero span is a single track
+		assert_eq!(span_bounds(5, 2, &lens, 2, 5), (39, 69)); // span past the end
+		assert_eq!(span_bounds(5, 2, &lens, 5, 1), (71, 71)); // beyond the last track
+	}
+
+	#[test]
+	fn arrange_stack() {
+		let mut node = Node::new(Kind::Stack {
+			horz: true,
+			spacing: 5,
+			items: vec![
+				(Track::Fixed(40), leaf(1, 0, 0)),
+				(Track::Auto, leaf(2, 30, 10)),
+				(Track::Star(1.0), leaf(3, 0, 0)),
+			],
+		});
+		node.margins = RECT { left: 10, top: 10, right: 10, bottom: 10 };
+
+		assert_eq!(arranged(&node, RECT { left: 0, top: 0, right: 200, bottom: 100 }), [
+			(1, (10, 10, 50, 90)),
+			(2, (55, 10, 85, 90)),
+			(3, (90, 10, 190, 90)),
+		]);
+		let sz = node.measure();
+		assert_eq!((sz.cx, sz.cy), (40 + 30 + 0 + 5 * 2 + 20, 10 + 20));
+	}
+
+	#[test]
+	fn arrange_clamped_leaves() {
+		let mut first = leaf(1, 10, 10);
+		first.margins.top = 5;
+		first.max = SIZE::new(0, 30);
+		let node = Node::new(Kind::Stack {
+			horz: false,
+			spacing: 0,
+			items: vec![(Track::Star(1.0), first), (Track::Star(1.0), leaf(2, 10, 10))],
+		});
+
+		// The first star is limited to its max plus margins, the second takes the rest.
+		assert_eq!(arranged(&node, RECT { left: 0, top: 0, right: 100, bottom: 200 }), [
+			(1, (0, 5, 100, 35)),
+			(2, (0, 35, 100, 200)),
+		]);
+
+		let mut big = leaf(1, 10, 10);
+		big.min = SIZE::new(150, 0);
+		assert_eq!(arranged(&big, RECT { left: 0, top: 0, right: 100, bottom: 50 }),
+			[(1, (0, 0, 150, 50))]); // overflows to the right, sticking to top left
+	}
+
+	#[test]
+	fn arrange_grid() {
+		let node = Node::new(Kind::Grid {
+			cols: vec![Track::Fixed(50), Track::Star(1.0)],
+			rows: vec![Track::Auto, Track::Auto],
+			spacing: SIZE::new(4, 6),
+			cells: vec![
+				Cell { row: 0, col: 0, row_span: 1, col_span: 1, node: leaf(1, 20, 10) },
+				Cell { row: 0, col: 1, row_span: 1, col_span: 1, node: leaf(2, 30, 20) },
+				Cell { row: 1, col: 0, row_span: 1, col_span: 2, node: leaf(3, 300, 15) },
+			],
+		});
+
+		assert_eq!(arranged(&node, RECT { left: 0, top: 0, right: 154, bottom: 100 }), [
+			(1, (0, 0, 50, 20)),
+			(2, (54, 0, 154, 20)),
+			(3, (0, 26, 154, 41)), // spans both columns
+		]);
+		let sz = node.measure(); // spanning cell doesn't widen the columns
+		assert_eq!((sz.cx, sz.cy), (50 + 4 + 30, 20 + 6 + 15));
+	}
+
+	#[test]
+	fn resz_axis_grows() {
+		// Control at 10, 100 pixels long, parent growing from 400 to 500.
+		let axis = |resz| resz_axis(resz, 500, 400, 10, 100, 0, 0);
+		assert_eq!(axis(Resz::Nothing), (10, 100));
+		assert_eq!(axis(Resz::Repos), (110, 100));
+		assert_eq!(axis(Resz::Resize), (10, 200));
+		assert_eq!(axis(Resz::Ratio { pos: 50, size: 50 }), (60, 150));
+	}
+
+	#[test]
+	fn resz_axis_limits() {
+		assert_eq!(resz_axis(Resz::Resize, 500, 400, 10, 100, 0, 150), (10, 150)); // max
+		assert_eq!(resz_axis(Resz::Resize, 200, 400, 10, 100, 20, 0), (10, 20)); // min
+		assert_eq!(resz_axis(Resz::Resize, 380, 400, 10, 100, 20, 0), (10, 80)); // within limits
+	}
+
+	#[test]
+	fn resz_min_parent() {
+		assert_eq!(resz_min_parent_len(Resz::Nothing, 400, 10, 100, 50), 0);
+		assert_eq!(resz_min_parent_len(Resz::Resize, 400, 10, 100, 50), 350); // keeps min length
+		assert_eq!(resz_min_parent_len(Resz::Resize, 400, 10, 100, 0), 0); // no min length
+		assert_eq!(resz_min_parent_len(Resz::Repos, 400, 10, 100, 0), 390); // keeps away from left edge
+		assert_eq!(resz_min_parent_len(
+			Resz::Ratio { pos: 50, size: 50 }, 400, 10, 100, 60), 380); // most restrictive
+	}
+
+	#[test]
+	fn fit_rect() {
+		let fit = |l, t, r, b, area: RECT| {
+			let rc = fit_rect_in_area(RECT { left: l, top: t, right: r, bottom: b }, area);
+			(rc.left, rc.top, rc.right, rc.bottom)
+		};
+		let work = RECT { left: 0, top: 0, right: 1000, bottom: 700 };
+		assert_eq!(fit(10, 20, 410, 320, work), (10, 20, 410, 320)); // already inside
+		assert_eq!(fit(900, 600, 1300, 900, work), (600, 400, 1000, 700)); // past the bottom right
+		assert_eq!(fit(-300, -50, 100, 250, work), (0, 0, 400, 300)); // past the top left
+		assert_eq!(fit(-10, 10, 1990, 1010, work), (0, 0, 1000, 700)); // larger than the area
+
+		let left_monitor = RECT { left: -1280, top: 0, right: 0, bottom: 1024 };
+		assert_eq!(fit(-1200, 100, -800, 400, left_monitor), (-1200, 100, -800, 400));
+		assert_eq!(fit(200, 100, 600, 400, left_monitor), (-400, 100, 0, 400)); // monitor gone
+	}
+
+	#[test]
+	fn status_bar_edges() {
+		let parts = [
+			StatusBarPart::Fixed(100),
+			StatusBarPart::Proportional(1),
+			StatusBarPart::Proportional(2),
+		];
+		let mut right_edges = [0; 3];
+
+		status_bar_right_edges(&parts, 400, &mut right_edges);
+		assert_eq!(right_edges, [100, 200, -1]);
+
+		status_bar_right_edges(&parts, 50, &mut right_edges); // no room for proportional parts
+		assert_eq!(right_edges, [50, 50, -1]);
+
+		status_bar_right_edges(&parts, -10, &mut right_edges);
+		assert_eq!(right_edges, [0, 0, -1]);
+	}
+
+	#[test]
+	fn status_bar_edges_fixed_only() {
+		let parts = [StatusBarPart::Fixed(100), StatusBarPart::Fixed(50)];
+		let mut right_edges = [0; 2];
+		status_bar_right_edges(&parts, 400, &mut right_edges);
+		assert_eq!(right_edges, [350, -1]);
+	}
+
+	#[test]
+	fn distribute_proportional() {
+		assert_eq!(distribute_space(300, &[Some((1.0, 0)), Some((2.0, 0))]), [100, 200]);
+		// Rounding leftover goes to the last visible pane.
+		assert_eq!(distribute_space(100, &[Some((1.0, 0)), Some((1.0, 0)), Some((1.0, 0))]), [33, 33, 34]);
+		assert_eq!(distribute_space(100, &[Some((1.0, 0)), Some((1.0, 0)), Some((1.0, 0)), None]), [33, 33, 34, 0]);
+	}
+
+	#[test]
+	fn distribute_min_sizes() {
+		// Clamped panes are frozen, the others share what's left.
+		assert_eq!(distribute_space(300, &[Some((1.0, 150)), Some((3.0, 0))]), [150, 150]);
+		// Not enough room: min sizes are kept, even if they overflow.
+		assert_eq!(distribute_space(100, &[Some((1.0, 80)), Some((1.0, 80))]), [80, 80]);
+		assert_eq!(distribute_space(-10, &[Some((1.0, 0)), Some((1.0, 0))]), [0, 0]);
+	}
+
+	#[test]
+	fn distribute_collapsed() {
+		assert_eq!(distribute_space(200, &[Some((1.0, 0)), None, Some((1.0, 0))]), [100, 0, 100]);
+		assert_eq!(distribute_space(200, &[None, None]), [0, 0]);
+	}
+
+	#[test]
+	fn bar_moves() {
+		assert_eq!(move_bar((100, 200), (50, 50), 150), (150, 150));
+		assert_eq!(move_bar((100, 200), (50, 50), 10), (50, 250)); // first pane at its min
+		assert_eq!(move_bar((100, 200), (50, 50), 290), (250, 50)); // second pane at its min
+		assert_eq!(move_bar((40, 40), (50, 50), 60), (40, 40)); // no room to move
+	}
+}
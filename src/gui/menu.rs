@@ -0,0 +1,431 @@
+use std::sync::Arc;
+
+use crate::aliases::WinResult;
+use crate::co;
+use crate::enums::IdPos;
+use crate::funcs::PostQuitMessage;
+use crate::gui::privs::auto_ctrl_id;
+use crate::gui::traits::{baseref_from_parent, Parent};
+use crate::gui::very_unsafe_cell::VeryUnsafeCell;
+use crate::handles::{HBITMAP, HMENU, HWND};
+use crate::msg::wm;
+use crate::structs::{MENUITEMINFO, POINT};
+use crate::WString;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ItemKind { Normal, Check, Radio }
+
+/// A command item of a [`MenuBuilder`](crate::gui::MenuBuilder), which is
+/// bound to a closure.
+pub struct MenuItem {
+	text: String,
+	id: u16,
+	accel_text: Option<String>,
+	icon: Option<HBITMAP>,
+	kind: ItemKind,
+	checked: bool,
+	enabled: bool,
+	func: Box<dyn FnMut()>,
+}
+
+impl MenuItem {
+	/// Creates a new item, which will call the closure when clicked. The
+	/// command ID is automatically generated.
+	pub fn new<F>(text: &str, func: F) -> MenuItem
+		where F: FnMut() + 'static,
+	{
+		Self {
+			text: text.to_owned(),
+			id: auto_ctrl_id(),
+			accel_text: None,
+			icon: None,
+			kind: ItemKind::Normal,
+			checked: false,
+			enabled: true,
+			func: Box::new(func),
+		}
+	}
+
+	/// Sets the command ID of the item, instead of the automatically generated
+	/// one. Use it to share the ID with an accelerator, or to refer to the item
+	/// later, in [`Menu`](crate::gui::Menu) methods.
+	pub fn id(mut self, id: u16) -> MenuItem {
+		self.id = id;
+		self
+	}
+
+	/// Sets the accelerator text, like `"Ctrl+S"`, displayed at the right of
+	/// the item text. The accelerator itself must be set up separately.
+	pub fn accel_text(mut self, accel_text: &str) -> MenuItem {
+		self.accel_text = Some(accel_text.to_owned());
+		self
+	}
+
+	/// Sets the bitmap displayed at the left of the item text. The bitmap is
+	/// not owned by the menu, and must be kept alive while the menu exists.
+	pub fn icon(mut self, hbmp: HBITMAP) -> MenuItem {
+		self.icon = Some(hbmp);
+		self
+	}
+
+	/// Makes the item a check item, whose check mark is toggled when clicked,
+	/// before the closure is called.
+	pub fn check(mut self, checked: bool) -> MenuItem {
+		self.kind = ItemKind::Check;
+		self.checked = checked;
+		self
+	}
+
+	/// Makes the item a radio item. Adjacent radio items form a group, in which
+	/// only the last clicked one is checked.
+	pub fn radio(mut self, checked: bool) -> MenuItem {
+		self.kind = ItemKind::Radio;
+		self.checked = checked;
+		self
+	}
+
+	/// Sets whether the item is initially enabled.
+	pub fn enabled(mut self, enabled: bool) -> MenuItem {
+		self.enabled = enabled;
+		self
+	}
+}
+
+enum Entry {
+	Item(MenuItem),
+	Submenu(String, MenuBuilder),
+	Separator,
+}
+
+//------------------------------------------------------------------------------
+
+/// Builds a menu bar or a popup menu, whose items are bound to closures,
+/// producing a [`Menu`](crate::gui::Menu).
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use winsafe::gui::{MenuBuilder, MenuItem, WindowMain, WindowMainOpts};
+///
+/// let menu = MenuBuilder::new()
+///     .submenu("&File", MenuBuilder::new()
+///         .add_item(MenuItem::new("&Open", || println!("Open")).accel_text("Ctrl+O"))
+///         .separator()
+///         .item("E&xit", || println!("Exit")))
+///     .submenu("&View", MenuBuilder::new()
+///         .add_item(MenuItem::new("&Status bar", || println!("Toggled")).check(true)))
+///     .build_bar()
+///     .unwrap();
+///
+/// let wnd = WindowMain::new(WindowMainOpts {
+///     menu: menu.hmenu(),
+///     ..Default::default()
+/// });
+/// menu.bind(&wnd);
+/// ```
+#[derive(Default)]
+pub struct MenuBuilder {
+	entries: Vec<Entry>,
+}
+
+impl MenuBuilder {
+	/// Creates a new, empty builder.
+	pub fn new() -> MenuBuilder {
+		Self::default()
+	}
+
+	/// Appends a simple item, which will call the closure when clicked.
+	pub fn item<F>(self, text: &str, func: F) -> MenuBuilder
+		where F: FnMut() + 'static,
+	{
+		self.add_item(MenuItem::new(text, func))
+	}
+
+	/// Appends an item.
+	pub fn add_item(mut self, item: MenuItem) -> MenuBuilder {
+		self.entries.push(Entry::Item(item));
+		self
+	}
+
+	/// Appends a submenu.
+	pub fn submenu(mut self, text: &str, submenu: MenuBuilder) -> MenuBuilder {
+		self.entries.push(Entry::Submenu(text.to_owned(), submenu));
+		self
+	}
+
+	/// Appends a separator.
+	pub fn separator(mut self) -> MenuBuilder {
+		self.entries.push(Entry::Separator);
+		self
+	}
+
+	/// Creates a menu bar, to be set in
+	/// [`WindowMainOpts::menu`](crate::gui::WindowMainOpts::menu). The menu bar
+	/// is owned by the window, which destroys it.
+	pub fn build_bar(self) -> WinResult<Menu> {
+		self.build(HMENU::CreateMenu()?, false)
+	}
+
+	/// Creates a popup menu, to be displayed with
+	/// [`Menu::track_popup`](crate::gui::Menu::track_popup). The popup menu is
+	/// destroyed when the last clone of the `Menu` is dropped.
+	pub fn build_popup(self) -> WinResult<Menu> {
+		self.build(HMENU::CreatePopupMenu()?, true)
+	}
+
+	fn build(self, hmenu: HMENU, owned: bool) -> WinResult<Menu> {
+		let mut cmds = Vec::default();
+		let mut num_groups = 0;
+		self.fill(hmenu, &mut cmds, &mut num_groups)
+			.inspect_err(|_| { hmenu.DestroyMenu().ok(); })?;
+
+		Ok(Menu(
+			Arc::new(VeryUnsafeCell::new(
+				Obj { hmenu, owned, cmds },
+			)),
+		))
+	}
+
+	fn fill(self, hmenu: HMENU,
+		cmds: &mut Vec<Cmd>, num_groups: &mut usize) -> WinResult<()>
+	{
+		let mut prev_radio = false;
+
+		for (pos, entry) in self.entries.into_iter().enumerate() {
+			let mut mii = MENUITEMINFO::default();
+
+			match entry {
+				Entry::Separator => {
+					mii.fMask = co::MIIM::FTYPE;
+					mii.fType = co::MFT::SEPARATOR;
+					hmenu.InsertMenuItem(IdPos::Pos(pos as u32), &mii)?;
+					prev_radio = false;
+				},
+				Entry::Submenu(text, builder) => {
+					let hsub = HMENU::CreatePopupMenu()?;
+					builder.fill(hsub, cmds, num_groups)
+						.inspect_err(|_| { hsub.DestroyMenu().ok(); })?;
+
+					let mut text_buf = WString::from_str(&text);
+					mii.fMask = co::MIIM::STRING | co::MIIM::SUBMENU;
+					mii.hSubMenu = hsub;
+					mii.dwTypeData = unsafe { text_buf.as_mut_ptr() };
+					hmenu.InsertMenuItem(IdPos::Pos(pos as u32), &mii)
+						.inspect_err(|_| { hsub.DestroyMenu().ok(); })?; // not owned by the menu yet
+					prev_radio = false;
+				},
+				Entry::Item(item) => {
+					let text = match &item.accel_text {
+						Some(accel_text) => format!("{}\t{}", item.text, accel_text),
+						None => item.text.clone(),
+					};
+					let mut text_buf = WString::from_str(&text);
+
+					mii.fMask = co::MIIM::FTYPE | co::MIIM::STRING | co::MIIM::ID | co::MIIM::STATE;
+					mii.wID = item.id as u32;
+					mii.dwTypeData = unsafe { text_buf.as_mut_ptr() };
+					if item.kind == ItemKind::Radio {
+						mii.fType = co::MFT::RADIOCHECK;
+					}
+					if item.checked {
+						mii.fState |= co::MFS::CHECKED;
+					}
+					if !item.enabled {
+						mii.fState |= co::MFS::DISABLED;
+					}
+					if let Some(hbmp) = item.icon {
+						mii.fMask |= co::MIIM::BITMAP;
+						mii.hbmpItem = hbmp;
+					}
+					hmenu.InsertMenuItem(IdPos::Pos(pos as u32), &mii)?;
+
+					let is_radio = item.kind == ItemKind::Radio;
+					if is_radio && !prev_radio {
+						*num_groups += 1; // a new group of adjacent radio items
+					}
+					prev_radio = is_radio;
+
+					cmds.push(Cmd {
+						id: item.id,
+						hmenu,
+						kind: item.kind,
+						group: if is_radio { *num_groups } else { 0 },
+						checked: item.checked,
+						func: item.func,
+					});
+				},
+			}
+		}
+		Ok(())
+	}
+}
+
+//------------------------------------------------------------------------------
+
+struct Cmd {
+	id: u16,
+	hmenu: HMENU, // menu which contains the item
+	kind: ItemKind,
+	group: usize, // radio items only
+	checked: bool,
+	func: Box<dyn FnMut()>,
+}
+
+/// A menu bar or a popup menu, created by
+/// [`MenuBuilder`](crate::gui::MenuBuilder), whose items are bound to
+/// closures.
+#[derive(Clone)]
+pub struct Menu(Arc<VeryUnsafeCell<Obj>>);
+
+struct Obj { // actual fields of Menu
+	hmenu: HMENU,
+	owned: bool, // popup menus are destroyed by us
+	cmds: Vec<Cmd>,
+}
+
+impl Drop for Obj {
+	fn drop(&mut self) {
+		if self.owned && self.hmenu.IsMenu() {
+			self.hmenu.DestroyMenu().ok();
+		}
+	}
+}
+
+unsafe impl Send for Menu {}
+unsafe impl Sync for Menu {}
+
+impl Menu {
+	/// Returns the underlying handle for this menu.
+	pub fn hmenu(&self) -> HMENU {
+		self.0.hmenu
+	}
+
+	/// Makes the parent window call the bound closures when the items are
+	/// clicked, or when accelerators with the same command IDs are pressed.
+	/// Used with menu bars, since popup menus displayed with
+	/// [`track_popup`](crate::gui::Menu::track_popup) call the closures
+	/// themselves.
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window is already created.
+	pub fn bind(&self, parent: &dyn Parent) {
+		let parent_events = baseref_from_parent(parent).privileged_events_ref();
+
+		for cmd in self.0.cmds.iter() {
+			let id = cmd.id;
+			parent_events.wm_command_accel_menu(id, {
+				let me = self.clone();
+				move || me.run_command(id).unwrap_or_else(|err| PostQuitMessage(err))
+			});
+		}
+	}
+
+	/// Displays the popup menu at the given screen coordinates, then calls the
+	/// closure of the clicked item, if any.
+	pub fn track_popup(&self, hwnd_owner: HWND, pt_screen: POINT) -> WinResult<()> {
+		let cmd_id = self.0.hmenu.TrackPopupMenu(
+			co::TPM::LEFTALIGN | co::TPM::RIGHTBUTTON | co::TPM::RETURNCMD,
+			pt_screen.x, pt_screen.y, hwnd_owner)?;
+
+		match cmd_id {
+			Some(cmd_id) => self.run_command(cmd_id as u16),
+			None => Ok(()), // menu canceled
+		}
+	}
+
+	/// Displays the popup menu whenever the parent window receives a
+	/// [`WM_CONTEXTMENU`](crate::msg::wm::ContextMenu) message, either because
+	/// the user right-clicked it, or pressed Shift+F10 or the context menu key.
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window is already created.
+	pub fn attach_context_menu(&self, parent: &dyn Parent) {
+		let parent_ref = baseref_from_parent(parent);
+		let hparent_ptr = parent_ref.hwnd_ref() as *const HWND;
+
+		parent_ref.privileged_events_ref().wm_context_menu_params({
+			let me = self.clone();
+			move |p: wm::ContextMenu| {
+				|| -> WinResult<()> {
+					let hparent = unsafe { *hparent_ptr };
+					let mut pt = p.cursor_pos;
+					if pt.x == -1 && pt.y == -1 { // invoked from keyboard
+						let rc = p.hwnd.GetWindowRect()?;
+						pt = POINT::new(rc.left, rc.top);
+					}
+					me.track_popup(hparent, pt)
+				}
+				().unwrap_or_else(|err| PostQuitMessage(err))
+			}
+		});
+	}
+
+	/// Tells whether the item with the given command ID is checked.
+	///
+	/// # Panics
+	///
+	/// Panics if the command ID doesn't belong to this menu.
+	pub fn is_checked(&self, cmd_id: u16) -> bool {
+		self.0.cmds[self.cmd_index(cmd_id)].checked
+	}
+
+	/// Sets the check mark of the item with the given command ID. If a radio
+	/// item is checked, the other items of its group are unchecked.
+	///
+	/// # Panics
+	///
+	/// Panics if the command ID doesn't belong to this menu.
+	pub fn set_checked(&self, cmd_id: u16, checked: bool) -> WinResult<()> {
+		let idx = self.cmd_index(cmd_id);
+		let cmds = &mut self.0.as_mut().cmds;
+
+		if cmds[idx].kind == ItemKind::Radio && checked {
+			let group = cmds[idx].group;
+			for cmd in cmds.iter_mut().filter(|cmd| cmd.kind == ItemKind::Radio && cmd.group == group) {
+				cmd.checked = false;
+				cmd.hmenu.CheckMenuItem(IdPos::Id(cmd.id), false)?;
+			}
+		}
+
+		cmds[idx].checked = checked;
+		cmds[idx].hmenu.CheckMenuItem(IdPos::Id(cmd_id), checked).map(|_| ())
+	}
+
+	/// Enables or disables the item with the given command ID.
+	///
+	/// # Panics
+	///
+	/// Panics if the command ID doesn't belong to this menu.
+	pub fn set_enabled(&self, cmd_id: u16, enabled: bool) -> WinResult<()> {
+		let cmd = &self.0.cmds[self.cmd_index(cmd_id)];
+		cmd.hmenu.EnableMenuItem(IdPos::Id(cmd_id),
+			if enabled { co::MF::ENABLED } else { co::MF::GRAYED }).map(|_| ())
+	}
+
+	fn cmd_index(&self, cmd_id: u16) -> usize {
+		match self.0.cmds.iter().position(|cmd| cmd.id == cmd_id) {
+			Some(idx) => idx,
+			None => panic!("Command ID {} doesn't belong to this menu.", cmd_id),
+		}
+	}
+
+	/// Updates the check marks, if needed, then calls the closure bound to the
+	/// command ID.
+	fn run_command(&self, cmd_id: u16) -> WinResult<()> {
+		let idx = match self.0.cmds.iter().position(|cmd| cmd.id == cmd_id) {
+			Some(idx) => idx,
+			None => return Ok(()), // not an item of ours
+		};
+
+		match self.0.cmds[idx].kind {
+			ItemKind::Normal => {},
+			ItemKind::Check => self.set_checked(cmd_id, !self.0.cmds[idx].checked)?,
+			ItemKind::Radio => self.set_checked(cmd_id, true)?,
+		}
+
+		(self.0.as_mut().cmds[idx].func)();
+		Ok(())
+	}
+}
@@ -4,31 +4,82 @@
 //! [`WindowMain`](crate::gui::WindowMain).
 
 pub mod events;
+pub mod headless;
+pub mod testing;
 
+mod accel_table;
 mod base;
+mod binding;
+mod clipboard;
+mod color_dialog;
 mod dlg_base;
 mod dlg_control;
 mod dlg_main;
 mod dlg_modal;
+mod dlg_modeless;
+mod drag_drop;
+mod executor;
+mod file_dialog;
+mod font_dialog;
+mod global_hotkey;
+mod layout;
+mod layout_calc;
+mod menu;
 mod native_controls;
 mod privs;
+mod property_page;
+mod property_sheet;
 mod raw_base;
 mod raw_control;
 mod raw_main;
+mod raw_mdi_child;
 mod raw_modal;
+mod raw_modeless;
 mod resizer;
+mod splitter;
+mod task_dialog;
+mod timer;
 mod traits;
+mod tray_icon;
+mod ui_thread;
 mod very_unsafe_cell;
 mod window_control;
 mod window_main;
+mod window_mdi_child;
+mod window_mdi_frame;
 mod window_modal;
+mod window_modeless;
+mod window_state;
 
+pub use accel_table::{AccelTableBuilder, parse_shortcut};
+pub use binding::{Converter, Observable};
+pub use clipboard::Clipboard;
+pub use color_dialog::ColorDialog;
+pub use drag_drop::{DragData, DropData, DropTarget};
+pub use executor::{sleep, spawn_blocking, spawn_local};
+pub use file_dialog::FileDialog;
+pub use font_dialog::FontDialog;
+pub use layout::{GridCell, Layout, LayoutNode, Track};
+pub use menu::{Menu, MenuBuilder, MenuItem};
 pub use native_controls::*;
+pub use property_page::{PropertyPage, PropertyPageOpts};
+pub use property_sheet::{PropertySheet, PropertySheetKind, PropertySheetOpts};
 pub use raw_control::WindowControlOpts;
 pub use raw_main::WindowMainOpts;
+pub use raw_mdi_child::WindowMdiChildOpts;
 pub use raw_modal::WindowModalOpts;
+pub use raw_modeless::WindowModelessOpts;
 pub use resizer::{Resizer, Resz};
+pub use splitter::{Splitter, SplitterOpts, SplitterOrient};
+pub use task_dialog::{TaskDialog, TaskDialogButton, TaskDialogCtrl, TaskDialogNotif, TaskDialogResult};
+pub use timer::{Debouncer, Throttler, TimerHandle};
 pub use traits::{Child, Parent};
+pub use tray_icon::{TrayIcon, TrayIconEvents, TrayIconOpts};
+pub use ui_thread::UiThreadSender;
 pub use window_control::WindowControl;
 pub use window_main::WindowMain;
+pub use window_mdi_child::WindowMdiChild;
+pub use window_mdi_frame::WindowMdiFrame;
 pub use window_modal::WindowModal;
+pub use window_modeless::WindowModeless;
+pub use window_state::{WindowState, WindowStateKeeper, WindowStateStorage};
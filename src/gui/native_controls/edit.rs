@@ -6,7 +6,7 @@ use crate::co;
 use crate::funcs::PostQuitMessage;
 use crate::gui::events::{EditEvents, WindowEvents};
 use crate::gui::native_controls::native_control_base::{NativeControlBase, OptsId};
-use crate::gui::privs::{auto_ctrl_id, multiply_dpi, ui_font};
+use crate::gui::privs::{auto_ctrl_id, multiply_dpi_for, ui_font_for_dpi};
 use crate::gui::traits::{baseref_from_parent, Child, Parent};
 use crate::handles::HWND;
 use crate::msg::wm;
@@ -88,9 +88,10 @@ impl Edit {
 		|| -> WinResult<()> {
 			match &self.0.opts_id {
 				OptsId::Wnd(opts) => {
+					let parent_ref = self.0.base.parent_ref();
 					let mut pos = opts.position;
 					let mut sz = SIZE::new(opts.width as i32, opts.height as i32);
-					multiply_dpi(Some(&mut pos), Some(&mut sz))?;
+					multiply_dpi_for(parent_ref, Some(&mut pos), Some(&mut sz))?;
 
 					let our_hwnd = self.0.base.create_window( // may panic
 						"EDIT", Some(&opts.text), pos, sz,
@@ -99,7 +100,7 @@ impl Edit {
 						opts.window_style | opts.edit_style.into(),
 					)?;
 
-					our_hwnd.SendMessage(wm::SetFont{ hfont: ui_font(), redraw: true });
+					our_hwnd.SendMessage(wm::SetFont{ hfont: ui_font_for_dpi(parent_ref.dpi()?)?, redraw: true });
 					Ok(())
 				},
 				OptsId::Dlg(ctrl_id) => self.0.base.create_dlg(*ctrl_id).map(|_| ()), // may panic
@@ -0,0 +1,197 @@
+use std::any::Any;
+use std::sync::Arc;
+
+use crate::aliases::WinResult;
+use crate::co;
+use crate::funcs::PostQuitMessage;
+use crate::gui::events::{HotKeyEvents, WindowEvents};
+use crate::gui::native_controls::native_control_base::{NativeControlBase, OptsId};
+use crate::gui::privs::{auto_ctrl_id, multiply_dpi_for, ui_font_for_dpi};
+use crate::gui::traits::{baseref_from_parent, Child, Parent};
+use crate::handles::HWND;
+use crate::msg::{hkm, wm};
+use crate::structs::{POINT, SIZE};
+
+/// Native
+/// [hot key](https://docs.microsoft.com/en-us/windows/win32/controls/hot-key-controls)
+/// control.
+///
+/// Implements [`Child`](crate::gui::Child) trait.
+#[derive(Clone)]
+pub struct HotKey(Arc<Obj>);
+
+struct Obj { // actual fields of HotKey
+	base: NativeControlBase,
+	opts_id: OptsId<HotKeyOpts>,
+	events: HotKeyEvents,
+}
+
+unsafe impl Send for HotKey {}
+unsafe impl Sync for HotKey {}
+
+impl Child for HotKey {
+	fn as_any(&self) -> &dyn Any {
+		self
+	}
+}
+
+impl HotKey {
+	/// Instantiates a new `HotKey` object, to be created on the parent window
+	/// with [`CreateWindowEx`](crate::HWND::CreateWindowEx).
+	pub fn new(parent: &dyn Parent, opts: HotKeyOpts) -> HotKey {
+		let parent_ref = baseref_from_parent(parent);
+		let opts = HotKeyOpts::define_ctrl_id(opts);
+		let ctrl_id = opts.ctrl_id;
+
+		let new_self = Self(
+			Arc::new(
+				Obj {
+					base: NativeControlBase::new(parent_ref),
+					opts_id: OptsId::Wnd(opts),
+					events: HotKeyEvents::new(parent_ref, ctrl_id),
+				},
+			),
+		);
+
+		parent_ref.privileged_events_ref().wm(parent_ref.create_wm(), {
+			let me = new_self.clone();
+			move |_| { me.create(); 0 }
+		});
+
+		new_self
+	}
+
+	/// Instantiates a new `HotKey` object, to be loaded from a dialog resource
+	/// with [`GetDlgItem`](crate::HWND::GetDlgItem).
+	pub fn new_dlg(parent: &dyn Parent, ctrl_id: u16) -> HotKey {
+		let parent_ref = baseref_from_parent(parent);
+
+		let new_self = Self(
+			Arc::new(
+				Obj {
+					base: NativeControlBase::new(parent_ref),
+					opts_id: OptsId::Dlg(ctrl_id),
+					events: HotKeyEvents::new(parent_ref, ctrl_id),
+				},
+			),
+		);
+
+		parent_ref.privileged_events_ref().wm_init_dialog({
+			let me = new_self.clone();
+			move |_| { me.create(); true }
+		});
+
+		new_self
+	}
+
+	fn create(&self) {
+		|| -> WinResult<()> {
+			match &self.0.opts_id {
+				OptsId::Wnd(opts) => {
+					let parent_ref = self.0.base.parent_ref();
+					let mut pos = opts.position;
+					let mut sz = SIZE::new(opts.width as i32, opts.height as i32);
+					multiply_dpi_for(parent_ref, Some(&mut pos), Some(&mut sz))?;
+
+					let our_hwnd = self.0.base.create_window( // may panic
+						"msctls_hotkey32", None, pos, sz,
+						opts.ctrl_id,
+						opts.ex_window_style,
+						opts.window_style,
+					)?;
+
+					our_hwnd.SendMessage(wm::SetFont{ hfont: ui_font_for_dpi(parent_ref.dpi()?)?, redraw: true });
+					Ok(())
+				},
+				OptsId::Dlg(ctrl_id) => self.0.base.create_dlg(*ctrl_id).map(|_| ()), // may panic
+			}
+		}().unwrap_or_else(|err| PostQuitMessage(err))
+	}
+
+	hwnd_ctrlid_on_onsubclass!(HotKeyEvents);
+
+	/// Retrieves the virtual key code and the modifier flags of the key
+	/// combination currently displayed in the control, by sending an
+	/// [`HKM_GETHOTKEY`](crate::msg::hkm::GetHotKey) message.
+	///
+	/// If no key combination was chosen, the returned virtual key code is zero.
+	///
+	/// The Shift and Alt bits of `HOTKEYF` are swapped in relation to
+	/// [`co::MOD`](crate::co::MOD), so convert the modifiers with
+	/// `co::MOD::from` before passing them to
+	/// [`WindowMain::register_global_hotkey`](crate::gui::WindowMain::register_global_hotkey).
+	pub fn hotkey(&self) -> (co::VK, co::HOTKEYF) {
+		self.hwnd().SendMessage(hkm::GetHotKey {})
+	}
+
+	/// Sets the key combination displayed in the control, by sending an
+	/// [`HKM_SETHOTKEY`](crate::msg::hkm::SetHotKey) message.
+	pub fn set_hotkey(&self, vkey_code: co::VK, modifiers: co::HOTKEYF) {
+		self.hwnd().SendMessage(hkm::SetHotKey { vkey_code, modifiers });
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Options to create a [`HotKey`](crate::gui::HotKey) programatically with
+/// [`HotKey::new`](crate::gui::HotKey::new).
+pub struct HotKeyOpts {
+	/// Control position within parent client area, in pixels, to be
+	/// [created](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Will be adjusted to match current system DPI.
+	///
+	/// Defaults to 0 x 0.
+	pub position: POINT,
+	/// Control width, in pixels, to be
+	/// [created](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Will be adjusted to match current system DPI.
+	///
+	/// Defaults to 100.
+	pub width: u32,
+	/// Control height, in pixels, to be
+	/// [created](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Will be adjusted to match current system DPI.
+	///
+	/// Defaults to 21.
+	pub height: u32,
+	/// Window styles to be
+	/// [created](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS::CHILD | WS::VISIBLE | WS::TABSTOP | WS::GROUP`.
+	pub window_style: co::WS,
+	/// Extended window styles to be
+	/// [created](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS_EX::LEFT | WS_EX::CLIENTEDGE`.
+	pub ex_window_style: co::WS_EX,
+
+	/// The control ID.
+	///
+	/// Defaults to an auto-generated ID.
+	pub ctrl_id: u16,
+}
+
+impl Default for HotKeyOpts {
+	fn default() -> Self {
+		Self {
+			position: POINT::new(0, 0),
+			width: 100,
+			height: 21,
+			window_style: co::WS::CHILD | co::WS::VISIBLE | co::WS::TABSTOP | co::WS::GROUP,
+			ex_window_style: co::WS_EX::LEFT | co::WS_EX::CLIENTEDGE,
+			ctrl_id: 0,
+		}
+	}
+}
+
+impl HotKeyOpts {
+	fn define_ctrl_id(mut self) -> Self {
+		if self.ctrl_id == 0 {
+			self.ctrl_id = auto_ctrl_id();
+		}
+		self
+	}
+}
@@ -0,0 +1,356 @@
+use std::any::Any;
+use std::sync::Arc;
+
+use crate::aliases::WinResult;
+use crate::co;
+use crate::enums::{BitmapIcon, IdStr};
+use crate::funcs::PostQuitMessage;
+use crate::gui::base::Base;
+use crate::gui::events::{ImageViewEvents, WindowEvents};
+use crate::gui::native_controls::native_control_base::{NativeControlBase, OptsId};
+use crate::gui::privs::{auto_ctrl_id, multiply_dpi_for};
+use crate::gui::traits::{baseref_from_parent, Child, Parent};
+use crate::gui::very_unsafe_cell::VeryUnsafeCell;
+use crate::handles::HWND;
+use crate::msg::{acm, stm};
+use crate::structs::{POINT, SIZE};
+use crate::WString;
+
+/// Native
+/// [static](https://docs.microsoft.com/en-us/windows/win32/controls/about-static-controls)
+/// control which displays a bitmap or an icon, or a native
+/// [animation](https://docs.microsoft.com/en-us/windows/win32/controls/animation-control-overview)
+/// control which plays an AVI clip.
+///
+/// Implements [`Child`](crate::gui::Child) trait.
+#[derive(Clone)]
+pub struct ImageView(Arc<VeryUnsafeCell<Obj>>);
+
+struct Obj { // actual fields of ImageView
+	base: NativeControlBase,
+	opts_id: OptsId<ImageViewOpts>,
+	events: ImageViewEvents,
+	owned_image: Option<BitmapIcon>, // loaded from resource, must be destroyed
+}
+
+unsafe impl Send for ImageView {}
+unsafe impl Sync for ImageView {}
+
+impl Child for ImageView {
+	fn as_any(&self) -> &dyn Any {
+		self
+	}
+}
+
+impl ImageView {
+	/// Instantiates a new `ImageView` object, to be created on the parent window
+	/// with [`CreateWindowEx`](crate::HWND::CreateWindowEx).
+	pub fn new(parent: &dyn Parent, opts: ImageViewOpts) -> ImageView {
+		let parent_ref = baseref_from_parent(parent);
+		let opts = ImageViewOpts::define_ctrl_id(opts);
+		let ctrl_id = opts.ctrl_id;
+
+		let new_self = Self(
+			Arc::new(VeryUnsafeCell::new(
+				Obj {
+					base: NativeControlBase::new(parent_ref),
+					opts_id: OptsId::Wnd(opts),
+					events: ImageViewEvents::new(parent_ref, ctrl_id),
+					owned_image: None,
+				},
+			)),
+		);
+
+		parent_ref.privileged_events_ref().wm(parent_ref.create_wm(), {
+			let me = new_self.clone();
+			move |_| { me.create(); 0 }
+		});
+		new_self.cleanup_on_nc_destroy(parent_ref);
+
+		new_self
+	}
+
+	/// Instantiates a new `ImageView` object, to be loaded from a dialog
+	/// resource with [`GetDlgItem`](crate::HWND::GetDlgItem).
+	pub fn new_dlg(parent: &dyn Parent, ctrl_id: u16) -> ImageView {
+		let parent_ref = baseref_from_parent(parent);
+
+		let new_self = Self(
+			Arc::new(VeryUnsafeCell::new(
+				Obj {
+					base: NativeControlBase::new(parent_ref),
+					opts_id: OptsId::Dlg(ctrl_id),
+					events: ImageViewEvents::new(parent_ref, ctrl_id),
+					owned_image: None,
+				},
+			)),
+		);
+
+		parent_ref.privileged_events_ref().wm_init_dialog({
+			let me = new_self.clone();
+			move |_| { me.create(); true }
+		});
+		new_self.cleanup_on_nc_destroy(parent_ref);
+
+		new_self
+	}
+
+	fn create(&self) {
+		|| -> WinResult<()> {
+			match &self.0.opts_id {
+				OptsId::Wnd(opts) => {
+					let parent_ref = self.0.base.parent_ref();
+					let mut pos = opts.position;
+					let mut sz = SIZE::new(opts.width as i32, opts.height as i32);
+					multiply_dpi_for(parent_ref, Some(&mut pos), Some(&mut sz))?;
+
+					let (class_name, style) = if opts.animated {
+						let mut acs = co::ACS::TRANSPARENT;
+						if opts.scale == ImageViewScale::Center {
+							acs |= co::ACS::CENTER;
+						}
+						("SysAnimate32", opts.window_style | acs.into())
+					} else {
+						("STATIC", opts.window_style | co::SS::BITMAP.into() |
+							match opts.scale {
+								ImageViewScale::Original => co::SS::LEFT,
+								ImageViewScale::Center => co::SS::CENTERIMAGE | co::SS::REALSIZEIMAGE,
+								ImageViewScale::Stretch => co::SS::REALSIZECONTROL,
+							}.into())
+					};
+
+					self.0.base.create_window( // may panic
+						class_name, None, pos, sz,
+						opts.ctrl_id,
+						opts.ex_window_style,
+						style,
+					)?;
+					Ok(())
+				},
+				OptsId::Dlg(ctrl_id) => self.0.base.create_dlg(*ctrl_id).map(|_| ()), // may panic
+			}
+		}().unwrap_or_else(|err| PostQuitMessage(err))
+	}
+
+	fn cleanup_on_nc_destroy(&self, parent_ref: &Base) {
+		parent_ref.privileged_events_ref().wm_nc_destroy({
+			let me = self.clone();
+			move || {
+				me.destroy_owned_image()
+					.unwrap_or_else(|err| PostQuitMessage(err))
+			}
+		});
+	}
+
+	fn destroy_owned_image(&self) -> WinResult<()> {
+		match self.0.as_mut().owned_image.take() {
+			Some(BitmapIcon::Bitmap(hbmp)) => hbmp.DeleteObject(),
+			Some(BitmapIcon::Icon(hicon)) => hicon.DestroyIcon(),
+			None => Ok(()),
+		}
+	}
+
+	hwnd_ctrlid_on_onsubclass!(ImageViewEvents);
+
+	/// Displays a bitmap or an icon by sending an
+	/// [`STM_SETIMAGE`](crate::msg::stm::SetImage) message. The
+	/// [`SS_BITMAP`](crate::co::SS::BITMAP) or [`SS_ICON`](crate::co::SS::ICON)
+	/// style is automatically set, according to the image type.
+	///
+	/// The handle is still owned by the caller, who must keep it alive while
+	/// it's being displayed, and destroy it afterwards.
+	///
+	/// **Note:** Not available in animated image views.
+	pub fn set_image(&self, image: BitmapIcon) -> WinResult<()> {
+		let hwnd = self.hwnd();
+		let style = hwnd.GetWindowLongPtr(co::GWLP::STYLE) as u32;
+		let img_type = match image {
+			BitmapIcon::Bitmap(_) => co::SS::BITMAP,
+			BitmapIcon::Icon(_) => co::SS::ICON,
+		};
+		hwnd.SetWindowLongPtr(co::GWLP::STYLE,
+			((style & !co::SS::TYPEMASK.0) | img_type.0) as isize);
+
+		hwnd.SendMessage(stm::SetImage { image })?;
+		self.destroy_owned_image() // the previous image is no longer displayed
+	}
+
+	/// Loads a bitmap or an icon from the application resources with
+	/// [`LoadImageBitmap`](crate::HINSTANCE::LoadImageBitmap) or
+	/// [`LoadImageIcon`](crate::HINSTANCE::LoadImageIcon), then displays it with
+	/// [`set_image`](crate::gui::ImageView::set_image).
+	///
+	/// If the control has the
+	/// [`SS_REALSIZECONTROL`](crate::co::SS::REALSIZECONTROL) style, the image
+	/// is loaded with the size of the control, otherwise icons are loaded with
+	/// the system default size. The loaded image is owned by the control, and
+	/// automatically destroyed.
+	///
+	/// **Note:** Not available in animated image views.
+	pub fn set_resource_image(&self,
+		name: IdStr, img_type: co::IMAGE_TYPE) -> WinResult<()>
+	{
+		let hwnd = self.hwnd();
+		let hinst = self.0.base.parent_ref().parent_hinstance()?;
+		let style = co::SS(hwnd.GetWindowLongPtr(co::GWLP::STYLE) as u32);
+
+		let (cx, cy, lr) = if style.has(co::SS::REALSIZECONTROL) {
+			let rc = hwnd.GetClientRect()?;
+			(rc.right, rc.bottom, co::LR::DEFAULTCOLOR)
+		} else {
+			(0, 0, co::LR::DEFAULTSIZE)
+		};
+
+		let image = match img_type {
+			co::IMAGE_TYPE::BITMAP => BitmapIcon::Bitmap(
+				hinst.LoadImageBitmap(name, cx, cy, co::LR::DEFAULTCOLOR)?),
+			co::IMAGE_TYPE::ICON => BitmapIcon::Icon(
+				hinst.LoadImageIcon(name, cx, cy, lr)?),
+			_ => return Err(co::ERROR::BAD_ARGUMENTS),
+		};
+		let owned = image;
+
+		self.set_image(image)?;
+		self.0.as_mut().owned_image = Some(owned);
+		Ok(())
+	}
+
+	/// Opens an AVI clip from the application resources by sending an
+	/// [`ACM_OPEN`](crate::msg::acm::Open) message.
+	///
+	/// **Note:** Only available in animated image views.
+	pub fn open_animation(&self, name: IdStr) -> WinResult<()> {
+		let hinst = self.0.base.parent_ref().parent_hinstance()?;
+		self.hwnd().SendMessage(acm::Open { hinstance: Some(hinst), name })
+	}
+
+	/// Opens an AVI clip from a file by sending an
+	/// [`ACM_OPEN`](crate::msg::acm::Open) message.
+	///
+	/// **Note:** Only available in animated image views.
+	pub fn open_animation_file(&self, path: &str) -> WinResult<()> {
+		self.hwnd().SendMessage(acm::Open {
+			hinstance: None,
+			name: IdStr::Str(WString::from_str(path)),
+		})
+	}
+
+	/// Plays the whole opened AVI clip by sending an
+	/// [`ACM_PLAY`](crate::msg::acm::Play) message. If `repeat_count` is
+	/// `None`, the clip is replayed indefinitely.
+	///
+	/// **Note:** Only available in animated image views.
+	pub fn play(&self, repeat_count: Option<u32>) -> WinResult<()> {
+		self.hwnd().SendMessage(acm::Play {
+			repeat_count,
+			from_frame: 0,
+			to_frame: 0xffff,
+		})
+	}
+
+	/// Stops playing the AVI clip by sending an
+	/// [`ACM_STOP`](crate::msg::acm::Stop) message.
+	///
+	/// **Note:** Only available in animated image views.
+	pub fn stop(&self) -> WinResult<()> {
+		self.hwnd().SendMessage(acm::Stop {})
+	}
+
+	/// Tells whether the AVI clip is playing by sending an
+	/// [`ACM_ISPLAYING`](crate::msg::acm::IsPlaying) message.
+	///
+	/// **Note:** Only available in animated image views.
+	pub fn is_playing(&self) -> bool {
+		self.hwnd().SendMessage(acm::IsPlaying {})
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// How the image is scaled within an [`ImageView`](crate::gui::ImageView).
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ImageViewScale {
+	/// The control is resized to fit the image, which is displayed at its
+	/// original size.
+	Original,
+	/// The image is centered within the control, at its original size.
+	Center,
+	/// The image is stretched to fill the whole control. Not available in
+	/// animated image views.
+	Stretch,
+}
+
+/// Options to create an [`ImageView`](crate::gui::ImageView) programatically
+/// with [`ImageView::new`](crate::gui::ImageView::new).
+pub struct ImageViewOpts {
+	/// Control position within parent client area, in pixels, to be
+	/// [created](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Will be adjusted to match current system DPI.
+	///
+	/// Defaults to 0 x 0.
+	pub position: POINT,
+	/// Control width, in pixels, to be
+	/// [created](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Will be adjusted to match current system DPI.
+	///
+	/// Defaults to 100.
+	pub width: u32,
+	/// Control height, in pixels, to be
+	/// [created](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Will be adjusted to match current system DPI.
+	///
+	/// Defaults to 100.
+	pub height: u32,
+	/// How the image is scaled within the control.
+	///
+	/// Defaults to `ImageViewScale::Center`.
+	pub scale: ImageViewScale,
+	/// If `true`, an
+	/// [animation](https://docs.microsoft.com/en-us/windows/win32/controls/animation-control-overview)
+	/// control will be created to play AVI clips, instead of a static control.
+	///
+	/// Defaults to `false`.
+	pub animated: bool,
+	/// Window styles to be
+	/// [created](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS::CHILD | WS::VISIBLE`.
+	pub window_style: co::WS,
+	/// Extended window styles to be
+	/// [created](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS_EX::LEFT`.
+	pub ex_window_style: co::WS_EX,
+
+	/// The control ID.
+	///
+	/// Defaults to an auto-generated ID.
+	pub ctrl_id: u16,
+}
+
+impl Default for ImageViewOpts {
+	fn default() -> Self {
+		Self {
+			position: POINT::new(0, 0),
+			width: 100,
+			height: 100,
+			scale: ImageViewScale::Center,
+			animated: false,
+			window_style: co::WS::CHILD | co::WS::VISIBLE,
+			ex_window_style: co::WS_EX::LEFT,
+			ctrl_id: 0,
+		}
+	}
+}
+
+impl ImageViewOpts {
+	fn define_ctrl_id(mut self) -> Self {
+		if self.ctrl_id == 0 {
+			self.ctrl_id = auto_ctrl_id();
+		}
+		self
+	}
+}
@@ -7,7 +7,7 @@ use crate::enums::HwndPlace;
 use crate::funcs::PostQuitMessage;
 use crate::gui::events::{LabelEvents, WindowEvents};
 use crate::gui::native_controls::native_control_base::{NativeControlBase, OptsId};
-use crate::gui::privs::{auto_ctrl_id, calc_text_bound_box, multiply_dpi, ui_font};
+use crate::gui::privs::{auto_ctrl_id, calc_text_bound_box, multiply_dpi_for, ui_font_for_dpi};
 use crate::gui::traits::{baseref_from_parent, Child, Parent};
 use crate::handles::HWND;
 use crate::msg::wm;
@@ -89,11 +89,12 @@ impl Label {
 		|| -> WinResult<()> {
 			match &self.0.opts_id {
 				OptsId::Wnd(opts) => {
+					let parent_ref = self.0.base.parent_ref();
 					let mut pos = opts.position;
 					if opts.baseline_text_align { pos.y += 3; }
-					multiply_dpi(Some(&mut pos), None)?;
+					multiply_dpi_for(parent_ref, Some(&mut pos), None)?;
 
-					let bound_box = calc_text_bound_box(&opts.text)?;
+					let bound_box = calc_text_bound_box(&opts.text, parent_ref.dpi()?)?;
 
 					let our_hwnd = self.0.base.create_window( // may panic
 						"STATIC", Some(&opts.text), pos, bound_box,
@@ -102,7 +103,7 @@ impl Label {
 						opts.window_style | opts.label_style.into(),
 					)?;
 
-					our_hwnd.SendMessage(wm::SetFont{ hfont: ui_font(), redraw: true });
+					our_hwnd.SendMessage(wm::SetFont{ hfont: ui_font_for_dpi(parent_ref.dpi()?)?, redraw: true });
 					Ok(())
 				},
 				OptsId::Dlg(ctrl_id) => {
@@ -129,7 +130,7 @@ impl Label {
 	}
 
 	fn adjust_size_to_fit_text(&self, text: &str) -> WinResult<()> {
-		let bound_box = calc_text_bound_box(text)?;
+		let bound_box = calc_text_bound_box(text, self.0.base.parent_ref().dpi()?)?;
 		self.hwnd().SetWindowPos(
 			HwndPlace::None, 0, 0, bound_box.cx, bound_box.cy,
 			co::SWP::NOZORDER | co::SWP::NOMOVE)
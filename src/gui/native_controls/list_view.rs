@@ -9,7 +9,7 @@ use crate::gui::events::{ListViewEvents, WindowEvents};
 use crate::gui::native_controls::list_view_columns::ListViewColumns;
 use crate::gui::native_controls::list_view_items::ListViewItems;
 use crate::gui::native_controls::native_control_base::{NativeControlBase, OptsId};
-use crate::gui::privs::{auto_ctrl_id, multiply_dpi};
+use crate::gui::privs::{auto_ctrl_id, multiply_dpi_for};
 use crate::gui::traits::{baseref_from_parent, Child, Parent};
 use crate::handles::HWND;
 use crate::msg::lvm;
@@ -55,7 +55,7 @@ impl ListView {
 					base: NativeControlBase::new(parent_ref),
 					opts_id: OptsId::Wnd(opts),
 					events: ListViewEvents::new(parent_ref, ctrl_id),
-					columns: ListViewColumns::new(parent_ref.hwnd_ref()), // wrong HWND, just to construct the object
+					columns: ListViewColumns::new(parent_ref), // wrong HWND, just to construct the object
 					items: ListViewItems::new(parent_ref.hwnd_ref()),
 				},
 			),
@@ -83,7 +83,7 @@ impl ListView {
 					base: NativeControlBase::new(parent_ref),
 					opts_id: OptsId::Dlg(ctrl_id),
 					events: ListViewEvents::new(parent_ref, ctrl_id),
-					columns: ListViewColumns::new(parent_ref.hwnd_ref()), // wrong HWND, just to construct the object
+					columns: ListViewColumns::new(parent_ref), // wrong HWND, just to construct the object
 					items: ListViewItems::new(parent_ref.hwnd_ref()),
 				},
 			),
@@ -104,9 +104,10 @@ impl ListView {
 		|| -> WinResult<()> {
 			match &self.0.opts_id {
 				OptsId::Wnd(opts) => {
+					let parent_ref = self.0.base.parent_ref();
 					let mut pos = opts.position;
 					let mut sz = opts.size;
-					multiply_dpi(Some(&mut pos), Some(&mut sz))?;
+					multiply_dpi_for(parent_ref, Some(&mut pos), Some(&mut sz))?;
 
 					self.0.base.create_window( // may panic
 						"SysListView32", None, pos, sz,
@@ -2,7 +2,8 @@ use std::ptr::NonNull;
 
 use crate::aliases::WinResult;
 use crate::co;
-use crate::gui::privs::multiply_dpi;
+use crate::gui::base::Base;
+use crate::gui::privs::multiply_dpi_for;
 use crate::gui::very_unsafe_cell::VeryUnsafeCell;
 use crate::handles::HWND;
 use crate::msg::{hdm, lvm};
@@ -15,12 +16,14 @@ use crate::WString;
 /// control.
 pub struct ListViewColumns {
 	hwnd_ptr: VeryUnsafeCell<NonNull<HWND>>,
+	parent_ptr: NonNull<Base>,
 }
 
 impl ListViewColumns {
-	pub(crate) fn new(hwnd_ref: &HWND) -> ListViewColumns {
+	pub(crate) fn new(parent_ref: &Base) -> ListViewColumns {
 		Self {
-			hwnd_ptr: VeryUnsafeCell::new(NonNull::from(hwnd_ref)), // ref implicitly converted to pointer
+			hwnd_ptr: VeryUnsafeCell::new(NonNull::from(parent_ref.hwnd_ref())), // ref implicitly converted to pointer
+			parent_ptr: NonNull::from(parent_ref),
 		}
 	}
 
@@ -35,11 +38,11 @@ impl ListViewColumns {
 	/// Adds many columns at once by sending an
 	/// [`LVM_INSERTCOLUMN`](crate::msg::lvm::InsertColumn) message.
 	///
-	/// Widths will be adjusted to match current system DPI.
+	/// Widths will be adjusted to match the current DPI of the parent window.
 	pub fn add(&self, texts_and_widths: &[(&str, u32)]) -> WinResult<()> {
 		for (text, width) in texts_and_widths.iter() {
 			let mut col_cx = SIZE::new(*width as i32, 0);
-			multiply_dpi(None, Some(&mut col_cx))?;
+			multiply_dpi_for(unsafe { self.parent_ptr.as_ref() }, None, Some(&mut col_cx))?;
 
 			let mut lvc = LVCOLUMN::default();
 			lvc.mask = co::LVCF::TEXT | co::LVCF::WIDTH;
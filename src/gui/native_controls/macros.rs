@@ -24,14 +24,10 @@ macro_rules! hwnd_on_onsubclass {
 		///
 		/// # Panics
 		///
-		/// Panics if the control or the parent window are already created. Events
-		/// must be set before control and parent window creation.
+		/// Adding an event panics if the parent window is already created, so
+		/// events must be set before control and parent window creation. Events
+		/// can be removed at any time.
 		pub fn on(&self) -> &$evstruc {
-			if !self.0.base.hwnd_ref().is_null() {
-				panic!("Cannot add events after the control is created.");
-			} else if !self.0.base.parent_ref().hwnd_ref().is_null() {
-				panic!("Cannot add events after the parent window is created.");
-			}
 			&self.0.events
 		}
 
@@ -9,6 +9,8 @@ mod combo_box_items;
 mod combo_box;
 mod date_time_picker;
 mod edit;
+mod hot_key;
+mod image_view;
 mod label;
 mod list_box_items;
 mod list_box;
@@ -27,6 +29,8 @@ pub use combo_box_items::ComboBoxItems;
 pub use combo_box::{ComboBox, ComboBoxOpts};
 pub use date_time_picker::{DateTimePicker, DateTimePickerOpts};
 pub use edit::{Edit, EditOpts};
+pub use hot_key::{HotKey, HotKeyOpts};
+pub use image_view::{ImageView, ImageViewOpts, ImageViewScale};
 pub use label::{Label, LabelOpts};
 pub use list_box_items::ListBoxItems;
 pub use list_box::{ListBox, ListBoxOpts};
@@ -7,7 +7,7 @@ use crate::enums::HwndPlace;
 use crate::funcs::PostQuitMessage;
 use crate::gui::events::{MonthCalendarEvents, WindowEvents};
 use crate::gui::native_controls::native_control_base::{NativeControlBase, OptsId};
-use crate::gui::privs::{auto_ctrl_id, multiply_dpi};
+use crate::gui::privs::{auto_ctrl_id, multiply_dpi_for};
 use crate::gui::traits::{baseref_from_parent, Child, Parent};
 use crate::handles::HWND;
 use crate::msg::mcm;
@@ -89,8 +89,9 @@ impl MonthCalendar {
 		|| -> WinResult<()> {
 			match &self.0.opts_id {
 				OptsId::Wnd(opts) => {
+					let parent_ref = self.0.base.parent_ref();
 					let mut pos = opts.position;
-					multiply_dpi(Some(&mut pos), None)?;
+					multiply_dpi_for(parent_ref, Some(&mut pos), None)?;
 
 					let our_hwnd = self.0.base.create_window( // may panic
 						"SysMonthCal32", None, pos, SIZE::new(0, 0),
@@ -84,7 +84,7 @@ impl NativeControlBase {
 	{
 		let hparent = *self.parent_ref().hwnd_ref();
 
-		if !self.0.hwnd.is_null() {
+		if !self.0.hwnd.is_null() { // reset when the parent is destroyed
 			panic!("Cannot create control twice.");
 		} else if hparent.is_null() {
 			panic!("Cannot create control before parent window is created.");
@@ -101,6 +101,7 @@ impl NativeControlBase {
 			None,
 		)?;
 
+		self.parent_ref().add_child_hwnd(NonNull::from(&self.0.hwnd));
 		self.install_subclass_if_needed()?;
 		Ok(self.0.hwnd)
 	}
@@ -112,18 +113,28 @@ impl NativeControlBase {
 
 		let hparent = *self.parent_ref().hwnd_ref();
 
-		if !self.0.hwnd.is_null() {
+		if !self.0.hwnd.is_null() { // reset when the parent is destroyed
 			panic!("Cannot create control twice.");
 		} else if hparent.is_null() {
 			panic!("Cannot create control before parent window is created.");
 		}
 
 		self.0.as_mut().hwnd = hparent.GetDlgItem(ctrl_id as i32)?;
+		self.parent_ref().add_child_hwnd(NonNull::from(&self.0.hwnd));
 		self.install_subclass_if_needed()?;
 		Ok(self.0.hwnd)
 	}
 
 	fn install_subclass_if_needed(&self) -> WinResult<()> {
+		if self.0.subclass_events.accepts_files() {
+			let hwnd = self.0.hwnd;
+			hwnd.DragAcceptFiles(true);
+			self.0.subclass_events.set_drop_files_off(move || hwnd.DragAcceptFiles(false));
+		}
+		if self.0.subclass_events.listens_clipboard() {
+			self.0.hwnd.AddClipboardFormatListener()?;
+		}
+
 		if !self.0.subclass_events.is_empty() {
 			let subclass_id = unsafe {
 				BASE_SUBCLASS_ID += 1;
@@ -158,6 +169,9 @@ impl NativeControlBase {
 
 			if msg == co::WM::NCDESTROY { // always check
 				hwnd.RemoveWindowSubclass(Self::subclass_proc, subclass_id)?;
+				if !ptr_self.is_null() && unsafe { &*ptr_self }.0.subclass_events.listens_clipboard() {
+					hwnd.RemoveClipboardFormatListener()?;
+				}
 			}
 
 			Ok(match maybe_processed {
@@ -122,16 +122,10 @@ impl RadioGroup {
 	///
 	/// # Panics
 	///
-	/// Panics if the control or the parent window are already created. Events
-	/// must be set before control and parent window creation.
+	/// Adding an event panics if the parent window is already created, so
+	/// events must be set before control and parent window creation. Events
+	/// can be removed at any time.
 	pub fn on(&self) -> &RadioGroupEvents {
-		let first_radio = self.index(0);
-
-		if !first_radio.hwnd().is_null() {
-			panic!("Cannot add events after the control is created.");
-		} else if !first_radio.base_ref().parent_ref().hwnd_ref().is_null() {
-			panic!("Cannot add events after the parent window is created.");
-		}
 		&self.0.parent_events
 	}
 
@@ -3,10 +3,11 @@ use std::sync::Arc;
 
 use crate::aliases::WinResult;
 use crate::co;
-use crate::funcs::PostQuitMessage;
+use crate::funcs::{MulDiv, PostQuitMessage};
 use crate::gui::events::{StatusBarEvents, WindowEvents};
+use crate::gui::layout_calc::status_bar_right_edges;
 use crate::gui::native_controls::native_control_base::NativeControlBase;
-use crate::gui::privs::{auto_ctrl_id, multiply_dpi};
+use crate::gui::privs::{auto_ctrl_id, multiply_dpi_for};
 use crate::gui::traits::{baseref_from_parent, Child, Parent};
 use crate::gui::very_unsafe_cell::VeryUnsafeCell;
 use crate::handles::HWND;
@@ -66,6 +67,10 @@ impl StatusBar {
 			let me = new_self.clone();
 			move |p| me.resize(&p)
 		});
+		parent_ref.privileged_dpi_rescaler({
+			let me = new_self.clone();
+			move |old_dpi, new_dpi| me.rescale(old_dpi, new_dpi)
+		});
 
 		new_self
 	}
@@ -75,7 +80,7 @@ impl StatusBar {
 			for part in self.0.as_mut().parts_info.iter_mut() {
 				if let StatusBarPart::Fixed(width) = part { // adjust fixed-width parts to DPI
 					let mut col_cx = SIZE::new(*width as i32, 0);
-					multiply_dpi(None, Some(&mut col_cx))?;
+					multiply_dpi_for(self.0.base.parent_ref(), None, Some(&mut col_cx))?;
 					*width = col_cx.cx as u32;
 				}
 			}
@@ -120,33 +125,25 @@ impl StatusBar {
 
 			self.hwnd().SendMessage(p.as_generic_wm()); // send WM_SIZE to status bar, so it resizes itself to fit parent
 
-			let mut total_proportions: u8 = 0;
-			let mut cx_available = p.client_area.cx as u32;
-
-			for part_info in self.0.parts_info.iter() {
-				match part_info {
-					StatusBarPart::Fixed(pixels) => cx_available -= pixels,
-					StatusBarPart::Proportional(prop) => total_proportions += prop,
-				}
-			}
-
 			let right_edges = &mut self.0.as_mut().right_edges;
-			let mut total_cx = p.client_area.cx as u32;
-
-			for (idx, part_info) in self.0.parts_info.iter().rev().enumerate() {
-				right_edges[self.0.parts_info.len() - idx - 1] = total_cx as i32;
-				total_cx -= match part_info {
-					StatusBarPart::Fixed(pixels) => *pixels,
-					StatusBarPart::Proportional(pp) => (cx_available / total_proportions as u32) * (*pp as u32),
-				};
-			}
-			*right_edges.last_mut().unwrap() = -1;
+			status_bar_right_edges(&self.0.parts_info, p.client_area.cx, right_edges);
 
 			self.hwnd().SendMessage(sb::SetParts { right_edges: &right_edges })
 		}
 		(p).unwrap_or_else(|err| PostQuitMessage(err))
 	}
 
+	fn rescale(&self, old_dpi: u32, new_dpi: u32) {
+		if self.hwnd().is_null() {
+			return; // widths not scaled yet, will be scaled with the new DPI
+		}
+		for part in self.0.as_mut().parts_info.iter_mut() {
+			if let StatusBarPart::Fixed(width) = part {
+				*width = MulDiv(*width as i32, new_dpi as i32, old_dpi as i32) as u32;
+			}
+		}
+	}
+
 	hwnd_on_onsubclass!(StatusBarEvents);
 
 	/// Retrieves the number of parts by sending an
@@ -186,7 +183,7 @@ impl StatusBar {
 pub enum StatusBarPart {
 	/// A part that has a fixed size, in pixels.
 	//
-	/// Will be adjusted to match current system DPI.
+	/// Will be adjusted to match the current DPI of the parent window.
 	Fixed(u32),
 	/// A part that will resize when the parent window resizes, filling the space
 	/// left by the fixed-size parts. Has the resizing proportion.
@@ -1,10 +1,23 @@
 //! Global objects used within `gui` module.
 
+use std::ptr::NonNull;
+
 use crate::aliases::WinResult;
 use crate::co;
+use crate::enums::HwndPlace;
 use crate::ffi::kernel32;
-use crate::funcs::{GetSystemMetrics, SystemParametersInfo};
-use crate::handles::{HFONT, HTHEME, HWND};
+use crate::funcs::{
+	GetSystemMetrics,
+	IsWindowsVistaOrGreater,
+	MulDiv,
+	PostQuitMessage,
+	SetProcessDPIAware,
+	SetProcessDpiAwarenessContext,
+	SystemParametersInfo,
+	SystemParametersInfoForDpi,
+};
+use crate::gui::base::Base;
+use crate::handles::{HDWP, HFONT, HTHEME, HWND};
 use crate::msg::wm;
 use crate::structs::{NONCLIENTMETRICS, POINT, RECT, SIZE};
 
@@ -25,13 +38,17 @@ pub fn create_ui_font() -> WinResult<()> {
 	Ok(())
 }
 
-/// Frees the global UI font object.
+/// Frees the global UI font object, along with the ones created for other
+/// DPIs.
 pub fn delete_ui_font() -> WinResult<()> {
 	unsafe {
 		if let Some(hfont) = UI_HFONT {
 			hfont.DeleteObject()?;
 			UI_HFONT = None;
 		}
+		for (_, hfont) in (*std::ptr::addr_of_mut!(UI_HFONTS_DPI)).drain(..) {
+			hfont.DeleteObject()?;
+		}
 	}
 	Ok(())
 }
@@ -59,10 +76,81 @@ pub fn auto_ctrl_id() -> u16 {
 	}
 }
 
+static mut BASE_HOTKEY_ID: i32 = 0x0001; // applications must use 0x0000 - 0xbfff
+
+/// Returns the next sequential global hot key ID.
+pub fn auto_hotkey_id() -> i32 {
+	unsafe {
+		let new_id = BASE_HOTKEY_ID;
+		BASE_HOTKEY_ID += 1;
+		new_id
+	}
+}
+
+/// UI font objects for DPIs other than the system one, created on demand.
+static mut UI_HFONTS_DPI: Vec<(u32, HFONT)> = Vec::new();
+
+/// Retrieves the UI font object for the given DPI, creating it if needed.
+pub fn ui_font_for_dpi(dpi: u32) -> WinResult<HFONT> {
+	if dpi == system_dpi()? {
+		return Ok(ui_font());
+	}
+
+	unsafe {
+		let fonts = &mut *std::ptr::addr_of_mut!(UI_HFONTS_DPI);
+		if let Some((_, hfont)) = fonts.iter().find(|(font_dpi, _)| *font_dpi == dpi) {
+			return Ok(*hfont);
+		}
+
+		let mut ncm = NONCLIENTMETRICS::default();
+		if SystemParametersInfoForDpi(
+			co::SPI::GETNONCLIENTMETRICS,
+			std::mem::size_of::<NONCLIENTMETRICS>() as u32,
+			&mut ncm, co::SPIF::ZERO, dpi,
+		).is_err() { // not available before Windows 10 1607, scale the system font
+			SystemParametersInfo(
+				co::SPI::GETNONCLIENTMETRICS,
+				std::mem::size_of::<NONCLIENTMETRICS>() as u32,
+				&mut ncm, co::SPIF::ZERO,
+			)?;
+			ncm.lfMenuFont.lfHeight = MulDiv(ncm.lfMenuFont.lfHeight,
+				dpi as i32, system_dpi()? as i32);
+		}
+		let hfont = HFONT::CreateFontIndirect(&ncm.lfMenuFont)?;
+		fonts.push((dpi, hfont));
+		Ok(hfont)
+	}
+}
+
 //------------------------------------------------------------------------------
 
+/// Sets the process as per-monitor v2 DPI aware, if available, otherwise as
+/// system DPI aware.
+pub fn set_process_dpi_awareness() -> WinResult<()> {
+	match SetProcessDpiAwarenessContext(
+		co::DPI_AWARENESS_CONTEXT::PER_MONITOR_AWARE_V2)
+	{
+		Ok(_) => return Ok(()),
+		Err(co::ERROR::ACCESS_DENIED) => return Ok(()), // already set, by manifest or previous call
+		Err(_) => {}, // function or per-monitor v2 not available
+	}
+
+	if IsWindowsVistaOrGreater()? {
+		SetProcessDPIAware()?;
+	}
+	Ok(())
+}
+
 static mut DPI: POINT = POINT { x: 0, y: 0 };
 
+/// Returns the system DPI, which is used to create all the windows and
+/// controls.
+pub fn system_dpi() -> WinResult<u32> {
+	let mut sz = SIZE::new(96, 96);
+	multiply_dpi(None, Some(&mut sz))?;
+	Ok(sz.cx as u32)
+}
+
 /// Multiplies the given coordinates by current system DPI.
 pub fn multiply_dpi(
 	pt: Option<&mut POINT>, sz: Option<&mut SIZE>) -> WinResult<()>
@@ -87,14 +175,127 @@ pub fn multiply_dpi(
 	Ok(())
 }
 
+/// Multiplies the given coordinates by the current DPI of the window, so that
+/// controls created after a DPI change match the ones already created.
+pub fn multiply_dpi_for(base_ref: &Base,
+	pt: Option<&mut POINT>, sz: Option<&mut SIZE>) -> WinResult<()>
+{
+	if pt.is_none() && sz.is_none() {
+		return Ok(());
+	}
+	let dpi = base_ref.dpi()? as i32;
+
+	if let Some(pt) = pt {
+		pt.x = MulDiv(pt.x, dpi, 96);
+		pt.y = MulDiv(pt.y, dpi, 96);
+	}
+
+	if let Some(sz) = sz {
+		sz.cx = MulDiv(sz.cx, dpi, 96);
+		sz.cy = MulDiv(sz.cy, dpi, 96);
+	}
+	Ok(())
+}
+
+/// Adds a privileged handler which rescales the direct children of the window
+/// when its DPI changes. A top-level window handles `WM_DPICHANGED`, also
+/// moving itself to the suggested rectangle; a child window handles
+/// `WM_DPICHANGED_AFTERPARENT`.
+///
+/// The new DPI is stored in the window base, whose rescalers update the lengths
+/// kept by helpers like `Resizer`, before the window receives `WM_SIZE`.
+pub fn add_dpi_changed_handler(base_ref: &Base, is_top_level: bool) {
+	let base_ptr = NonNull::from(base_ref); // convert reference to pointer
+
+	let on_dpi_changed = move |suggested: Option<(u32, &RECT)>| {
+		|| -> WinResult<()> {
+			let base_ref = unsafe { base_ptr.as_ref() };
+			let hwnd = *base_ref.hwnd_ref();
+			let old_dpi = base_ref.dpi()?;
+			let new_dpi = match hwnd.GetDpiForWindow() {
+				Ok(dpi) => dpi,
+				Err(_) => match (suggested, base_ref.parent_ref()) { // not available before Windows 10 1607
+					(Some((msg_dpi, _)), _) => msg_dpi,
+					(None, Some(parent_ref)) => parent_ref.dpi()?,
+					(None, None) => old_dpi,
+				},
+			};
+			if new_dpi != old_dpi {
+				base_ref.change_dpi(new_dpi)?;
+				rescale_children_dpi(hwnd, old_dpi, new_dpi)?;
+			}
+
+			match suggested {
+				Some((_, rc)) => hwnd.SetWindowPos(HwndPlace::None,
+					rc.left, rc.top, rc.right - rc.left, rc.bottom - rc.top,
+					co::SWP::NOZORDER | co::SWP::NOACTIVATE),
+				None if new_dpi == old_dpi => Ok(()),
+				None => {
+					// A child window was already resized by its parent, before
+					// the rescalers ran, so the helpers must lay out again.
+					let rc = hwnd.GetClientRect()?;
+					hwnd.SendMessage(wm::Size {
+						request: co::SIZE_R::RESTORED,
+						client_area: SIZE::new(rc.right, rc.bottom),
+					});
+					Ok(())
+				},
+			}
+		}().unwrap_or_else(|err| PostQuitMessage(err))
+	};
+
+	if is_top_level {
+		base_ref.privileged_events_ref().wm_dpi_changed(move |p| {
+			on_dpi_changed(Some((p.dpi_x as u32, p.suggested_rect)))
+		});
+	} else {
+		base_ref.privileged_events_ref().wm(co::WM::DPICHANGED_AFTERPARENT, move |_| {
+			on_dpi_changed(None);
+			0
+		});
+	}
+}
+
+/// Rescales the position and size of the direct children of the window,
+/// setting the UI font for the new DPI.
+fn rescale_children_dpi(
+	hparent: HWND, old_dpi: u32, new_dpi: u32) -> WinResult<()>
+{
+	let hchildren = hparent.EnumChildWindowsVec().into_iter()
+		.filter(|hchild| hchild.GetParent().ok() == Some(hparent)) // direct children only
+		.collect::<Vec<_>>();
+	if hchildren.is_empty() {
+		return Ok(());
+	}
+
+	let scale = |v: i32| MulDiv(v, new_dpi as i32, old_dpi as i32);
+	let mut hdwp = HDWP::BeginDeferWindowPos(hchildren.len() as u32)?;
+	for hchild in hchildren.iter() {
+		let mut rc = hchild.GetWindowRect()?;
+		hparent.ScreenToClientRc(&mut rc)?;
+		hdwp = hdwp.DeferWindowPos(*hchild, HwndPlace::None,
+			scale(rc.left), scale(rc.top),
+			scale(rc.right - rc.left), scale(rc.bottom - rc.top),
+			co::SWP::NOZORDER | co::SWP::NOACTIVATE)?;
+	}
+	hdwp.EndDeferWindowPos()?;
+
+	let hfont = ui_font_for_dpi(new_dpi)?;
+	for hchild in hchildren.iter() {
+		hchild.SendMessage(wm::SetFont { hfont, redraw: true });
+	}
+	Ok(())
+}
+
 //------------------------------------------------------------------------------
 
-/// Calculates the bound rectangle to fit the text with current system font.
-pub fn calc_text_bound_box(text: &str) -> WinResult<SIZE> {
+/// Calculates the bound rectangle to fit the text with the UI font for the
+/// given DPI.
+pub fn calc_text_bound_box(text: &str, dpi: u32) -> WinResult<SIZE> {
 	let desktop_hwnd = HWND::GetDesktopWindow();
 	let desktop_hdc = desktop_hwnd.GetDC()?;
 	let clone_dc = desktop_hdc.CreateCompatibleDC()?;
-	let prev_hfont = clone_dc.SelectObjectFont(ui_font())?;
+	let prev_hfont = clone_dc.SelectObjectFont(ui_font_for_dpi(dpi)?)?;
 
 	let mut bounds = if text.is_empty() {
 		clone_dc.GetTextExtentPoint32("Pj")? // just a placeholder to get the text height
@@ -112,14 +313,17 @@ pub fn calc_text_bound_box(text: &str) -> WinResult<SIZE> {
 	Ok(bounds)
 }
 
-/// Calculates the bound rectangle to fit the text with current system font,
-/// adding a check box.
-pub fn calc_text_bound_box_check(text: &str) -> WinResult<SIZE> {
-	let mut bound_box = calc_text_bound_box(text)?;
-	bound_box.cx += GetSystemMetrics(co::SM::CXMENUCHECK) // https://stackoverflow.com/a/1165052/6923555
-		+ GetSystemMetrics(co::SM::CXEDGE);
+/// Calculates the bound rectangle to fit the text with the UI font for the
+/// given DPI, adding a check box.
+pub fn calc_text_bound_box_check(text: &str, dpi: u32) -> WinResult<SIZE> {
+	let sys_dpi = system_dpi()? as i32;
+	let metric = |index| MulDiv(GetSystemMetrics(index), dpi as i32, sys_dpi); // metrics are in system DPI
+
+	let mut bound_box = calc_text_bound_box(text, dpi)?;
+	bound_box.cx += metric(co::SM::CXMENUCHECK) // https://stackoverflow.com/a/1165052/6923555
+		+ metric(co::SM::CXEDGE);
 
-	let cy_check = GetSystemMetrics(co::SM::CYMENUCHECK);
+	let cy_check = metric(co::SM::CYMENUCHECK);
 	if cy_check > bound_box.cy {
 		bound_box.cy = cy_check; // if the check is taller than the font, use its height
 	}
@@ -0,0 +1,232 @@
+use std::any::Any;
+use std::sync::Arc;
+
+use crate::co;
+use crate::enums::IdStr;
+use crate::gui::base::Base;
+use crate::gui::dlg_base::DlgBase;
+use crate::gui::events::{PropertyPageEvents, WindowEvents};
+use crate::gui::traits::Parent;
+use crate::gui::very_unsafe_cell::VeryUnsafeCell;
+use crate::handles::{HINSTANCE, HWND};
+use crate::msg::psm;
+use crate::structs::PROPSHEETPAGE;
+use crate::WString;
+
+/// A page of a [`PropertySheet`](crate::gui::PropertySheet), which can host
+/// child controls and handle events. Can be programatically created or load a
+/// dialog resource from a `.rc` script.
+///
+/// Implements [`Parent`](crate::gui::Parent) trait.
+#[derive(Clone)]
+pub struct PropertyPage(Arc<VeryUnsafeCell<Obj>>);
+
+struct Obj { // actual fields of PropertyPage
+	base: Arc<DlgBase>, // separated, so the events can point to it
+	template: Vec<u32>, // in-memory DLGTEMPLATE, DWORD-aligned; empty if dialog resource
+	title: WString,
+	wiz_buttons: co::PSWIZB, // set by the sheet, if wizard
+	events: PropertyPageEvents,
+}
+
+unsafe impl Send for PropertyPage {}
+unsafe impl Sync for PropertyPage {}
+
+impl Parent for PropertyPage {
+	fn as_any(&self) -> &dyn Any {
+		self
+	}
+}
+
+impl PropertyPage {
+	/// Instantiates a new `PropertyPage` object, whose dialog template is built
+	/// in memory. Child controls can be created on it just like on a
+	/// [`WindowControl`](crate::gui::WindowControl).
+	pub fn new(opts: PropertyPageOpts) -> PropertyPage {
+		let template = Self::build_template(opts.width, opts.height);
+		Self::new_impl(0, template, &opts.title)
+	}
+
+	/// Instantiates a new `PropertyPage` object, to be loaded from a dialog
+	/// resource. If `title` is `None`, the caption of the dialog resource is
+	/// used.
+	pub fn new_dlg(dialog_id: i32, title: Option<&str>) -> PropertyPage {
+		Self::new_impl(dialog_id, Vec::default(), title.unwrap_or_default())
+	}
+
+	fn new_impl(dialog_id: i32, template: Vec<u32>, title: &str) -> PropertyPage {
+		let base = Arc::new(DlgBase::new(None, dialog_id));
+		let events = PropertyPageEvents::new(base.base_ref(), 0); // PSN notifications come with zero idFrom
+
+		let new_self = Self(
+			Arc::new(VeryUnsafeCell::new(
+				Obj {
+					base,
+					template,
+					title: WString::from_str(title),
+					wiz_buttons: co::PSWIZB::from(0),
+					events,
+				},
+			)),
+		);
+		new_self.default_message_handlers();
+		new_self
+	}
+
+	pub(crate) fn base_ref(&self) -> &Base {
+		self.0.base.base_ref()
+	}
+
+	/// Returns the underlying handle for this page.
+	///
+	/// Note that the handle is initially null, receiving an actual value only
+	/// after the page is created, which happens when it's first shown.
+	pub fn hwnd(&self) -> HWND {
+		*self.base_ref().hwnd_ref()
+	}
+
+	/// Exposes the window events.
+	///
+	/// # Panics
+	///
+	/// Adding an event panics if the page is already created, so events must be
+	/// set before page creation. Events can be removed at any time.
+	pub fn on(&self) -> &WindowEvents {
+		self.base_ref().user_events_ref()
+	}
+
+	/// Exposes the property sheet page notifications, which are used to
+	/// validate the page and to apply or discard its changes.
+	///
+	/// # Panics
+	///
+	/// Adding an event panics if the page is already created, so events must be
+	/// set before page creation. Events can be removed at any time.
+	pub fn on_page(&self) -> &PropertyPageEvents {
+		&self.0.events
+	}
+
+	/// Marks the page as changed or unchanged, by sending a
+	/// [`PSM_CHANGED`](crate::msg::psm::Changed) or
+	/// [`PSM_UNCHANGED`](crate::msg::psm::UnChanged) message to the property
+	/// sheet. The Apply button is enabled while any page is changed.
+	pub fn set_modified(&self, modified: bool) {
+		let hpage = self.hwnd();
+		if let Ok(hsheet) = hpage.GetParent() {
+			if modified {
+				hsheet.SendMessage(psm::Changed { hpage });
+			} else {
+				hsheet.SendMessage(psm::UnChanged { hpage });
+			}
+		}
+	}
+
+	pub(crate) fn set_wizard_buttons(&self, buttons: co::PSWIZB) {
+		self.0.as_mut().wiz_buttons = buttons;
+	}
+
+	/// Fills a `PROPSHEETPAGE` pointing to this page. The struct borrows the
+	/// page buffers.
+	pub(crate) fn fill_propsheetpage<'a>(&'a self,
+		hinst: HINSTANCE, psp: &mut PROPSHEETPAGE<'a, 'a>, res_id: &'a IdStr)
+	{
+		psp.hInstance = hinst;
+		psp.pfnDlgProc = Some(DlgBase::page_proc);
+		psp.lParam = Arc::as_ptr(&self.0.base) as isize; // pass pointer to DlgBase
+
+		if self.0.template.is_empty() {
+			psp.set_pszTemplate(res_id);
+		} else {
+			psp.dwFlags |= co::PSP::DLGINDIRECT;
+			psp.set_pResource(unsafe {
+				std::slice::from_raw_parts(
+					self.0.template.as_ptr() as *const u16,
+					self.0.template.len() * 2,
+				)
+			});
+		}
+
+		if self.0.title.len() > 0 {
+			psp.dwFlags |= co::PSP::USETITLE;
+			psp.set_pszTitle(&self.0.title);
+		}
+	}
+
+	pub(crate) fn dialog_id(&self) -> i32 {
+		self.0.base.dialog_id()
+	}
+
+	fn default_message_handlers(&self) {
+		self.base_ref().privileged_events_ref().add_nfy(0, co::PSN::SETACTIVE.into(), {
+			let self2 = self.clone();
+			move |p| {
+				if self2.0.wiz_buttons != co::PSWIZB::from(0) {
+					p.nmhdr.hwndFrom.PostMessage(
+						psm::SetWizButtons { buttons: self2.0.wiz_buttons })
+						.ok(); // posted, so it's processed after the page is activated
+				}
+				None
+			}
+		});
+	}
+
+	/// Builds an empty in-memory
+	/// [`DLGTEMPLATE`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-dlgtemplate)
+	/// with the given size in pixels, converted to dialog units of the shell
+	/// font at 96 DPI.
+	fn build_template(width: u32, height: u32) -> Vec<u32> {
+		let style = co::WS::from(co::DS::SHELLFONT | co::DS::CONTROL | co::DS::_3DLOOK)
+			| co::WS::CHILD | co::WS::CAPTION | co::WS::DISABLED;
+
+		let mut words: Vec<u16> = vec![
+			(style.0 & 0xffff) as u16, (style.0 >> 16) as u16, // style
+			0, 0, // dwExtendedStyle
+			0, // cdit
+			0, 0, // x, y
+			(width * 4 / 6) as u16, (height * 8 / 13) as u16, // cx, cy
+			0, // no menu
+			0, // predefined dialog box class
+			0, // no title
+			8, // font point size
+		];
+		words.extend(WString::from_str("MS Shell Dlg").as_slice());
+
+		if words.len() % 2 != 0 {
+			words.push(0);
+		}
+		words.chunks(2)
+			.map(|pair| pair[0] as u32 | (pair[1] as u32) << 16)
+			.collect()
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Options to create a [`PropertyPage`](crate::gui::PropertyPage)
+/// programatically with [`PropertyPage::new`](crate::gui::PropertyPage::new).
+pub struct PropertyPageOpts {
+	/// Page title, shown in the tab or in the wizard header.
+	///
+	/// Defaults to empty string.
+	pub title: String,
+	/// Page width, in pixels. The property sheet is sized to fit its largest
+	/// page.
+	///
+	/// Defaults to 300.
+	pub width: u32,
+	/// Page height, in pixels. The property sheet is sized to fit its largest
+	/// page.
+	///
+	/// Defaults to 200.
+	pub height: u32,
+}
+
+impl Default for PropertyPageOpts {
+	fn default() -> Self {
+		Self {
+			title: "".to_owned(),
+			width: 300,
+			height: 200,
+		}
+	}
+}
@@ -0,0 +1,201 @@
+use std::sync::Arc;
+
+use crate::aliases::WinResult;
+use crate::co;
+use crate::enums::IdStr;
+use crate::funcs::{InitCommonControls, PropertySheet as PropertySheetFn};
+use crate::gui::privs::{create_ui_font, delete_ui_font, set_process_dpi_awareness};
+use crate::gui::property_page::PropertyPage;
+use crate::gui::traits::{baseref_from_parent, Parent};
+use crate::gui::very_unsafe_cell::VeryUnsafeCell;
+use crate::handles::{HINSTANCE, HWND};
+use crate::structs::{PROPSHEETHEADER, PROPSHEETPAGE};
+use crate::WString;
+
+/// A
+/// [property sheet](https://docs.microsoft.com/en-us/windows/win32/controls/property-sheets),
+/// which displays a set of [`PropertyPage`](crate::gui::PropertyPage) objects
+/// either as tabs, with OK, Cancel and Apply buttons, or as a wizard, with
+/// Back, Next and Finish buttons.
+#[derive(Clone)]
+pub struct PropertySheet(Arc<VeryUnsafeCell<Obj>>);
+
+struct Obj { // actual fields of PropertySheet
+	opts: PropertySheetOpts,
+	pages: Vec<PropertyPage>,
+}
+
+unsafe impl Send for PropertySheet {}
+unsafe impl Sync for PropertySheet {}
+
+impl PropertySheet {
+	/// Instantiates a new `PropertySheet` object, to be created with
+	/// [`PropertySheet`](crate::PropertySheet) function.
+	pub fn new(opts: PropertySheetOpts) -> PropertySheet {
+		Self(
+			Arc::new(VeryUnsafeCell::new(
+				Obj {
+					opts,
+					pages: Vec::default(),
+				},
+			)),
+		)
+	}
+
+	/// Appends a new page to the property sheet. Pages are displayed in the
+	/// order they were added.
+	///
+	/// In wizard mode, the Back, Next and Finish buttons are automatically set
+	/// according to the page position.
+	pub fn add_page(&self, page: &PropertyPage) -> &PropertySheet {
+		self.0.as_mut().pages.push(page.clone());
+		self
+	}
+
+	/// Returns the number of pages added so far.
+	pub fn page_count(&self) -> usize {
+		self.0.pages.len()
+	}
+
+	/// Physically creates the property sheet as a modal window, owned by the
+	/// given parent. This method will block until the sheet is closed.
+	///
+	/// Returns `true` if the user saved the changes, by clicking OK, Apply or
+	/// Finish, or `false` if the sheet was canceled.
+	///
+	/// # Panics
+	///
+	/// Panics if no pages were added.
+	pub fn show_modal(&self, parent: &dyn Parent) -> WinResult<bool> {
+		let hparent = *baseref_from_parent(parent).hwnd_ref();
+		self.show(hparent.hinstance(), Some(hparent))
+	}
+
+	/// Physically creates the property sheet as the main window of the
+	/// application, then runs the modal loop. This method will block until the
+	/// sheet is closed.
+	///
+	/// This is useful for applications whose whole user interface is a wizard,
+	/// like installers. Returns the same value of
+	/// [`show_modal`](crate::gui::PropertySheet::show_modal).
+	///
+	/// # Panics
+	///
+	/// Panics if no pages were added.
+	pub fn run_main(&self) -> WinResult<bool> {
+		set_process_dpi_awareness()?;
+		InitCommonControls();
+		create_ui_font()?;
+
+		let res = self.show(HINSTANCE::GetModuleHandle(None)?, None)?;
+
+		delete_ui_font()?; // cleanup
+		Ok(res)
+	}
+
+	fn show(&self, hinst: HINSTANCE, hparent: Option<HWND>) -> WinResult<bool> {
+		let opts = &self.0.opts;
+		let pages = &self.0.pages;
+		if pages.is_empty() {
+			panic!("Cannot show a property sheet without pages.");
+		}
+
+		if opts.kind != PropertySheetKind::Tabs {
+			for (idx, page) in pages.iter().enumerate() {
+				page.set_wizard_buttons(
+					if pages.len() == 1 {
+						co::PSWIZB::FINISH
+					} else if idx == 0 {
+						co::PSWIZB::NEXT
+					} else if idx == pages.len() - 1 {
+						co::PSWIZB::BACK | co::PSWIZB::FINISH
+					} else {
+						co::PSWIZB::BACK | co::PSWIZB::NEXT
+					},
+				);
+			}
+		}
+
+		let res_ids = pages.iter()
+			.map(|page| IdStr::Id(page.dialog_id()))
+			.collect::<Vec<_>>();
+
+		let psps = pages.iter()
+			.zip(res_ids.iter())
+			.map(|(page, res_id)| {
+				let mut psp = PROPSHEETPAGE::default();
+				page.fill_propsheetpage(hinst, &mut psp, res_id);
+				psp
+			})
+			.collect::<Vec<_>>();
+
+		let title_buf = WString::from_str(&opts.title);
+
+		let mut psh = PROPSHEETHEADER::default();
+		psh.dwFlags = co::PSH::PROPSHEETPAGE | match opts.kind {
+			PropertySheetKind::Tabs => if opts.apply_button {
+				co::PSH::from(0)
+			} else {
+				co::PSH::NOAPPLYNOW
+			},
+			PropertySheetKind::Wizard => co::PSH::WIZARD97,
+			PropertySheetKind::AeroWizard => co::PSH::AEROWIZARD,
+		};
+		psh.hwndParent = hparent.unwrap_or(unsafe { HWND::null_handle() });
+		psh.hInstance = hinst;
+		psh.set_pszCaption(&title_buf);
+		psh.nStartPage = opts.start_page.min(pages.len() as u32 - 1);
+		psh.set_ppsp(&psps);
+
+		PropertySheetFn(&psh).map(|ret| ret > 0)
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// How a [`PropertySheet`](crate::gui::PropertySheet) displays its pages.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum PropertySheetKind {
+	/// Pages are displayed as tabs, with OK, Cancel and, optionally, Apply
+	/// buttons.
+	Tabs,
+	/// Pages are displayed one at a time, with Back, Next and Finish buttons, in
+	/// the classic wizard style.
+	Wizard,
+	/// Pages are displayed one at a time, with Back, Next and Finish buttons, in
+	/// the [Aero wizard](https://docs.microsoft.com/en-us/windows/win32/uxguide/win-wizards)
+	/// style.
+	AeroWizard,
+}
+
+/// Options to create a [`PropertySheet`](crate::gui::PropertySheet) with
+/// [`PropertySheet::new`](crate::gui::PropertySheet::new).
+pub struct PropertySheetOpts {
+	/// Property sheet title.
+	///
+	/// Defaults to empty string.
+	pub title: String,
+	/// How the pages are displayed.
+	///
+	/// Defaults to `PropertySheetKind::Tabs`.
+	pub kind: PropertySheetKind,
+	/// Zero-based index of the page initially displayed.
+	///
+	/// Defaults to 0.
+	pub start_page: u32,
+	/// Whether the Apply button is displayed. Ignored in wizard mode.
+	///
+	/// Defaults to `true`.
+	pub apply_button: bool,
+}
+
+impl Default for PropertySheetOpts {
+	fn default() -> Self {
+		Self {
+			title: "".to_owned(),
+			kind: PropertySheetKind::Tabs,
+			start_page: 0,
+			apply_button: true,
+		}
+	}
+}
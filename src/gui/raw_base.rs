@@ -6,12 +6,25 @@ use crate::gui::base::Base;
 use crate::gui::events::ProcessResult;
 use crate::handles::HWND;
 use crate::msg::{MsgSendRecv, wm, WndMsg};
-use crate::structs::{ATOM, POINT, SIZE, WNDCLASSEX};
+use crate::structs::{ATOM, MDICREATESTRUCT, POINT, SIZE, WNDCLASSEX};
 use crate::WString;
 
+/// Default window procedure called for messages not handled by the user.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DefProc {
+	/// `DefWindowProc`, for ordinary windows.
+	Window,
+	/// `DefFrameProc`, for MDI frame windows.
+	MdiFrame,
+	/// `DefMDIChildProc`, for MDI child windows.
+	MdiChild,
+}
+
 /// Base to all ordinary windows.
 pub(crate) struct RawBase {
 	base: Base,
+	def_proc: DefProc,
+	hmdiclient: Option<HWND>, // MDI frames only, set after MDICLIENT is created
 }
 
 impl Drop for RawBase {
@@ -24,8 +37,16 @@ impl Drop for RawBase {
 
 impl RawBase {
 	pub fn new(parent_ref: Option<&Base>) -> RawBase {
+		Self::new_with_def_proc(parent_ref, DefProc::Window)
+	}
+
+	pub fn new_with_def_proc(
+		parent_ref: Option<&Base>, def_proc: DefProc) -> RawBase
+	{
 		Self {
 			base: Base::new(parent_ref, false),
+			def_proc,
+			hmdiclient: None,
 		}
 	}
 
@@ -33,6 +54,14 @@ impl RawBase {
 		&self.base
 	}
 
+	pub fn hmdiclient(&self) -> Option<HWND> {
+		self.hmdiclient
+	}
+
+	pub fn set_hmdiclient(&mut self, hmdiclient: HWND) {
+		self.hmdiclient = Some(hmdiclient);
+	}
+
 	pub fn focus_first_child(&self) {
 		// https://stackoverflow.com/a/2835220/6923555
 		if let Ok(hchild) = self.base.hwnd_ref().GetWindow(co::GW::CHILD) {
@@ -41,7 +70,10 @@ impl RawBase {
 	}
 
 	pub fn register_class(&self, wcx: &mut WNDCLASSEX) -> WinResult<ATOM> {
-		wcx.lpfnWndProc = Some(Self::window_proc);
+		wcx.lpfnWndProc = Some(match self.def_proc {
+			DefProc::MdiChild => Self::mdi_child_proc,
+			_ => Self::window_proc,
+		});
 		SetLastError(co::ERROR::SUCCESS);
 
 		RegisterClassEx(&wcx)
@@ -87,6 +119,34 @@ impl RawBase {
 		).map(|_| ())
 	}
 
+	pub fn create_mdi_child(
+		&self,
+		class_name: &str,
+		title: Option<&str>,
+		pos: POINT,
+		sz: SIZE,
+		ex_styles: co::WS_EX,
+		styles: co::WS,
+		hmdiclient: HWND) -> WinResult<()>
+	{
+		if !self.base.hwnd_ref().is_null() {
+			panic!("Cannot create window twice.");
+		}
+
+		// The pointer to self is passed within the MDICREATESTRUCT, which is
+		// retrieved during WM_NCCREATE processing.
+		HWND::CreateWindowEx(
+			ex_styles | co::WS_EX::MDICHILD,
+			AtomStr::Str(WString::from_str(class_name)),
+			title, styles,
+			pos.x, pos.y, sz.cx, sz.cy,
+			Some(hmdiclient),
+			IdMenu::None,
+			self.base.parent_hinstance()?,
+			Some(self as *const Self as isize), // pass pointer to self
+		).map(|_| ())
+	}
+
 	/// Generates a hash string from current fields, so it must called after all
 	/// the fields are set.
 	pub fn generate_wcx_class_name_hash(wcx: &WNDCLASSEX) -> WString {
@@ -107,6 +167,22 @@ impl RawBase {
 
 	extern "system" fn window_proc(
 		hwnd: HWND, msg: co::WM, wparam: usize, lparam: isize) -> isize
+	{
+		Self::process_proc(hwnd, msg, wparam, lparam, false)
+	}
+
+	/// Window procedure for MDI child windows, whose `WM_NCCREATE` carries a
+	/// pointer to a [`MDICREATESTRUCT`](crate::MDICREATESTRUCT), which then
+	/// carries the pointer to self in `lParam`.
+	extern "system" fn mdi_child_proc(
+		hwnd: HWND, msg: co::WM, wparam: usize, lparam: isize) -> isize
+	{
+		Self::process_proc(hwnd, msg, wparam, lparam, true)
+	}
+
+	fn process_proc(
+		hwnd: HWND, msg: co::WM, wparam: usize, lparam: isize,
+		is_mdi_child: bool) -> isize
 	{
 		|hwnd: HWND, msg, wparam, lparam| -> WinResult<isize>
 		{
@@ -115,7 +191,14 @@ impl RawBase {
 			let ptr_self = match msg {
 				co::WM::NCCREATE => { // first message being handled
 					let wm_ncc = wm::NcCreate::from_generic_wm(wm_any);
-					let ptr_self = wm_ncc.createstruct.lpCreateParams as *mut Self;
+					let ptr_self = if is_mdi_child {
+						let mdics = unsafe {
+							&*(wm_ncc.createstruct.lpCreateParams as *const MDICREATESTRUCT)
+						};
+						mdics.lParam as *mut Self
+					} else {
+						wm_ncc.createstruct.lpCreateParams as *mut Self
+					};
 					hwnd.SetWindowLongPtr(co::GWLP::USERDATA, ptr_self as isize); // store
 					let ref_self = unsafe { &mut *ptr_self };
 					ref_self.base.set_hwnd(hwnd); // store HWND in struct field
@@ -127,12 +210,19 @@ impl RawBase {
 			// If no pointer stored, then no processing is done.
 			// Prevents processing before WM_NCCREATE and after WM_NCDESTROY.
 			if ptr_self.is_null() {
-				return Ok(hwnd.DefWindowProc(wm_any));
+				return Ok(if is_mdi_child {
+					hwnd.DefMDIChildProc(wm_any)
+				} else {
+					hwnd.DefWindowProc(wm_any)
+				});
 			}
 
 			// Execute privileged closures.
 			let ref_self = unsafe { &mut *ptr_self };
 			ref_self.base.process_privileged_messages(wm_any);
+			if msg == co::WM::SETCURSOR && ref_self.base.run_cursor_setters() {
+				return Ok(true as isize); // cursor set, halt further processing
+			}
 
 			// Execute user closure, if any.
 			let maybe_processed = ref_self.base.process_effective_message(wm_any);
@@ -142,13 +232,45 @@ impl RawBase {
 				ref_self.base.set_hwnd(unsafe { HWND::null_handle() }); // clear stored HWND
 			}
 
+			// Some messages must reach the MDI default procedures even if handled.
+			// WM_SIZE is not forwarded to DefFrameProc when handled, because it
+			// would resize the MDI client over the user's layout.
+			let must_forward = match ref_self.def_proc {
+				DefProc::Window => false,
+				DefProc::MdiFrame => match msg {
+					co::WM::MENUCHAR | co::WM::SETFOCUS => true,
+					_ => false,
+				},
+				DefProc::MdiChild => match msg {
+					co::WM::CHILDACTIVATE | co::WM::GETMINMAXINFO | co::WM::MENUCHAR
+						| co::WM::MOVE | co::WM::SETFOCUS | co::WM::SIZE
+						| co::WM::SYSCOMMAND => true,
+					_ => false,
+				},
+			};
+
 			Ok(match maybe_processed {
-				ProcessResult::HandledWithRet(res) => res.into(),
-				ProcessResult::HandledWithoutRet => 0,
-				ProcessResult::NotHandled => hwnd.DefWindowProc(wm_any).into(),
+				ProcessResult::HandledWithRet(res) => {
+					if must_forward { ref_self.call_def_proc(hwnd, wm_any); }
+					res.into()
+				},
+				ProcessResult::HandledWithoutRet => if must_forward {
+					ref_self.call_def_proc(hwnd, wm_any)
+				} else {
+					0
+				},
+				ProcessResult::NotHandled => ref_self.call_def_proc(hwnd, wm_any),
 			})
 		}
 		(hwnd, msg, wparam, lparam)
 			.unwrap_or_else(|err| { PostQuitMessage(err); 0 })
 	}
+
+	fn call_def_proc(&self, hwnd: HWND, wm_any: WndMsg) -> isize {
+		match self.def_proc {
+			DefProc::Window => hwnd.DefWindowProc(wm_any),
+			DefProc::MdiFrame => hwnd.DefFrameProc(self.hmdiclient, wm_any),
+			DefProc::MdiChild => hwnd.DefMDIChildProc(wm_any),
+		}
+	}
 }
@@ -5,7 +5,7 @@ use crate::co;
 use crate::enums::{IdIdcStr, IdMenu};
 use crate::funcs::PostQuitMessage;
 use crate::gui::base::Base;
-use crate::gui::privs::{multiply_dpi, paint_control_borders};
+use crate::gui::privs::{add_dpi_changed_handler, multiply_dpi_for, paint_control_borders};
 use crate::gui::raw_base::RawBase;
 use crate::gui::very_unsafe_cell::VeryUnsafeCell;
 use crate::handles::{HBRUSH, HCURSOR, HICON, HINSTANCE};
@@ -39,6 +39,8 @@ impl RawControl {
 	}
 
 	fn default_message_handlers(&self, parent_ref: &Base) {
+		add_dpi_changed_handler(self.base_ref(), false);
+
 		parent_ref.privileged_events_ref().wm(parent_ref.create_wm(), {
 			let self2 = self.clone();
 			move |p| {
@@ -53,7 +55,8 @@ impl RawControl {
 
 					let mut wnd_pos = opts.position;
 					let mut wnd_sz = opts.size;
-					multiply_dpi(Some(&mut wnd_pos), Some(&mut wnd_sz))?;
+					multiply_dpi_for(self2.base_ref().parent_ref().unwrap(),
+						Some(&mut wnd_pos), Some(&mut wnd_sz))?;
 
 					self2.0.base.create_window( // may panic
 						&class_name_buf.to_string(),
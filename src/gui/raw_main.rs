@@ -2,16 +2,20 @@ use std::sync::Arc;
 
 use crate::aliases::WinResult;
 use crate::co;
-use crate::enums::{IdIdcStr, IdMenu};
+use crate::enums::{AtomStr, IdIdcStr, IdMenu};
 use crate::funcs::{AdjustWindowRectEx, GetSystemMetrics, PostQuitMessage};
 use crate::gui::base::Base;
-use crate::gui::privs::multiply_dpi;
-use crate::gui::raw_base::RawBase;
+use crate::gui::privs::{add_dpi_changed_handler, multiply_dpi};
+use crate::gui::raw_base::{DefProc, RawBase};
 use crate::gui::very_unsafe_cell::VeryUnsafeCell;
 use crate::handles::{HACCEL, HBRUSH, HCURSOR, HICON, HINSTANCE, HMENU, HWND};
-use crate::structs::{POINT, RECT, SIZE, WNDCLASSEX};
+use crate::structs::{CLIENTCREATESTRUCT, POINT, RECT, SIZE, WNDCLASSEX};
 use crate::WString;
 
+/// Command ID of the first MDI child window in the window menu. Must not
+/// collide with the application command IDs.
+const MDI_FIRST_CHILD_ID: u32 = 0xff00;
+
 #[derive(Clone)]
 pub(crate) struct RawMain(Arc<VeryUnsafeCell<Obj>>);
 
@@ -19,6 +23,7 @@ struct Obj { // actual fields of RawMain
 	base: RawBase,
 	opts: WindowMainOpts,
 	hchild_prev_focus: Option<HWND>, // WM_ACTIVATE woes
+	mdi_window_menu: Option<HMENU>, // MDI frames only
 }
 
 impl RawMain {
@@ -29,6 +34,7 @@ impl RawMain {
 					base: RawBase::new(None), // no parent
 					opts,
 					hchild_prev_focus: None,
+					mdi_window_menu: None,
 				},
 			)),
 		);
@@ -36,10 +42,29 @@ impl RawMain {
 		wnd
 	}
 
+	pub fn new_mdi_frame(opts: WindowMainOpts, window_menu: HMENU) -> RawMain {
+		let wnd = Self(
+			Arc::new(VeryUnsafeCell::new(
+				Obj {
+					base: RawBase::new_with_def_proc(None, DefProc::MdiFrame), // no parent
+					opts,
+					hchild_prev_focus: None,
+					mdi_window_menu: Some(window_menu),
+				},
+			)),
+		);
+		wnd.mdi_frame_message_handlers();
+		wnd
+	}
+
 	pub fn base_ref(&self) -> &Base {
 		self.0.base.base_ref()
 	}
 
+	pub fn hmdiclient(&self) -> Option<HWND> {
+		self.0.base.hmdiclient()
+	}
+
 	pub fn run_main(&self, cmd_show: Option<co::SW>) -> WinResult<()> {
 		let opts = &self.0.opts;
 
@@ -85,10 +110,49 @@ impl RawMain {
 		hwnd.ShowWindow(cmd_show.unwrap_or(co::SW::SHOW));
 		hwnd.UpdateWindow()?;
 
-		Base::run_main_loop(opts.accel_table.as_opt()) // blocks until window is closed
+		Base::run_main_loop(opts.accel_table.as_opt(), self.hmdiclient()) // blocks until window is closed
+	}
+
+	fn mdi_frame_message_handlers(&self) {
+		add_dpi_changed_handler(self.base_ref(), true);
+
+		self.base_ref().privileged_events_ref().wm_create({
+			let self2 = self.clone();
+			move |_| {
+				|| -> WinResult<i32> {
+					let mut ccs = CLIENTCREATESTRUCT::default();
+					if let Some(hmenu) = self2.0.mdi_window_menu {
+						ccs.hWindowMenu = hmenu; // MDI children will be listed in this menu
+					}
+					ccs.idFirstChild = MDI_FIRST_CHILD_ID;
+
+					let hframe = *self2.base_ref().hwnd_ref();
+					let hmdiclient = HWND::CreateWindowEx(
+						co::WS_EX::CLIENTEDGE,
+						AtomStr::Str(WString::from_str("MDICLIENT")),
+						None,
+						co::WS::CHILD | co::WS::CLIPCHILDREN | co::WS::VSCROLL
+							| co::WS::HSCROLL | co::WS::VISIBLE,
+						0, 0, 0, 0,
+						Some(hframe),
+						IdMenu::None,
+						self2.base_ref().parent_hinstance()?,
+						Some(&ccs as *const _ as isize),
+					)?;
+					self2.0.as_mut().base.set_hmdiclient(hmdiclient);
+					Ok(0)
+				}().unwrap_or_else(|err| { PostQuitMessage(err); -1 })
+			}
+		});
+
+		self.base_ref().user_events_ref().wm_nc_destroy(|| {
+			PostQuitMessage(co::ERROR::SUCCESS);
+		});
 	}
 
 	fn default_message_handlers(&self) {
+		add_dpi_changed_handler(self.base_ref(), true);
+
 		self.base_ref().user_events_ref().wm_activate({
 			let self2 = self.clone();
 			move |p| {
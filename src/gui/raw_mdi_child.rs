@@ -0,0 +1,171 @@
+use std::sync::Arc;
+
+use crate::aliases::WinResult;
+use crate::co;
+use crate::enums::IdIdcStr;
+use crate::gui::base::Base;
+use crate::gui::privs::{add_dpi_changed_handler, multiply_dpi};
+use crate::gui::raw_base::{DefProc, RawBase};
+use crate::gui::very_unsafe_cell::VeryUnsafeCell;
+use crate::handles::{HBRUSH, HCURSOR, HICON, HINSTANCE, HWND};
+use crate::privs::CW_USEDEFAULT;
+use crate::structs::{POINT, SIZE, WNDCLASSEX};
+use crate::WString;
+
+#[derive(Clone)]
+pub(crate) struct RawMdiChild(Arc<VeryUnsafeCell<Obj>>);
+
+struct Obj { // actual fields of RawMdiChild
+	base: RawBase,
+	opts: WindowMdiChildOpts,
+}
+
+impl RawMdiChild {
+	pub fn new(parent_ref: &Base, opts: WindowMdiChildOpts) -> RawMdiChild {
+		let wnd = Self(
+			Arc::new(VeryUnsafeCell::new(
+				Obj {
+					base: RawBase::new_with_def_proc(Some(parent_ref), DefProc::MdiChild),
+					opts,
+				},
+			)),
+		);
+		add_dpi_changed_handler(wnd.base_ref(), false);
+		wnd
+	}
+
+	pub fn base_ref(&self) -> &Base {
+		self.0.base.base_ref()
+	}
+
+	pub fn create(&self, hmdiclient: HWND) -> WinResult<()> {
+		let opts = &self.0.opts;
+
+		let mut wcx = WNDCLASSEX::default();
+		let mut class_name_buf = WString::default();
+		opts.generate_wndclassex(self.base_ref().parent_hinstance()?,
+			&mut wcx, &mut class_name_buf)?;
+		self.0.base.register_class(&mut wcx)?;
+
+		let mut wnd_sz = opts.size;
+		if wnd_sz.cx == 0 && wnd_sz.cy == 0 {
+			wnd_sz = SIZE::new(CW_USEDEFAULT, CW_USEDEFAULT); // let the MDI client choose
+		} else {
+			multiply_dpi(None, Some(&mut wnd_sz))?;
+		}
+		let wnd_pos = POINT::new(CW_USEDEFAULT, CW_USEDEFAULT); // cascaded by the MDI client
+
+		self.0.base.create_mdi_child( // may panic
+			&class_name_buf.to_string(),
+			Some(&opts.title),
+			wnd_pos, wnd_sz,
+			opts.ex_style, opts.style,
+			hmdiclient,
+		)
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Options to create a [`WindowMdiChild`](crate::gui::WindowMdiChild)
+/// programatically with
+/// [`WindowMdiChild::new`](crate::gui::WindowMdiChild::new).
+pub struct WindowMdiChildOpts {
+	/// Window class name to be
+	/// [registered](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerclassexw).
+	///
+	/// Defaults to an auto-generated string.
+	pub class_name: String,
+	/// Window class styles to be
+	/// [registered](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerclassexw).
+	///
+	/// Defaults to `co::CS::DBLCLKS`.
+	pub class_style: co::CS,
+	/// Window icon to be
+	/// [registered](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerclassexw).
+	///
+	/// Defaults to none.
+	pub class_icon: HICON,
+	/// Window cursor to be
+	/// [registered](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerclassexw).
+	///
+	/// Defaults to `co::IDC::ARROW`.
+	pub class_cursor: HCURSOR,
+	/// Window background brush to be
+	/// [registered](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerclassexw).
+	///
+	/// Defaults to `co::COLOR::BTNFACE`.
+	pub class_bg_brush: HBRUSH,
+
+	/// Window title to be
+	/// [created](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	/// It's also displayed in the window menu of the MDI frame.
+	///
+	/// Defaults to empty string.
+	pub title: String,
+	/// Size of window, in pixels, to be
+	/// [created](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	/// If 0 x 0, the MDI client chooses the size.
+	///
+	/// Will be adjusted to match current system DPI.
+	///
+	/// Defaults to 0 x 0.
+	pub size: SIZE,
+	/// Window styles to be
+	/// [created](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS::CHILD | WS::OVERLAPPEDWINDOW | WS::CLIPCHILDREN | WS::VISIBLE`.
+	pub style: co::WS,
+	/// Extended window styles to be
+	/// [created](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	/// The `WS_EX::MDICHILD` style is always added.
+	///
+	/// Defaults to `WS_EX::LEFT`.
+	pub ex_style: co::WS_EX,
+}
+
+impl Default for WindowMdiChildOpts {
+	fn default() -> Self {
+		Self {
+			class_name: "".to_owned(),
+			class_style: co::CS::DBLCLKS,
+			class_icon: unsafe { HICON::null_handle() },
+			class_cursor: unsafe { HCURSOR::null_handle() },
+			class_bg_brush: HBRUSH::from_sys_color(co::COLOR::BTNFACE),
+			title: "".to_owned(),
+			size: SIZE { cx: 0, cy: 0 },
+			style: co::WS::CHILD | co::WS::OVERLAPPEDWINDOW | co::WS::CLIPCHILDREN | co::WS::VISIBLE,
+			ex_style: co::WS_EX::LEFT,
+		}
+	}
+}
+
+impl WindowMdiChildOpts {
+	fn generate_wndclassex<'a>( // https://stackoverflow.com/q/65481548/6923555
+		&self,
+		hinst: HINSTANCE,
+		wcx: &mut WNDCLASSEX<'a, 'a>,
+		class_name_buf: &'a mut WString) -> WinResult<()>
+	{
+		wcx.hInstance = hinst;
+		wcx.style = self.class_style;
+		wcx.hIcon = self.class_icon;
+		wcx.hIconSm = self.class_icon;
+		wcx.hbrBackground = self.class_bg_brush;
+
+		wcx.hCursor = match self.class_cursor.as_opt() {
+			Some(h) => h,
+			None => HINSTANCE::oem().LoadCursor(IdIdcStr::Idc(co::IDC::ARROW))?,
+		};
+
+		if wcx.lpszClassName().is_empty() {
+			// Prefixed, because MDI children use a different window procedure, so
+			// they cannot share the class of an ordinary window.
+			*class_name_buf = WString::from_str(&format!("MDI.{}",
+				RawBase::generate_wcx_class_name_hash(&wcx).to_string()));
+			wcx.set_lpszClassName(class_name_buf);
+		}
+
+		Ok(())
+	}
+}
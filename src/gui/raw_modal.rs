@@ -4,11 +4,12 @@ use crate::aliases::WinResult;
 use crate::co;
 use crate::enums::{IdIdcStr, IdMenu};
 use crate::funcs::{AdjustWindowRectEx, DispatchMessage, GetMessage, PostQuitMessage, TranslateMessage};
+use crate::gui::accel_table::{add_accel_table_handlers, translate_window_accel};
 use crate::gui::base::Base;
-use crate::gui::privs::multiply_dpi;
+use crate::gui::privs::{add_dpi_changed_handler, multiply_dpi};
 use crate::gui::raw_base::RawBase;
 use crate::gui::very_unsafe_cell::VeryUnsafeCell;
-use crate::handles::{HBRUSH, HCURSOR, HICON, HINSTANCE, HWND};
+use crate::handles::{HACCEL, HBRUSH, HCURSOR, HICON, HINSTANCE, HWND};
 use crate::structs::{MSG, POINT, RECT, SIZE, WNDCLASSEX};
 use crate::WString;
 
@@ -99,6 +100,17 @@ impl RawModal {
 			let hwnd_top_level = msg.hwnd.GetAncestor(co::GA::ROOT)
 				.unwrap_or(msg.hwnd);
 
+			// If the window has its own accelerator table, try to translate the
+			// message.
+			if translate_window_accel(hwnd_top_level, &mut msg) {
+				// The command handler may have closed our modal.
+				if self.base_ref().hwnd_ref().is_null() {
+					return Ok(0); // our modal was destroyed, terminate loop
+				} else {
+					continue; // message translated
+				}
+			}
+
 			// Try to process keyboard actions for child controls.
 			if hwnd_top_level.IsDialogMessage(&mut msg) {
 				// Processed all keyboard actions for child controls.
@@ -119,6 +131,12 @@ impl RawModal {
 	}
 
 	fn default_message_handlers(&self) {
+		add_dpi_changed_handler(self.base_ref(), true);
+
+		if let Some(haccel) = self.0.opts.accel_table.as_opt() {
+			add_accel_table_handlers(self.base_ref(), haccel);
+		}
+
 		self.base_ref().user_events_ref().wm_set_focus({
 			let self2 = self.clone();
 			move |_| {
@@ -204,6 +222,13 @@ pub struct WindowModalOpts {
 	///
 	/// Defaults to `WS_EX::LEFT | WS_EX::DLGMODALFRAME`.
 	pub ex_style: co::WS_EX,
+	/// Accelerator table of the window to be
+	/// [created](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw),
+	/// used while the window is active. Can be built with
+	/// [`AccelTableBuilder`](crate::gui::AccelTableBuilder).
+	///
+	/// Defaults to none.
+	pub accel_table: HACCEL,
 }
 
 impl Default for WindowModalOpts {
@@ -218,6 +243,7 @@ impl Default for WindowModalOpts {
 			size: SIZE { cx: 500, cy: 400 },
 			style: co::WS::CAPTION | co::WS::SYSMENU | co::WS::CLIPCHILDREN | co::WS::BORDER | co::WS::VISIBLE,
 			ex_style: co::WS_EX::LEFT | co::WS_EX::DLGMODALFRAME,
+			accel_table: unsafe { HACCEL::null_handle() },
 		}
 	}
 }
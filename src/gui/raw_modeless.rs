@@ -0,0 +1,228 @@
+use std::sync::Arc;
+
+use crate::aliases::WinResult;
+use crate::co;
+use crate::enums::{IdIdcStr, IdMenu};
+use crate::gui::accel_table::add_accel_table_handlers;
+use crate::gui::base::Base;
+use crate::gui::privs::{add_dpi_changed_handler, multiply_dpi};
+use crate::gui::raw_base::RawBase;
+use crate::gui::very_unsafe_cell::VeryUnsafeCell;
+use crate::handles::{HACCEL, HBRUSH, HCURSOR, HICON, HINSTANCE, HWND};
+use crate::structs::{POINT, SIZE, WNDCLASSEX};
+use crate::WString;
+
+#[derive(Clone)]
+pub(crate) struct RawModeless(Arc<VeryUnsafeCell<Obj>>);
+
+struct Obj { // actual fields of RawModeless
+	base: RawBase,
+	opts: WindowModelessOpts,
+	hchild_prev_focus: Option<HWND>,
+}
+
+impl RawModeless {
+	pub fn new(parent_ref: &Base, opts: WindowModelessOpts) -> RawModeless {
+		let wnd = Self(
+			Arc::new(VeryUnsafeCell::new(
+				Obj {
+					base: RawBase::new(Some(parent_ref)),
+					opts,
+					hchild_prev_focus: None,
+				},
+			)),
+		);
+		wnd.default_message_handlers();
+		wnd
+	}
+
+	pub fn base_ref(&self) -> &Base {
+		self.0.base.base_ref()
+	}
+
+	pub fn create(&self) -> WinResult<()> {
+		let hparent = *self.base_ref().parent_ref().unwrap().hwnd_ref();
+		let opts = &self.0.opts;
+
+		let mut wcx = WNDCLASSEX::default();
+		let mut class_name_buf = WString::default();
+		opts.generate_wndclassex(
+			self.base_ref().parent_hinstance()?, &mut wcx, &mut class_name_buf)?;
+		self.0.base.register_class(&mut wcx)?;
+
+		let mut wnd_pos = opts.position;
+		let mut wnd_sz = opts.size;
+		multiply_dpi(Some(&mut wnd_pos), Some(&mut wnd_sz))?;
+		hparent.ClientToScreen(&mut wnd_pos)?; // owned popups are positioned on the screen
+
+		self.0.as_mut().hchild_prev_focus = None;
+		self.0.base.create_window( // may panic
+			&class_name_buf.to_string(),
+			Some(&opts.title),
+			IdMenu::None,
+			wnd_pos, wnd_sz,
+			opts.ex_style, opts.style,
+		)
+	}
+
+	fn default_message_handlers(&self) {
+		add_dpi_changed_handler(self.base_ref(), true);
+
+		if let Some(haccel) = self.0.opts.accel_table.as_opt() {
+			add_accel_table_handlers(self.base_ref(), haccel);
+		}
+
+		self.base_ref().user_events_ref().wm_activate({
+			let self2 = self.clone();
+			move |p| {
+				if !p.is_minimized {
+					if p.event == co::WA::INACTIVE {
+						if let Some(hwnd_cur_focus) = HWND::GetFocus() {
+							if self2.base_ref().hwnd_ref().IsChild(hwnd_cur_focus) {
+								self2.0.as_mut().hchild_prev_focus = Some(hwnd_cur_focus); // save previously focused control
+							}
+						}
+					} else if let Some(hwnd_prev_focus) = self2.0.hchild_prev_focus {
+						hwnd_prev_focus.SetFocus(); // put focus back
+					}
+				}
+			}
+		});
+
+		self.base_ref().user_events_ref().wm_set_focus({
+			let self2 = self.clone();
+			move |_| {
+				if let Some(hwnd_cur_focus) = HWND::GetFocus() {
+					if *self2.base_ref().hwnd_ref() == hwnd_cur_focus {
+						self2.0.base.focus_first_child(); // if window receives focus, delegate to first child
+					}
+				}
+			}
+		});
+
+		self.base_ref().user_events_ref().wm_close({
+			let self2 = self.clone();
+			move || {
+				self2.base_ref().hwnd_ref().DestroyWindow(); // can be created again later
+			}
+		});
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Options to create a [`WindowModeless`](crate::gui::WindowModeless)
+/// programatically with [`WindowModeless::new`](crate::gui::WindowModeless::new).
+pub struct WindowModelessOpts {
+	/// Window class name to be
+	/// [registered](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerclassexw).
+	///
+	/// Defaults to an auto-generated string.
+	pub class_name: String,
+	/// Window class styles to be
+	/// [registered](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerclassexw).
+	///
+	/// Defaults to `co::CS::DBLCLKS`.
+	pub class_style: co::CS,
+	/// Window main icon to be
+	/// [registered](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerclassexw).
+	///
+	/// Defaults to none.
+	pub class_icon: HICON,
+	/// Window cursor to be
+	/// [registered](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerclassexw).
+	///
+	/// Defaults to `co::IDC::ARROW`.
+	pub class_cursor: HCURSOR,
+	/// Window background brush to be
+	/// [registered](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerclassexw).
+	///
+	/// Defaults to `co::COLOR::BTNFACE`.
+	pub class_bg_brush: HBRUSH,
+
+	/// Window title to be
+	/// [created](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to empty string.
+	pub title: String,
+	/// Position of window, relative to the parent's client area, in pixels, to
+	/// be [created](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Will be adjusted to match current system DPI.
+	///
+	/// Defaults to 0 x 0.
+	pub position: POINT,
+	/// Size of window, in pixels, to be
+	/// [created](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	/// Includes title bar and borders.
+	///
+	/// Will be adjusted to match current system DPI.
+	///
+	/// Defaults to 220 x 150.
+	pub size: SIZE,
+	/// Window styles to be
+	/// [created](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS::CAPTION | WS::SYSMENU | WS::CLIPCHILDREN | WS::BORDER | WS::VISIBLE`.
+	///
+	/// Suggestions:
+	/// * `WS::SIZEBOX` to make the window resizable.
+	pub style: co::WS,
+	/// Extended window styles to be
+	/// [created](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS_EX::LEFT | WS_EX::TOOLWINDOW`.
+	pub ex_style: co::WS_EX,
+	/// Accelerator table of the window to be
+	/// [created](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw),
+	/// used while the window is active. Can be built with
+	/// [`AccelTableBuilder`](crate::gui::AccelTableBuilder).
+	///
+	/// Defaults to none.
+	pub accel_table: HACCEL,
+}
+
+impl Default for WindowModelessOpts {
+	fn default() -> Self {
+		Self {
+			class_name: "".to_owned(),
+			class_style: co::CS::DBLCLKS,
+			class_icon: unsafe { HICON::null_handle() },
+			class_cursor: unsafe { HCURSOR::null_handle() },
+			class_bg_brush: HBRUSH::from_sys_color(co::COLOR::BTNFACE),
+			title: "".to_owned(),
+			position: POINT { x: 0, y: 0 },
+			size: SIZE { cx: 220, cy: 150 },
+			style: co::WS::CAPTION | co::WS::SYSMENU | co::WS::CLIPCHILDREN | co::WS::BORDER | co::WS::VISIBLE,
+			ex_style: co::WS_EX::LEFT | co::WS_EX::TOOLWINDOW,
+			accel_table: unsafe { HACCEL::null_handle() },
+		}
+	}
+}
+
+impl WindowModelessOpts {
+	fn generate_wndclassex<'a>( // https://stackoverflow.com/q/65481548/6923555
+		&self,
+		hinst: HINSTANCE,
+		wcx: &mut WNDCLASSEX<'_, 'a>,
+		class_name_buf: &'a mut WString) -> WinResult<()>
+	{
+		wcx.hInstance = hinst;
+		wcx.style = self.class_style;
+		wcx.hIcon = self.class_icon;
+		wcx.hIconSm = self.class_icon;
+		wcx.hbrBackground = self.class_bg_brush;
+
+		wcx.hCursor = match self.class_cursor.as_opt() {
+			Some(h) => h,
+			None => HINSTANCE::oem().LoadCursor(IdIdcStr::Idc(co::IDC::ARROW))?,
+		};
+
+		if wcx.lpszClassName().is_empty() {
+			*class_name_buf = RawBase::generate_wcx_class_name_hash(&wcx);
+			wcx.set_lpszClassName(class_name_buf);
+		}
+
+		Ok(())
+	}
+}
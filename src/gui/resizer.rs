@@ -4,12 +4,15 @@ use std::sync::Arc;
 use crate::aliases::WinResult;
 use crate::co;
 use crate::enums::HwndPlace;
-use crate::funcs::PostQuitMessage;
+use crate::funcs::{MulDiv, PostQuitMessage};
+use crate::gui::base::Base;
+use crate::gui::layout_calc::{resz_axis, resz_min_parent_len};
+use crate::gui::privs::multiply_dpi_for;
 use crate::gui::traits::{baseref_from_parent, Child, hwndref_from_child, Parent};
 use crate::gui::very_unsafe_cell::VeryUnsafeCell;
 use crate::handles::{HDWP, HWND};
 use crate::msg::wm;
-use crate::structs::{RECT, SIZE};
+use crate::structs::{MINMAXINFO, RECT, SIZE};
 
 /// In [`Resizer::add`](crate::gui::Resizer::add), determines how the child
 /// controls will be adjusted automatically when the parent window is resized.
@@ -23,26 +26,57 @@ pub enum Resz {
 	/// When parent window resizes, the control will be resized. Position will
 	/// remain fixed.
 	Resize,
+	/// When parent window resizes, the control will move by `pos` percent of
+	/// the size difference, and will be resized by `size` percent of the size
+	/// difference.
+	///
+	/// For example, two side-by-side controls which grow evenly are anchored
+	/// with `{ pos: 0, size: 50 }` and `{ pos: 50, size: 50 }`.
+	///
+	/// Note that `Repos` is equivalent to `{ pos: 100, size: 0 }`, and
+	/// `Resize` is equivalent to `{ pos: 0, size: 100 }`.
+	Ratio { pos: u8, size: u8 },
+}
+
+impl Resz {
+	/// Returns the position and size ratios applied to the parent growth.
+	pub(crate) fn ratios(&self) -> (f32, f32) {
+		match self {
+			Resz::Nothing => (0.0, 0.0),
+			Resz::Repos => (1.0, 0.0),
+			Resz::Resize => (0.0, 1.0),
+			Resz::Ratio { pos, size } => (*pos as f32 / 100.0, *size as f32 / 100.0),
+		}
+	}
 }
 
 struct Ctrl {
 	hwnd_ptr: NonNull<HWND>,
 	rc_orig: RECT, // original coordinates relative to parent
+	sz_parent_orig: SIZE, // parent client area when the control was added
 	horz: Resz,
 	vert: Resz,
+	min: SIZE, // zero means no limit
+	max: SIZE, // zero means no limit
 }
 
 //------------------------------------------------------------------------------
 
 /// When the parent window is resized, automatically adjusts position and size
 /// of child controls.
+///
+/// The minimum size of the parent window is automatically set, through
+/// [`WM_GETMINMAXINFO`](crate::msg::wm::GetMinMaxInfo), so that no control
+/// shrinks below its minimum size, neither moves beyond the left/top edge.
+///
+/// When the DPI of the parent window changes, the registered coordinates and
+/// limits are rescaled accordingly.
 #[derive(Clone)]
 pub struct Resizer(Arc<VeryUnsafeCell<Obj>>);
 
 struct Obj { // actual fields of Resizer
+	parent_ptr: NonNull<Base>,
 	ctrls: Vec<Ctrl>,
-	sz_parent_orig: SIZE, // original parent client area
-	resize_called: bool,
 }
 
 impl Resizer {
@@ -53,17 +87,26 @@ impl Resizer {
 		let resz = Self(
 			Arc::new(VeryUnsafeCell::new(
 				Obj {
+					parent_ptr: NonNull::from(parent_ref),
 					ctrls: Vec::with_capacity(16), // arbitrary, prealloc for speed
-					sz_parent_orig: SIZE::default(),
-					resize_called: false,
 				}
 			)),
 		);
 
-		parent_ref.privileged_events_ref().wm_size({
+		let parent_events = parent_ref.privileged_events_ref();
+
+		parent_events.wm_size({
 			let resz = resz.clone();
 			move |p| resz.resize(&p).unwrap_or_else(|err| PostQuitMessage(err))
 		});
+		parent_events.wm_get_min_max_info({
+			let resz = resz.clone();
+			move |p| resz.min_max_info(p.info).unwrap_or_else(|err| PostQuitMessage(err))
+		});
+		parent_ref.privileged_dpi_rescaler({
+			let resz = resz.clone();
+			move |old_dpi, new_dpi| resz.rescale(old_dpi, new_dpi)
+		});
 
 		resz
 	}
@@ -74,7 +117,8 @@ impl Resizer {
 	/// Usually you'll want to call this method on parent's
 	/// [`WM_CREATE`](crate::msg::wm::Create) or
 	/// [`WM_INITDIALOG`](crate::msg::wm::InitDialog) events, because the
-	/// controls will already be created.
+	/// controls will already be created. Controls can also be added later, in
+	/// which case the current parent size is taken as reference.
 	///
 	/// # Examples
 	///
@@ -103,15 +147,30 @@ impl Resizer {
 	///
 	/// # Panics
 	///
-	/// Panics if the slice is empty, if the first resizing already happened, or
-	/// if a passed control has not been created yet.
+	/// Panics if the slice is empty, or if a passed control has not been
+	/// created yet.
 	pub fn add(&self,
 		horz: Resz, vert: Resz, children: &[&dyn Child]) -> &Resizer
+	{
+		self.add_limited(horz, vert, SIZE::default(), SIZE::default(), children)
+	}
+
+	/// Registers one or more child controls, just like
+	/// [`add`](crate::gui::Resizer::add), but their sizes will be kept within
+	/// the given limits. A zero length means no limit in that direction.
+	///
+	/// The limits are in pixels, and will be adjusted to match the current DPI
+	/// of the parent window.
+	///
+	/// # Panics
+	///
+	/// Panics if the slice is empty, or if a passed control has not been
+	/// created yet.
+	pub fn add_limited(&self, horz: Resz, vert: Resz,
+		min: SIZE, max: SIZE, children: &[&dyn Child]) -> &Resizer
 	{
 		if children.is_empty() {
 			panic!("No children being added to Resizer.");
-		} else if self.0.resize_called {
-			panic!("Cannot add children after Resizer started working.");
 		}
 
 		let first_child_hwnd_ref = hwndref_from_child(children[0]);
@@ -119,15 +178,17 @@ impl Resizer {
 			panic!("Cannot add a child control to Resizer before it's created.");
 		}
 
-		|horz, vert, children: &[&dyn Child]| -> WinResult<&Resizer> {
+		|horz, vert, mut min, mut max, children: &[&dyn Child]| -> WinResult<&Resizer> {
+			let parent_ref = unsafe { self.0.parent_ptr.as_ref() };
+			multiply_dpi_for(parent_ref, None, Some(&mut min))?;
+			multiply_dpi_for(parent_ref, None, Some(&mut max))?;
+
 			let ctrls = &mut self.0.as_mut().ctrls;
-			ctrls.reserve(children.len() + children.len());
+			ctrls.reserve(children.len());
 
 			let hparent = first_child_hwnd_ref.GetParent()?;
-			if ctrls.is_empty() { // first call to add()
-				let rc_parent = hparent.GetClientRect()?;
-				self.0.as_mut().sz_parent_orig = SIZE::new(rc_parent.right, rc_parent.bottom); // save original parent size
-			}
+			let rc_parent = hparent.GetClientRect()?;
+			let sz_parent_orig = SIZE::new(rc_parent.right, rc_parent.bottom);
 
 			for child in children.iter() {
 				let child_hwnd_ref = hwndref_from_child(*child);
@@ -137,16 +198,30 @@ impl Resizer {
 				ctrls.push(Ctrl {
 					hwnd_ptr: NonNull::from(child_hwnd_ref), // ref implicitly converted to pointer
 					rc_orig,
+					sz_parent_orig,
 					horz,
 					vert,
+					min,
+					max,
 				});
 			}
 			Ok(self)
 		}
-		(horz, vert, children)
+		(horz, vert, min, max, children)
 			.unwrap_or_else(|err| { PostQuitMessage(err); self })
 	}
 
+	/// Unregisters one or more child controls, which will no longer be
+	/// adjusted on parent's resizing. Controls which were not registered are
+	/// ignored.
+	pub fn remove(&self, children: &[&dyn Child]) -> &Resizer {
+		let hwnd_ptrs = children.iter()
+			.map(|child| NonNull::from(hwndref_from_child(*child)))
+			.collect::<Vec<_>>();
+		self.0.as_mut().ctrls.retain(|ctrl| !hwnd_ptrs.contains(&ctrl.hwnd_ptr));
+		self
+	}
+
 	/// Resizes all registered children according to the defined rules.
 	fn resize(&self, size_parm: &wm::Size) -> WinResult<()> {
 		if self.0.ctrls.is_empty() || size_parm.request == co::SIZE_R::MINIMIZED {
@@ -159,6 +234,13 @@ impl Resizer {
 		let parent_cy = size_parm.client_area.cy;
 
 		for ctrl in self.0.ctrls.iter() {
+			let (x, cx) = resz_axis(ctrl.horz, parent_cx, ctrl.sz_parent_orig.cx,
+				ctrl.rc_orig.left, ctrl.rc_orig.right - ctrl.rc_orig.left,
+				ctrl.min.cx, ctrl.max.cx);
+			let (y, cy) = resz_axis(ctrl.vert, parent_cy, ctrl.sz_parent_orig.cy,
+				ctrl.rc_orig.top, ctrl.rc_orig.bottom - ctrl.rc_orig.top,
+				ctrl.min.cy, ctrl.max.cy);
+
 			let mut uflags = co::SWP::NOZORDER;
 			if ctrl.horz == Resz::Repos && ctrl.vert == Resz::Repos { // reposition both vert & horz
 				uflags |= co::SWP::NOSIZE;
@@ -169,27 +251,57 @@ impl Resizer {
 			hdwp.DeferWindowPos(
 				unsafe { *ctrl.hwnd_ptr.as_ref() },
 				HwndPlace::None,
-				match ctrl.horz {
-					Resz::Repos => parent_cx - self.0.sz_parent_orig.cx + ctrl.rc_orig.left,
-					_ => ctrl.rc_orig.left // keep original x pos
-				},
-				match ctrl.vert {
-					Resz::Repos => parent_cy - self.0.sz_parent_orig.cy + ctrl.rc_orig.top,
-					_ => ctrl.rc_orig.top // keep original y pos
-				},
-				match ctrl.horz {
-					Resz::Resize => parent_cx - self.0.sz_parent_orig.cx + ctrl.rc_orig.right - ctrl.rc_orig.left,
-					_ => ctrl.rc_orig.right - ctrl.rc_orig.left // keep original width
-				},
-				match ctrl.vert {
-					Resz::Resize => parent_cy - self.0.sz_parent_orig.cy + ctrl.rc_orig.bottom - ctrl.rc_orig.top,
-					_ =>ctrl.rc_orig.bottom - ctrl.rc_orig.top // keep original height
-				},
+				x, y, cx, cy,
 				uflags,
 			)?;
 		}
 
-		self.0.as_mut().resize_called = true;
 		hdwp.EndDeferWindowPos()
 	}
+
+	/// Rescales the stored coordinates and limits of all registered children,
+	/// when the parent DPI changes.
+	fn rescale(&self, old_dpi: u32, new_dpi: u32) {
+		let scale = |v: i32| MulDiv(v, new_dpi as i32, old_dpi as i32);
+		for ctrl in self.0.as_mut().ctrls.iter_mut() {
+			ctrl.rc_orig = RECT {
+				left: scale(ctrl.rc_orig.left),
+				top: scale(ctrl.rc_orig.top),
+				right: scale(ctrl.rc_orig.right),
+				bottom: scale(ctrl.rc_orig.bottom),
+			};
+			ctrl.sz_parent_orig = SIZE::new(
+				scale(ctrl.sz_parent_orig.cx), scale(ctrl.sz_parent_orig.cy));
+			ctrl.min = SIZE::new(scale(ctrl.min.cx), scale(ctrl.min.cy));
+			ctrl.max = SIZE::new(scale(ctrl.max.cx), scale(ctrl.max.cy));
+		}
+	}
+
+	/// Sets the minimum tracking size of the parent window, so that all
+	/// registered children remain within their constraints.
+	fn min_max_info(&self, info: &mut MINMAXINFO) -> WinResult<()> {
+		if self.0.ctrls.is_empty() {
+			return Ok(());
+		}
+
+		let mut min_client = SIZE::default();
+		for ctrl in self.0.ctrls.iter() {
+			min_client.cx = min_client.cx.max(
+				resz_min_parent_len(ctrl.horz, ctrl.sz_parent_orig.cx,
+					ctrl.rc_orig.left, ctrl.rc_orig.right - ctrl.rc_orig.left, ctrl.min.cx));
+			min_client.cy = min_client.cy.max(
+				resz_min_parent_len(ctrl.vert, ctrl.sz_parent_orig.cy,
+					ctrl.rc_orig.top, ctrl.rc_orig.bottom - ctrl.rc_orig.top, ctrl.min.cy));
+		}
+
+		let hparent = unsafe { self.0.parent_ptr.as_ref() }.hwnd_ref();
+		let rc_wnd = hparent.GetWindowRect()?;
+		let rc_client = hparent.GetClientRect()?;
+		let nc_cx = (rc_wnd.right - rc_wnd.left) - rc_client.right; // borders, caption and menu
+		let nc_cy = (rc_wnd.bottom - rc_wnd.top) - rc_client.bottom;
+
+		info.ptMinTrackSize.x = info.ptMinTrackSize.x.max(min_client.cx + nc_cx);
+		info.ptMinTrackSize.y = info.ptMinTrackSize.y.max(min_client.cy + nc_cy);
+		Ok(())
+	}
 }
@@ -0,0 +1,557 @@
+use std::ptr::NonNull;
+use std::sync::Arc;
+
+use crate::aliases::WinResult;
+use crate::co;
+use crate::enums::{HwndPlace, IdIdcStr};
+use crate::funcs::{GetCursorPos, MulDiv, PostQuitMessage, ReleaseCapture};
+use crate::gui::base::Base;
+use crate::gui::layout_calc::{distribute_space, move_bar};
+use crate::gui::privs::multiply_dpi_for;
+use crate::gui::traits::{baseref_from_parent, Child, hwndref_from_child, Parent};
+use crate::gui::very_unsafe_cell::VeryUnsafeCell;
+use crate::handles::{HCURSOR, HDWP, HINSTANCE, HWND};
+use crate::structs::{POINT, RECT, SIZE};
+
+/// In [`SplitterOpts`](crate::gui::SplitterOpts), determines how the panes of
+/// a [`Splitter`](crate::gui::Splitter) are laid out.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum SplitterOrient {
+	/// Panes are placed side by side, from left to right, divided by vertical
+	/// bars.
+	Horizontal,
+	/// Panes are stacked from top to bottom, divided by horizontal bars.
+	Vertical,
+}
+
+struct Pane {
+	hwnd_ptrs: Vec<NonNull<HWND>>,
+	min_size: i32, // along the split axis, adjusted to DPI on creation
+	ratio: f32, // share of the available space, kept while collapsed
+	collapsed: bool,
+}
+
+struct Drag {
+	bar_idx: usize,
+	offset: i32, // distance between cursor and bar start
+}
+
+//------------------------------------------------------------------------------
+
+/// Container which divides the parent client area into two or more panes,
+/// separated by bars which can be dragged by the user. Each pane hosts one or
+/// more child controls, which are positioned to fill the whole pane.
+///
+/// To nest splitters, use a [`WindowControl`](crate::gui::WindowControl) as a
+/// pane, and create another `Splitter` within it.
+///
+/// When the DPI of the parent window changes, the bar size, margins and
+/// minimum pane sizes are rescaled accordingly, while the pane ratios are
+/// kept.
+#[derive(Clone)]
+pub struct Splitter(Arc<VeryUnsafeCell<Obj>>);
+
+struct Obj { // actual fields of Splitter
+	parent_ptr: NonNull<Base>,
+	opts: SplitterOpts,
+	panes: Vec<Pane>,
+	sizes: Vec<i32>, // current pane sizes along the split axis
+	rc_area: RECT, // area of the parent client area occupied by the splitter
+	hcursor: Option<HCURSOR>,
+	drag: Option<Drag>,
+}
+
+impl Splitter {
+	/// Instantiates a new `Splitter`.
+	pub fn new(parent: &dyn Parent, opts: SplitterOpts) -> Splitter {
+		let parent_ref = baseref_from_parent(parent);
+
+		let splitter = Self(
+			Arc::new(VeryUnsafeCell::new(
+				Obj {
+					parent_ptr: NonNull::from(parent_ref),
+					opts,
+					panes: Vec::default(),
+					sizes: Vec::default(),
+					rc_area: RECT::default(),
+					hcursor: None,
+					drag: None,
+				},
+			)),
+		);
+
+		let parent_events = parent_ref.privileged_events_ref();
+
+		parent_events.wm(parent_ref.create_wm(), {
+			let splitter = splitter.clone();
+			move |_| {
+				splitter.create().unwrap_or_else(|err| PostQuitMessage(err));
+				0
+			}
+		});
+		parent_events.wm_size({
+			let splitter = splitter.clone();
+			move |p| {
+				if p.request != co::SIZE_R::MINIMIZED {
+					splitter.resize(p.client_area)
+						.unwrap_or_else(|err| PostQuitMessage(err));
+				}
+			}
+		});
+		parent_events.wm_l_button_down({
+			let splitter = splitter.clone();
+			move |p| splitter.begin_drag(p.coords)
+		});
+		parent_events.wm_mouse_move({
+			let splitter = splitter.clone();
+			move |p| {
+				splitter.mouse_move(p.coords)
+					.unwrap_or_else(|err| PostQuitMessage(err));
+			}
+		});
+		parent_events.wm_l_button_up({
+			let splitter = splitter.clone();
+			move |_| {
+				splitter.end_drag().unwrap_or_else(|err| PostQuitMessage(err));
+			}
+		});
+		parent_ref.privileged_cursor_setter({
+			let splitter = splitter.clone();
+			move || splitter.set_cursor()
+				.unwrap_or_else(|err| { PostQuitMessage(err); false })
+		});
+		parent_ref.privileged_dpi_rescaler({
+			let splitter = splitter.clone();
+			move |old_dpi, new_dpi| splitter.rescale(old_dpi, new_dpi)
+		});
+
+		splitter
+	}
+
+	/// Appends a new pane, which will host the given child controls. The child
+	/// controls will be positioned to fill the whole pane.
+	///
+	/// The `min_size` is the minimum size of the pane along the split axis, in
+	/// pixels, and will be adjusted to match the current DPI of the parent
+	/// window. The `ratio` is the initial share of space of the pane, relative
+	/// to the other panes.
+	///
+	/// # Examples
+	///
+	/// In the example below, the list view will initially take 1/3 of the
+	/// parent width, and the edit will take the remaining 2/3.
+	///
+	/// ```rust,ignore
+	/// use winsafe::gui::{Edit, ListView, Splitter};
+	///
+	/// let splitter: Splitter; // initialize them somewhere...
+	/// let list: ListView;
+	/// let txt: Edit;
+	///
+	/// splitter
+	///     .add_pane(100, 1.0, &[&list])
+	///     .add_pane(150, 2.0, &[&txt]);
+	/// ```
+	///
+	/// # Panics
+	///
+	/// Panics if the slice is empty.
+	pub fn add_pane(&self,
+		min_size: u32, ratio: f32, children: &[&dyn Child]) -> &Splitter
+	{
+		if children.is_empty() {
+			panic!("No children being added to Splitter pane.");
+		}
+
+		|min_size, ratio, children: &[&dyn Child]| -> WinResult<&Splitter> {
+			let mut pane = Pane {
+				hwnd_ptrs: children.iter()
+					.map(|child| NonNull::from(hwndref_from_child(*child)))
+					.collect(),
+				min_size: min_size as i32,
+				ratio,
+				collapsed: false,
+			};
+
+			if self.is_created() { // pane added after parent creation
+				pane.min_size = self.dpi_main_axis(pane.min_size)?;
+				self.0.as_mut().panes.push(pane);
+				self.relayout()?;
+			} else {
+				self.0.as_mut().panes.push(pane);
+			}
+			Ok(self)
+		}
+		(min_size, ratio, children)
+			.unwrap_or_else(|err| { PostQuitMessage(err); self })
+	}
+
+	/// Returns the number of panes.
+	pub fn pane_count(&self) -> usize {
+		self.0.panes.len()
+	}
+
+	/// Returns the current share of space of each pane, which sum up to 1.
+	/// Collapsed panes keep the share they had before being collapsed.
+	///
+	/// These values can be persisted, and later restored with
+	/// [`set_ratios`](crate::gui::Splitter::set_ratios).
+	pub fn ratios(&self) -> Vec<f32> {
+		let total: f32 = self.0.panes.iter().map(|p| p.ratio).sum();
+		self.0.panes.iter()
+			.map(|p| if total > 0.0 { p.ratio / total } else { 0.0 })
+			.collect()
+	}
+
+	/// Sets the share of space of each pane, as returned by
+	/// [`ratios`](crate::gui::Splitter::ratios), then lays out the panes.
+	///
+	/// # Panics
+	///
+	/// Panics if the number of ratios is different from the number of panes.
+	pub fn set_ratios(&self, ratios: &[f32]) -> WinResult<()> {
+		if ratios.len() != self.0.panes.len() {
+			panic!("Number of ratios doesn't match the number of Splitter panes.");
+		}
+		for (pane, ratio) in self.0.as_mut().panes.iter_mut().zip(ratios.iter()) {
+			pane.ratio = *ratio;
+		}
+		self.relayout()
+	}
+
+	/// Tells whether the given pane is collapsed.
+	///
+	/// # Panics
+	///
+	/// Panics if `pane_idx` is out of bounds.
+	pub fn is_pane_collapsed(&self, pane_idx: usize) -> bool {
+		self.0.panes[pane_idx].collapsed
+	}
+
+	/// Collapses the given pane, hiding its child controls and giving its
+	/// space to the other panes.
+	///
+	/// # Panics
+	///
+	/// Panics if `pane_idx` is out of bounds.
+	pub fn collapse_pane(&self, pane_idx: usize) -> WinResult<()> {
+		self.0.as_mut().panes[pane_idx].collapsed = true;
+		self.relayout()
+	}
+
+	/// Expands a previously collapsed pane, which will take back the share of
+	/// space it had before being collapsed.
+	///
+	/// # Panics
+	///
+	/// Panics if `pane_idx` is out of bounds.
+	pub fn expand_pane(&self, pane_idx: usize) -> WinResult<()> {
+		self.0.as_mut().panes[pane_idx].collapsed = false;
+		self.relayout()
+	}
+
+	fn hparent(&self) -> HWND {
+		unsafe { *self.0.parent_ptr.as_ref().hwnd_ref() }
+	}
+
+	fn is_created(&self) -> bool {
+		self.0.hcursor.is_some()
+	}
+
+	fn dpi_main_axis(&self, len: i32) -> WinResult<i32> {
+		let mut sz = SIZE::new(len, len);
+		multiply_dpi_for(unsafe { self.0.parent_ptr.as_ref() }, None, Some(&mut sz))?;
+		Ok(self.main_axis(sz))
+	}
+
+	fn create(&self) -> WinResult<()> {
+		let opts = &mut self.0.as_mut().opts;
+		let mut margin_pt = POINT::new(opts.margins.left, opts.margins.top);
+		let mut margin_sz = SIZE::new(opts.margins.right, opts.margins.bottom);
+		multiply_dpi_for(unsafe { self.0.parent_ptr.as_ref() },
+			Some(&mut margin_pt), Some(&mut margin_sz))?;
+
+		opts.bar_size = self.dpi_main_axis(opts.bar_size as i32)? as u32;
+		opts.margins = RECT {
+			left: margin_pt.x,
+			top: margin_pt.y,
+			right: margin_sz.cx,
+			bottom: margin_sz.cy,
+		};
+
+		for pane in self.0.as_mut().panes.iter_mut() {
+			pane.min_size = self.dpi_main_axis(pane.min_size)?;
+		}
+
+		self.0.as_mut().hcursor = Some(
+			HINSTANCE::oem().LoadCursor(IdIdcStr::Idc(
+				match self.0.opts.orientation {
+					SplitterOrient::Horizontal => co::IDC::SIZEWE,
+					SplitterOrient::Vertical => co::IDC::SIZENS,
+				},
+			))?,
+		);
+
+		let rc_parent = self.hparent().GetClientRect()?;
+		self.resize(SIZE::new(rc_parent.right, rc_parent.bottom))
+	}
+
+	fn rescale(&self, old_dpi: u32, new_dpi: u32) {
+		if !self.is_created() {
+			return; // lengths not scaled yet, will be scaled with the new DPI
+		}
+		let scale = |len: i32| MulDiv(len, new_dpi as i32, old_dpi as i32);
+
+		let opts = &mut self.0.as_mut().opts;
+		opts.bar_size = scale(opts.bar_size as i32) as u32;
+		opts.margins = RECT {
+			left: scale(opts.margins.left),
+			top: scale(opts.margins.top),
+			right: scale(opts.margins.right),
+			bottom: scale(opts.margins.bottom),
+		};
+		for pane in self.0.as_mut().panes.iter_mut() {
+			pane.min_size = scale(pane.min_size);
+		}
+	}
+
+	fn main_axis(&self, sz: SIZE) -> i32 {
+		match self.0.opts.orientation {
+			SplitterOrient::Horizontal => sz.cx,
+			SplitterOrient::Vertical => sz.cy,
+		}
+	}
+
+	fn main_coord(&self, pt: POINT) -> i32 {
+		match self.0.opts.orientation {
+			SplitterOrient::Horizontal => pt.x,
+			SplitterOrient::Vertical => pt.y,
+		}
+	}
+
+	fn area_start(&self) -> i32 {
+		match self.0.opts.orientation {
+			SplitterOrient::Horizontal => self.0.rc_area.left,
+			SplitterOrient::Vertical => self.0.rc_area.top,
+		}
+	}
+
+	fn area_len(&self) -> i32 {
+		match self.0.opts.orientation {
+			SplitterOrient::Horizontal => self.0.rc_area.right - self.0.rc_area.left,
+			SplitterOrient::Vertical => self.0.rc_area.bottom - self.0.rc_area.top,
+		}
+	}
+
+	fn available_len(&self) -> i32 {
+		let num_bars = self.0.panes.len().saturating_sub(1) as i32;
+		(self.area_len() - num_bars * self.0.opts.bar_size as i32).max(0)
+	}
+
+	fn resize(&self, client_area: SIZE) -> WinResult<()> {
+		let margins = &self.0.opts.margins;
+		self.0.as_mut().rc_area = RECT {
+			left: margins.left,
+			top: margins.top,
+			right: (client_area.cx - margins.right).max(margins.left),
+			bottom: (client_area.cy - margins.bottom).max(margins.top),
+		};
+		self.relayout()
+	}
+
+	/// Distributes the available space among the panes, according to their
+	/// ratios and minimum sizes, then positions the children.
+	fn relayout(&self) -> WinResult<()> {
+		if !self.is_created() {
+			return Ok(()); // dialogs receive WM_SIZE before WM_INITDIALOG
+		}
+
+		let sizes = distribute_space(
+			self.available_len(),
+			&self.0.panes.iter()
+				.map(|p| if p.collapsed { None } else { Some((p.ratio, p.min_size)) })
+				.collect::<Vec<_>>(),
+		);
+		self.0.as_mut().sizes = sizes;
+		self.position_children()
+	}
+
+	fn position_children(&self) -> WinResult<()> {
+		let num_hwnds = self.0.panes.iter().map(|p| p.hwnd_ptrs.len()).sum::<usize>();
+		if num_hwnds == 0 {
+			return Ok(());
+		}
+
+		let hdwp = HDWP::BeginDeferWindowPos(num_hwnds as u32)?;
+		let rc_area = self.0.rc_area;
+		let mut pos = self.area_start();
+
+		for (pane, sz) in self.0.panes.iter().zip(self.0.sizes.iter()) {
+			let (x, y, cx, cy) = match self.0.opts.orientation {
+				SplitterOrient::Horizontal => (pos, rc_area.top, *sz, rc_area.bottom - rc_area.top),
+				SplitterOrient::Vertical => (rc_area.left, pos, rc_area.right - rc_area.left, *sz),
+			};
+
+			for hwnd_ptr in pane.hwnd_ptrs.iter() {
+				hdwp.DeferWindowPos(
+					unsafe { *hwnd_ptr.as_ref() },
+					HwndPlace::None,
+					x, y, cx, cy,
+					co::SWP::NOZORDER | if pane.collapsed {
+						co::SWP::HIDEWINDOW
+					} else {
+						co::SWP::SHOWWINDOW
+					},
+				)?;
+			}
+			pos += *sz + self.0.opts.bar_size as i32;
+		}
+
+		hdwp.EndDeferWindowPos()
+	}
+
+	/// Returns the index of the bar under the given point, if any.
+	fn bar_at(&self, pt: POINT) -> Option<usize> {
+		let rc_area = self.0.rc_area;
+		if pt.x < rc_area.left || pt.x >= rc_area.right
+			|| pt.y < rc_area.top || pt.y >= rc_area.bottom
+		{
+			return None;
+		}
+
+		let coord = self.main_coord(pt);
+		let mut bar_start = self.area_start();
+		for (idx, sz) in self.0.sizes.iter().take(self.0.sizes.len().saturating_sub(1)).enumerate() {
+			bar_start += *sz;
+			if coord >= bar_start && coord < bar_start + self.0.opts.bar_size as i32 {
+				return Some(idx);
+			}
+			bar_start += self.0.opts.bar_size as i32;
+		}
+		None
+	}
+
+	fn begin_drag(&self, pt: POINT) {
+		if let Some(bar_idx) = self.bar_at(pt) {
+			let panes = &self.0.panes;
+			if panes[bar_idx].collapsed || panes[bar_idx + 1].collapsed {
+				return; // collapsed panes can only be restored with expand_pane()
+			}
+
+			let bar_start = self.area_start()
+				+ self.0.sizes[..=bar_idx].iter().sum::<i32>()
+				+ bar_idx as i32 * self.0.opts.bar_size as i32;
+
+			self.0.as_mut().drag = Some(Drag {
+				bar_idx,
+				offset: self.main_coord(pt) - bar_start,
+			});
+			self.hparent().SetCapture();
+		}
+	}
+
+	fn mouse_move(&self, pt: POINT) -> WinResult<()> {
+		match &self.0.drag {
+			Some(drag) => {
+				let idx = drag.bar_idx;
+				let pane_start = self.area_start()
+					+ self.0.sizes[..idx].iter().sum::<i32>()
+					+ idx as i32 * self.0.opts.bar_size as i32;
+
+				let new_sizes = move_bar(
+					(self.0.sizes[idx], self.0.sizes[idx + 1]),
+					(self.0.panes[idx].min_size, self.0.panes[idx + 1].min_size),
+					self.main_coord(pt) - drag.offset - pane_start,
+				);
+				if new_sizes.0 == self.0.sizes[idx] {
+					return Ok(()); // bar didn't move
+				}
+
+				let sizes = &mut self.0.as_mut().sizes;
+				sizes[idx] = new_sizes.0;
+				sizes[idx + 1] = new_sizes.1;
+				self.update_ratios();
+				self.position_children()
+			},
+			None => Ok(()), // cursor is set on WM_SETCURSOR
+		}
+	}
+
+	fn end_drag(&self) -> WinResult<()> {
+		if self.0.as_mut().drag.take().is_some() {
+			ReleaseCapture()?;
+		}
+		Ok(())
+	}
+
+	/// Called on `WM_SETCURSOR`; shows the resize cursor if the mouse is over a
+	/// bar. While dragging, the mouse is captured, and the cursor is kept.
+	fn set_cursor(&self) -> WinResult<bool> {
+		let hcursor = match self.0.hcursor {
+			Some(hcursor) => hcursor,
+			None => return Ok(false),
+		};
+
+		let mut pt = GetCursorPos()?;
+		self.hparent().ScreenToClient(&mut pt)?;
+		if self.bar_at(pt).is_none() {
+			return Ok(false);
+		}
+		hcursor.SetCursor();
+		Ok(true)
+	}
+
+	/// Recalculates the ratios of the visible panes after the user dragged a
+	/// bar, so the proportions are kept when the parent is resized.
+	fn update_ratios(&self) {
+		let visible_ratio: f32 = self.0.panes.iter()
+			.filter(|p| !p.collapsed)
+			.map(|p| p.ratio)
+			.sum();
+		let available = self.available_len();
+		if available == 0 {
+			return;
+		}
+
+		for (pane, sz) in self.0.as_mut().panes.iter_mut().zip(self.0.sizes.iter()) {
+			if !pane.collapsed {
+				pane.ratio = visible_ratio * (*sz as f32 / available as f32);
+			}
+		}
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Options to create a [`Splitter`](crate::gui::Splitter) with
+/// [`Splitter::new`](crate::gui::Splitter::new).
+pub struct SplitterOpts {
+	/// How the panes are laid out.
+	///
+	/// Defaults to `SplitterOrient::Horizontal`.
+	pub orientation: SplitterOrient,
+	/// Thickness of the bars between the panes, in pixels.
+	///
+	/// Will be adjusted to match the current DPI of the parent window.
+	///
+	/// Defaults to 4.
+	pub bar_size: u32,
+	/// Distances, in pixels, between the splitter and each edge of the parent
+	/// client area. Useful to leave room to other controls, like a
+	/// [`StatusBar`](crate::gui::StatusBar).
+	///
+	/// Will be adjusted to match the current DPI of the parent window.
+	///
+	/// Defaults to zero on all edges.
+	pub margins: RECT,
+}
+
+impl Default for SplitterOpts {
+	fn default() -> Self {
+		Self {
+			orientation: SplitterOrient::Horizontal,
+			bar_size: 4,
+			margins: RECT::default(),
+		}
+	}
+}
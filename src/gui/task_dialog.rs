@@ -0,0 +1,545 @@
+use crate::aliases::WinResult;
+use crate::co;
+use crate::funcs::{MAKEDWORD, TaskDialogIndirect};
+use crate::gui::traits::{baseref_from_parent, Parent};
+use crate::handles::HWND;
+use crate::msg::WndMsg;
+use crate::structs::{TASKDIALOG_BUTTON, TASKDIALOGCONFIG};
+use crate::WString;
+
+/// A button of a [`TaskDialog`](crate::gui::TaskDialog).
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum TaskDialogButton {
+	/// One of the common buttons, set with
+	/// [`TaskDialog::common_buttons`](crate::gui::TaskDialog::common_buttons).
+	/// Also `co::DLGID::CANCEL` if the dialog was cancelled.
+	Common(co::DLGID),
+	/// A custom button, added with
+	/// [`TaskDialog::button`](crate::gui::TaskDialog::button).
+	Custom(u16),
+}
+
+impl TaskDialogButton {
+	fn id(self) -> usize {
+		match self {
+			TaskDialogButton::Common(dlg_id) => dlg_id.0 as usize,
+			TaskDialogButton::Custom(id) => id as usize,
+		}
+	}
+}
+
+/// The result of a [`TaskDialog`](crate::gui::TaskDialog), returned by
+/// [`TaskDialog::show`](crate::gui::TaskDialog::show).
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct TaskDialogResult {
+	/// The button which closed the dialog.
+	pub button: TaskDialogButton,
+	/// The ID of the selected radio button, if any. Radio button IDs are never
+	/// zero.
+	pub radio: Option<u16>,
+	/// Whether the verification checkbox was checked.
+	pub verification_checked: bool,
+}
+
+/// A notification received by the
+/// [`TaskDialog::callback`](crate::gui::TaskDialog::callback) closure.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum TaskDialogNotif {
+	/// The dialog was created, and is about to be displayed.
+	Created,
+	/// A button was clicked. Return `false` from the callback to prevent the
+	/// dialog from closing.
+	ButtonClicked(TaskDialogButton),
+	/// A hyperlink was clicked. Carries the `href` attribute of the link.
+	HyperlinkClicked(String),
+	/// Sent about every 200 milliseconds, if
+	/// [`TaskDialog::timer`](crate::gui::TaskDialog::timer) was set. Carries the
+	/// number of milliseconds since the dialog was created, or since the
+	/// callback last returned `false` for this notification.
+	Timer(u32),
+	/// A radio button was clicked. Carries its ID.
+	RadioClicked(u16),
+	/// The verification checkbox was clicked. Carries its new state.
+	VerificationClicked(bool),
+	/// The expanded information was shown or hidden. Carries whether it's
+	/// expanded now.
+	ExpandoClicked(bool),
+	/// The user pressed F1.
+	Help,
+	/// The dialog is about to be destroyed.
+	Destroyed,
+}
+
+//------------------------------------------------------------------------------
+
+/// Allows updating a [`TaskDialog`](crate::gui::TaskDialog) while it's being
+/// displayed, from within the
+/// [`TaskDialog::callback`](crate::gui::TaskDialog::callback) closure.
+pub struct TaskDialogCtrl {
+	hwnd: HWND,
+}
+
+impl TaskDialogCtrl {
+	/// Returns the handle of the task dialog window.
+	pub fn hwnd(&self) -> HWND {
+		self.hwnd
+	}
+
+	fn send(&self, msg: co::TDM, wparam: usize, lparam: isize) -> isize {
+		self.hwnd.SendMessage(WndMsg { msg_id: msg.into(), wparam, lparam })
+	}
+
+	/// Simulates the click of a button, which may close the dialog.
+	pub fn click_button(&self, button: TaskDialogButton) {
+		self.send(co::TDM::CLICK_BUTTON, button.id(), 0);
+	}
+
+	/// Closes the dialog, as if it was cancelled.
+	pub fn close(&self) {
+		self.click_button(TaskDialogButton::Common(co::DLGID::CANCEL));
+	}
+
+	/// Enables or disables a button.
+	pub fn enable_button(&self, button: TaskDialogButton, enable: bool) {
+		self.send(co::TDM::ENABLE_BUTTON, button.id(), enable as isize);
+	}
+
+	/// Selects a radio button.
+	pub fn click_radio(&self, radio_id: u16) {
+		self.send(co::TDM::CLICK_RADIO_BUTTON, radio_id as usize, 0);
+	}
+
+	/// Enables or disables a radio button.
+	pub fn enable_radio(&self, radio_id: u16, enable: bool) {
+		self.send(co::TDM::ENABLE_RADIO_BUTTON, radio_id as usize, enable as isize);
+	}
+
+	/// Checks or unchecks the verification checkbox.
+	pub fn set_verification_checked(&self, checked: bool) {
+		self.send(co::TDM::CLICK_VERIFICATION, checked as usize, 0);
+	}
+
+	/// Replaces one of the texts of the dialog. The dialog is resized to fit
+	/// the new text.
+	pub fn set_element_text(&self, element: co::TDE, text: &str) {
+		let text_buf = WString::from_str(text);
+		self.send(co::TDM::SET_ELEMENT_TEXT,
+			element.0 as usize, unsafe { text_buf.as_ptr() } as isize);
+	}
+
+	/// Sets the position of the progress bar.
+	pub fn set_progress_pos(&self, pos: u32) {
+		self.send(co::TDM::SET_PROGRESS_BAR_POS, pos as usize, 0);
+	}
+
+	/// Sets the minimum and maximum values of the progress bar. Default range
+	/// is 0 to 100.
+	pub fn set_progress_range(&self, min: u16, max: u16) {
+		self.send(co::TDM::SET_PROGRESS_BAR_RANGE, 0, MAKEDWORD(min, max) as isize);
+	}
+
+	/// Sets the state of the progress bar, which changes its color.
+	pub fn set_progress_state(&self, state: co::PBST) {
+		self.send(co::TDM::SET_PROGRESS_BAR_STATE, state.0 as usize, 0);
+	}
+
+	/// Switches the progress bar between marquee and regular modes. When
+	/// `marquee` is true, the animation runs at the given interval, in
+	/// milliseconds; zero means the default.
+	pub fn set_marquee(&self, marquee: bool, interval_ms: u32) {
+		self.send(co::TDM::SET_MARQUEE_PROGRESS_BAR, marquee as usize, 0);
+		if marquee {
+			self.send(co::TDM::SET_PROGRESS_BAR_MARQUEE, 1, interval_ms as isize);
+		}
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Builds and displays a
+/// [task dialog](https://docs.microsoft.com/en-us/windows/win32/controls/task-dialogs-overview),
+/// with [`TaskDialogIndirect`](crate::TaskDialogIndirect). It's a richer
+/// replacement of [`MessageBox`](crate::HWND::MessageBox).
+///
+/// Custom button IDs should not collide with the common ones in
+/// [`co::DLGID`](crate::co::DLGID); numbers from 100 upwards are safe.
+///
+/// # Examples
+///
+/// An error dialog with details and a "don't ask again" checkbox:
+///
+/// ```rust,ignore
+/// use winsafe::co;
+/// use winsafe::gui::{TaskDialog, TaskDialogButton, WindowMain};
+///
+/// let wnd: WindowMain; // initialize it somewhere...
+///
+/// const ID_RETRY: u16 = 100;
+///
+/// let res = TaskDialog::new()
+///     .title("My app")
+///     .main_icon(co::TD_ICON::ERROR)
+///     .main_instruction("Could not save the file.")
+///     .content("The disk is full.")
+///     .expanded_info("Error 112: there is not enough space on the disk.")
+///     .button(ID_RETRY, "Try again")
+///     .common_buttons(co::TDCBF::CANCEL)
+///     .verification("Don't ask again", false)
+///     .show(&wnd)
+///     .unwrap();
+///
+/// if res.button == TaskDialogButton::Custom(ID_RETRY) {
+///     // ...
+/// }
+/// ```
+pub struct TaskDialog {
+	title: Option<String>,
+	main_instruction: Option<String>,
+	content: Option<String>,
+	expanded_info: Option<String>,
+	footer: Option<String>,
+	main_icon: Option<co::TD_ICON>,
+	footer_icon: Option<co::TD_ICON>,
+	common_buttons: co::TDCBF,
+	buttons: Vec<(u16, String)>,
+	default_button: Option<TaskDialogButton>,
+	radios: Vec<(u16, String)>,
+	default_radio: Option<u16>,
+	verification: Option<(String, bool)>,
+	flags: co::TDF,
+	callback: Option<Box<dyn FnMut(&TaskDialogCtrl, TaskDialogNotif) -> bool>>,
+}
+
+impl Default for TaskDialog {
+	fn default() -> Self {
+		Self {
+			title: None,
+			main_instruction: None,
+			content: None,
+			expanded_info: None,
+			footer: None,
+			main_icon: None,
+			footer_icon: None,
+			common_buttons: co::TDCBF::from(0),
+			buttons: Vec::default(),
+			default_button: None,
+			radios: Vec::default(),
+			default_radio: None,
+			verification: None,
+			flags: co::TDF::POSITION_RELATIVE_TO_WINDOW,
+			callback: None,
+		}
+	}
+}
+
+impl TaskDialog {
+	/// Creates a new builder. If no buttons are added, the dialog has an OK
+	/// button.
+	pub fn new() -> TaskDialog {
+		Self::default()
+	}
+
+	fn set_flag(mut self, flag: co::TDF, set: bool) -> TaskDialog {
+		if set {
+			self.flags |= flag;
+		} else {
+			self.flags &= !flag;
+		}
+		self
+	}
+
+	/// Sets the text of the title bar. Defaults to the executable name.
+	pub fn title(mut self, title: &str) -> TaskDialog {
+		self.title = Some(title.to_owned());
+		self
+	}
+
+	/// Sets the main instruction, displayed in a larger font.
+	pub fn main_instruction(mut self, text: &str) -> TaskDialog {
+		self.main_instruction = Some(text.to_owned());
+		self
+	}
+
+	/// Sets the content text, displayed below the main instruction.
+	pub fn content(mut self, text: &str) -> TaskDialog {
+		self.content = Some(text.to_owned());
+		self
+	}
+
+	/// Sets the additional information, hidden until the user clicks the
+	/// "show details" button.
+	pub fn expanded_info(mut self, text: &str) -> TaskDialog {
+		self.expanded_info = Some(text.to_owned());
+		self
+	}
+
+	/// Sets the footer text, displayed at the bottom of the dialog.
+	pub fn footer(mut self, text: &str) -> TaskDialog {
+		self.footer = Some(text.to_owned());
+		self
+	}
+
+	/// Sets the main icon.
+	pub fn main_icon(mut self, icon: co::TD_ICON) -> TaskDialog {
+		self.main_icon = Some(icon);
+		self
+	}
+
+	/// Sets the footer icon.
+	pub fn footer_icon(mut self, icon: co::TD_ICON) -> TaskDialog {
+		self.footer_icon = Some(icon);
+		self
+	}
+
+	/// Sets the common buttons, displayed after the custom ones.
+	pub fn common_buttons(mut self, buttons: co::TDCBF) -> TaskDialog {
+		self.common_buttons = buttons;
+		self
+	}
+
+	/// Adds a custom button, identified by the given ID. Buttons are displayed
+	/// in the order they are added.
+	pub fn button(mut self, id: u16, text: &str) -> TaskDialog {
+		self.buttons.push((id, text.to_owned()));
+		self
+	}
+
+	/// Sets the button which is focused when the dialog is displayed.
+	pub fn default_button(mut self, button: TaskDialogButton) -> TaskDialog {
+		self.default_button = Some(button);
+		self
+	}
+
+	/// Sets whether the custom buttons are displayed as command links, with a
+	/// bigger font and an arrow icon. The text after the first line break is
+	/// displayed as a note, in a smaller font.
+	///
+	/// Defaults to `false`.
+	pub fn command_links(self, command_links: bool) -> TaskDialog {
+		self.set_flag(co::TDF::USE_COMMAND_LINKS, command_links)
+	}
+
+	/// Adds a radio button, identified by the given ID. Radio buttons are
+	/// displayed in the order they are added. The first one is selected by
+	/// default, unless another one is set with
+	/// [`default_radio`](crate::gui::TaskDialog::default_radio).
+	///
+	/// # Panics
+	///
+	/// Panics if `id` is zero, which is reserved to mean no selection.
+	pub fn radio(mut self, id: u16, text: &str) -> TaskDialog {
+		if id == 0 {
+			panic!("TaskDialog radio button ID cannot be zero.");
+		}
+		self.radios.push((id, text.to_owned()));
+		self
+	}
+
+	/// Sets the radio button initially selected.
+	pub fn default_radio(mut self, id: u16) -> TaskDialog {
+		self.default_radio = Some(id);
+		self
+	}
+
+	/// Adds a verification checkbox, like "Don't ask again", with the given
+	/// initial state.
+	pub fn verification(mut self, text: &str, checked: bool) -> TaskDialog {
+		self.verification = Some((text.to_owned(), checked));
+		self
+	}
+
+	/// Sets whether a progress bar is displayed. It can be updated with
+	/// [`TaskDialogCtrl`](crate::gui::TaskDialogCtrl) methods.
+	///
+	/// Defaults to `false`.
+	pub fn progress_bar(self, show: bool) -> TaskDialog {
+		self.set_flag(co::TDF::SHOW_PROGRESS_BAR, show)
+	}
+
+	/// Sets whether a marquee progress bar is displayed.
+	///
+	/// Defaults to `false`.
+	pub fn marquee_progress_bar(self, show: bool) -> TaskDialog {
+		self.set_flag(co::TDF::SHOW_MARQUEE_PROGRESS_BAR, show)
+	}
+
+	/// Sets whether hyperlinks, written as `<a href="target">text</a>` in the
+	/// content, footer and expanded information, are enabled. A click on a
+	/// link is reported as
+	/// [`TaskDialogNotif::HyperlinkClicked`](crate::gui::TaskDialogNotif::HyperlinkClicked).
+	///
+	/// Defaults to `false`.
+	pub fn hyperlinks(self, enable: bool) -> TaskDialog {
+		self.set_flag(co::TDF::ENABLE_HYPERLINKS, enable)
+	}
+
+	/// Sets whether the dialog can be closed with Esc, Alt+F4 or the title bar
+	/// close button, even without a Cancel button.
+	///
+	/// Defaults to `false`.
+	pub fn allow_cancel(self, allow: bool) -> TaskDialog {
+		self.set_flag(co::TDF::ALLOW_DIALOG_CANCELLATION, allow)
+	}
+
+	/// Sets whether the expanded information is initially visible.
+	///
+	/// Defaults to `false`.
+	pub fn expanded_by_default(self, expanded: bool) -> TaskDialog {
+		self.set_flag(co::TDF::EXPANDED_BY_DEFAULT, expanded)
+	}
+
+	/// Sets whether the callback receives
+	/// [`TaskDialogNotif::Timer`](crate::gui::TaskDialogNotif::Timer)
+	/// notifications, about every 200 milliseconds.
+	///
+	/// Defaults to `false`.
+	pub fn timer(self, enable: bool) -> TaskDialog {
+		self.set_flag(co::TDF::CALLBACK_TIMER, enable)
+	}
+
+	/// Sets a closure to receive the dialog notifications, which can update the
+	/// dialog through the [`TaskDialogCtrl`](crate::gui::TaskDialogCtrl).
+	///
+	/// The returned value is only meaningful to
+	/// [`ButtonClicked`](crate::gui::TaskDialogNotif::ButtonClicked), where
+	/// `false` keeps the dialog open, and to
+	/// [`Timer`](crate::gui::TaskDialogNotif::Timer), where `false` resets the
+	/// elapsed time. Otherwise, return `true`.
+	pub fn callback<F>(mut self, func: F) -> TaskDialog
+		where F: FnMut(&TaskDialogCtrl, TaskDialogNotif) -> bool + 'static,
+	{
+		self.callback = Some(Box::new(func));
+		self
+	}
+
+	/// Displays the modal dialog. This method will block until the dialog is
+	/// closed.
+	pub fn show(&mut self, parent: &dyn Parent) -> WinResult<TaskDialogResult> {
+		let hparent = *baseref_from_parent(parent).hwnd_ref();
+
+		let opt_buf = |text: &Option<String>| text.as_ref().map(|t| WString::from_str(t));
+		let title_buf = opt_buf(&self.title);
+		let instruction_buf = opt_buf(&self.main_instruction);
+		let content_buf = opt_buf(&self.content);
+		let expanded_buf = opt_buf(&self.expanded_info);
+		let footer_buf = opt_buf(&self.footer);
+		let verification_buf = self.verification.as_ref().map(|(t, _)| WString::from_str(t));
+
+		let button_bufs = self.buttons.iter()
+			.map(|(_, text)| WString::from_str(text))
+			.collect::<Vec<_>>();
+		let buttons = self.buttons.iter()
+			.zip(button_bufs.iter())
+			.map(|((id, _), buf)| {
+				let mut btn = TASKDIALOG_BUTTON::default();
+				btn.nButtonID = *id as i32;
+				btn.set_pszButtonText(buf);
+				btn
+			})
+			.collect::<Vec<_>>();
+
+		let radio_bufs = self.radios.iter()
+			.map(|(_, text)| WString::from_str(text))
+			.collect::<Vec<_>>();
+		let radios = self.radios.iter()
+			.zip(radio_bufs.iter())
+			.map(|((id, _), buf)| {
+				let mut btn = TASKDIALOG_BUTTON::default();
+				btn.nButtonID = *id as i32;
+				btn.set_pszButtonText(buf);
+				btn
+			})
+			.collect::<Vec<_>>();
+
+		let mut tdc = TASKDIALOGCONFIG::default();
+		tdc.hwndParent = hparent;
+		tdc.dwFlags = self.flags;
+		tdc.dwCommonButtons = self.common_buttons;
+
+		if let Some(buf) = &title_buf { tdc.set_pszWindowTitle(buf); }
+		if let Some(buf) = &instruction_buf { tdc.set_pszMainInstruction(buf); }
+		if let Some(buf) = &content_buf { tdc.set_pszContent(buf); }
+		if let Some(buf) = &expanded_buf { tdc.set_pszExpandedInformation(buf); }
+		if let Some(buf) = &footer_buf { tdc.set_pszFooter(buf); }
+		if let Some(icon) = self.main_icon { tdc.set_pszMainIcon(icon); }
+		if let Some(icon) = self.footer_icon { tdc.set_pszFooterIcon(icon); }
+
+		if !buttons.is_empty() {
+			tdc.set_pButtons(&buttons);
+		}
+		if let Some(button) = self.default_button {
+			tdc.nDefaultButton = button.id() as i32;
+		}
+		if !radios.is_empty() {
+			tdc.set_pRadioButtons(&radios);
+		}
+		if let Some(radio_id) = self.default_radio {
+			tdc.nDefaultRadioButton = radio_id as i32;
+		}
+		if let (Some(buf), Some((_, checked))) = (&verification_buf, &self.verification) {
+			tdc.set_pszVerificationText(buf);
+			#[allow(clippy::assign_op_pattern)] // |= would take a reference to a packed field
+			if *checked {
+				tdc.dwFlags = tdc.dwFlags | co::TDF::VERIFICATION_FLAG_CHECKED;
+			}
+		}
+
+		let mut cb_data = CallbackData {
+			custom_ids: self.buttons.iter().map(|(id, _)| *id).collect(),
+			func: self.callback.as_mut(),
+		};
+		tdc.pfCallback = Some(task_dialog_proc);
+		tdc.lpCallbackData = &mut cb_data as *mut _ as isize;
+
+		let (button_id, radio_id, checked) = TaskDialogIndirect(&tdc)?;
+		Ok(TaskDialogResult {
+			button: cb_data.button_from_id(button_id as usize),
+			radio: if radio_id == 0 { None } else { Some(radio_id as u16) },
+			verification_checked: checked,
+		})
+	}
+}
+
+struct CallbackData<'a> {
+	custom_ids: Vec<u16>,
+	func: Option<&'a mut Box<dyn FnMut(&TaskDialogCtrl, TaskDialogNotif) -> bool>>,
+}
+
+impl<'a> CallbackData<'a> {
+	fn button_from_id(&self, id: usize) -> TaskDialogButton {
+		if self.custom_ids.contains(&(id as u16)) {
+			TaskDialogButton::Custom(id as u16)
+		} else {
+			TaskDialogButton::Common(co::DLGID(id as u16))
+		}
+	}
+}
+
+extern "system" fn task_dialog_proc(
+	hwnd: HWND, msg: co::TDN, wparam: usize, lparam: isize, ref_data: isize) -> i32
+{
+	let cb_data = unsafe { &mut *(ref_data as *mut CallbackData) };
+
+	let notif = match msg {
+		co::TDN::CREATED => TaskDialogNotif::Created,
+		co::TDN::BUTTON_CLICKED => TaskDialogNotif::ButtonClicked(cb_data.button_from_id(wparam)),
+		co::TDN::HYPERLINK_CLICKED => TaskDialogNotif::HyperlinkClicked(
+			WString::from_wchars_nullt(lparam as *const u16).to_string()),
+		co::TDN::TIMER => TaskDialogNotif::Timer(wparam as u32),
+		co::TDN::RADIO_BUTTON_CLICKED => TaskDialogNotif::RadioClicked(wparam as u16),
+		co::TDN::VERIFICATION_CLICKED => TaskDialogNotif::VerificationClicked(wparam != 0),
+		co::TDN::EXPANDO_BUTTON_CLICKED => TaskDialogNotif::ExpandoClicked(wparam != 0),
+		co::TDN::HELP => TaskDialogNotif::Help,
+		co::TDN::DESTROYED => TaskDialogNotif::Destroyed,
+		_ => return co::ERROR::S_OK.0 as i32,
+	};
+
+	match cb_data.func.as_mut() {
+		Some(func) => if func(&TaskDialogCtrl { hwnd }, notif) {
+			co::ERROR::S_OK.0 as i32
+		} else {
+			co::ERROR::S_FALSE.0 as i32 // prevents closing, or resets the timer
+		},
+		None => co::ERROR::S_OK.0 as i32,
+	}
+}
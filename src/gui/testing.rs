@@ -0,0 +1,383 @@
+//! Automation of running windows, so applications can be scripted by
+//! integration tests: controls are found by ID or text, clicked, typed into,
+//! and the whole window can be dumped into a text snapshot.
+//!
+//! All the calls must be made from the thread which created the windows, since
+//! the driver pumps their messages while waiting.
+
+use crate::aliases::WinResult;
+use crate::co;
+use crate::funcs::{DispatchMessage, GetTickCount64, PeekMessage, Sleep, TranslateMessage};
+use crate::gui::traits::{baseref_from_parent, Parent};
+use crate::handles::HWND;
+use crate::msg::{bm, cb, lb, wm};
+use crate::structs::{MSG, RECT};
+
+/// Drives a created window, whose controls are accessed as
+/// [`TestCtrl`](crate::gui::testing::TestCtrl) objects.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use winsafe::gui::WindowMain;
+/// use winsafe::gui::testing::UiDriver;
+///
+/// let wnd: WindowMain; // initialize it somewhere...
+///
+/// let drv = UiDriver::new(&wnd);
+/// drv.find_by_id(1001).unwrap().type_text("John").unwrap();
+/// drv.find_by_text("OK").unwrap().click().unwrap();
+///
+/// assert!(drv.wait_until(2000, |drv| drv.find_by_text("Hello, John").is_some()));
+/// assert_eq!(drv.snapshot(), std::fs::read_to_string("golden.txt").unwrap());
+/// ```
+pub struct UiDriver {
+	hwnd: HWND,
+}
+
+impl UiDriver {
+	/// Creates a new `UiDriver` for the given window.
+	///
+	/// # Panics
+	///
+	/// Panics if the window is not created yet.
+	pub fn new(wnd: &dyn Parent) -> UiDriver {
+		let hwnd = *baseref_from_parent(wnd).hwnd_ref();
+		if hwnd.is_null() {
+			panic!("Cannot drive a window which is not created yet.");
+		}
+		Self { hwnd }
+	}
+
+	/// Creates a new `UiDriver` for any window handle, like a message box.
+	pub fn from_hwnd(hwnd: HWND) -> UiDriver {
+		Self { hwnd }
+	}
+
+	/// Returns the handle of the driven window.
+	pub fn hwnd(&self) -> HWND {
+		self.hwnd
+	}
+
+	/// Returns all the controls of the window, including the nested ones, in
+	/// the same order of the [`snapshot`](crate::gui::testing::UiDriver::snapshot).
+	pub fn controls(&self) -> Vec<TestCtrl> {
+		let mut ctrls = Vec::default();
+		for_each_child(self.hwnd, 0, &mut |hchild, _| {
+			ctrls.push(TestCtrl { hwnd: hchild, hroot: self.hwnd });
+		});
+		ctrls
+	}
+
+	/// Returns the first control with the given ID.
+	pub fn find_by_id(&self, ctrl_id: u16) -> Option<TestCtrl> {
+		self.controls().into_iter()
+			.find(|ctrl| ctrl.ctrl_id() == ctrl_id)
+	}
+
+	/// Returns the first control with the given text. Ampersands of mnemonics
+	/// are ignored, so `"&Save"` is found by `"Save"` too.
+	pub fn find_by_text(&self, text: &str) -> Option<TestCtrl> {
+		self.controls().into_iter()
+			.find(|ctrl| {
+				let ctrl_text = ctrl.text();
+				ctrl_text == text || remove_mnemonics(&ctrl_text) == text
+			})
+	}
+
+	/// Returns all the controls of the given window class, like `"Button"` or
+	/// `"Edit"`, compared case-insensitively.
+	pub fn find_by_class(&self, class_name: &str) -> Vec<TestCtrl> {
+		self.controls().into_iter()
+			.filter(|ctrl| ctrl.class_name().eq_ignore_ascii_case(class_name))
+			.collect()
+	}
+
+	/// Dispatches all the messages waiting in the queue of the current thread,
+	/// so posted actions, like [`click`](crate::gui::testing::TestCtrl::click),
+	/// take effect.
+	pub fn pump_messages(&self) {
+		let mut msg = MSG::default();
+		while PeekMessage(&mut msg, unsafe { HWND::null_handle() },
+			0, 0, co::PM::REMOVE)
+		{
+			TranslateMessage(&msg);
+			DispatchMessage(&msg);
+		}
+	}
+
+	/// Pumps the messages until the predicate returns `true`, or until the
+	/// timeout, in milliseconds, is reached. Returns the last predicate result.
+	pub fn wait_until<F>(&self, timeout_ms: u32, mut pred: F) -> bool
+		where F: FnMut(&UiDriver) -> bool,
+	{
+		let deadline = GetTickCount64() + timeout_ms as u64;
+		loop {
+			self.pump_messages();
+			if pred(self) {
+				return true;
+			} else if GetTickCount64() >= deadline {
+				return false;
+			}
+			Sleep(10);
+		}
+	}
+
+	/// Pumps the messages until the window is visible, or hidden.
+	pub fn wait_visible(&self, visible: bool, timeout_ms: u32) -> bool {
+		self.wait_until(timeout_ms, |drv| drv.hwnd.IsWindowVisible() == visible)
+	}
+
+	/// Pumps the messages until the window is destroyed, like a closed modal.
+	pub fn wait_destroyed(&self, timeout_ms: u32) -> bool {
+		self.wait_until(timeout_ms, |drv| !drv.hwnd.IsWindow())
+	}
+
+	/// Pumps the messages until an enabled popup owned by the window shows up,
+	/// like a modal window or a message box, returning a driver to it.
+	///
+	/// Since a modal runs its own message loop, a click which opens it must be
+	/// posted, like [`click`](crate::gui::testing::TestCtrl::click) does.
+	pub fn wait_popup(&self, timeout_ms: u32) -> Option<UiDriver> {
+		let mut hpopup = None;
+		self.wait_until(timeout_ms, |drv| {
+			hpopup = drv.hwnd.GetWindow(co::GW::ENABLEDPOPUP).ok()
+				.filter(|h| *h != drv.hwnd && h.IsWindowVisible());
+			hpopup.is_some()
+		});
+		hpopup.map(|hwnd| Self { hwnd })
+	}
+
+	/// Dumps the window and all its controls, one per line, indented by
+	/// nesting level, in the format:
+	///
+	/// ```text
+	/// class id=ctrl_id text="text" rect=(left,top,right,bottom) style=0xstyle
+	/// ```
+	///
+	/// The rectangles are relative to the client area of the window, so the
+	/// output doesn't depend on where the window is on the screen, and can be
+	/// compared against a golden file. For the same reason, class names
+	/// generated by the library are normalized, as in
+	/// [`TestCtrl::class_name`](crate::gui::testing::TestCtrl::class_name).
+	pub fn snapshot(&self) -> String {
+		let mut lines = Vec::default();
+		let rc_client = self.hwnd.GetClientRect().unwrap_or_default();
+		lines.push(snapshot_line(self.hwnd, 0, 0, rc_client)); // a top-level window has no ID
+		for_each_child(self.hwnd, 1, &mut |hchild, level| {
+			let ctrl = TestCtrl { hwnd: hchild, hroot: self.hwnd };
+			lines.push(snapshot_line(hchild, level, ctrl.ctrl_id(), ctrl.rect()));
+		});
+		lines.push(String::default()); // trailing newline
+		lines.join("\n")
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// A control found by a [`UiDriver`](crate::gui::testing::UiDriver).
+#[derive(Clone, Copy)]
+pub struct TestCtrl {
+	hwnd: HWND,
+	hroot: HWND,
+}
+
+impl TestCtrl {
+	/// Returns the handle of the control.
+	pub fn hwnd(&self) -> HWND {
+		self.hwnd
+	}
+
+	/// Returns the control ID.
+	pub fn ctrl_id(&self) -> u16 {
+		self.hwnd.GetDlgCtrlID().unwrap_or_default() as u16
+	}
+
+	/// Returns the window class name, like `"Button"`.
+	///
+	/// Class names generated by the library, for windows created without an
+	/// explicit class name, contain addresses which change at each run, so they
+	/// are normalized to `"WNDCLASS.*"`.
+	pub fn class_name(&self) -> String {
+		normalized_class_name(self.hwnd)
+	}
+
+	/// Returns the text of the control.
+	pub fn text(&self) -> String {
+		self.hwnd.GetWindowTextStr().unwrap_or_default()
+	}
+
+	/// Returns the rectangle of the control, relative to the client area of
+	/// the driven window.
+	pub fn rect(&self) -> RECT {
+		let mut rc = self.hwnd.GetWindowRect().unwrap_or_default();
+		self.hroot.ScreenToClientRc(&mut rc).ok();
+		rc
+	}
+
+	/// Returns the window style.
+	pub fn style(&self) -> co::WS {
+		co::WS(self.hwnd.GetWindowLongPtr(co::GWLP::STYLE) as u32)
+	}
+
+	/// Tells whether the control is visible.
+	pub fn is_visible(&self) -> bool {
+		self.hwnd.IsWindowVisible()
+	}
+
+	/// Tells whether the control is enabled.
+	pub fn is_enabled(&self) -> bool {
+		self.hwnd.IsWindowEnabled()
+	}
+
+	/// Tells whether a check box or radio button is checked, by sending a
+	/// [`BM_GETCHECK`](crate::msg::bm::GetCheck) message.
+	pub fn is_checked(&self) -> bool {
+		self.hwnd.SendMessage(bm::GetCheck {}) == co::BST::CHECKED
+	}
+
+	/// Clicks a button, check box or radio button, just like
+	/// [`Button::trigger_click`](crate::gui::Button::trigger_click), by posting
+	/// a [`BM_CLICK`](crate::msg::bm::Click) message.
+	///
+	/// The click is asynchronous, it takes effect when the messages are
+	/// pumped, like in [`wait_until`](crate::gui::testing::UiDriver::wait_until).
+	/// This allows the click to open a modal window.
+	///
+	/// Fails with [`ERROR::NOT_SUPPORTED`](crate::co::ERROR::NOT_SUPPORTED) if
+	/// the control is not a button.
+	pub fn click(&self) -> WinResult<()> {
+		if !self.class_name().eq_ignore_ascii_case("Button") {
+			return Err(co::ERROR::NOT_SUPPORTED);
+		}
+		self.hwnd.PostMessage(bm::Click {})
+	}
+
+	/// Types the text at the caret position, by sending a
+	/// [`WM_CHAR`](crate::msg::wm::Char) message for each character, so the
+	/// control notifies its changes as if the user typed them.
+	pub fn type_text(&self, text: &str) -> WinResult<()> {
+		self.hwnd.SetFocus();
+		for ch in text.encode_utf16() {
+			self.hwnd.SendMessage(wm::Char {
+				char_code: ch as u32,
+				repeat_count: 1,
+				scan_code: 0,
+				is_extended_key: false,
+				has_alt_key: false,
+				key_was_previously_down: false,
+				key_is_being_released: false,
+			});
+		}
+		Ok(())
+	}
+
+	/// Replaces the whole text with
+	/// [`SetWindowText`](crate::HWND::SetWindowText).
+	pub fn set_text(&self, text: &str) -> WinResult<()> {
+		self.hwnd.SetWindowText(text)
+	}
+
+	/// Selects the item at the given index of a combo box or list box, by
+	/// sending a [`CB_SETCURSEL`](crate::msg::cb::SetCurSel) or
+	/// [`LB_SETCURSEL`](crate::msg::lb::SetCurSel) message, then notifies the
+	/// parent with [`CBN_SELCHANGE`](crate::co::CBN::SELCHANGE) or
+	/// [`LBN_SELCHANGE`](crate::co::LBN::SELCHANGE), as if the user selected
+	/// it.
+	///
+	/// Fails with [`ERROR::NOT_SUPPORTED`](crate::co::ERROR::NOT_SUPPORTED) if
+	/// the control is neither a combo box nor a list box.
+	pub fn select(&self, index: u32) -> WinResult<()> {
+		let class_name = self.class_name();
+		let code: co::CMD = if class_name.eq_ignore_ascii_case("ComboBox") {
+			self.hwnd.SendMessage(cb::SetCurSel { index: Some(index) });
+			co::CBN::SELCHANGE.into()
+		} else if class_name.eq_ignore_ascii_case("ListBox") {
+			self.hwnd.SendMessage(lb::SetCurSel { index: Some(index) })?;
+			co::LBN::SELCHANGE.into()
+		} else {
+			return Err(co::ERROR::NOT_SUPPORTED);
+		};
+
+		self.hwnd.GetParent()?.SendMessage(wm::Command {
+			code,
+			ctrl_id: self.ctrl_id(),
+			ctrl_hwnd: Some(self.hwnd),
+		});
+		Ok(())
+	}
+}
+
+//------------------------------------------------------------------------------
+
+fn for_each_child<F>(hparent: HWND, level: usize, func: &mut F)
+	where F: FnMut(HWND, usize),
+{
+	let mut hchild = hparent.GetWindow(co::GW::CHILD).ok(); // Z-order, which is the creation order
+	while let Some(h) = hchild {
+		func(h, level);
+		for_each_child(h, level + 1, func);
+		hchild = h.GetWindow(co::GW::HWNDNEXT).ok();
+	}
+}
+
+fn snapshot_line(hwnd: HWND, level: usize, ctrl_id: u16, rc: RECT) -> String {
+	format!("{}{} id={} text={:?} rect=({},{},{},{}) style=0x{:08x}",
+		"  ".repeat(level),
+		normalized_class_name(hwnd),
+		ctrl_id,
+		hwnd.GetWindowTextStr().unwrap_or_default(),
+		rc.left, rc.top, rc.right, rc.bottom,
+		hwnd.GetWindowLongPtr(co::GWLP::STYLE) as u32)
+}
+
+fn normalized_class_name(hwnd: HWND) -> String {
+	normalize_class_name(hwnd.GetClassName().unwrap_or_default())
+}
+
+/// Replaces a class name generated by `RawBase::generate_wcx_class_name_hash`,
+/// which contains the window procedure and handle addresses.
+fn normalize_class_name(class_name: String) -> String {
+	if class_name.starts_with("WNDCLASS.") {
+		"WNDCLASS.*".to_owned()
+	} else {
+		class_name
+	}
+}
+
+fn remove_mnemonics(text: &str) -> String {
+	let mut res = String::with_capacity(text.len());
+	let mut chars = text.chars();
+	while let Some(ch) = chars.next() {
+		if ch == '&' {
+			if let Some(next) = chars.next() { // "&&" is a literal ampersand
+				res.push(next);
+			}
+		} else {
+			res.push(ch);
+		}
+	}
+	res
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn generated_class_names() {
+		assert_eq!(normalize_class_name("WNDCLASS.0x8.0x7ff6a1c0.0x0".to_owned()), "WNDCLASS.*");
+		assert_eq!(normalize_class_name("Button".to_owned()), "Button");
+		assert_eq!(normalize_class_name("MY_WNDCLASS.1".to_owned()), "MY_WNDCLASS.1");
+	}
+
+	#[test]
+	fn mnemonics() {
+		assert_eq!(remove_mnemonics("&Open"), "Open");
+		assert_eq!(remove_mnemonics("Save &as..."), "Save as...");
+		assert_eq!(remove_mnemonics("Fish && &Chips"), "Fish & Chips");
+		assert_eq!(remove_mnemonics("Trailing&"), "Trailing");
+		assert_eq!(remove_mnemonics("Plain text"), "Plain text");
+		assert_eq!(remove_mnemonics(""), "");
+	}
+}
@@ -0,0 +1,272 @@
+use std::collections::HashMap;
+use std::ptr::NonNull;
+use std::sync::Arc;
+
+use crate::aliases::WinResult;
+use crate::co;
+use crate::funcs::PostQuitMessage;
+use crate::gui::base::Base;
+use crate::gui::traits::{baseref_from_parent, Parent};
+use crate::gui::very_unsafe_cell::VeryUnsafeCell;
+use crate::handles::HWND;
+
+struct Timer {
+	func: Option<Box<dyn FnMut()>>, // taken out while running
+	one_shot: bool,
+}
+
+/// Timers of all windows, keyed by window handle and timer ID.
+static mut TIMERS: Option<HashMap<(usize, usize), Timer>> = None;
+
+// IDs of the timers set here share the namespace of the window timers, so they
+// must not collide with the u32 IDs of WindowEvents::wm_timer. On 32-bit
+// targets, the upper half of the range is reserved.
+#[cfg(target_pointer_width = "64")]
+static mut BASE_TIMER_ID: usize = 0x1_0000_0000; // beyond u32::MAX
+#[cfg(not(target_pointer_width = "64"))]
+static mut BASE_TIMER_ID: usize = 0x8000_0000;
+
+fn timers() -> &'static mut HashMap<(usize, usize), Timer> {
+	unsafe {
+		(*std::ptr::addr_of_mut!(TIMERS)).get_or_insert_with(HashMap::new)
+	}
+}
+
+fn auto_timer_id() -> usize {
+	unsafe {
+		let new_id = BASE_TIMER_ID;
+		BASE_TIMER_ID += 1;
+		new_id
+	}
+}
+
+extern "system" fn timer_proc(hwnd: HWND, _: co::WM, timer_id: usize, _: u32) {
+	let key = (hwnd.ptr as usize, timer_id);
+	let (mut func, one_shot) = match timers().get_mut(&key) {
+		Some(timer) => match timer.func.take() {
+			Some(func) => (func, timer.one_shot),
+			None => return, // already running, from a nested modal loop
+		},
+		None => return, // WM_TIMER already in the queue when the timer was killed
+	};
+
+	if one_shot {
+		timers().remove(&key);
+		hwnd.KillTimer(timer_id).ok();
+	}
+
+	func(); // may kill this or other timers, or set new ones
+
+	if let Some(timer) = timers().get_mut(&key) {
+		timer.func = Some(func); // put it back, unless killed
+	}
+}
+
+/// Sets a new timer in the given window.
+pub(crate) fn set_timer(hwnd: HWND,
+	interval_ms: u32, one_shot: bool, func: Box<dyn FnMut()>) -> WinResult<TimerHandle>
+{
+	if hwnd.is_null() {
+		panic!("Cannot set a timer before the window is created.");
+	}
+
+	let timer_id = hwnd.SetTimer(auto_timer_id(), interval_ms, Some(timer_proc))?;
+	timers().insert((hwnd.ptr as usize, timer_id), Timer { func: Some(func), one_shot });
+	Ok(TimerHandle { hwnd, timer_id })
+}
+
+/// Removes all timers of the window being destroyed. The timers themselves are
+/// killed by the system.
+pub(crate) fn remove_window_timers(hwnd: HWND) {
+	unsafe {
+		if let Some(timers) = (*std::ptr::addr_of_mut!(TIMERS)).as_mut() {
+			timers.retain(|(hwnd_key, _), _| *hwnd_key != hwnd.ptr as usize);
+		}
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Identifies a timer set with `set_timer` or `set_timeout`, like
+/// [`WindowMain::set_timer`](crate::gui::WindowMain::set_timer), and allows it
+/// to be killed.
+///
+/// Timers are automatically killed when their window is destroyed.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct TimerHandle {
+	hwnd: HWND,
+	timer_id: usize,
+}
+
+impl TimerHandle {
+	/// Tells whether the timer is still active, that is, it was not killed, and
+	/// it's not a one-shot timer which already fired.
+	pub fn is_active(&self) -> bool {
+		timers().contains_key(&(self.hwnd.ptr as usize, self.timer_id))
+	}
+
+	/// Kills the timer, which will no longer fire. Does nothing if the timer is
+	/// no longer active.
+	pub fn kill(&self) -> WinResult<()> {
+		match timers().remove(&(self.hwnd.ptr as usize, self.timer_id)) {
+			Some(_) => self.hwnd.KillTimer(self.timer_id),
+			None => Ok(()),
+		}
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Delays the execution of a closure until a given time has passed without
+/// new calls to [`trigger`](crate::gui::Debouncer::trigger). Useful to act
+/// only after the user stops typing, for example.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use winsafe::gui::{Debouncer, Edit, WindowMain};
+///
+/// let wnd: WindowMain; // initialize them somewhere...
+/// let txt: Edit;
+///
+/// let search = Debouncer::new(&wnd, 300, || println!("Searching..."));
+///
+/// txt.on().en_change({
+///     let search = search.clone();
+///     move || search.trigger()
+/// });
+/// ```
+#[derive(Clone)]
+pub struct Debouncer(Arc<VeryUnsafeCell<DebouncerObj>>);
+
+struct DebouncerObj { // actual fields of Debouncer
+	parent_ptr: NonNull<Base>,
+	delay_ms: u32,
+	func: Box<dyn FnMut()>,
+	timer: Option<TimerHandle>,
+}
+
+impl Debouncer {
+	/// Instantiates a new `Debouncer`, whose timer will be set in the given
+	/// parent window.
+	pub fn new<F>(parent: &dyn Parent, delay_ms: u32, func: F) -> Debouncer
+		where F: FnMut() + 'static,
+	{
+		Self(
+			Arc::new(VeryUnsafeCell::new(
+				DebouncerObj {
+					parent_ptr: NonNull::from(baseref_from_parent(parent)),
+					delay_ms,
+					func: Box::new(func),
+					timer: None,
+				},
+			)),
+		)
+	}
+
+	/// Restarts the delay, after which the closure will be called.
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window is not created yet.
+	pub fn trigger(&self) {
+		self.restart().unwrap_or_else(|err| PostQuitMessage(err));
+	}
+
+	/// Cancels the pending call, if any.
+	pub fn cancel(&self) -> WinResult<()> {
+		match self.0.as_mut().timer.take() {
+			Some(timer) => timer.kill(),
+			None => Ok(()),
+		}
+	}
+
+	fn restart(&self) -> WinResult<()> {
+		self.cancel()?;
+		let hparent = unsafe { *self.0.parent_ptr.as_ref().hwnd_ref() };
+		self.0.as_mut().timer = Some(set_timer(hparent, self.0.delay_ms, true, {
+			let me = self.clone();
+			Box::new(move || {
+				me.0.as_mut().timer = None;
+				(me.0.as_mut().func)();
+			})
+		})?);
+		Ok(())
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Limits the execution of a closure to once per interval, no matter how many
+/// times [`trigger`](crate::gui::Throttler::trigger) is called. The first
+/// trigger calls the closure immediately; triggers within the interval result
+/// in a single call at the end of it.
+#[derive(Clone)]
+pub struct Throttler(Arc<VeryUnsafeCell<ThrottlerObj>>);
+
+struct ThrottlerObj { // actual fields of Throttler
+	parent_ptr: NonNull<Base>,
+	interval_ms: u32,
+	func: Box<dyn FnMut()>,
+	timer: Option<TimerHandle>,
+	pending: bool,
+}
+
+impl Throttler {
+	/// Instantiates a new `Throttler`, whose timer will be set in the given
+	/// parent window.
+	pub fn new<F>(parent: &dyn Parent, interval_ms: u32, func: F) -> Throttler
+		where F: FnMut() + 'static,
+	{
+		Self(
+			Arc::new(VeryUnsafeCell::new(
+				ThrottlerObj {
+					parent_ptr: NonNull::from(baseref_from_parent(parent)),
+					interval_ms,
+					func: Box::new(func),
+					timer: None,
+					pending: false,
+				},
+			)),
+		)
+	}
+
+	/// Calls the closure now, if the interval has passed, or at the end of the
+	/// current interval.
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window is not created yet.
+	pub fn trigger(&self) {
+		if self.0.timer.is_some() {
+			self.0.as_mut().pending = true;
+		} else {
+			self.fire().unwrap_or_else(|err| PostQuitMessage(err));
+		}
+	}
+
+	/// Cancels the pending call, if any.
+	pub fn cancel(&self) -> WinResult<()> {
+		self.0.as_mut().pending = false;
+		match self.0.as_mut().timer.take() {
+			Some(timer) => timer.kill(),
+			None => Ok(()),
+		}
+	}
+
+	fn fire(&self) -> WinResult<()> {
+		let hparent = unsafe { *self.0.parent_ptr.as_ref().hwnd_ref() };
+		self.0.as_mut().pending = false;
+		self.0.as_mut().timer = Some(set_timer(hparent, self.0.interval_ms, true, {
+			let me = self.clone();
+			Box::new(move || {
+				me.0.as_mut().timer = None;
+				if me.0.pending {
+					me.fire().unwrap_or_else(|err| PostQuitMessage(err));
+				}
+			})
+		})?);
+		(self.0.as_mut().func)();
+		Ok(())
+	}
+}
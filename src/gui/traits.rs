@@ -7,6 +7,8 @@ use crate::gui::native_controls::{
 	ComboBox,
 	DateTimePicker,
 	Edit,
+	HotKey,
+	ImageView,
 	Label,
 	ListBox,
 	ListView,
@@ -14,7 +16,15 @@ use crate::gui::native_controls::{
 	RadioButton,
 	StatusBar,
 };
-use crate::gui::{WindowControl, WindowMain, WindowModal};
+use crate::gui::{
+	PropertyPage,
+	WindowControl,
+	WindowMain,
+	WindowMdiChild,
+	WindowMdiFrame,
+	WindowModal,
+	WindowModeless,
+};
 use crate::handles::HWND;
 
 /// Trait to any window which can host child controls.
@@ -32,8 +42,16 @@ pub(crate) fn baseref_from_parent(parent: &dyn Parent) -> &Base {
 		w.base_ref()
 	} else if let Some(w) = parent.as_any().downcast_ref::<WindowModal>() {
 		w.base_ref()
+	} else if let Some(w) = parent.as_any().downcast_ref::<WindowModeless>() {
+		w.base_ref()
 	} else if let Some(w) = parent.as_any().downcast_ref::<WindowControl>() {
 		w.base_ref()
+	} else if let Some(w) = parent.as_any().downcast_ref::<PropertyPage>() {
+		w.base_ref()
+	} else if let Some(w) = parent.as_any().downcast_ref::<WindowMdiFrame>() {
+		w.base_ref()
+	} else if let Some(w) = parent.as_any().downcast_ref::<WindowMdiChild>() {
+		w.base_ref()
 	} else {
 		panic!("Unknown Parent downcasting, something really bad happened.")
 	}
@@ -52,6 +70,10 @@ pub(crate) fn hwndref_from_child(child: &dyn Child) -> &HWND {
 		c.base_ref().hwnd_ref()
 	} else if let Some(c) = child.as_any().downcast_ref::<Edit>() {
 		c.base_ref().hwnd_ref()
+	} else if let Some(c) = child.as_any().downcast_ref::<HotKey>() {
+		c.base_ref().hwnd_ref()
+	} else if let Some(c) = child.as_any().downcast_ref::<ImageView>() {
+		c.base_ref().hwnd_ref()
 	} else if let Some(c) = child.as_any().downcast_ref::<Label>() {
 		c.base_ref().hwnd_ref()
 	} else if let Some(c) = child.as_any().downcast_ref::<ListBox>() {
@@ -0,0 +1,405 @@
+use std::ptr::NonNull;
+use std::sync::{Arc, Once};
+
+use crate::aliases::WinResult;
+use crate::co;
+use crate::funcs::{HIWORD, LOWORD, PostQuitMessage, RegisterWindowMessage, Shell_NotifyIcon};
+use crate::gui::base::Base;
+use crate::gui::events::{EventHandle, FuncStore};
+use crate::gui::menu::Menu;
+use crate::gui::privs::auto_ctrl_id;
+use crate::gui::traits::{baseref_from_parent, Parent};
+use crate::gui::very_unsafe_cell::VeryUnsafeCell;
+use crate::handles::{HICON, HWND};
+use crate::msg::WndMsg;
+use crate::structs::{NOTIFYICONDATA, POINT};
+
+static mut WM_TRAY_ICON: Option<WinResult<co::WM>> = None;
+
+/// Returns the private message sent by the shell to notify the tray icon
+/// events. Being registered, it doesn't collide with application messages.
+fn wm_tray_icon() -> WinResult<co::WM> {
+	static REGISTER: Once = Once::new();
+	REGISTER.call_once(|| unsafe {
+		WM_TRAY_ICON = Some(RegisterWindowMessage("WinSafe.TrayIcon"));
+	});
+	unsafe { WM_TRAY_ICON.unwrap() }
+}
+
+const NOTIFYICON_VERSION_4: u32 = 4;
+
+/// A [notification area](https://docs.microsoft.com/en-us/windows/win32/shell/notification-area)
+/// icon, also known as system tray icon, attached to a window.
+///
+/// The icon is added when the window is created, and removed when the window
+/// is destroyed. If Explorer restarts, the icon is added again.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use winsafe::gui::{MenuBuilder, TrayIcon, TrayIconOpts, WindowMain};
+/// use winsafe::HICON;
+///
+/// let wnd: WindowMain; // initialize them somewhere...
+/// let hicon: HICON;
+///
+/// let tray = TrayIcon::new(&wnd, TrayIconOpts {
+///     icon: hicon,
+///     tooltip: "My utility".to_owned(),
+///     menu: Some(MenuBuilder::new()
+///         .item("E&xit", || winsafe::PostQuitMessage(winsafe::co::ERROR::SUCCESS))
+///         .build_popup()
+///         .unwrap()),
+///     ..Default::default()
+/// });
+///
+/// tray.on().left_click(|| println!("Clicked."));
+/// ```
+#[derive(Clone)]
+pub struct TrayIcon(Arc<VeryUnsafeCell<Obj>>);
+
+struct Obj { // actual fields of TrayIcon
+	parent_ptr: NonNull<Base>,
+	opts: TrayIconOpts,
+	tray_id: u16,
+	events: TrayIconEvents,
+}
+
+unsafe impl Send for TrayIcon {}
+unsafe impl Sync for TrayIcon {}
+
+impl TrayIcon {
+	/// Instantiates a new `TrayIcon` object, to be added to the notification
+	/// area when the parent window is created.
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window is already created.
+	pub fn new(parent: &dyn Parent, opts: TrayIconOpts) -> TrayIcon {
+		let parent_ref = baseref_from_parent(parent);
+		let tray_id = auto_ctrl_id();
+
+		let me = Self(
+			Arc::new(VeryUnsafeCell::new(
+				Obj {
+					parent_ptr: NonNull::from(parent_ref),
+					opts,
+					tray_id,
+					events: TrayIconEvents {
+						funcs: VeryUnsafeCell::new(FuncStore::new()),
+					},
+				},
+			)),
+		);
+		me.default_message_handlers(parent_ref);
+		me
+	}
+
+	/// Exposes the tray icon events.
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window is already created. Events must be set
+	/// before parent window creation.
+	pub fn on(&self) -> &TrayIconEvents {
+		if !self.hparent().is_null() {
+			panic!("Cannot add event after the parent window is created.");
+		}
+		&self.0.events
+	}
+
+	/// Removes the event handler identified by the given handle, like
+	/// [`TrayIconEvents::remove`](crate::gui::TrayIconEvents::remove),
+	/// but also allowed after the parent window is created.
+	pub fn remove_event(&self, handle: EventHandle) -> bool {
+		self.0.events.remove(handle)
+	}
+
+	/// Replaces the icon.
+	pub fn set_icon(&self, hicon: HICON) -> WinResult<()> {
+		self.0.as_mut().opts.icon = hicon;
+		self.modify(co::NIF::ICON)
+	}
+
+	/// Replaces the tooltip text, displayed when the mouse hovers the icon.
+	/// Longer texts are truncated to 127 characters.
+	pub fn set_tooltip(&self, tooltip: &str) -> WinResult<()> {
+		self.0.as_mut().opts.tooltip = tooltip.to_owned();
+		self.modify(co::NIF::TIP | co::NIF::SHOWTIP)
+	}
+
+	/// Shows or hides the icon.
+	pub fn set_visible(&self, visible: bool) -> WinResult<()> {
+		self.0.as_mut().opts.visible = visible;
+		self.modify(co::NIF::STATE)
+	}
+
+	/// Replaces the popup menu displayed when the icon is right-clicked.
+	pub fn set_menu(&self, menu: Option<Menu>) {
+		self.0.as_mut().opts.menu = menu;
+	}
+
+	/// Displays a balloon notification next to the icon, with one of the system
+	/// icons: `co::NIIF::NONE`, `INFO`, `WARNING` or `ERROR`.
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window is not created yet.
+	pub fn show_balloon(&self,
+		title: &str, text: &str, icon: co::NIIF) -> WinResult<()>
+	{
+		let mut nid = self.notify_icon_data(co::NIF::INFO)?;
+		nid.set_szInfoTitle(title);
+		nid.set_szInfo(text);
+		nid.dwInfoFlags = icon;
+		self.notify(co::NIM::MODIFY, &nid)
+	}
+
+	/// Displays a toast-style notification, which carries a large version of
+	/// the tray icon itself. On Windows 10 and later, it goes to the action
+	/// center.
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window is not created yet.
+	pub fn show_toast(&self, title: &str, text: &str) -> WinResult<()> {
+		let mut nid = self.notify_icon_data(co::NIF::INFO)?;
+		nid.set_szInfoTitle(title);
+		nid.set_szInfo(text);
+		nid.dwInfoFlags = co::NIIF::USER | co::NIIF::LARGE_ICON;
+		nid.hBalloonIcon = self.0.opts.icon;
+		self.notify(co::NIM::MODIFY, &nid)
+	}
+
+	fn hparent(&self) -> HWND {
+		unsafe { *self.0.parent_ptr.as_ref().hwnd_ref() }
+	}
+
+	fn notify_icon_data(&self, flags: co::NIF) -> WinResult<NOTIFYICONDATA> {
+		let opts = &self.0.opts;
+		let mut nid = NOTIFYICONDATA::default();
+		nid.hWnd = self.hparent();
+		nid.uID = self.0.tray_id as u32;
+		nid.uFlags = flags;
+		nid.uCallbackMessage = wm_tray_icon()?;
+		nid.hIcon = opts.icon;
+		nid.set_szTip(&opts.tooltip);
+		nid.dwState = if opts.visible { co::NIS::from(0) } else { co::NIS::HIDDEN };
+		nid.dwStateMask = co::NIS::HIDDEN;
+		Ok(nid)
+	}
+
+	fn notify(&self, message: co::NIM, nid: &NOTIFYICONDATA) -> WinResult<()> {
+		if self.hparent().is_null() {
+			panic!("Cannot notify tray icon before the parent window is created.");
+		}
+		Shell_NotifyIcon(message, nid)
+	}
+
+	/// Applies the changed options, if the icon was already added.
+	fn modify(&self, flags: co::NIF) -> WinResult<()> {
+		if self.hparent().is_null() {
+			Ok(()) // will be applied when the icon is added
+		} else {
+			Shell_NotifyIcon(co::NIM::MODIFY, &self.notify_icon_data(flags)?)
+		}
+	}
+
+	fn add(&self) -> WinResult<()> {
+		let mut nid = self.notify_icon_data(
+			co::NIF::MESSAGE | co::NIF::ICON | co::NIF::TIP | co::NIF::SHOWTIP | co::NIF::STATE)?;
+		Shell_NotifyIcon(co::NIM::ADD, &nid)?;
+		nid.uTimeoutOrVersion = NOTIFYICON_VERSION_4; // coordinates in wParam, icon ID in HIWORD(lParam)
+		Shell_NotifyIcon(co::NIM::SETVERSION, &nid)
+	}
+
+	fn default_message_handlers(&self, parent_ref: &Base) {
+		let parent_events = parent_ref.privileged_events_ref();
+
+		parent_events.wm(parent_ref.create_wm(), {
+			let me = self.clone();
+			move |_| {
+				me.add().unwrap_or_else(|err| PostQuitMessage(err));
+				0
+			}
+		});
+
+		if let Ok(wm_taskbar_created) = RegisterWindowMessage("TaskbarCreated") {
+			parent_events.wm(wm_taskbar_created, {
+				let me = self.clone();
+				move |_| {
+					me.add().ok(); // Explorer restarted, the icon is gone
+					0
+				}
+			});
+		}
+
+		parent_events.wm_destroy({
+			let me = self.clone();
+			move || {
+				me.notify_icon_data(co::NIF::from(0))
+					.and_then(|nid| Shell_NotifyIcon(co::NIM::DELETE, &nid))
+					.ok();
+			}
+		});
+
+		let wm_tray = match wm_tray_icon() {
+			Ok(wm_tray) => wm_tray,
+			Err(err) => {
+				PostQuitMessage(err); // no icon events would arrive
+				return;
+			},
+		};
+		parent_events.wm(wm_tray, {
+			let me = self.clone();
+			move |p| {
+				if HIWORD(p.lparam as u32) != me.0.tray_id {
+					return 0; // another tray icon of the same window
+				}
+
+				let code = LOWORD(p.lparam as u32);
+				if let Some(event) = TrayEvent::from_code(code) {
+					me.0.events.funcs.as_mut().find_all(event, |func| func());
+				}
+
+				if co::WM(code as u32) == co::WM::CONTEXTMENU {
+					if let Some(menu) = me.0.opts.menu.as_ref() {
+						|| -> WinResult<()> {
+							let hparent = me.hparent();
+							hparent.SetForegroundWindow(); // so the menu is dismissed when clicking elsewhere
+							menu.track_popup(hparent, POINT::new(
+								LOWORD(p.wparam as u32) as i16 as i32,
+								HIWORD(p.wparam as u32) as i16 as i32,
+							))?;
+							hparent.PostMessage(WndMsg { msg_id: co::WM::NULL, wparam: 0, lparam: 0 })
+						}
+						().unwrap_or_else(|err| PostQuitMessage(err))
+					}
+				}
+				0
+			}
+		});
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// The tray icon events, each one mapped from one or more notification codes.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum TrayEvent {
+	Left,
+	Right,
+	Double,
+	Notification,
+}
+
+impl TrayEvent {
+	fn from_code(code: u16) -> Option<TrayEvent> {
+		if code == co::NIN::SELECT.into() || code == co::NIN::KEYSELECT.into() {
+			Some(Self::Left)
+		} else if code == co::WM::CONTEXTMENU.0 as u16 {
+			Some(Self::Right)
+		} else if code == co::WM::LBUTTONDBLCLK.0 as u16 {
+			Some(Self::Double)
+		} else if code == co::NIN::BALLOONUSERCLICK.into() {
+			Some(Self::Notification)
+		} else {
+			None
+		}
+	}
+}
+
+/// Exposes the events of a [`TrayIcon`](crate::gui::TrayIcon), which are
+/// routed through the events of its parent window.
+///
+/// Unlike the window events, all the closures added to the same event are
+/// executed, in the order they were added.
+pub struct TrayIconEvents {
+	funcs: VeryUnsafeCell<FuncStore<TrayEvent, Box<dyn FnMut()>>>,
+}
+
+impl TrayIconEvents {
+	fn add_tray_event<F>(&self, event: TrayEvent, func: F) -> EventHandle
+		where F: FnMut() + 'static,
+	{
+		let handle = EventHandle::next();
+		self.funcs.as_mut().insert(event, handle, Box::new(func));
+		handle
+	}
+
+	/// Removes the event handler identified by the given handle, returned when
+	/// it was added. Returns `false` if the handler was not found.
+	///
+	/// Unlike adding, removing is allowed after the parent window is created;
+	/// to do so, keep a clone of the [`TrayIcon`](crate::gui::TrayIcon) and
+	/// call [`remove_event`](crate::gui::TrayIcon::remove_event).
+	pub fn remove(&self, handle: EventHandle) -> bool {
+		self.funcs.as_mut().remove(handle)
+	}
+
+	/// The icon was clicked with the left mouse button, or selected with the
+	/// keyboard.
+	pub fn left_click<F>(&self, func: F) -> EventHandle
+		where F: FnMut() + 'static,
+	{
+		self.add_tray_event(TrayEvent::Left, func)
+	}
+
+	/// The icon was clicked with the right mouse button, or the context menu
+	/// key was pressed. Called before the popup menu, if any, is displayed, so
+	/// the closure can update the menu items.
+	pub fn right_click<F>(&self, func: F) -> EventHandle
+		where F: FnMut() + 'static,
+	{
+		self.add_tray_event(TrayEvent::Right, func)
+	}
+
+	/// The icon was double-clicked with the left mouse button.
+	pub fn double_click<F>(&self, func: F) -> EventHandle
+		where F: FnMut() + 'static,
+	{
+		self.add_tray_event(TrayEvent::Double, func)
+	}
+
+	/// A balloon or toast notification was clicked.
+	pub fn notification_click<F>(&self, func: F) -> EventHandle
+		where F: FnMut() + 'static,
+	{
+		self.add_tray_event(TrayEvent::Notification, func)
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Options to create a [`TrayIcon`](crate::gui::TrayIcon) with
+/// [`TrayIcon::new`](crate::gui::TrayIcon::new).
+pub struct TrayIconOpts {
+	/// The icon displayed in the notification area.
+	///
+	/// Defaults to none.
+	pub icon: HICON,
+	/// Text displayed when the mouse hovers the icon. Longer texts are truncated
+	/// to 127 characters.
+	///
+	/// Defaults to empty string.
+	pub tooltip: String,
+	/// Popup menu displayed when the icon is right-clicked.
+	///
+	/// Defaults to none.
+	pub menu: Option<Menu>,
+	/// Whether the icon is initially visible.
+	///
+	/// Defaults to `true`.
+	pub visible: bool,
+}
+
+impl Default for TrayIconOpts {
+	fn default() -> Self {
+		Self {
+			icon: unsafe { HICON::null_handle() },
+			tooltip: "".to_owned(),
+			menu: None,
+			visible: true,
+		}
+	}
+}
@@ -0,0 +1,168 @@
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex, Once};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::aliases::WinResult;
+use crate::co;
+use crate::funcs::RegisterWindowMessage;
+use crate::gui::base::Base;
+use crate::handles::HWND;
+use crate::msg::WndMsg;
+
+type PostedFunc = Box<dyn FnOnce() + Send>;
+
+struct Queued {
+	id: u64, // identifies a closure which must be removed if not run
+	func: PostedFunc,
+}
+
+/// Closures waiting to be run in the GUI thread, owned by each window.
+#[derive(Clone, Default)]
+pub(crate) struct UiThreadQueue(Arc<Mutex<VecDeque<Queued>>>);
+
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+impl UiThreadQueue {
+	fn push(&self, func: PostedFunc) -> u64 {
+		let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+		self.0.lock().unwrap().push_back(Queued { id, func });
+		id
+	}
+
+	fn remove(&self, id: u64) {
+		let removed = {
+			let mut queue = self.0.lock().unwrap();
+			queue.iter().position(|q| q.id == id)
+				.and_then(|idx| queue.remove(idx))
+		};
+		drop(removed); // closure dropped after the lock is released
+	}
+
+	/// Runs the queued closures, if the message is the one posted by
+	/// [`UiThreadSender`](crate::gui::UiThreadSender). The message parameters
+	/// are never read, so any other sender can, at most, wake the queue.
+	pub(crate) fn process_message(&self, msg_id: co::WM) {
+		if wm_ui_thread().ok() != Some(msg_id) {
+			return;
+		}
+		loop {
+			let queued = self.0.lock().unwrap().pop_front(); // lock released before running
+			match queued {
+				Some(queued) => (queued.func)(),
+				None => break,
+			}
+		}
+	}
+
+	/// Drops all the queued closures, which will never run because the window
+	/// is being destroyed.
+	pub(crate) fn clear(&self) {
+		let pending = std::mem::take(&mut *self.0.lock().unwrap());
+		drop(pending); // closures dropped after the lock is released
+	}
+}
+
+static mut WM_UI_THREAD: Option<WinResult<co::WM>> = None;
+
+/// Returns the private message which wakes the window to run the queued
+/// closures. Being registered, it doesn't collide with application messages.
+fn wm_ui_thread() -> WinResult<co::WM> {
+	static REGISTER: Once = Once::new();
+	REGISTER.call_once(|| unsafe {
+		WM_UI_THREAD = Some(RegisterWindowMessage("WinSafe.UiThread"));
+	});
+	unsafe { WM_UI_THREAD.unwrap() }
+}
+
+//------------------------------------------------------------------------------
+
+/// Sends closures to be run in the GUI thread, which owns the window. It can be
+/// cloned and moved to other threads, so background jobs can safely update the
+/// user interface.
+///
+/// Created with
+/// [`WindowMain::ui_thread_sender`](crate::gui::WindowMain::ui_thread_sender).
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use winsafe::gui::{Label, WindowMain};
+///
+/// let wnd: WindowMain; // initialize them somewhere...
+/// let lbl: Label;
+///
+/// let sender = wnd.ui_thread_sender();
+/// std::thread::spawn(move || {
+///     let result = 2 + 2; // some heavy work
+///     sender.post({
+///         let lbl = lbl.clone();
+///         move || lbl.set_text(&format!("Result: {}", result)).unwrap()
+///     }).unwrap();
+/// });
+/// ```
+#[derive(Clone)]
+pub struct UiThreadSender {
+	hwnd: HWND,
+	queue: UiThreadQueue,
+}
+
+unsafe impl Send for UiThreadSender {}
+unsafe impl Sync for UiThreadSender {}
+
+impl UiThreadSender {
+	pub(crate) fn new(base_ref: &Base) -> UiThreadSender {
+		Self {
+			hwnd: *base_ref.hwnd_ref(),
+			queue: base_ref.ui_thread_queue().clone(),
+		}
+	}
+
+	/// Posts the closure to the window, returning immediately. The closure will
+	/// be run in the GUI thread.
+	///
+	/// Fails if the window was already destroyed. If the window is destroyed
+	/// after the closure is posted, the closure is dropped without being run.
+	pub fn post<F>(&self, func: F) -> WinResult<()>
+		where F: FnOnce() + Send + 'static,
+	{
+		let msg_id = wm_ui_thread()?;
+		let id = self.queue.push(Box::new(func));
+
+		self.hwnd.PostMessage(WndMsg { msg_id, wparam: 0, lparam: 0 })
+			.inspect_err(|_| self.queue.remove(id)) // window is gone, release the closure
+	}
+
+	/// Runs the closure in the GUI thread, blocking until it returns, then
+	/// returns its result. If called from the GUI thread itself, the closure
+	/// is run immediately.
+	///
+	/// Like in [`post`](crate::gui::UiThreadSender::post), the closure cannot
+	/// borrow from the caller, because it may still be owned by the GUI thread
+	/// after this method returns, if the window is destroyed meanwhile.
+	///
+	/// Fails with [`co::ERROR::INVALID_WINDOW_HANDLE`](crate::co::ERROR::INVALID_WINDOW_HANDLE)
+	/// if the window was already destroyed.
+	///
+	/// **Note:** If the GUI thread is itself blocked waiting for the calling
+	/// thread, this method will deadlock.
+	pub fn invoke<F, R>(&self, func: F) -> WinResult<R>
+		where F: FnOnce() -> R + Send + 'static,
+			R: Send + 'static,
+	{
+		let msg_id = wm_ui_thread()?;
+		let ret = Arc::new(Mutex::new(None));
+		let id = self.queue.push({
+			let ret = ret.clone();
+			Box::new(move || {
+				let val = func(); // lock not held while running user code
+				*ret.lock().unwrap() = Some(val);
+			})
+		});
+
+		self.hwnd.SendMessage(WndMsg { msg_id, wparam: 0, lparam: 0 });
+		self.queue.remove(id); // not run, window is gone
+
+		let val = ret.lock().unwrap().take();
+		val.ok_or(co::ERROR::INVALID_WINDOW_HANDLE)
+	}
+}
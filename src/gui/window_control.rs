@@ -1,9 +1,11 @@
 use std::any::Any;
 
+use crate::aliases::WinResult;
 use crate::gui::base::Base;
 use crate::gui::dlg_control::DlgControl;
 use crate::gui::events::WindowEvents;
 use crate::gui::raw_control::{WindowControlOpts, RawControl};
+use crate::gui::timer::{set_timer, TimerHandle};
 use crate::gui::traits::{baseref_from_parent, Child, Parent};
 use crate::handles::HWND;
 use crate::structs::POINT;
@@ -85,9 +87,47 @@ impl WindowControl {
 	///
 	/// # Panics
 	///
-	/// Panics if the window is already created. Events must be set before window
-	/// creation.
+	/// Adding an event panics if the window is already created, so events must be
+	/// set before window creation. Events can be removed at any time.
 	pub fn on(&self) -> &WindowEvents {
 		self.base_ref().user_events_ref()
 	}
+
+	/// Sets a timer which calls the closure every `interval_ms` milliseconds,
+	/// until it's killed or the window is destroyed.
+	///
+	/// # Panics
+	///
+	/// Panics if the window is not created yet.
+	///
+	/// # Examples
+	///
+	/// ```rust,ignore
+	/// use winsafe::gui::WindowControl;
+	///
+	/// let wnd: WindowControl; // initialize it somewhere...
+	///
+	/// let autosave = wnd.set_timer(60_000, || println!("Saving...")).unwrap();
+	/// autosave.kill().unwrap(); // when no longer needed
+	/// ```
+	pub fn set_timer<F>(&self, interval_ms: u32, func: F) -> WinResult<TimerHandle>
+		where F: FnMut() + 'static,
+	{
+		set_timer(self.hwnd(), interval_ms, false, Box::new(func))
+	}
+
+	/// Sets a one-shot timer, which calls the closure once, after `delay_ms`
+	/// milliseconds, unless it's killed or the window is destroyed before.
+	///
+	/// # Panics
+	///
+	/// Panics if the window is not created yet.
+	pub fn set_timeout<F>(&self, delay_ms: u32, func: F) -> WinResult<TimerHandle>
+		where F: FnOnce() + 'static,
+	{
+		let mut func = Some(func);
+		set_timer(self.hwnd(), delay_ms, true, Box::new(move || {
+			if let Some(func) = func.take() { func() }
+		}))
+	}
 }
@@ -2,14 +2,23 @@ use std::any::Any;
 
 use crate::aliases::WinResult;
 use crate::co;
-use crate::funcs::{InitCommonControls, IsWindowsVistaOrGreater, SetProcessDPIAware};
+use crate::funcs::InitCommonControls;
 use crate::gui::base::Base;
 use crate::gui::dlg_main::DlgMain;
 use crate::gui::events::WindowEvents;
-use crate::gui::privs::{create_ui_font, delete_ui_font};
+use crate::gui::executor::add_executor_handlers;
+use crate::gui::global_hotkey::{add_hotkey_handlers, register_hotkey, unregister_hotkey};
+use crate::gui::privs::{
+	create_ui_font,
+	delete_ui_font,
+	set_process_dpi_awareness,
+};
 use crate::gui::raw_main::{WindowMainOpts, RawMain};
+use crate::gui::timer::{set_timer, TimerHandle};
 use crate::gui::traits::Parent;
+use crate::gui::ui_thread::UiThreadSender;
 use crate::handles::HWND;
+use crate::msg::wm;
 
 #[derive(Clone)]
 enum RawDlg { Raw(RawMain), Dlg(DlgMain) }
@@ -136,11 +145,14 @@ impl WindowMain {
 	/// Instantiates a new `WindowMain` object, to be created with
 	/// [`CreateWindowEx`](crate::HWND::CreateWindowEx).
 	pub fn new(opts: WindowMainOpts) -> WindowMain {
-		Self {
+		let wnd = Self {
 			raw_dlg: RawDlg::Raw(
 				RawMain::new(opts),
 			),
-		}
+		};
+		add_executor_handlers(wnd.base_ref());
+		add_hotkey_handlers(wnd.base_ref());
+		wnd
 	}
 
 	/// Instantiates a new `WindowMain` object, to be loaded from a dialog
@@ -150,11 +162,14 @@ impl WindowMain {
 		icon_id: Option<i32>,
 		accel_table_id: Option<i32>) -> WindowMain
 	{
-		Self {
+		let wnd = Self {
 			raw_dlg: RawDlg::Dlg(
 				DlgMain::new(dialog_id, icon_id, accel_table_id),
 			),
-		}
+		};
+		add_executor_handlers(wnd.base_ref());
+		add_hotkey_handlers(wnd.base_ref());
+		wnd
 	}
 
 	pub(crate) fn base_ref(&self) -> &Base {
@@ -176,12 +191,128 @@ impl WindowMain {
 	///
 	/// # Panics
 	///
-	/// Panics if the window is already created. Events must be set before window
-	/// creation.
+	/// Adding an event panics if the window is already created, so events must be
+	/// set before window creation. Events can be removed at any time.
 	pub fn on(&self) -> &WindowEvents {
 		self.base_ref().user_events_ref()
 	}
 
+	/// Sets a timer which calls the closure every `interval_ms` milliseconds,
+	/// until it's killed or the window is destroyed.
+	///
+	/// # Panics
+	///
+	/// Panics if the window is not created yet.
+	///
+	/// # Examples
+	///
+	/// ```rust,ignore
+	/// use winsafe::gui::WindowMain;
+	///
+	/// let wnd: WindowMain; // initialize it somewhere...
+	///
+	/// let autosave = wnd.set_timer(60_000, || println!("Saving...")).unwrap();
+	/// autosave.kill().unwrap(); // when no longer needed
+	/// ```
+	pub fn set_timer<F>(&self, interval_ms: u32, func: F) -> WinResult<TimerHandle>
+		where F: FnMut() + 'static,
+	{
+		set_timer(self.hwnd(), interval_ms, false, Box::new(func))
+	}
+
+	/// Sets a one-shot timer, which calls the closure once, after `delay_ms`
+	/// milliseconds, unless it's killed or the window is destroyed before.
+	///
+	/// # Panics
+	///
+	/// Panics if the window is not created yet.
+	pub fn set_timeout<F>(&self, delay_ms: u32, func: F) -> WinResult<TimerHandle>
+		where F: FnOnce() + 'static,
+	{
+		let mut func = Some(func);
+		set_timer(self.hwnd(), delay_ms, true, Box::new(move || {
+			if let Some(func) = func.take() { func() }
+		}))
+	}
+
+	/// Runs the closure in the GUI thread, by posting it to the window message
+	/// queue. This method can be called from any thread, and returns
+	/// immediately.
+	///
+	/// This is a shorthand to
+	/// [`ui_thread_sender`](crate::gui::WindowMain::ui_thread_sender)`().post(func)`.
+	///
+	/// # Panics
+	///
+	/// Panics if the window is not created yet.
+	pub fn run_ui_thread<F>(&self, func: F) -> WinResult<()>
+		where F: FnOnce() + Send + 'static,
+	{
+		self.ui_thread_sender().post(func)
+	}
+
+	/// Returns a [`UiThreadSender`](crate::gui::UiThreadSender), which can be
+	/// moved to other threads to run closures in the GUI thread.
+	///
+	/// # Panics
+	///
+	/// Panics if the window is not created yet.
+	pub fn ui_thread_sender(&self) -> UiThreadSender {
+		if self.hwnd().is_null() {
+			panic!("Cannot send to the UI thread before the window is created.");
+		}
+		UiThreadSender::new(self.base_ref())
+	}
+
+	/// Registers a system-wide hot key with
+	/// [`RegisterHotKey`](crate::HWND::RegisterHotKey), which will be delivered
+	/// to the given closure as a [`WM_HOTKEY`](crate::msg::wm::HotKey) message.
+	/// Returns the auto-generated hot key ID.
+	///
+	/// Fails with [`co::ERROR::HOTKEY_ALREADY_REGISTERED`](crate::co::ERROR::HOTKEY_ALREADY_REGISTERED)
+	/// if the key combination is taken by another application. The hot keys
+	/// still registered are unregistered when the window is destroyed.
+	///
+	/// The modifiers of a [`HotKey`](crate::gui::HotKey) control, returned by
+	/// [`HotKey::hotkey`](crate::gui::HotKey::hotkey), must be converted with
+	/// `co::MOD::from`, because their Shift and Alt bits are swapped.
+	///
+	/// # Panics
+	///
+	/// Panics if the window is not created yet.
+	///
+	/// # Examples
+	///
+	/// ```rust,ignore
+	/// use winsafe::{co, gui::{HotKey, WindowMain}};
+	///
+	/// let wnd: WindowMain; // initialize them somewhere...
+	/// let hk: HotKey;
+	///
+	/// let (vkey_code, modifiers) = hk.hotkey();
+	/// match wnd.register_global_hotkey(co::MOD::from(modifiers), vkey_code,
+	///     move |_| println!("Hot key pressed."))
+	/// {
+	///     Ok(hotkey_id) => println!("Registered {}.", hotkey_id),
+	///     Err(co::ERROR::HOTKEY_ALREADY_REGISTERED) => println!("Choose another."),
+	///     Err(err) => eprintln!("{}", err),
+	/// }
+	/// ```
+	pub fn register_global_hotkey<F>(&self,
+		modifiers: co::MOD, vkey_code: co::VK, func: F) -> WinResult<i32>
+		where F: FnMut(wm::HotKey) + 'static,
+	{
+		register_hotkey(self.hwnd(), modifiers, vkey_code, Box::new(func))
+	}
+
+	/// Unregisters a hot key registered with
+	/// [`register_global_hotkey`](crate::gui::WindowMain::register_global_hotkey),
+	/// so another one can be registered, like when the user changes it. Does
+	/// nothing if the hot key is no longer registered.
+	pub fn unregister_global_hotkey(&self, hotkey_id: i32) -> WinResult<()> {
+		unregister_hotkey(self.hwnd(), hotkey_id)
+	}
+
 	/// Physically creates the window, then runs the main application loop. This
 	/// method will block until the window is closed.
 	///
@@ -189,10 +320,7 @@ impl WindowMain {
 	///
 	/// Panics if the window is already created.
 	pub fn run_main(&self, cmd_show: Option<co::SW>) -> WinResult<()> {
-		if IsWindowsVistaOrGreater()? {
-			SetProcessDPIAware()?;
-		}
-
+		set_process_dpi_awareness()?;
 		InitCommonControls();
 		create_ui_font()?;
 
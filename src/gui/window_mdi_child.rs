@@ -0,0 +1,100 @@
+use std::any::Any;
+
+use crate::aliases::WinResult;
+use crate::gui::base::Base;
+use crate::gui::events::WindowEvents;
+use crate::gui::raw_mdi_child::{RawMdiChild, WindowMdiChildOpts};
+use crate::gui::traits::Parent;
+use crate::gui::window_mdi_frame::WindowMdiFrame;
+use crate::handles::HWND;
+use crate::msg::wm;
+
+/// An user
+/// [MDI](https://docs.microsoft.com/en-us/windows/win32/winmsg/multiple-document-interface)
+/// child window, which can handle events. It lives within the MDI client area
+/// of a [`WindowMdiFrame`](crate::gui::WindowMdiFrame).
+///
+/// Unlike other windows, a MDI child is not created along with its parent:
+/// instantiate it and call [`create`](crate::gui::WindowMdiChild::create)
+/// whenever a new document must be displayed. Keep the object alive while the
+/// window exists.
+///
+/// Implements [`Parent`](crate::gui::Parent) trait.
+#[derive(Clone)]
+pub struct WindowMdiChild {
+	raw: RawMdiChild,
+	frame: WindowMdiFrame,
+}
+
+unsafe impl Send for WindowMdiChild {}
+unsafe impl Sync for WindowMdiChild {}
+
+impl Parent for WindowMdiChild {
+	fn as_any(&self) -> &dyn Any {
+		self
+	}
+}
+
+impl WindowMdiChild {
+	/// Instantiates a new `WindowMdiChild` object, to be created with
+	/// [`CreateWindowEx`](crate::HWND::CreateWindowEx) when
+	/// [`create`](crate::gui::WindowMdiChild::create) is called.
+	pub fn new(frame: &WindowMdiFrame, opts: WindowMdiChildOpts) -> WindowMdiChild {
+		Self {
+			raw: RawMdiChild::new(frame.base_ref(), opts),
+			frame: frame.clone(),
+		}
+	}
+
+	pub(crate) fn base_ref(&self) -> &Base {
+		self.raw.base_ref()
+	}
+
+	/// Returns the underlying handle for this window.
+	///
+	/// Note that the handle is initially null, receiving an actual value only
+	/// after the window is created.
+	pub fn hwnd(&self) -> HWND {
+		*self.base_ref().hwnd_ref()
+	}
+
+	/// Exposes the window events.
+	///
+	/// # Panics
+	///
+	/// Adding an event panics if the window is already created, so events must be
+	/// set before window creation. Events can be removed at any time.
+	pub fn on(&self) -> &WindowEvents {
+		self.base_ref().user_events_ref()
+	}
+
+	/// Physically creates the window within the MDI client area of the frame.
+	///
+	/// # Panics
+	///
+	/// Panics if the window is already created, or if the frame was not created
+	/// yet.
+	pub fn create(&self) -> WinResult<()> {
+		match self.frame.hwnd_mdi_client() {
+			Some(hmdiclient) => self.raw.create(hmdiclient),
+			None => panic!("Cannot create MDI child before the MDI frame."),
+		}
+	}
+
+	/// Activates this window, by sending a
+	/// [`WM_MDIACTIVATE`](crate::msg::wm::MdiActivate) message to the MDI
+	/// client.
+	pub fn activate(&self) {
+		if let Some(hmdiclient) = self.frame.hwnd_mdi_client() {
+			hmdiclient.SendMessage(wm::MdiActivate { hwnd_child: self.hwnd() });
+		}
+	}
+
+	/// Closes this window, by sending a
+	/// [`WM_MDIDESTROY`](crate::msg::wm::MdiDestroy) message to the MDI client.
+	pub fn close(&self) {
+		if let Some(hmdiclient) = self.frame.hwnd_mdi_client() {
+			hmdiclient.SendMessage(wm::MdiDestroy { hwnd_child: self.hwnd() });
+		}
+	}
+}
@@ -0,0 +1,167 @@
+use std::any::Any;
+
+use crate::aliases::WinResult;
+use crate::co;
+use crate::funcs::InitCommonControls;
+use crate::gui::base::Base;
+use crate::gui::events::WindowEvents;
+use crate::gui::executor::add_executor_handlers;
+use crate::gui::privs::{create_ui_font, delete_ui_font, set_process_dpi_awareness};
+use crate::gui::raw_main::{RawMain, WindowMainOpts};
+use crate::gui::traits::Parent;
+use crate::gui::ui_thread::UiThreadSender;
+use crate::handles::{HMENU, HWND};
+use crate::msg::wm;
+
+/// An user main window which hosts
+/// [MDI](https://docs.microsoft.com/en-us/windows/win32/winmsg/multiple-document-interface)
+/// child windows, which can handle events. Usually, this is the first window of
+/// your application, launched directly from the `main` function.
+///
+/// The MDI client window, which is the actual parent of the
+/// [`WindowMdiChild`](crate::gui::WindowMdiChild) windows, is created
+/// automatically and fills the client area of the frame.
+///
+/// The MDI client is resized by the default frame procedure only when
+/// [`wm_size`](crate::gui::events::WindowEvents::wm_size) is not handled. So,
+/// if the frame has other controls, like a
+/// [`StatusBar`](crate::gui::StatusBar), handle `wm_size` and resize the
+/// [`hwnd_mdi_client`](crate::gui::WindowMdiFrame::hwnd_mdi_client) yourself,
+/// otherwise it will cover them.
+///
+/// Implements [`Parent`](crate::gui::Parent) trait.
+#[derive(Clone)]
+pub struct WindowMdiFrame {
+	raw: RawMain,
+}
+
+unsafe impl Send for WindowMdiFrame {}
+unsafe impl Sync for WindowMdiFrame {}
+
+impl Parent for WindowMdiFrame {
+	fn as_any(&self) -> &dyn Any {
+		self
+	}
+}
+
+impl WindowMdiFrame {
+	/// Instantiates a new `WindowMdiFrame` object, to be created with
+	/// [`CreateWindowEx`](crate::HWND::CreateWindowEx).
+	///
+	/// The `window_menu` is the submenu of the main menu where the titles of the
+	/// MDI child windows will be listed, usually the "Window" menu. Pass a null
+	/// handle if you don't want the list.
+	pub fn new(opts: WindowMainOpts, window_menu: HMENU) -> WindowMdiFrame {
+		let wnd = Self {
+			raw: RawMain::new_mdi_frame(opts, window_menu),
+		};
+		add_executor_handlers(wnd.base_ref());
+		wnd
+	}
+
+	pub(crate) fn base_ref(&self) -> &Base {
+		self.raw.base_ref()
+	}
+
+	/// Returns the underlying handle for this window.
+	///
+	/// Note that the handle is initially null, receiving an actual value only
+	/// after the window is created.
+	pub fn hwnd(&self) -> HWND {
+		*self.base_ref().hwnd_ref()
+	}
+
+	/// Returns the handle of the MDI client window, which is created along with
+	/// the frame.
+	pub fn hwnd_mdi_client(&self) -> Option<HWND> {
+		self.raw.hmdiclient()
+	}
+
+	/// Exposes the window events.
+	///
+	/// # Panics
+	///
+	/// Adding an event panics if the window is already created, so events must be
+	/// set before window creation. Events can be removed at any time.
+	pub fn on(&self) -> &WindowEvents {
+		self.base_ref().user_events_ref()
+	}
+
+	/// Physically creates the window, then runs the main application loop. This
+	/// method will block until the window is closed.
+	///
+	/// # Panics
+	///
+	/// Panics if the window is already created.
+	pub fn run_main(&self, cmd_show: Option<co::SW>) -> WinResult<()> {
+		set_process_dpi_awareness()?;
+		InitCommonControls();
+		create_ui_font()?;
+		self.raw.run_main(cmd_show)?;
+		delete_ui_font() // cleanup
+	}
+
+	/// Runs the closure in the GUI thread, by posting it to the window message
+	/// queue. This method can be called from any thread, and returns
+	/// immediately.
+	///
+	/// This is a shorthand to
+	/// [`ui_thread_sender`](crate::gui::WindowMdiFrame::ui_thread_sender)`().post(func)`.
+	///
+	/// # Panics
+	///
+	/// Panics if the window is not created yet.
+	pub fn run_ui_thread<F>(&self, func: F) -> WinResult<()>
+		where F: FnOnce() + Send + 'static,
+	{
+		self.ui_thread_sender().post(func)
+	}
+
+	/// Returns a [`UiThreadSender`](crate::gui::UiThreadSender), which can be
+	/// moved to other threads to run closures in the GUI thread.
+	///
+	/// # Panics
+	///
+	/// Panics if the window is not created yet.
+	pub fn ui_thread_sender(&self) -> UiThreadSender {
+		if self.hwnd().is_null() {
+			panic!("Cannot send to the UI thread before the window is created.");
+		}
+		UiThreadSender::new(self.base_ref())
+	}
+
+	/// Returns the handle of the active MDI child window, if any, by sending a
+	/// [`WM_MDIGETACTIVE`](crate::msg::wm::MdiGetActive) message.
+	pub fn active_child(&self) -> Option<HWND> {
+		self.hwnd_mdi_client()
+			.and_then(|hmdiclient| hmdiclient.SendMessage(wm::MdiGetActive {}))
+	}
+
+	/// Arranges the minimized MDI child windows, by sending a
+	/// [`WM_MDIICONARRANGE`](crate::msg::wm::MdiIconArrange) message.
+	pub fn arrange_icons(&self) {
+		if let Some(hmdiclient) = self.hwnd_mdi_client() {
+			hmdiclient.SendMessage(wm::MdiIconArrange {});
+		}
+	}
+
+	/// Arranges the MDI child windows in a cascade format, by sending a
+	/// [`WM_MDICASCADE`](crate::msg::wm::MdiCascade) message.
+	pub fn cascade(&self) {
+		if let Some(hmdiclient) = self.hwnd_mdi_client() {
+			hmdiclient.SendMessage(wm::MdiCascade { flags: co::MDITILE::SKIPDISABLED });
+		}
+	}
+
+	/// Arranges the MDI child windows side by side, by sending a
+	/// [`WM_MDITILE`](crate::msg::wm::MdiTile) message. If `horizontal` is
+	/// true, windows are stacked one above the other.
+	pub fn tile(&self, horizontal: bool) {
+		if let Some(hmdiclient) = self.hwnd_mdi_client() {
+			hmdiclient.SendMessage(wm::MdiTile {
+				flags: co::MDITILE::SKIPDISABLED
+					| if horizontal { co::MDITILE::HORIZONTAL } else { co::MDITILE::VERTICAL },
+			});
+		}
+	}
+}
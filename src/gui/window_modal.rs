@@ -1,10 +1,13 @@
 use std::any::Any;
+use std::future::Future;
 
 use crate::aliases::WinResult;
 use crate::gui::base::Base;
 use crate::gui::dlg_modal::DlgModal;
 use crate::gui::events::WindowEvents;
+use crate::gui::executor::run_deferred;
 use crate::gui::raw_modal::{WindowModalOpts, RawModal};
+use crate::gui::timer::{set_timer, TimerHandle};
 use crate::gui::traits::{baseref_from_parent, Parent};
 use crate::handles::HWND;
 
@@ -69,12 +72,50 @@ impl WindowModal {
 	///
 	/// # Panics
 	///
-	/// Panics if the window is already created. Events must be set before window
-	/// creation.
+	/// Adding an event panics if the window is already created, so events must be
+	/// set before window creation. Events can be removed at any time.
 	pub fn on(&self) -> &WindowEvents {
 		self.base_ref().user_events_ref()
 	}
 
+	/// Sets a timer which calls the closure every `interval_ms` milliseconds,
+	/// until it's killed or the window is destroyed.
+	///
+	/// # Panics
+	///
+	/// Panics if the window is not created yet.
+	///
+	/// # Examples
+	///
+	/// ```rust,ignore
+	/// use winsafe::gui::WindowModal;
+	///
+	/// let wnd: WindowModal; // initialize it somewhere...
+	///
+	/// let autosave = wnd.set_timer(60_000, || println!("Saving...")).unwrap();
+	/// autosave.kill().unwrap(); // when no longer needed
+	/// ```
+	pub fn set_timer<F>(&self, interval_ms: u32, func: F) -> WinResult<TimerHandle>
+		where F: FnMut() + 'static,
+	{
+		set_timer(self.hwnd(), interval_ms, false, Box::new(func))
+	}
+
+	/// Sets a one-shot timer, which calls the closure once, after `delay_ms`
+	/// milliseconds, unless it's killed or the window is destroyed before.
+	///
+	/// # Panics
+	///
+	/// Panics if the window is not created yet.
+	pub fn set_timeout<F>(&self, delay_ms: u32, func: F) -> WinResult<TimerHandle>
+		where F: FnOnce() + 'static,
+	{
+		let mut func = Some(func);
+		set_timer(self.hwnd(), delay_ms, true, Box::new(move || {
+			if let Some(func) = func.take() { func() }
+		}))
+	}
+
 	/// Physically creates the window, then runs the modal loop. This method will
 	/// block until the window is closed.
 	///
@@ -87,4 +128,18 @@ impl WindowModal {
 			RawDlg::Dlg(d) => d.show_modal(),
 		}
 	}
+
+	/// Physically creates the window, then runs the modal loop, just like
+	/// [`show_modal`](crate::gui::WindowModal::show_modal), but returns a
+	/// future which resolves when the window is closed. To be awaited within
+	/// [`spawn_local`](crate::gui::spawn_local).
+	///
+	/// # Panics
+	///
+	/// Panics if the window is already created, or if the main window is not
+	/// created yet.
+	pub fn show_modal_async(&self) -> impl Future<Output = WinResult<i32>> {
+		let me = self.clone();
+		run_deferred(move || me.show_modal())
+	}
 }
@@ -0,0 +1,173 @@
+use std::any::Any;
+
+use crate::aliases::WinResult;
+use crate::co;
+use crate::gui::base::Base;
+use crate::gui::dlg_modeless::DlgModeless;
+use crate::gui::events::WindowEvents;
+use crate::gui::raw_modeless::{RawModeless, WindowModelessOpts};
+use crate::gui::timer::{set_timer, TimerHandle};
+use crate::gui::traits::{baseref_from_parent, Parent};
+use crate::handles::HWND;
+use crate::msg::wm;
+
+#[derive(Clone)]
+enum RawDlg { Raw(RawModeless), Dlg(DlgModeless) }
+
+/// An user modeless window, which can handle events. It's owned by its parent,
+/// staying on top of it, but doesn't block it. Can be programatically created
+/// or load a dialog resource from a `.rc` script.
+///
+/// Unlike other windows, a modeless window is not created along with its
+/// parent: call [`show`](crate::gui::WindowModeless::show) whenever it must be
+/// displayed. When the user closes it, the window is destroyed, and it can be
+/// shown again later, being created anew, along with its child controls. Keep
+/// the object alive while the parent window exists.
+///
+/// Implements [`Parent`](crate::gui::Parent) trait.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use winsafe::gui::{WindowMain, WindowModeless, WindowModelessOpts};
+///
+/// let wnd: WindowMain; // initialize it somewhere...
+///
+/// let palette = WindowModeless::new(&wnd, WindowModelessOpts {
+///     title: "Tools".to_owned(),
+///     ..Default::default()
+/// });
+///
+/// wnd.on().wm_l_button_dbl_clk({
+///     let palette = palette.clone();
+///     move |_| palette.show().unwrap()
+/// });
+/// ```
+#[derive(Clone)]
+pub struct WindowModeless {
+	raw_dlg: RawDlg,
+}
+
+unsafe impl Send for WindowModeless {}
+unsafe impl Sync for WindowModeless {}
+
+impl Parent for WindowModeless {
+	fn as_any(&self) -> &dyn Any {
+		self
+	}
+}
+
+impl WindowModeless {
+	/// Instantiates a new `WindowModeless` object, to be created with
+	/// [`CreateWindowEx`](crate::HWND::CreateWindowEx) when
+	/// [`show`](crate::gui::WindowModeless::show) is called.
+	pub fn new(parent: &dyn Parent, opts: WindowModelessOpts) -> WindowModeless {
+		Self {
+			raw_dlg: RawDlg::Raw(
+				RawModeless::new(baseref_from_parent(parent), opts),
+			),
+		}
+	}
+
+	/// Instantiates a new `WindowModeless` object, to be loaded from a dialog
+	/// resource with [`CreateDialogParam`](crate::HINSTANCE::CreateDialogParam)
+	/// when [`show`](crate::gui::WindowModeless::show) is called.
+	pub fn new_dlg(parent: &dyn Parent, dialog_id: i32) -> WindowModeless {
+		Self {
+			raw_dlg: RawDlg::Dlg(
+				DlgModeless::new(baseref_from_parent(parent), dialog_id),
+			),
+		}
+	}
+
+	pub(crate) fn base_ref(&self) -> &Base {
+		match &self.raw_dlg {
+			RawDlg::Raw(r) => r.base_ref(),
+			RawDlg::Dlg(d) => d.base_ref(),
+		}
+	}
+
+	/// Returns the underlying handle for this window.
+	///
+	/// Note that the handle is null while the window is not created, that is,
+	/// before it's shown and after it's closed.
+	pub fn hwnd(&self) -> HWND {
+		*self.base_ref().hwnd_ref()
+	}
+
+	/// Exposes the window events.
+	///
+	/// # Panics
+	///
+	/// Adding an event panics if the window is already created, so events must be
+	/// set before window creation. Events can be removed at any time.
+	pub fn on(&self) -> &WindowEvents {
+		self.base_ref().user_events_ref()
+	}
+
+	/// Tells whether the window is currently created, that is, it was shown and
+	/// not closed yet.
+	pub fn is_open(&self) -> bool {
+		!self.hwnd().is_null()
+	}
+
+	/// Physically creates the window, if not created yet, then shows it and
+	/// brings it to the foreground. Returns immediately.
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window is not created yet.
+	pub fn show(&self) -> WinResult<()> {
+		if self.base_ref().parent_ref().unwrap().hwnd_ref().is_null() {
+			panic!("Cannot show modeless window before its parent is created.");
+		}
+
+		if !self.is_open() {
+			match &self.raw_dlg {
+				RawDlg::Raw(r) => r.create()?,
+				RawDlg::Dlg(d) => d.create()?,
+			}
+		}
+
+		let hwnd = self.hwnd();
+		hwnd.ShowWindow(co::SW::SHOW);
+		hwnd.SetForegroundWindow();
+		Ok(())
+	}
+
+	/// Closes the window, if created, by sending it a
+	/// [`WM_CLOSE`](crate::msg::wm::Close) message. Unless the message is
+	/// handled otherwise, the window is destroyed.
+	pub fn close(&self) {
+		if self.is_open() {
+			self.hwnd().SendMessage(wm::Close {});
+		}
+	}
+
+	/// Sets a timer which calls the closure every `interval_ms` milliseconds,
+	/// until it's killed or the window is destroyed.
+	///
+	/// # Panics
+	///
+	/// Panics if the window is not created yet.
+	pub fn set_timer<F>(&self, interval_ms: u32, func: F) -> WinResult<TimerHandle>
+		where F: FnMut() + 'static,
+	{
+		set_timer(self.hwnd(), interval_ms, false, Box::new(func))
+	}
+
+	/// Sets a one-shot timer, which calls the closure once, after `delay_ms`
+	/// milliseconds, unless it's killed or the window is destroyed before.
+	///
+	/// # Panics
+	///
+	/// Panics if the window is not created yet.
+	pub fn set_timeout<F>(&self, delay_ms: u32, func: F) -> WinResult<TimerHandle>
+		where F: FnOnce() + 'static,
+	{
+		let mut func = Some(func);
+		set_timer(self.hwnd(), delay_ms, true, Box::new(move || {
+			if let Some(func) = func.take() { func() }
+		}))
+	}
+}
@@ -0,0 +1,410 @@
+use std::ptr::NonNull;
+use std::sync::Arc;
+
+use crate::aliases::WinResult;
+use crate::co;
+use crate::enums::RegistryValue;
+use crate::funcs::PostQuitMessage;
+use crate::gui::base::Base;
+use crate::gui::layout_calc::fit_rect_in_area;
+use crate::gui::traits::{baseref_from_parent, Parent};
+use crate::gui::very_unsafe_cell::VeryUnsafeCell;
+use crate::handles::{HKEY, HMONITOR, HWND};
+use crate::structs::{MONITORINFO, RECT, WINDOWPLACEMENT};
+
+/// Version of the binary format written by
+/// [`WindowState::to_bytes`](crate::gui::WindowState::to_bytes).
+const BLOB_VERSION: u8 = 1;
+
+/// The restorable state of a window: its position and size when not maximized
+/// or minimized, whether it's maximized, and any extra values, like splitter
+/// positions or list view column widths.
+///
+/// The state can be serialized to a byte blob, or to a binary registry value.
+/// To save and restore it automatically, use
+/// [`WindowStateKeeper`](crate::gui::WindowStateKeeper).
+#[derive(Clone, Eq, PartialEq)]
+pub struct WindowState {
+	/// Position and size of the window when restored, in workspace
+	/// coordinates, as used by [`WINDOWPLACEMENT`](crate::WINDOWPLACEMENT).
+	pub normal_rect: RECT,
+	/// Whether the window is maximized.
+	pub maximized: bool,
+	/// Application-defined values, like splitter positions or column widths.
+	pub extras: Vec<i32>,
+}
+
+impl WindowState {
+	/// Retrieves the current state of the window with
+	/// [`GetWindowPlacement`](crate::HWND::GetWindowPlacement). The `extras`
+	/// field is left empty.
+	pub fn capture(hwnd: HWND) -> WinResult<WindowState> {
+		let mut wp = WINDOWPLACEMENT::default();
+		hwnd.GetWindowPlacement(&mut wp)?;
+
+		Ok(Self {
+			normal_rect: wp.rcNormalPosition,
+			maximized: wp.showCmd == co::SW::SHOWMAXIMIZED
+				|| (wp.showCmd == co::SW::SHOWMINIMIZED && wp.flags.has(co::WPF::RESTORETOMAXIMIZED)),
+			extras: Vec::default(),
+		})
+	}
+
+	/// Applies the position, size and maximized state to the window with
+	/// [`SetWindowPlacement`](crate::HWND::SetWindowPlacement). The `extras`
+	/// field is ignored.
+	///
+	/// The window is moved and shrunk, if needed, to fit the work area of the
+	/// nearest monitor, so it doesn't end up on a monitor which is no longer
+	/// connected. A hidden window is kept hidden, unless it's maximized.
+	pub fn apply(&self, hwnd: HWND) -> WinResult<()> {
+		let mut wp = WINDOWPLACEMENT::default();
+		hwnd.GetWindowPlacement(&mut wp)?;
+
+		wp.rcNormalPosition = clamp_to_monitor(hwnd, self.normal_rect)?;
+		wp.flags = co::WPF::from(0);
+		wp.showCmd = if self.maximized {
+			co::SW::SHOWMAXIMIZED
+		} else if hwnd.IsWindowVisible() {
+			co::SW::SHOWNORMAL
+		} else {
+			co::SW::HIDE // will be shown by the caller
+		};
+		hwnd.SetWindowPlacement(&wp)
+	}
+
+	/// Serializes the state into a byte blob, which can be read back with
+	/// [`from_bytes`](crate::gui::WindowState::from_bytes).
+	///
+	/// # Panics
+	///
+	/// Panics if there are more than 65535 extra values.
+	pub fn to_bytes(&self) -> Vec<u8> {
+		if self.extras.len() > u16::MAX as usize {
+			panic!("Cannot serialize more than {} extra values.", u16::MAX);
+		}
+
+		let mut blob = Vec::with_capacity(1 + 4 * 4 + 1 + 2 + 4 * self.extras.len());
+		blob.push(BLOB_VERSION);
+		for n in [self.normal_rect.left, self.normal_rect.top,
+			self.normal_rect.right, self.normal_rect.bottom].iter()
+		{
+			blob.extend_from_slice(&n.to_le_bytes());
+		}
+		blob.push(self.maximized as u8);
+		blob.extend_from_slice(&(self.extras.len() as u16).to_le_bytes());
+		for n in self.extras.iter() {
+			blob.extend_from_slice(&n.to_le_bytes());
+		}
+		blob
+	}
+
+	/// Deserializes a byte blob written by
+	/// [`to_bytes`](crate::gui::WindowState::to_bytes). Returns `None` if the
+	/// blob is malformed.
+	pub fn from_bytes(blob: &[u8]) -> Option<WindowState> {
+		let read_i32 = |off: usize| -> Option<i32> {
+			let bytes = blob.get(off..off + 4)?;
+			Some(i32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+		};
+
+		if *blob.get(0)? != BLOB_VERSION {
+			return None;
+		}
+		let normal_rect = RECT {
+			left: read_i32(1)?,
+			top: read_i32(5)?,
+			right: read_i32(9)?,
+			bottom: read_i32(13)?,
+		};
+		let maximized = *blob.get(17)? != 0;
+		let num_extras = blob.get(18..20)
+			.map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]))? as usize;
+		if blob.len() != 20 + 4 * num_extras {
+			return None;
+		}
+		let extras = (0..num_extras)
+			.map(|i| read_i32(20 + 4 * i))
+			.collect::<Option<Vec<_>>>()?;
+
+		Some(Self { normal_rect, maximized, extras })
+	}
+
+	/// Writes the state as a binary value into the registry with
+	/// [`RegSetKeyValue`](crate::HKEY::RegSetKeyValue). The subkey is created
+	/// if it doesn't exist.
+	pub fn save_to_registry(&self,
+		hkey: HKEY, sub_key: &str, value_name: &str) -> WinResult<()>
+	{
+		hkey.RegSetKeyValue(sub_key, value_name, RegistryValue::Binary(self.to_bytes()))
+	}
+
+	/// Reads the state from a binary value in the registry, with
+	/// [`RegGetValue`](crate::HKEY::RegGetValue). Returns `None` if the value
+	/// doesn't exist or is malformed.
+	pub fn load_from_registry(
+		hkey: HKEY, sub_key: &str, value_name: &str) -> WinResult<Option<WindowState>>
+	{
+		match hkey.RegGetValue(sub_key, value_name) {
+			Ok(RegistryValue::Binary(blob)) => Ok(Self::from_bytes(&blob)),
+			Ok(_) => Ok(None), // value of another type
+			Err(co::ERROR::FILE_NOT_FOUND) => Ok(None), // not saved yet
+			Err(err) => Err(err),
+		}
+	}
+}
+
+/// Moves and shrinks the rectangle, in workspace coordinates, to fit the work
+/// area of the nearest monitor.
+fn clamp_to_monitor(hwnd: HWND, rc: RECT) -> WinResult<RECT> {
+	// Workspace coordinates are offset from screen coordinates by the taskbar
+	// on the primary monitor, except for tool windows.
+	let (off_x, off_y) = if co::WS_EX(hwnd.GetWindowLongPtr(co::GWLP::EXSTYLE) as u32)
+		.has(co::WS_EX::TOOLWINDOW)
+	{
+		(0, 0)
+	} else {
+		let mut mi_primary = MONITORINFO::default();
+		HMONITOR::MonitorFromRect(&RECT::default(), co::MONITOR::DEFAULTTOPRIMARY)
+			.GetMonitorInfo(&mut mi_primary)?;
+		(mi_primary.rcWork.left - mi_primary.rcMonitor.left,
+			mi_primary.rcWork.top - mi_primary.rcMonitor.top)
+	};
+
+	let rc_screen = RECT {
+		left: rc.left + off_x,
+		top: rc.top + off_y,
+		right: rc.right + off_x,
+		bottom: rc.bottom + off_y,
+	};
+	let mut mi = MONITORINFO::default();
+	HMONITOR::MonitorFromRect(&rc_screen, co::MONITOR::DEFAULTTONEAREST)
+		.GetMonitorInfo(&mut mi)?;
+
+	let rc = fit_rect_in_area(rc_screen, mi.rcWork);
+	Ok(RECT {
+		left: rc.left - off_x,
+		top: rc.top - off_y,
+		right: rc.right - off_x,
+		bottom: rc.bottom - off_y,
+	})
+}
+
+//------------------------------------------------------------------------------
+
+/// Where a [`WindowStateKeeper`](crate::gui::WindowStateKeeper) stores the
+/// [`WindowState`](crate::gui::WindowState).
+pub enum WindowStateStorage {
+	/// A binary value in the registry, usually under `HKEY::CURRENT_USER`.
+	Registry {
+		/// The registry key, which must be open.
+		hkey: HKEY,
+		/// The subkey, created if it doesn't exist.
+		sub_key: String,
+		/// The name of the binary value.
+		value_name: String,
+	},
+	/// A byte blob loaded and saved by the given closures, to be stored
+	/// anywhere, like a file. The load closure returns `None` if nothing was
+	/// saved yet.
+	Blob {
+		/// Called when the window is created, to load the blob.
+		load: Box<dyn FnMut() -> Option<Vec<u8>>>,
+		/// Called when the window is destroyed, to save the blob.
+		save: Box<dyn FnMut(&[u8])>,
+	},
+}
+
+/// Automatically restores the [`WindowState`](crate::gui::WindowState) of a
+/// window when it's created, and saves it when it's destroyed.
+///
+/// Extra values, like splitter positions or list view column widths, can be
+/// persisted along with the window state with
+/// [`set_extras`](crate::gui::WindowStateKeeper::set_extras). Since the extras
+/// are restored when the window is created, the `WindowStateKeeper` must be
+/// instantiated after the child controls it restores.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use winsafe::HKEY;
+/// use winsafe::gui::{WindowMain, WindowStateKeeper, WindowStateStorage};
+///
+/// let wnd: WindowMain; // initialize it somewhere...
+///
+/// let _keeper = WindowStateKeeper::new(&wnd, WindowStateStorage::Registry {
+///     hkey: HKEY::CURRENT_USER,
+///     sub_key: "Software\\My Company\\My App".to_owned(),
+///     value_name: "MainWindow".to_owned(),
+/// });
+/// ```
+#[derive(Clone)]
+pub struct WindowStateKeeper(Arc<VeryUnsafeCell<Obj>>);
+
+struct Obj { // actual fields of WindowStateKeeper
+	parent_ptr: NonNull<Base>,
+	storage: WindowStateStorage,
+	extras_get: Option<Box<dyn FnMut() -> Vec<i32>>>,
+	extras_set: Option<Box<dyn FnMut(&[i32])>>,
+}
+
+unsafe impl Send for WindowStateKeeper {}
+unsafe impl Sync for WindowStateKeeper {}
+
+impl WindowStateKeeper {
+	/// Instantiates a new `WindowStateKeeper` object, which will restore the
+	/// state of the window when it's created.
+	///
+	/// # Panics
+	///
+	/// Panics if the window is already created.
+	pub fn new(parent: &dyn Parent, storage: WindowStateStorage) -> WindowStateKeeper {
+		let parent_ref = baseref_from_parent(parent);
+		if !parent_ref.hwnd_ref().is_null() {
+			panic!("Cannot add a window state keeper after the window is created.");
+		}
+
+		let me = Self(
+			Arc::new(VeryUnsafeCell::new(
+				Obj {
+					parent_ptr: NonNull::from(parent_ref),
+					storage,
+					extras_get: None,
+					extras_set: None,
+				},
+			)),
+		);
+		me.default_message_handlers(parent_ref);
+		me
+	}
+
+	/// Sets the closures to retrieve and restore the extra values. The `get`
+	/// closure is called when the window is destroyed, and `set` is called
+	/// when the window is created, if there are values saved.
+	///
+	/// # Panics
+	///
+	/// Panics if the window is already created.
+	pub fn set_extras<G, S>(&self, get: G, set: S)
+		where G: FnMut() -> Vec<i32> + 'static,
+			S: FnMut(&[i32]) + 'static,
+	{
+		if !self.hwnd().is_null() {
+			panic!("Cannot set extras after the window is created.");
+		}
+		self.0.as_mut().extras_get = Some(Box::new(get));
+		self.0.as_mut().extras_set = Some(Box::new(set));
+	}
+
+	fn hwnd(&self) -> HWND {
+		unsafe { *self.0.parent_ptr.as_ref().hwnd_ref() }
+	}
+
+	fn load(&self) -> WinResult<Option<WindowState>> {
+		match &mut self.0.as_mut().storage {
+			WindowStateStorage::Registry { hkey, sub_key, value_name } =>
+				WindowState::load_from_registry(*hkey, sub_key, value_name),
+			WindowStateStorage::Blob { load, .. } =>
+				Ok(load().and_then(|blob| WindowState::from_bytes(&blob))),
+		}
+	}
+
+	fn save(&self, state: &WindowState) -> WinResult<()> {
+		match &mut self.0.as_mut().storage {
+			WindowStateStorage::Registry { hkey, sub_key, value_name } =>
+				state.save_to_registry(*hkey, sub_key, value_name),
+			WindowStateStorage::Blob { save, .. } => {
+				save(&state.to_bytes());
+				Ok(())
+			},
+		}
+	}
+
+	fn default_message_handlers(&self, parent_ref: &Base) {
+		parent_ref.privileged_events_ref().wm(parent_ref.create_wm(), {
+			let me = self.clone();
+			move |_| {
+				|| -> WinResult<()> {
+					if let Some(state) = me.load()? {
+						state.apply(me.hwnd())?;
+						if !state.extras.is_empty() {
+							if let Some(extras_set) = me.0.as_mut().extras_set.as_mut() {
+								extras_set(&state.extras);
+							}
+						}
+					}
+					Ok(())
+				}
+				().unwrap_or_else(|err| PostQuitMessage(err));
+				0
+			}
+		});
+
+		parent_ref.privileged_events_ref().wm_destroy({
+			let me = self.clone();
+			move || {
+				|| -> WinResult<()> {
+					let mut state = WindowState::capture(me.hwnd())?;
+					if let Some(extras_get) = me.0.as_mut().extras_get.as_mut() {
+						state.extras = extras_get();
+					}
+					me.save(&state)
+				}
+				().unwrap_or_else(|err| PostQuitMessage(err));
+			}
+		});
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn state(extras: Vec<i32>) -> WindowState {
+		WindowState {
+			normal_rect: RECT { left: -1200, top: 30, right: -400, bottom: 630 },
+			maximized: true,
+			extras,
+		}
+	}
+
+	#[test]
+	fn round_trip() {
+		for state in [state(Vec::default()), state(vec![250, -1, i32::MAX, i32::MIN])].iter() {
+			let blob = state.to_bytes();
+			assert_eq!(blob.len(), 20 + 4 * state.extras.len());
+			assert!(WindowState::from_bytes(&blob).as_ref() == Some(state));
+		}
+
+		let restored = WindowState { maximized: false, ..state(vec![7]) };
+		assert!(WindowState::from_bytes(&restored.to_bytes()) == Some(restored));
+	}
+
+	#[test]
+	fn malformed_blobs() {
+		let blob = state(vec![1, 2]).to_bytes();
+
+		assert!(WindowState::from_bytes(&[]).is_none());
+		assert!(WindowState::from_bytes(&blob[..17]).is_none()); // truncated rect
+		assert!(WindowState::from_bytes(&blob[..19]).is_none()); // truncated extras count
+		assert!(WindowState::from_bytes(&blob[..blob.len() - 1]).is_none()); // truncated extra
+
+		let mut longer = blob.clone();
+		longer.push(0);
+		assert!(WindowState::from_bytes(&longer).is_none()); // trailing garbage
+
+		let mut other_version = blob.clone();
+		other_version[0] = BLOB_VERSION + 1;
+		assert!(WindowState::from_bytes(&other_version).is_none());
+
+		let mut wrong_count = blob;
+		wrong_count[18] = 3; // 3 extras declared, 2 present
+		assert!(WindowState::from_bytes(&wrong_count).is_none());
+	}
+
+	#[test]
+	#[should_panic]
+	fn too_many_extras() {
+		state(vec![0; u16::MAX as usize + 1]).to_bytes();
+	}
+}
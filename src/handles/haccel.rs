@@ -19,7 +19,7 @@ impl HACCEL {
 		match ptr_as_opt(
 			unsafe {
 				user32::CreateAcceleratorTableW(
-					&mut paccel[0] as *mut _ as *mut _,
+					paccel.as_mut_ptr() as *mut _,
 					paccel.len() as i32,
 				)
 			},
@@ -3,7 +3,7 @@
 use crate::aliases::WinResult;
 use crate::co;
 use crate::ffi::user32;
-use crate::privs::bool_to_winresult;
+use crate::privs::{bool_to_winresult, ptr_as_opt};
 
 handle_type! {
 	/// Handle to a
@@ -12,6 +12,13 @@ handle_type! {
 }
 
 impl HCURSOR {
+	/// [`SetCursor`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setcursor)
+	/// method.
+	pub fn SetCursor(self) -> Option<HCURSOR> {
+		ptr_as_opt(unsafe { user32::SetCursor(self.ptr) })
+			.map(|ptr| Self { ptr })
+	}
+
 	/// [`SetSystemCursor`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setsystemcursor)
 	/// method.
 	pub fn SetSystemCursor(self, id: co::OCR) -> WinResult<()> {
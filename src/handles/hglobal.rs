@@ -0,0 +1,72 @@
+#![allow(non_snake_case)]
+
+use std::ffi::c_void;
+
+use crate::aliases::WinResult;
+use crate::co;
+use crate::ffi::kernel32;
+use crate::funcs::{GetLastError, SetLastError};
+use crate::privs::ptr_as_opt;
+
+handle_type! {
+	/// Handle to a
+	/// [global memory block](https://docs.microsoft.com/en-us/windows/win32/winprog/windows-data-types#hglobal).
+	HGLOBAL
+}
+
+impl HGLOBAL {
+	/// [`GlobalAlloc`](https://docs.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-globalalloc)
+	/// static method.
+	///
+	/// **Note:** Must be paired with a
+	/// [`GlobalFree`](crate::HGLOBAL::GlobalFree) call, unless the ownership
+	/// of the memory block is transferred to the system.
+	pub fn GlobalAlloc(uFlags: co::GMEM, dwBytes: usize) -> WinResult<HGLOBAL> {
+		ptr_as_opt(unsafe { kernel32::GlobalAlloc(uFlags.0, dwBytes) })
+			.map(|ptr| Self { ptr })
+			.ok_or_else(|| GetLastError())
+	}
+
+	/// [`GlobalFree`](https://docs.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-globalfree)
+	/// method.
+	pub fn GlobalFree(self) -> WinResult<()> {
+		match ptr_as_opt(unsafe { kernel32::GlobalFree(self.ptr) }) {
+			Some(_) => Err(GetLastError()),
+			None => Ok(()),
+		}
+	}
+
+	/// [`GlobalLock`](https://docs.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-globallock)
+	/// method.
+	///
+	/// **Note:** Must be paired with a
+	/// [`GlobalUnlock`](crate::HGLOBAL::GlobalUnlock) call.
+	pub fn GlobalLock(self) -> WinResult<*mut c_void> {
+		ptr_as_opt(unsafe { kernel32::GlobalLock(self.ptr) })
+			.ok_or_else(|| GetLastError())
+	}
+
+	/// [`GlobalSize`](https://docs.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-globalsize)
+	/// method.
+	pub fn GlobalSize(self) -> WinResult<usize> {
+		match unsafe { kernel32::GlobalSize(self.ptr) } {
+			0 => Err(GetLastError()),
+			sz => Ok(sz),
+		}
+	}
+
+	/// [`GlobalUnlock`](https://docs.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-globalunlock)
+	/// method.
+	///
+	/// Returns `true` if the memory block is still locked.
+	pub fn GlobalUnlock(self) -> WinResult<bool> {
+		SetLastError(co::ERROR::SUCCESS);
+		match unsafe { kernel32::GlobalUnlock(self.ptr) } {
+			0 => match GetLastError() {
+				co::ERROR::SUCCESS => Ok(false), // block is now unlocked
+				err => Err(err),
+			},
+			_ => Ok(true),
+		}
+	}
+}
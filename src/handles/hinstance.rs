@@ -1,5 +1,7 @@
 #![allow(non_snake_case)]
 
+use std::ffi::c_void;
+
 use crate::aliases::{DLGPROC, WinResult};
 use crate::co;
 use crate::enums::{IdIdcStr, IdIdiStr, IdStr};
@@ -132,6 +134,21 @@ impl HINSTANCE {
 		}
 	}
 
+	/// [`GetProcAddress`](https://docs.microsoft.com/en-us/windows/win32/api/libloaderapi/nf-libloaderapi-getprocaddress)
+	/// method.
+	///
+	/// The returned pointer must be transmuted to the correct function type
+	/// before being called.
+	pub fn GetProcAddress(self, lpProcName: &str) -> WinResult<*const c_void> {
+		let proc_name = lpProcName.bytes().chain(std::iter::once(0)).collect::<Vec<_>>(); // null-terminated ANSI
+		match unsafe {
+			kernel32::GetProcAddress(self.ptr, proc_name.as_ptr())
+		} {
+			ptr if ptr.is_null() => Err(GetLastError()),
+			ptr => Ok(ptr),
+		}
+	}
+
 	/// [`LoadAccelerators`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-loadacceleratorsw)
 	/// method.
 	pub fn LoadAccelerators(self, lpTableName: IdStr) -> WinResult<HACCEL> {
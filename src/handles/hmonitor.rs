@@ -0,0 +1,46 @@
+#![allow(non_snake_case)]
+
+use crate::aliases::WinResult;
+use crate::co;
+use crate::ffi::user32;
+use crate::handles::HWND;
+use crate::privs::bool_to_winresult;
+use crate::structs::{MONITORINFO, RECT};
+
+handle_type! {
+	/// Handle to a
+	/// [display monitor](https://docs.microsoft.com/en-us/windows/win32/gdi/hmonitor-and-the-device-context).
+	HMONITOR
+}
+
+impl HMONITOR {
+	/// [`GetMonitorInfo`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getmonitorinfow)
+	/// method.
+	pub fn GetMonitorInfo(self, lpmi: &mut MONITORINFO) -> WinResult<()> {
+		bool_to_winresult(
+			unsafe { user32::GetMonitorInfoW(self.ptr, lpmi as *mut _ as *mut _) },
+		)
+	}
+
+	/// [`MonitorFromRect`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-monitorfromrect)
+	/// static method.
+	///
+	/// Returns a null handle if no monitor intersects the rectangle and
+	/// `co::MONITOR::DEFAULTTONULL` was passed.
+	pub fn MonitorFromRect(lprc: &RECT, dwFlags: co::MONITOR) -> HMONITOR {
+		Self {
+			ptr: unsafe { user32::MonitorFromRect(lprc as *const _ as *const _, dwFlags.0) },
+		}
+	}
+
+	/// [`MonitorFromWindow`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-monitorfromwindow)
+	/// static method.
+	///
+	/// Returns a null handle if the window doesn't intersect any monitor and
+	/// `co::MONITOR::DEFAULTTONULL` was passed.
+	pub fn MonitorFromWindow(hwnd: HWND, dwFlags: co::MONITOR) -> HMONITOR {
+		Self {
+			ptr: unsafe { user32::MonitorFromWindow(hwnd.ptr, dwFlags.0) },
+		}
+	}
+}
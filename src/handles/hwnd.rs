@@ -1,13 +1,13 @@
 #![allow(non_snake_case)]
 
-use crate::aliases::{SUBCLASSPROC, WinResult, WNDENUMPROC};
+use crate::aliases::{SUBCLASSPROC, TIMERPROC, WinResult, WNDENUMPROC};
 use crate::co;
 use crate::enums::{AtomStr, HwndPlace, IdMenu, IdPos};
-use crate::ffi::{comctl32, user32, uxtheme};
+use crate::ffi::{comctl32, HANDLE, shell32, user32, uxtheme};
 use crate::funcs::{GetLastError, SetLastError};
 use crate::handles::{HACCEL, HDC, HINSTANCE, HMENU, HRGN, HTHEME};
 use crate::msg::MsgSend;
-use crate::privs::{bool_to_winresult, ptr_as_opt};
+use crate::privs::{bool_to_winresult, ptr_as_opt, user32_proc};
 use crate::structs::{
 	MSG,
 	PAINTSTRUCT,
@@ -32,6 +32,16 @@ impl HWND {
 		HINSTANCE { ptr: self.GetWindowLongPtr(co::GWLP::HINSTANCE) as *mut _ }
 	}
 
+	/// [`AddClipboardFormatListener`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-addclipboardformatlistener)
+	/// method.
+	///
+	/// **Note:** Must be paired with a
+	/// [`RemoveClipboardFormatListener`](crate::HWND::RemoveClipboardFormatListener)
+	/// call.
+	pub fn AddClipboardFormatListener(self) -> WinResult<()> {
+		bool_to_winresult(unsafe { user32::AddClipboardFormatListener(self.ptr) })
+	}
+
 	/// [`ArrangeIconicWindows`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-arrangeiconicwindows)
 	/// method.
 	pub fn ArrangeIconicWindows(self) -> WinResult<u32> {
@@ -161,6 +171,39 @@ impl HWND {
 		)
 	}
 
+	/// [`DefFrameProc`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-defframeprocw)
+	/// method.
+	pub fn DefFrameProc<M: MsgSend>(self,
+		hWndMDIClient: Option<HWND>, uMsg: M) -> M::RetType
+	{
+		let wmAny = uMsg.as_generic_wm();
+		uMsg.convert_ret(
+			unsafe {
+				user32::DefFrameProcW(
+					self.ptr,
+					match hWndMDIClient {
+						Some(h) => h.ptr,
+						None => std::ptr::null_mut(),
+					},
+					wmAny.msg_id.0, wmAny.wparam, wmAny.lparam,
+				)
+			},
+		)
+	}
+
+	/// [`DefMDIChildProc`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-defmdichildprocw)
+	/// method.
+	pub fn DefMDIChildProc<M: MsgSend>(self, uMsg: M) -> M::RetType {
+		let wmAny = uMsg.as_generic_wm();
+		uMsg.convert_ret(
+			unsafe {
+				user32::DefMDIChildProcW(
+					self.ptr, wmAny.msg_id.0, wmAny.wparam, wmAny.lparam,
+				)
+			},
+		)
+	}
+
 	/// [`DefWindowProc`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-defwindowprocw)
 	/// method.
 	///
@@ -184,6 +227,12 @@ impl HWND {
 		unsafe { user32::DestroyWindow(self.ptr); }
 	}
 
+	/// [`DragAcceptFiles`](https://docs.microsoft.com/en-us/windows/win32/api/shellapi/nf-shellapi-dragacceptfiles)
+	/// method.
+	pub fn DragAcceptFiles(self, fAccept: bool) {
+		unsafe { shell32::DragAcceptFiles(self.ptr, fAccept as i32); }
+	}
+
 	/// [`EnableWindow`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-enablewindow)
 	/// method.
 	pub fn EnableWindow(self, bEnable: bool) -> bool {
@@ -291,6 +340,18 @@ impl HWND {
 		unsafe { user32::GetClassLongPtrW(self.ptr, nIndex.0) }
 	}
 
+	/// [`GetClassName`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getclassnamew)
+	/// method.
+	pub fn GetClassName(self) -> WinResult<String> {
+		let mut buf = WString::new_alloc_buffer(256 + 1); // according to WNDCLASSEX docs
+		match unsafe {
+			user32::GetClassNameW(self.ptr, buf.as_mut_ptr(), buf.buffer_size() as i32)
+		} {
+			0 => Err(GetLastError()),
+			_ => Ok(buf.to_string()),
+		}
+	}
+
 	/// [`GetClientRect`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getclientrect)
 	/// method.
 	pub fn GetClientRect(self) -> WinResult<RECT> {
@@ -344,6 +405,22 @@ impl HWND {
 		}
 	}
 
+	/// [`GetDpiForWindow`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getdpiforwindow)
+	/// method.
+	///
+	/// Available since Windows 10 version 1607. The function is resolved at
+	/// runtime, and fails with
+	/// [`ERROR::PROC_NOT_FOUND`](crate::co::ERROR::PROC_NOT_FOUND) on older
+	/// systems.
+	pub fn GetDpiForWindow(self) -> WinResult<u32> {
+		let func: extern "system" fn(HANDLE) -> u32 =
+			unsafe { std::mem::transmute(user32_proc("GetDpiForWindow")?) };
+		match func(self.ptr) {
+			0 => Err(co::ERROR::INVALID_WINDOW_HANDLE),
+			dpi => Ok(dpi),
+		}
+	}
+
 	/// [`GetFocus`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getfocus)
 	/// static method.
 	pub fn GetFocus() -> Option<HWND> {
@@ -705,6 +782,12 @@ impl HWND {
 		unsafe { user32::IsZoomed(self.ptr) != 0 }
 	}
 
+	/// [`KillTimer`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-killtimer)
+	/// method.
+	pub fn KillTimer(self, uIDEvent: usize) -> WinResult<()> {
+		bool_to_winresult(unsafe { user32::KillTimer(self.ptr, uIDEvent) })
+	}
+
 	/// [`MapDialogRect`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-mapdialogrect)
 	/// method.
 	pub fn MapDialogRect(self, lpRect: &mut RECT) -> WinResult<()> {
@@ -766,6 +849,15 @@ impl HWND {
 		)
 	}
 
+	/// [`OpenClipboard`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-openclipboard)
+	/// method.
+	///
+	/// **Note:** Must be paired with a [`CloseClipboard`](crate::CloseClipboard)
+	/// call.
+	pub fn OpenClipboard(self) -> WinResult<()> {
+		bool_to_winresult(unsafe { user32::OpenClipboard(self.ptr) })
+	}
+
 	/// [`OpenThemeData`](https://docs.microsoft.com/en-us/windows/win32/api/uxtheme/nf-uxtheme-openthemedata)
 	/// method.
 	///
@@ -843,6 +935,23 @@ impl HWND {
 		)
 	}
 
+	/// [`RegisterHotKey`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerhotkey)
+	/// method.
+	///
+	/// **Note:** Must be paired with an
+	/// [`UnregisterHotKey`](crate::HWND::UnregisterHotKey) call.
+	pub fn RegisterHotKey(self,
+		id: i32, fsModifiers: co::MOD, vk: co::VK) -> WinResult<()>
+	{
+		bool_to_winresult(
+			unsafe {
+				user32::RegisterHotKey(
+					self.ptr, id, fsModifiers.0 as u32, vk.0 as u32,
+				)
+			},
+		)
+	}
+
 	/// [`ReleaseDC`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-releasedc)
 	/// method.
 	pub fn ReleaseDC(self, hDC: HDC) -> WinResult<()> {
@@ -851,6 +960,12 @@ impl HWND {
 		)
 	}
 
+	/// [`RemoveClipboardFormatListener`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-removeclipboardformatlistener)
+	/// method.
+	pub fn RemoveClipboardFormatListener(self) -> WinResult<()> {
+		bool_to_winresult(unsafe { user32::RemoveClipboardFormatListener(self.ptr) })
+	}
+
 	/// [`RemoveWindowSubclass`](https://docs.microsoft.com/en-us/windows/win32/api/commctrl/nf-commctrl-removewindowsubclass)
 	/// method.
 	pub fn RemoveWindowSubclass(self,
@@ -981,6 +1096,13 @@ impl HWND {
 		)
 	}
 
+	/// [`SetCapture`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setcapture)
+	/// method.
+	pub fn SetCapture(self) -> Option<HWND> {
+		ptr_as_opt(unsafe { user32::SetCapture(self.ptr) })
+			.map(|ptr| Self { ptr })
+	}
+
 	/// [`SetFocus`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setfocus)
 	/// method.
 	pub fn SetFocus(self) -> Option<HWND> {
@@ -1008,6 +1130,24 @@ impl HWND {
 		}
 	}
 
+	/// [`SetTimer`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-settimer)
+	/// method.
+	pub fn SetTimer(self, nIDEvent: usize,
+		uElapse: u32, lpTimerFunc: Option<TIMERPROC>) -> WinResult<usize>
+	{
+		match unsafe {
+			user32::SetTimer(
+				self.ptr,
+				nIDEvent,
+				uElapse,
+				lpTimerFunc.map_or(std::ptr::null(), |lp| lp as *const _),
+			)
+		} {
+			0 => Err(GetLastError()),
+			tid => Ok(tid),
+		}
+	}
+
 	/// [`SetWindowDisplayAffinity`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setwindowdisplayaffinity)
 	/// method.
 	pub fn SetWindowDisplayAffinity(self, dwAffinity: co::WDA) -> WinResult<()> {
@@ -1115,6 +1255,22 @@ impl HWND {
 		}
 	}
 
+	/// [`TranslateMDISysAccel`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-translatemdisysaccel)
+	/// method.
+	///
+	/// This method must be called on the MDI client window.
+	pub fn TranslateMDISysAccel(self, lpMsg: &mut MSG) -> bool {
+		unsafe {
+			user32::TranslateMDISysAccel(self.ptr, lpMsg as *mut _ as *mut _) != 0
+		}
+	}
+
+	/// [`UnregisterHotKey`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-unregisterhotkey)
+	/// method.
+	pub fn UnregisterHotKey(self, id: i32) -> WinResult<()> {
+		bool_to_winresult(unsafe { user32::UnregisterHotKey(self.ptr, id) })
+	}
+
 	/// [`UpdateWindow`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-updatewindow)
 	/// method.
 	pub fn UpdateWindow(self) -> WinResult<()> {
@@ -11,6 +11,7 @@ mod hdc;
 mod hdrop;
 mod hdwp;
 mod hfont;
+mod hglobal;
 mod hhook;
 mod hicon;
 mod himagelist;
@@ -18,6 +19,7 @@ mod hinstance;
 mod hkey;
 mod hlocal;
 mod hmenu;
+mod hmonitor;
 mod hrgn;
 mod htheme;
 mod hwnd;
@@ -30,6 +32,7 @@ pub use hdc::HDC;
 pub use hdrop::HDROP;
 pub use hdwp::HDWP;
 pub use hfont::HFONT;
+pub use hglobal::HGLOBAL;
 pub use hhook::HHOOK;
 pub use hicon::HICON;
 pub use himagelist::HIMAGELIST;
@@ -37,6 +40,7 @@ pub use hinstance::HINSTANCE;
 pub use hkey::HKEY;
 pub use hlocal::HLOCAL;
 pub use hmenu::HMENU;
+pub use hmonitor::HMONITOR;
 pub use hrgn::HRGN;
 pub use htheme::HTHEME;
 pub use hwnd::HWND;
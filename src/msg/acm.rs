@@ -0,0 +1,125 @@
+//! Animation control
+//! [messages](https://docs.microsoft.com/en-us/windows/win32/controls/bumper-animation-control-reference-messages),
+//! whose constants have [`ACM`](crate::co::ACM) prefix.
+
+use crate::aliases::WinResult;
+use crate::co;
+use crate::enums::IdStr;
+use crate::funcs::MAKEDWORD;
+use crate::handles::HINSTANCE;
+use crate::msg::{MsgSend, WndMsg};
+
+/// [`ACM_ISPLAYING`](https://docs.microsoft.com/en-us/windows/win32/controls/acm-isplaying)
+/// message, which has no parameters.
+///
+/// Return type: `bool`.
+pub struct IsPlaying {}
+
+impl MsgSend for IsPlaying {
+	type RetType = bool;
+
+	fn convert_ret(&self, v: isize) -> Self::RetType {
+		v != 0
+	}
+
+	fn as_generic_wm(&self) -> WndMsg {
+		WndMsg {
+			msg_id: co::ACM::ISPLAYING.into(),
+			wparam: 0,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`ACM_OPEN`](https://docs.microsoft.com/en-us/windows/win32/controls/acm-open)
+/// message parameters.
+///
+/// If `hinstance` is `None`, `name` is the path of an AVI file.
+///
+/// Return type: `WinResult<()>`.
+pub struct Open {
+	pub hinstance: Option<HINSTANCE>,
+	pub name: IdStr,
+}
+
+impl MsgSend for Open {
+	type RetType = WinResult<()>;
+
+	fn convert_ret(&self, v: isize) -> Self::RetType {
+		match v {
+			0 => Err(co::ERROR::BAD_ARGUMENTS),
+			_ => Ok(()),
+		}
+	}
+
+	fn as_generic_wm(&self) -> WndMsg {
+		WndMsg {
+			msg_id: co::ACM::OPEN.into(),
+			wparam: match self.hinstance {
+				Some(hinst) => hinst.ptr as usize,
+				None => 0,
+			},
+			lparam: self.name.as_ptr() as isize,
+		}
+	}
+}
+
+/// [`ACM_PLAY`](https://docs.microsoft.com/en-us/windows/win32/controls/acm-play)
+/// message parameters.
+///
+/// Use `None` in `repeat_count` to replay the clip indefinitely, and
+/// `0xffff` in `to_frame` to play until the last frame.
+///
+/// Return type: `WinResult<()>`.
+pub struct Play {
+	pub repeat_count: Option<u32>,
+	pub from_frame: u16,
+	pub to_frame: u16,
+}
+
+impl MsgSend for Play {
+	type RetType = WinResult<()>;
+
+	fn convert_ret(&self, v: isize) -> Self::RetType {
+		match v {
+			0 => Err(co::ERROR::BAD_ARGUMENTS),
+			_ => Ok(()),
+		}
+	}
+
+	fn as_generic_wm(&self) -> WndMsg {
+		WndMsg {
+			msg_id: co::ACM::PLAY.into(),
+			wparam: match self.repeat_count {
+				Some(count) => count as usize,
+				None => -1isize as usize,
+			},
+			lparam: MAKEDWORD(self.from_frame, self.to_frame) as isize,
+		}
+	}
+}
+
+/// [`ACM_STOP`](https://docs.microsoft.com/en-us/windows/win32/controls/acm-stop)
+/// message, which has no parameters.
+///
+/// Return type: `WinResult<()>`.
+pub struct Stop {}
+
+impl MsgSend for Stop {
+	type RetType = WinResult<()>;
+
+	fn convert_ret(&self, v: isize) -> Self::RetType {
+		match v {
+			0 => Err(co::ERROR::BAD_ARGUMENTS),
+			_ => Ok(()),
+		}
+	}
+
+	fn as_generic_wm(&self) -> WndMsg {
+		WndMsg {
+			msg_id: co::ACM::STOP.into(),
+			wparam: 0,
+			lparam: 0,
+		}
+	}
+}
@@ -0,0 +1,55 @@
+//! Hot key control
+//! [messages](https://docs.microsoft.com/en-us/windows/win32/controls/bumper-hot-key-control-reference-messages),
+//! whose constants have [`HKM`](crate::co::HKM) prefix.
+
+use crate::co;
+use crate::funcs::{HIBYTE, LOBYTE, LOWORD, MAKEWORD};
+use crate::msg::{MsgSend, WndMsg};
+
+/// [`HKM_GETHOTKEY`](https://docs.microsoft.com/en-us/windows/win32/controls/hkm-gethotkey)
+/// message, which has no parameters.
+///
+/// Return type: `(co::VK, co::HOTKEYF)`.
+pub struct GetHotKey {}
+
+impl MsgSend for GetHotKey {
+	type RetType = (co::VK, co::HOTKEYF);
+
+	fn convert_ret(&self, v: isize) -> Self::RetType {
+		let w = LOWORD(v as u32);
+		(co::VK(LOBYTE(w) as u16), co::HOTKEYF(HIBYTE(w)))
+	}
+
+	fn as_generic_wm(&self) -> WndMsg {
+		WndMsg {
+			msg_id: co::HKM::GETHOTKEY.into(),
+			wparam: 0,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`HKM_SETHOTKEY`](https://docs.microsoft.com/en-us/windows/win32/controls/hkm-sethotkey)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct SetHotKey {
+	pub vkey_code: co::VK,
+	pub modifiers: co::HOTKEYF,
+}
+
+impl MsgSend for SetHotKey {
+	type RetType = ();
+
+	fn convert_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&self) -> WndMsg {
+		WndMsg {
+			msg_id: co::HKM::SETHOTKEY.into(),
+			wparam: MAKEWORD(self.vkey_code.0 as u8, self.modifiers.0) as usize,
+			lparam: 0,
+		}
+	}
+}
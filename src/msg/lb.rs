@@ -732,3 +732,33 @@ impl MsgSend for SetAnchorIndex {
 		}
 	}
 }
+
+/// [`LB_SETCURSEL`](https://docs.microsoft.com/en-us/windows/win32/controls/lb-setcursel)
+/// message parameters.
+///
+/// Return type: `WinResult<()>`.
+pub struct SetCurSel {
+	pub index: Option<u32>,
+}
+
+impl MsgSend for SetCurSel {
+	type RetType = WinResult<()>;
+
+	fn convert_ret(&self, v: isize) -> Self::RetType {
+		match (v as i32, self.index) {
+			(LB_ERR, Some(_)) => Err(co::ERROR::BAD_ARGUMENTS), // clearing the selection also returns LB_ERR
+			_ => Ok(()),
+		}
+	}
+
+	fn as_generic_wm(&self) -> WndMsg {
+		WndMsg {
+			msg_id: co::LB::SETCURSEL.into(),
+			wparam: match self.index {
+				Some(index) => index as i32,
+				None => -1,
+			} as usize,
+			lparam: 0,
+		}
+	}
+}
@@ -208,7 +208,7 @@ pub fn point_to_lp(p: POINT) -> isize {
 /// Converts the `LPARAM` field to a `POINT`.
 pub fn lp_to_point(p: WndMsg) -> POINT {
 	POINT::new(
-		LOWORD(p.lparam as u32) as i32,
-		HIWORD(p.lparam as u32) as i32,
+		LOWORD(p.lparam as u32) as i16 as i32, // signed, like GET_X_LPARAM
+		HIWORD(p.lparam as u32) as i16 as i32,
 	)
 }
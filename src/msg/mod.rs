@@ -39,14 +39,17 @@ mod macros;
 mod message;
 pub use message::{MsgSend, MsgSendRecv, WndMsg};
 
+pub mod acm;
 pub mod bm;
 pub mod cb;
 pub mod dtm;
 pub mod em;
 pub mod hdm;
+pub mod hkm;
 pub mod lb;
 pub mod lvm;
 pub mod mcm;
+pub mod psm;
 pub mod sb;
 pub mod stm;
 pub mod wm;
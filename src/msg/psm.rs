@@ -0,0 +1,200 @@
+//! Property sheet
+//! [messages](https://docs.microsoft.com/en-us/windows/win32/controls/bumper-property-sheets-reference-messages),
+//! whose constants have [`PSM`](crate::co::PSM) prefix.
+
+use crate::co;
+use crate::handles::HWND;
+use crate::msg::{MsgSend, WndMsg};
+use crate::privs::ptr_as_opt;
+use crate::WString;
+
+/// [`PSM_CHANGED`](https://docs.microsoft.com/en-us/windows/win32/controls/psm-changed)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct Changed {
+	pub hpage: HWND,
+}
+
+impl MsgSend for Changed {
+	type RetType = ();
+
+	fn convert_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&self) -> WndMsg {
+		WndMsg {
+			msg_id: co::PSM::CHANGED.into(),
+			wparam: self.hpage.ptr as usize,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`PSM_GETCURRENTPAGEHWND`](https://docs.microsoft.com/en-us/windows/win32/controls/psm-getcurrentpagehwnd)
+/// message, which has no parameters.
+///
+/// Return type: `Option<HWND>`.
+pub struct GetCurrentPageHwnd {}
+
+impl MsgSend for GetCurrentPageHwnd {
+	type RetType = Option<HWND>;
+
+	fn convert_ret(&self, v: isize) -> Self::RetType {
+		ptr_as_opt(v as *mut _).map(|ptr| HWND { ptr })
+	}
+
+	fn as_generic_wm(&self) -> WndMsg {
+		WndMsg {
+			msg_id: co::PSM::GETCURRENTPAGEHWND.into(),
+			wparam: 0,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`PSM_PRESSBUTTON`](https://docs.microsoft.com/en-us/windows/win32/controls/psm-pressbutton)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct PressButton {
+	pub button: co::PSBTN,
+}
+
+impl MsgSend for PressButton {
+	type RetType = ();
+
+	fn convert_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&self) -> WndMsg {
+		WndMsg {
+			msg_id: co::PSM::PRESSBUTTON.into(),
+			wparam: self.button.0 as usize,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`PSM_SETCURSEL`](https://docs.microsoft.com/en-us/windows/win32/controls/psm-setcursel)
+/// message parameters.
+///
+/// Return type: `bool`.
+pub struct SetCurSel {
+	pub index: u32,
+}
+
+impl MsgSend for SetCurSel {
+	type RetType = bool;
+
+	fn convert_ret(&self, v: isize) -> Self::RetType {
+		v != 0
+	}
+
+	fn as_generic_wm(&self) -> WndMsg {
+		WndMsg {
+			msg_id: co::PSM::SETCURSEL.into(),
+			wparam: self.index as usize,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`PSM_SETFINISHTEXT`](https://docs.microsoft.com/en-us/windows/win32/controls/psm-setfinishtext)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct SetFinishText<'a> {
+	pub text: &'a WString,
+}
+
+impl<'a> MsgSend for SetFinishText<'a> {
+	type RetType = ();
+
+	fn convert_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&self) -> WndMsg {
+		WndMsg {
+			msg_id: co::PSM::SETFINISHTEXT.into(),
+			wparam: 0,
+			lparam: unsafe { self.text.as_ptr() } as isize,
+		}
+	}
+}
+
+/// [`PSM_SETTITLE`](https://docs.microsoft.com/en-us/windows/win32/controls/psm-settitle)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct SetTitle<'a> {
+	pub properties_for: bool,
+	pub text: &'a WString,
+}
+
+impl<'a> MsgSend for SetTitle<'a> {
+	type RetType = ();
+
+	fn convert_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&self) -> WndMsg {
+		WndMsg {
+			msg_id: co::PSM::SETTITLE.into(),
+			wparam: self.properties_for as usize,
+			lparam: unsafe { self.text.as_ptr() } as isize,
+		}
+	}
+}
+
+/// [`PSM_SETWIZBUTTONS`](https://docs.microsoft.com/en-us/windows/win32/controls/psm-setwizbuttons)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct SetWizButtons {
+	pub buttons: co::PSWIZB,
+}
+
+impl MsgSend for SetWizButtons {
+	type RetType = ();
+
+	fn convert_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&self) -> WndMsg {
+		WndMsg {
+			msg_id: co::PSM::SETWIZBUTTONS.into(),
+			wparam: 0,
+			lparam: self.buttons.0 as isize,
+		}
+	}
+}
+
+/// [`PSM_UNCHANGED`](https://docs.microsoft.com/en-us/windows/win32/controls/psm-unchanged)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct UnChanged {
+	pub hpage: HWND,
+}
+
+impl MsgSend for UnChanged {
+	type RetType = ();
+
+	fn convert_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&self) -> WndMsg {
+		WndMsg {
+			msg_id: co::PSM::UNCHANGED.into(),
+			wparam: self.hpage.ptr as usize,
+			lparam: 0,
+		}
+	}
+}
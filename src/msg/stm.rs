@@ -4,7 +4,8 @@
 
 use crate::aliases::WinResult;
 use crate::co;
-use crate::handles::HICON;
+use crate::enums::BitmapIcon;
+use crate::handles::{HBITMAP, HICON};
 use crate::msg::{MsgSend, WndMsg};
 
 /// [`STM_GETICON`](https://docs.microsoft.com/en-us/windows/win32/controls/stm-geticon)
@@ -32,6 +33,34 @@ impl MsgSend for GetIcon {
 	}
 }
 
+/// [`STM_GETIMAGE`](https://docs.microsoft.com/en-us/windows/win32/controls/stm-getimage)
+/// message parameters.
+///
+/// Return type: `WinResult<BitmapIcon>`.
+pub struct GetImage {
+	pub img_type: co::IMAGE_TYPE,
+}
+
+impl MsgSend for GetImage {
+	type RetType = WinResult<BitmapIcon>;
+
+	fn convert_ret(&self, v: isize) -> Self::RetType {
+		match self.img_type {
+			co::IMAGE_TYPE::BITMAP => Ok(BitmapIcon::Bitmap(HBITMAP { ptr: v as *mut _ })),
+			co::IMAGE_TYPE::ICON => Ok(BitmapIcon::Icon(HICON { ptr: v as *mut _ })),
+			_ => Err(co::ERROR::BAD_ARGUMENTS),
+		}
+	}
+
+	fn as_generic_wm(&self) -> WndMsg {
+		WndMsg {
+			msg_id: co::STM::GETIMAGE.into(),
+			wparam: self.img_type.0 as usize,
+			lparam: 0,
+		}
+	}
+}
+
 /// [`STM_SETICON`](https://docs.microsoft.com/en-us/windows/win32/controls/stm-seticon)
 /// message parameters.
 ///
@@ -58,3 +87,33 @@ impl MsgSend for SetIcon {
 		}
 	}
 }
+
+/// [`STM_SETIMAGE`](https://docs.microsoft.com/en-us/windows/win32/controls/stm-setimage)
+/// message parameters.
+///
+/// Return type: `WinResult<BitmapIcon>`.
+pub struct SetImage {
+	pub image: BitmapIcon,
+}
+
+impl MsgSend for SetImage {
+	type RetType = WinResult<BitmapIcon>;
+
+	fn convert_ret(&self, v: isize) -> Self::RetType {
+		match self.image {
+			BitmapIcon::Bitmap(_) => Ok(BitmapIcon::Bitmap(HBITMAP { ptr: v as *mut _ })),
+			BitmapIcon::Icon(_) => Ok(BitmapIcon::Icon(HICON { ptr: v as *mut _ })),
+		}
+	}
+
+	fn as_generic_wm(&self) -> WndMsg {
+		WndMsg {
+			msg_id: co::STM::SETIMAGE.into(),
+			wparam: match self.image {
+				BitmapIcon::Bitmap(_) => co::IMAGE_TYPE::BITMAP.0,
+				BitmapIcon::Icon(_) => co::IMAGE_TYPE::ICON.0,
+			} as usize,
+			lparam: self.image.as_isize(),
+		}
+	}
+}
@@ -9,7 +9,7 @@ use crate::funcs::{HIBYTE, HIWORD, LOBYTE, LOWORD, MAKEDWORD, MAKEWORD};
 use crate::handles::{HBRUSH, HDC, HDROP, HFONT, HICON, HMENU, HRGN, HWND};
 use crate::msg::{MsgSend, MsgSendRecv, WndMsg};
 use crate::msg::macros::{lp_to_point, point_to_lp};
-use crate::privs::FAPPCOMMAND_MASK;
+use crate::privs::{FAPPCOMMAND_MASK, ptr_as_opt};
 use crate::structs::{
 	CREATESTRUCT,
 	HELPINFO,
@@ -137,6 +137,10 @@ empty_msg_handleable! { ChildActivate, co::WM::CHILDACTIVATE,
 	/// [`WM_CHILDACTIVATE`](https://docs.microsoft.com/en-us/windows/win32/winmsg/wm-childactivate)
 }
 
+empty_msg_handleable! { ClipboardUpdate, co::WM::CLIPBOARDUPDATE,
+	/// [`WM_CLIPBOARDUPDATE`](https://docs.microsoft.com/en-us/windows/win32/dataxchg/wm-clipboardupdate)
+}
+
 empty_msg_handleable! { Close, co::WM::CLOSE,
 	/// [`WM_CLOSE`](https://docs.microsoft.com/en-us/windows/win32/winmsg/wm-close)
 }
@@ -288,6 +292,42 @@ empty_msg_handleable! { Destroy, co::WM::DESTROY,
 	/// [`WM_DESTROY`](https://docs.microsoft.com/en-us/windows/win32/winmsg/wm-destroy)
 }
 
+/// [`WM_DPICHANGED`](https://docs.microsoft.com/en-us/windows/win32/hidpi/wm-dpichanged)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct DpiChanged<'a> {
+	pub dpi_x: u16,
+	pub dpi_y: u16,
+	pub suggested_rect: &'a RECT,
+}
+
+impl<'a> MsgSend for DpiChanged<'a> {
+	type RetType = ();
+
+	fn convert_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&self) -> WndMsg {
+		WndMsg {
+			msg_id: co::WM::DPICHANGED,
+			wparam: MAKEDWORD(self.dpi_x, self.dpi_y) as usize,
+			lparam: self.suggested_rect as *const _ as isize,
+		}
+	}
+}
+
+impl<'a> MsgSendRecv for DpiChanged<'a> {
+	fn from_generic_wm(p: WndMsg) -> Self {
+		Self {
+			dpi_x: LOWORD(p.wparam as u32),
+			dpi_y: HIWORD(p.wparam as u32),
+			suggested_rect: unsafe { &*(p.lparam as *const _) },
+		}
+	}
+}
+
 /// [`WM_DROPFILES`](https://docs.microsoft.com/en-us/windows/win32/shell/wm-dropfiles)
 /// message parameters.
 ///
@@ -528,6 +568,42 @@ impl<'a> MsgSendRecv for Help<'a> {
 	}
 }
 
+/// [`WM_HOTKEY`](https://docs.microsoft.com/en-us/windows/win32/inputdev/wm-hotkey)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct HotKey {
+	pub hotkey_id: i32,
+	pub modifiers: co::MOD,
+	pub vkey_code: co::VK,
+}
+
+impl MsgSend for HotKey {
+	type RetType = ();
+
+	fn convert_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&self) -> WndMsg {
+		WndMsg {
+			msg_id: co::WM::HOTKEY,
+			wparam: self.hotkey_id as usize,
+			lparam: MAKEDWORD(self.modifiers.into(), self.vkey_code.into()) as isize,
+		}
+	}
+}
+
+impl MsgSendRecv for HotKey {
+	fn from_generic_wm(p: WndMsg) -> Self {
+		Self {
+			hotkey_id: p.wparam as i32,
+			modifiers: co::MOD(LOWORD(p.lparam as u32)),
+			vkey_code: co::VK(HIWORD(p.lparam as u32)),
+		}
+	}
+}
+
 /// [`WM_INITDIALOG`](https://docs.microsoft.com/en-us/windows/win32/dlgbox/wm-initdialog)
 /// message parameters.
 ///
@@ -668,6 +744,128 @@ button_msg! { MButtonUp, co::WM::MBUTTONUP,
 	/// [`WM_MBUTTONUP`](https://docs.microsoft.com/en-us/windows/win32/inputdev/wm-mbuttonup)
 }
 
+/// [`WM_MDIACTIVATE`](https://docs.microsoft.com/en-us/windows/win32/winmsg/wm-mdiactivate)
+/// message parameters, when sent to the MDI client window.
+///
+/// Return type: `()`.
+pub struct MdiActivate {
+	pub hwnd_child: HWND,
+}
+
+impl MsgSend for MdiActivate {
+	type RetType = ();
+
+	fn convert_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&self) -> WndMsg {
+		WndMsg {
+			msg_id: co::WM::MDIACTIVATE,
+			wparam: self.hwnd_child.ptr as usize,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`WM_MDICASCADE`](https://docs.microsoft.com/en-us/windows/win32/winmsg/wm-mdicascade)
+/// message parameters.
+///
+/// Return type: `bool`.
+pub struct MdiCascade {
+	pub flags: co::MDITILE,
+}
+
+impl MsgSend for MdiCascade {
+	type RetType = bool;
+
+	fn convert_ret(&self, v: isize) -> Self::RetType {
+		v != 0
+	}
+
+	fn as_generic_wm(&self) -> WndMsg {
+		WndMsg {
+			msg_id: co::WM::MDICASCADE,
+			wparam: self.flags.0 as usize,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`WM_MDIDESTROY`](https://docs.microsoft.com/en-us/windows/win32/winmsg/wm-mdidestroy)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct MdiDestroy {
+	pub hwnd_child: HWND,
+}
+
+impl MsgSend for MdiDestroy {
+	type RetType = ();
+
+	fn convert_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&self) -> WndMsg {
+		WndMsg {
+			msg_id: co::WM::MDIDESTROY,
+			wparam: self.hwnd_child.ptr as usize,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`WM_MDIGETACTIVE`](https://docs.microsoft.com/en-us/windows/win32/winmsg/wm-mdigetactive)
+/// message, which has no parameters.
+///
+/// Return type: `Option<HWND>`.
+pub struct MdiGetActive {}
+
+impl MsgSend for MdiGetActive {
+	type RetType = Option<HWND>;
+
+	fn convert_ret(&self, v: isize) -> Self::RetType {
+		ptr_as_opt(v as *mut _).map(|ptr| HWND { ptr })
+	}
+
+	fn as_generic_wm(&self) -> WndMsg {
+		WndMsg {
+			msg_id: co::WM::MDIGETACTIVE,
+			wparam: 0,
+			lparam: 0,
+		}
+	}
+}
+
+empty_msg! { MdiIconArrange, co::WM::MDIICONARRANGE,
+	/// [`WM_MDIICONARRANGE`](https://docs.microsoft.com/en-us/windows/win32/winmsg/wm-mdiiconarrange)
+}
+
+/// [`WM_MDITILE`](https://docs.microsoft.com/en-us/windows/win32/winmsg/wm-mditile)
+/// message parameters.
+///
+/// Return type: `bool`.
+pub struct MdiTile {
+	pub flags: co::MDITILE,
+}
+
+impl MsgSend for MdiTile {
+	type RetType = bool;
+
+	fn convert_ret(&self, v: isize) -> Self::RetType {
+		v != 0
+	}
+
+	fn as_generic_wm(&self) -> WndMsg {
+		WndMsg {
+			msg_id: co::WM::MDITILE,
+			wparam: self.flags.0 as usize,
+			lparam: 0,
+		}
+	}
+}
+
 button_msg! { MouseHover, co::WM::MOUSEHOVER,
 	/// [`WM_MOUSEHOVER`](https://docs.microsoft.com/en-us/windows/win32/inputdev/wm-mousehover)
 }
@@ -3,12 +3,14 @@
 use std::ffi::c_void;
 
 use crate::aliases::WinResult;
-use crate::ffi::BOOL;
+use crate::ffi::{BOOL, PFUNC};
 use crate::funcs::GetLastError;
+use crate::handles::HINSTANCE;
 use crate::WString;
 
 pub const CB_ERR: i32 = -1;
 pub const CB_ERRSPACE: i32 = -2;
+pub const CW_USEDEFAULT: i32 = 0x80000000u32 as i32;
 pub const FAPPCOMMAND_MASK: u16 = 0xf000;
 pub const GDI_ERROR: u32 = 0xffffffff;
 pub const GDT_ERROR: i32 = -1;
@@ -39,6 +41,13 @@ pub fn bool_to_winresult(expr: BOOL) -> WinResult<()> {
 	}
 }
 
+/// Resolves a function exported by user32.dll, which is always loaded. Used for
+/// functions which are not present in all supported Windows versions, and thus
+/// cannot be statically linked.
+pub fn user32_proc(proc_name: &str) -> WinResult<PFUNC> {
+	HINSTANCE::GetModuleHandle(Some("user32.dll"))?.GetProcAddress(proc_name)
+}
+
 /// Parses a null-delimited multi-string, which must terminate with two nulls.
 pub fn parse_multi_z_str(src: *const u16) -> Vec<String> {
 	let mut src = src;
@@ -9,8 +9,9 @@ use crate::aliases::WNDPROC;
 use crate::co;
 use crate::enums::{HwndHmenu, HwndPlace, IdStr};
 use crate::funcs::{IsWindowsVistaOrGreater, HIDWORD, HIWORD, LOBYTE, LODWORD, LOWORD};
-use crate::handles::{HBITMAP, HBRUSH, HCURSOR, HDC, HICON, HINSTANCE, HMENU, HWND};
+use crate::handles::{HBITMAP, HBRUSH, HCURSOR, HDC, HGLOBAL, HICON, HINSTANCE, HMENU, HWND};
 use crate::privs::LF_FACESIZE;
+use crate::structs::GUID;
 use crate::WString;
 
 /// [`ACCEL`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-accel)
@@ -67,6 +68,75 @@ impl Default for BITMAPINFOHEADER {
 	}
 }
 
+/// [`CHOOSECOLOR`](https://docs.microsoft.com/en-us/windows/win32/api/commdlg/ns-commdlg-choosecolorw-r1)
+/// struct.
+///
+/// The `lpCustColors` field is a raw pointer to an array of 16 colors, which
+/// must be kept alive by the caller.
+#[repr(C)]
+pub struct CHOOSECOLOR {
+	lStructSize: u32,
+	pub hwndOwner: HWND,
+	pub hInstance: HWND,
+	pub rgbResult: COLORREF,
+	pub lpCustColors: *mut COLORREF,
+	pub Flags: co::CC,
+	pub lCustData: isize,
+	lpfnHook: *mut c_void,
+	lpTemplateName: *const u16,
+}
+
+impl Default for CHOOSECOLOR {
+	fn default() -> Self {
+		let mut obj = unsafe { std::mem::zeroed::<Self>() };
+		obj.lStructSize = std::mem::size_of::<Self>() as u32;
+		obj
+	}
+}
+
+/// [`CHOOSEFONT`](https://docs.microsoft.com/en-us/windows/win32/api/commdlg/ns-commdlg-choosefontw)
+/// struct.
+///
+/// The `lpLogFont` field is a raw pointer to a [`LOGFONT`](crate::LOGFONT),
+/// which must be kept alive by the caller.
+#[repr(C)]
+pub struct CHOOSEFONT {
+	lStructSize: u32,
+	pub hwndOwner: HWND,
+	pub hDC: HDC,
+	pub lpLogFont: *mut LOGFONT,
+	pub iPointSize: i32,
+	pub Flags: co::CHOOSEFONTF,
+	pub rgbColors: COLORREF,
+	pub lCustData: isize,
+	lpfnHook: *mut c_void,
+	lpTemplateName: *const u16,
+	pub hInstance: HINSTANCE,
+	lpszStyle: *mut u16,
+	pub nFontType: u16,
+	___MISSING_ALIGNMENT__: u16,
+	pub nSizeMin: i32,
+	pub nSizeMax: i32,
+}
+
+impl Default for CHOOSEFONT {
+	fn default() -> Self {
+		let mut obj = unsafe { std::mem::zeroed::<Self>() };
+		obj.lStructSize = std::mem::size_of::<Self>() as u32;
+		obj
+	}
+}
+
+/// [`CLIENTCREATESTRUCT`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-clientcreatestruct)
+/// struct.
+#[repr(C)]
+pub struct CLIENTCREATESTRUCT {
+	pub hWindowMenu: HMENU,
+	pub idFirstChild: u32,
+}
+
+impl_default_zero!(CLIENTCREATESTRUCT);
+
 /// [`COLORREF`](https://docs.microsoft.com/en-us/windows/win32/gdi/colorref)
 /// struct.
 #[repr(C)]
@@ -155,6 +225,21 @@ impl<'a, 'b> CREATESTRUCT<'a, 'b> {
 	}
 }
 
+/// [`DROPFILES`](https://docs.microsoft.com/en-us/windows/win32/api/shlobj_core/ns-shlobj_core-dropfiles)
+/// struct.
+///
+/// This is the header of the memory block of the
+/// [`CF::HDROP`](crate::co::CF::HDROP) clipboard format, which is followed by
+/// the double null-terminated list of file names.
+#[repr(C)]
+#[derive(Default, Clone, Eq, PartialEq)]
+pub struct DROPFILES {
+	pub pFiles: u32,
+	pub pt: POINT,
+	pub fNC: i32,
+	pub fWide: i32,
+}
+
 /// [`FILETIME`](https://docs.microsoft.com/en-us/windows/win32/api/minwinbase/ns-minwinbase-filetime)
 /// struct.
 #[repr(C)]
@@ -164,6 +249,39 @@ pub struct FILETIME {
 	pub dwHighDateTime: u32,
 }
 
+/// [`FORMATETC`](https://docs.microsoft.com/en-us/windows/win32/api/objidl/ns-objidl-formatetc)
+/// struct.
+///
+/// The `ptd` target device is always null.
+#[repr(C)]
+#[derive(Clone, Eq, PartialEq)]
+pub struct FORMATETC {
+	pub cfFormat: co::CF,
+	ptd: *mut c_void,
+	pub dwAspect: co::DVASPECT,
+	pub lindex: i32,
+	pub tymed: co::TYMED,
+}
+
+impl Default for FORMATETC {
+	fn default() -> Self {
+		Self::new(co::CF(0), co::TYMED::HGLOBAL)
+	}
+}
+
+impl FORMATETC {
+	/// Creates a new `FORMATETC` for the whole content of the given format.
+	pub fn new(cfFormat: co::CF, tymed: co::TYMED) -> FORMATETC {
+		Self {
+			cfFormat,
+			ptd: std::ptr::null_mut(),
+			dwAspect: co::DVASPECT::CONTENT,
+			lindex: -1,
+			tymed,
+		}
+	}
+}
+
 /// [`HELPINFO`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-helpinfo)
 /// struct.
 ///
@@ -220,6 +338,47 @@ impl LOGFONT {
 	}
 }
 
+/// [`MDICREATESTRUCT`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-mdicreatestructw)
+/// struct.
+#[repr(C)]
+pub struct MDICREATESTRUCT<'a, 'b> {
+	szClass: *const u16,
+	szTitle: *const u16,
+	pub hOwner: HINSTANCE,
+	pub x: i32,
+	pub y: i32,
+	pub cx: i32,
+	pub cy: i32,
+	pub style: co::WS,
+	pub lParam: isize,
+	m_szClass: PhantomData<&'a u16>,
+	m_szTitle: PhantomData<&'b u16>,
+}
+
+impl_default_zero!(MDICREATESTRUCT, 'a, 'b);
+
+impl<'a, 'b> MDICREATESTRUCT<'a, 'b> {
+	/// Returns the `szClass` field.
+	pub fn szClass(&self) -> String {
+		WString::from_wchars_nullt(self.szClass).to_string()
+	}
+
+	/// Sets the `szClass` field.
+	pub fn set_szClass(&mut self, buf: &'a WString) {
+		self.szClass = unsafe { buf.as_ptr() };
+	}
+
+	/// Returns the `szTitle` field.
+	pub fn szTitle(&self) -> String {
+		WString::from_wchars_nullt(self.szTitle).to_string()
+	}
+
+	/// Sets the `szTitle` field.
+	pub fn set_szTitle(&mut self, buf: &'b WString) {
+		self.szTitle = unsafe { buf.as_ptr() };
+	}
+}
+
 /// [`MENUINFO`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-menuinfo)
 /// struct.
 #[repr(C)]
@@ -279,6 +438,24 @@ pub struct MINMAXINFO {
 	pub ptMaxTrackSize: POINT,
 }
 
+/// [`MONITORINFO`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-monitorinfo)
+/// struct.
+#[repr(C)]
+pub struct MONITORINFO {
+	cbSize: u32,
+	pub rcMonitor: RECT,
+	pub rcWork: RECT,
+	pub dwFlags: co::MONITORINFOF,
+}
+
+impl Default for MONITORINFO {
+	fn default() -> Self {
+		let mut obj = unsafe { std::mem::zeroed::<Self>() };
+		obj.cbSize = std::mem::size_of::<Self>() as u32;
+		obj
+	}
+}
+
 /// [`MSG`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-msg)
 /// struct.
 #[repr(C)]
@@ -375,6 +552,107 @@ impl Default for NONCLIENTMETRICS {
 	}
 }
 
+/// [`NOTIFYICONDATA`](https://docs.microsoft.com/en-us/windows/win32/api/shellapi/ns-shellapi-notifyicondataw)
+/// struct.
+#[repr(C)]
+pub struct NOTIFYICONDATA {
+	cbSize: u32,
+	pub hWnd: HWND,
+	pub uID: u32,
+	pub uFlags: co::NIF,
+	pub uCallbackMessage: co::WM,
+	pub hIcon: HICON,
+	szTip: [u16; 128],
+	pub dwState: co::NIS,
+	pub dwStateMask: co::NIS,
+	szInfo: [u16; 256],
+	pub uTimeoutOrVersion: u32,
+	szInfoTitle: [u16; 64],
+	pub dwInfoFlags: co::NIIF,
+	pub guidItem: GUID,
+	pub hBalloonIcon: HICON,
+}
+
+impl Default for NOTIFYICONDATA {
+	fn default() -> Self {
+		let mut obj = unsafe { std::mem::zeroed::<Self>() };
+		obj.cbSize = std::mem::size_of::<Self>() as u32;
+		obj
+	}
+}
+
+impl NOTIFYICONDATA {
+	/// Returns the `szTip` field.
+	pub fn szTip(&self) -> String {
+		WString::from_wchars_slice(&self.szTip).to_string()
+	}
+
+	/// Sets the `szTip` field. The text is truncated to 127 characters.
+	pub fn set_szTip(&mut self, text: &str) {
+		WString::from_str(text).copy_to_slice(&mut self.szTip);
+	}
+
+	/// Returns the `szInfo` field.
+	pub fn szInfo(&self) -> String {
+		WString::from_wchars_slice(&self.szInfo).to_string()
+	}
+
+	/// Sets the `szInfo` field. The text is truncated to 255 characters.
+	pub fn set_szInfo(&mut self, text: &str) {
+		WString::from_str(text).copy_to_slice(&mut self.szInfo);
+	}
+
+	/// Returns the `szInfoTitle` field.
+	pub fn szInfoTitle(&self) -> String {
+		WString::from_wchars_slice(&self.szInfoTitle).to_string()
+	}
+
+	/// Sets the `szInfoTitle` field. The text is truncated to 63 characters.
+	pub fn set_szInfoTitle(&mut self, text: &str) {
+		WString::from_str(text).copy_to_slice(&mut self.szInfoTitle);
+	}
+}
+
+/// [`OPENFILENAME`](https://docs.microsoft.com/en-us/windows/win32/api/commdlg/ns-commdlg-openfilenamew)
+/// struct.
+///
+/// The string fields are raw pointers, whose buffers must be kept alive by the
+/// caller.
+#[repr(C)]
+pub struct OPENFILENAME {
+	lStructSize: u32,
+	pub hwndOwner: HWND,
+	pub hInstance: HINSTANCE,
+	pub lpstrFilter: *const u16,
+	pub lpstrCustomFilter: *mut u16,
+	pub nMaxCustFilter: u32,
+	pub nFilterIndex: u32,
+	pub lpstrFile: *mut u16,
+	pub nMaxFile: u32,
+	pub lpstrFileTitle: *mut u16,
+	pub nMaxFileTitle: u32,
+	pub lpstrInitialDir: *const u16,
+	pub lpstrTitle: *const u16,
+	pub Flags: co::OFN,
+	pub nFileOffset: u16,
+	pub nFileExtension: u16,
+	pub lpstrDefExt: *const u16,
+	pub lCustData: isize,
+	lpfnHook: *mut c_void,
+	lpTemplateName: *const u16,
+	pvReserved: *mut c_void,
+	dwReserved: u32,
+	pub FlagsEx: u32,
+}
+
+impl Default for OPENFILENAME {
+	fn default() -> Self {
+		let mut obj = unsafe { std::mem::zeroed::<Self>() };
+		obj.lStructSize = std::mem::size_of::<Self>() as u32;
+		obj
+	}
+}
+
 /// [`OSVERSIONINFOEX`](https://docs.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-osversioninfoexw)
 /// struct.
 #[repr(C)]
@@ -534,6 +812,40 @@ impl SIZE {
 	}
 }
 
+/// [`STGMEDIUM`](https://docs.microsoft.com/en-us/windows/win32/api/objidl/ns-objidl-ustgmedium-r1)
+/// struct.
+///
+/// **Note:** When returned by
+/// [`IDataObject::GetData`](crate::IDataObject::GetData), must be released
+/// with [`ReleaseStgMedium`](crate::ReleaseStgMedium).
+#[repr(C)]
+pub struct STGMEDIUM {
+	pub tymed: co::TYMED,
+	data: *mut c_void, // union of handles and pointers
+	pUnkForRelease: *mut c_void,
+}
+
+impl_default_zero!(STGMEDIUM);
+
+impl STGMEDIUM {
+	/// Returns the `hGlobal` field, if `tymed` is
+	/// [`TYMED::HGLOBAL`](crate::co::TYMED::HGLOBAL).
+	pub fn hGlobal(&self) -> Option<HGLOBAL> {
+		if self.tymed == co::TYMED::HGLOBAL {
+			Some(HGLOBAL { ptr: self.data })
+		} else {
+			None
+		}
+	}
+
+	/// Sets the `hGlobal` field, also setting `tymed` to
+	/// [`TYMED::HGLOBAL`](crate::co::TYMED::HGLOBAL).
+	pub fn set_hGlobal(&mut self, hglobal: HGLOBAL) {
+		self.tymed = co::TYMED::HGLOBAL;
+		self.data = hglobal.ptr;
+	}
+}
+
 /// [`STYLESTRUCT`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-stylestruct)
 /// struct for [`WS`](crate::co::WS).
 #[repr(C)]
@@ -2,10 +2,13 @@
 
 #![allow(non_snake_case)]
 
+use std::ffi::c_void;
 use std::marker::PhantomData;
 
+use crate::aliases::{DLGPROC, PFTASKDIALOGCALLBACK};
 use crate::co;
-use crate::handles::{HDC, HIMAGELIST, HTREEITEM, HWND};
+use crate::enums::IdStr;
+use crate::handles::{HBITMAP, HDC, HICON, HIMAGELIST, HINSTANCE, HTREEITEM, HWND};
 use crate::privs::{L_MAX_URL_LENGTH, MAX_LINKID_TEXT};
 use crate::structs::{COLORREF, NMHDR, POINT, RECT, SIZE, SYSTEMTIME};
 use crate::WString;
@@ -586,3 +589,279 @@ pub struct NMVIEWCHANGE {
 	pub dwOldView: co::MCMV,
 	pub dwNewView: co::MCMV,
 }
+
+/// [`PROPSHEETHEADER`](https://docs.microsoft.com/en-us/windows/win32/api/prsht/ns-prsht-propsheetheaderw_v2)
+/// struct.
+#[repr(C)]
+pub struct PROPSHEETHEADER<'a, 'b> {
+	dwSize: u32,
+	pub dwFlags: co::PSH,
+	pub hwndParent: HWND,
+	pub hInstance: HINSTANCE,
+	pub hIcon: HICON,
+	pszCaption: *const u16,
+	nPages: u32,
+	pub nStartPage: u32,
+	ppsp: *const c_void,
+	pfnCallback: *const c_void,
+	pub hbmWatermark: HBITMAP,
+	hplWatermark: *mut c_void,
+	pub hbmHeader: HBITMAP,
+	m_pszCaption: PhantomData<&'a u16>,
+	m_ppsp: PhantomData<&'b PROPSHEETPAGE<'b, 'b>>,
+}
+
+impl<'a, 'b> Default for PROPSHEETHEADER<'a, 'b> {
+	fn default() -> Self {
+		let mut obj = unsafe { std::mem::zeroed::<Self>() };
+		obj.dwSize = std::mem::size_of::<Self>() as u32;
+		obj
+	}
+}
+
+impl<'a, 'b> PROPSHEETHEADER<'a, 'b> {
+	/// Returns the `pszCaption` field.
+	pub fn pszCaption(&self) -> String {
+		WString::from_wchars_nullt(self.pszCaption).to_string()
+	}
+
+	/// Sets the `pszCaption` field.
+	pub fn set_pszCaption(&mut self, buf: &'a WString) {
+		self.pszCaption = unsafe { buf.as_ptr() };
+	}
+
+	/// Sets the `nPages` and `ppsp` fields. The
+	/// [`PSH_PROPSHEETPAGE`](crate::co::PSH::PROPSHEETPAGE) flag must be set.
+	pub fn set_ppsp(&mut self, pages: &'b [PROPSHEETPAGE]) {
+		self.nPages = pages.len() as u32;
+		self.ppsp = pages.as_ptr() as *const _;
+	}
+}
+
+/// [`PROPSHEETPAGE`](https://docs.microsoft.com/en-us/windows/win32/api/prsht/ns-prsht-propsheetpagew_v4)
+/// struct.
+#[repr(C)]
+pub struct PROPSHEETPAGE<'a, 'b> {
+	dwSize: u32,
+	pub dwFlags: co::PSP,
+	pub hInstance: HINSTANCE,
+	pszTemplate: *const u16,
+	pub hIcon: HICON,
+	pszTitle: *const u16,
+	pub pfnDlgProc: Option<DLGPROC>,
+	pub lParam: isize,
+	pfnCallback: *const c_void,
+	pcRefParent: *mut u32,
+	pszHeaderTitle: *const u16,
+	pszHeaderSubTitle: *const u16,
+	hActCtx: *mut c_void,
+	pub hbmHeader: HBITMAP,
+	m_pszTemplate: PhantomData<&'a u16>,
+	m_pszTitle: PhantomData<&'b u16>,
+}
+
+impl<'a, 'b> Default for PROPSHEETPAGE<'a, 'b> {
+	fn default() -> Self {
+		let mut obj = unsafe { std::mem::zeroed::<Self>() };
+		obj.dwSize = std::mem::size_of::<Self>() as u32;
+		obj
+	}
+}
+
+impl<'a, 'b> PROPSHEETPAGE<'a, 'b> {
+	/// Sets the `pszTemplate` field, with a dialog resource.
+	pub fn set_pszTemplate(&mut self, template: &'a IdStr) {
+		self.pszTemplate = template.as_ptr();
+	}
+
+	/// Sets the `pResource` field, with an in-memory
+	/// [`DLGTEMPLATE`](https://docs.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-dlgtemplate).
+	/// The [`PSP_DLGINDIRECT`](crate::co::PSP::DLGINDIRECT) flag must be set.
+	pub fn set_pResource(&mut self, template: &'a [u16]) {
+		self.pszTemplate = template.as_ptr();
+	}
+
+	/// Returns the `pszTitle` field.
+	pub fn pszTitle(&self) -> String {
+		WString::from_wchars_nullt(self.pszTitle).to_string()
+	}
+
+	/// Sets the `pszTitle` field. The
+	/// [`PSP_USETITLE`](crate::co::PSP::USETITLE) flag must be set.
+	pub fn set_pszTitle(&mut self, buf: &'b WString) {
+		self.pszTitle = unsafe { buf.as_ptr() };
+	}
+
+	/// Returns the `pszHeaderTitle` field.
+	pub fn pszHeaderTitle(&self) -> String {
+		WString::from_wchars_nullt(self.pszHeaderTitle).to_string()
+	}
+
+	/// Sets the `pszHeaderTitle` field. The
+	/// [`PSP_USEHEADERTITLE`](crate::co::PSP::USEHEADERTITLE) flag must be set.
+	pub fn set_pszHeaderTitle(&mut self, buf: &'b WString) {
+		self.pszHeaderTitle = unsafe { buf.as_ptr() };
+	}
+
+	/// Returns the `pszHeaderSubTitle` field.
+	pub fn pszHeaderSubTitle(&self) -> String {
+		WString::from_wchars_nullt(self.pszHeaderSubTitle).to_string()
+	}
+
+	/// Sets the `pszHeaderSubTitle` field. The
+	/// [`PSP_USEHEADERSUBTITLE`](crate::co::PSP::USEHEADERSUBTITLE) flag must
+	/// be set.
+	pub fn set_pszHeaderSubTitle(&mut self, buf: &'b WString) {
+		self.pszHeaderSubTitle = unsafe { buf.as_ptr() };
+	}
+}
+
+/// [`PSHNOTIFY`](https://docs.microsoft.com/en-us/windows/win32/api/prsht/ns-prsht-pshnotify)
+/// struct.
+#[repr(C)]
+pub struct PSHNOTIFY {
+	pub hdr: NMHDR,
+	pub lParam: isize,
+}
+
+/// [`TASKDIALOG_BUTTON`](https://docs.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-taskdialog_button)
+/// struct.
+#[repr(C, packed)]
+pub struct TASKDIALOG_BUTTON<'a> {
+	pub nButtonID: i32,
+	pszButtonText: *const u16,
+	m_pszButtonText: PhantomData<&'a u16>,
+}
+
+impl<'a> Default for TASKDIALOG_BUTTON<'a> {
+	fn default() -> Self {
+		unsafe { std::mem::zeroed::<Self>() }
+	}
+}
+
+impl<'a> TASKDIALOG_BUTTON<'a> {
+	/// Sets the `pszButtonText` field.
+	pub fn set_pszButtonText(&mut self, buf: &'a WString) {
+		self.pszButtonText = unsafe { buf.as_ptr() };
+	}
+}
+
+/// [`TASKDIALOGCONFIG`](https://docs.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-taskdialogconfig)
+/// struct.
+///
+/// All the strings are borrowed with lifetime `'a`, and the button arrays with
+/// lifetime `'b`.
+#[repr(C, packed)]
+pub struct TASKDIALOGCONFIG<'a, 'b> {
+	cbSize: u32,
+	pub hwndParent: HWND,
+	pub hInstance: HINSTANCE,
+	pub dwFlags: co::TDF,
+	pub dwCommonButtons: co::TDCBF,
+	pszWindowTitle: *const u16,
+	hMainIcon: *const c_void,
+	pszMainInstruction: *const u16,
+	pszContent: *const u16,
+	cButtons: u32,
+	pButtons: *const c_void,
+	pub nDefaultButton: i32,
+	cRadioButtons: u32,
+	pRadioButtons: *const c_void,
+	pub nDefaultRadioButton: i32,
+	pszVerificationText: *const u16,
+	pszExpandedInformation: *const u16,
+	pszExpandedControlText: *const u16,
+	pszCollapsedControlText: *const u16,
+	hFooterIcon: *const c_void,
+	pszFooter: *const u16,
+	pub pfCallback: Option<PFTASKDIALOGCALLBACK>,
+	pub lpCallbackData: isize,
+	pub cxWidth: u32,
+	m_psz: PhantomData<&'a u16>,
+	m_pButtons: PhantomData<&'b TASKDIALOG_BUTTON<'b>>,
+}
+
+impl<'a, 'b> Default for TASKDIALOGCONFIG<'a, 'b> {
+	fn default() -> Self {
+		let mut obj = unsafe { std::mem::zeroed::<Self>() };
+		obj.cbSize = std::mem::size_of::<Self>() as u32;
+		obj
+	}
+}
+
+impl<'a, 'b> TASKDIALOGCONFIG<'a, 'b> {
+	/// Sets the `pszWindowTitle` field.
+	pub fn set_pszWindowTitle(&mut self, buf: &'a WString) {
+		self.pszWindowTitle = unsafe { buf.as_ptr() };
+	}
+
+	/// Sets the `hMainIcon` field. The
+	/// [`TDF_USE_HICON_MAIN`](crate::co::TDF::USE_HICON_MAIN) flag must be set.
+	pub fn set_hMainIcon(&mut self, hicon: HICON) {
+		self.hMainIcon = hicon.ptr as *const _;
+	}
+
+	/// Sets the `pszMainIcon` field to one of the predefined icons.
+	pub fn set_pszMainIcon(&mut self, icon: co::TD_ICON) {
+		self.hMainIcon = icon.0 as usize as *const _;
+	}
+
+	/// Sets the `pszMainInstruction` field.
+	pub fn set_pszMainInstruction(&mut self, buf: &'a WString) {
+		self.pszMainInstruction = unsafe { buf.as_ptr() };
+	}
+
+	/// Sets the `pszContent` field.
+	pub fn set_pszContent(&mut self, buf: &'a WString) {
+		self.pszContent = unsafe { buf.as_ptr() };
+	}
+
+	/// Sets the `cButtons` and `pButtons` fields.
+	pub fn set_pButtons(&mut self, buttons: &'b [TASKDIALOG_BUTTON]) {
+		self.cButtons = buttons.len() as u32;
+		self.pButtons = buttons.as_ptr() as *const _;
+	}
+
+	/// Sets the `cRadioButtons` and `pRadioButtons` fields.
+	pub fn set_pRadioButtons(&mut self, buttons: &'b [TASKDIALOG_BUTTON]) {
+		self.cRadioButtons = buttons.len() as u32;
+		self.pRadioButtons = buttons.as_ptr() as *const _;
+	}
+
+	/// Sets the `pszVerificationText` field.
+	pub fn set_pszVerificationText(&mut self, buf: &'a WString) {
+		self.pszVerificationText = unsafe { buf.as_ptr() };
+	}
+
+	/// Sets the `pszExpandedInformation` field.
+	pub fn set_pszExpandedInformation(&mut self, buf: &'a WString) {
+		self.pszExpandedInformation = unsafe { buf.as_ptr() };
+	}
+
+	/// Sets the `pszExpandedControlText` field.
+	pub fn set_pszExpandedControlText(&mut self, buf: &'a WString) {
+		self.pszExpandedControlText = unsafe { buf.as_ptr() };
+	}
+
+	/// Sets the `pszCollapsedControlText` field.
+	pub fn set_pszCollapsedControlText(&mut self, buf: &'a WString) {
+		self.pszCollapsedControlText = unsafe { buf.as_ptr() };
+	}
+
+	/// Sets the `hFooterIcon` field. The
+	/// [`TDF_USE_HICON_FOOTER`](crate::co::TDF::USE_HICON_FOOTER) flag must be
+	/// set.
+	pub fn set_hFooterIcon(&mut self, hicon: HICON) {
+		self.hFooterIcon = hicon.ptr as *const _;
+	}
+
+	/// Sets the `pszFooterIcon` field to one of the predefined icons.
+	pub fn set_pszFooterIcon(&mut self, icon: co::TD_ICON) {
+		self.hFooterIcon = icon.0 as usize as *const _;
+	}
+
+	/// Sets the `pszFooter` field.
+	pub fn set_pszFooter(&mut self, buf: &'a WString) {
+		self.pszFooter = unsafe { buf.as_ptr() };
+	}
+}
@@ -1,6 +1,7 @@
 /// [`GUID`](https://docs.microsoft.com/en-us/windows/win32/api/guiddef/ns-guiddef-guid)
 /// struct.
 #[repr(C)]
+#[derive(Eq, PartialEq)]
 pub struct GUID {
 	data1: u32,
 	data2: u16,
@@ -1,6 +1,3 @@
-use std::ffi::OsStr;
-use std::os::windows::ffi::OsStrExt;
-
 use crate::ffi::kernel32;
 
 /// Stores a `Vec<u16>` buffer for an
@@ -36,8 +33,7 @@ impl WString {
 	pub fn from_str(val: &str) -> WString {
 		Self {
 			char_vec: Some(
-				OsStr::new(val)
-					.encode_wide()
+				val.encode_utf16()
 					.chain(std::iter::once(0))
 					.collect::<Vec<u16>>(),
 			),